@@ -0,0 +1,591 @@
+//! Central registry of user actions and their key bindings
+//!
+//! Both key dispatch and the contextual hints shown in the INFO panel are
+//! derived from [`BINDINGS`], so the two cannot drift apart.
+use crate::app::{App, Mode, PanelId, ResultsView, Tab};
+use crate::events::{
+    is_alt_number, is_alt_shift_number, is_back_tab, is_copy_paste_key, is_key, is_panel_number,
+    is_shift_key,
+};
+use crate::i18n::{tr, Text};
+use crate::operations::{ListFilter, VerdictMode};
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+/// Cursor motions available in Normal mode
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Motion {
+    Left,
+    Down,
+    Up,
+    Right,
+    WordForward,
+    WordBack,
+    LineStart,
+    LineEnd,
+    Top,
+    Bottom,
+}
+
+/// Everything the user can trigger from the keyboard
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Action {
+    /// Exit the application
+    Quit,
+    /// Leave INSERT mode
+    ExitInsert,
+    /// Enter INSERT mode
+    EnterInsert,
+    /// Show or hide the help modal
+    ToggleHelp,
+    /// Jump to a tab by index
+    GoToTab(Tab),
+    /// Switch to another workspace by index, opening it if needed
+    SwitchWorkspace(usize),
+    /// Focus the next panel in the current tab
+    NextPanel,
+    /// Focus the previous panel in the current tab
+    PreviousPanel,
+    /// Focus a panel of the current tab by index
+    FocusPanel(usize),
+    /// Save the active panel to its default file
+    Save,
+    /// Load the active panel from its default file
+    Load,
+    /// Save every non-empty panel to a timestamped directory
+    SaveWorkspace,
+    /// Switch the line ending used when saving (auto, LF, CRLF)
+    CycleLineEnding,
+    /// Switch the text encoding used when saving
+    CycleEncoding,
+    /// Keep or drop empty items (as in `a,,b`) when parsing lists
+    ToggleEmptyItems,
+    /// Trim pasted items and drop trailing delimiters, or paste as is
+    ToggleTrimOnPaste,
+    /// Pick a stored reference list to load into the active editor
+    OpenReferences,
+    /// Store the active editor in the reference library under a name
+    SaveReference,
+    /// Load List 1 and List 2 from files and compare them
+    LoadAndCompare,
+    /// Toggle case-sensitive comparison
+    ToggleCase,
+    /// Toggle trimming before comparison
+    ToggleTrim,
+    /// Switch the characters trimmed along with the spaces
+    CycleTrimSet,
+    /// Toggle folding typographic characters to ASCII before comparison
+    ToggleFoldTypography,
+    /// Toggle keeping comparison results in input order
+    TogglePreserveOrder,
+    /// Cycle the preset that normalizes items of a known kind before comparison
+    CycleNormalizePreset,
+    /// Choose the column of delimited rows that is compared
+    SelectCompareColumn,
+    /// Type the tolerance within which numbers match
+    SetTolerance,
+    /// Cycle the list delimiter
+    CycleDelimiter,
+    /// Type a custom delimiter for the lists, or the Convert source or target
+    SetCustomDelimiter,
+    /// Sort the active list ascending
+    SortAsc,
+    /// Sort the active list descending
+    SortDesc,
+    /// Trim and deduplicate the active list
+    TrimDedup,
+    /// Replace the active list with `item<TAB>count` lines, most frequent first
+    CountFrequencies,
+    /// Keep only the items of the active list that occur more than once (with their counts if set)
+    KeepDuplicates(bool),
+    /// Lock or unlock the active editor against edits
+    ToggleReadOnly,
+    /// Show or hide the INFO panel
+    ToggleInfo,
+    /// Make the INFO panel taller
+    GrowInfo,
+    /// Make the INFO panel shorter
+    ShrinkInfo,
+    /// Show or hide item counts and memory usage in the status bar
+    ToggleUsage,
+    /// Open the diagnostics modal (environment info and key tester)
+    ShowDiagnostics,
+    /// Cycle the converter source delimiter
+    CycleSourceDelimiter,
+    /// Cycle the converter target delimiter
+    CycleTargetDelimiter,
+    /// Compare List 1 and List 2
+    Compare,
+    /// Check every List 1 item against List 2 as a reference list
+    Validate(VerdictMode),
+    /// Compare List 1 and List 2 by key, read with the selected preset
+    CompareKeyed,
+    /// Cycle how List 1 and List 2 are read when compared by key
+    CycleKeyedPreset,
+    /// Remove from or keep in List 1 the items found in List 2
+    FilterList1(ListFilter),
+    /// Keep or drop the items of the active list matching a regular expression
+    FilterRegex,
+    /// Replace the matches of a regular expression in the lines of the active editor
+    ReplaceRegex,
+    /// Remove blank items and configured stopwords from the active list
+    RemoveStopwords,
+    /// Strip log prefixes (timestamps, levels, thread ids) from the active editor
+    StripLogPrefixes,
+    /// Restore the editor text saved before the last filter
+    Undo,
+    /// Put back the change the last undo reverted
+    Redo,
+    /// Rerun the comparison automatically whenever the lists change
+    ToggleAutoCompare,
+    /// Compare the files on disk again whenever they change
+    ToggleWatch,
+    /// Load a file at two git revisions into List 1 and List 2
+    LoadGitRevisions,
+    /// Rerun the comparison from the Results tab
+    RefreshResults,
+    /// Switch between grid and unified diff views
+    ToggleDiffView,
+    /// Switch between table and raw line rendering of record results
+    ToggleTableView,
+    /// Make table columns wider
+    WidenColumns,
+    /// Make table columns narrower
+    NarrowColumns,
+    /// Fit table columns to their content
+    AutoFitColumns,
+    /// Collapse or restore result panels without items
+    ToggleHideEmpty,
+    /// Cycle the display order of the focused result panel
+    CycleResultSort,
+    /// Move the column split of the active grid row (negative is left)
+    ResizeGridColumns(i16),
+    /// Move the split between the grid rows (negative is up)
+    ResizeGridRows(i16),
+    /// Run the delimiter conversion
+    Convert,
+    /// Switch the converter output between raw rows and an aligned preview
+    ToggleAlignedPreview,
+    /// Quote comma/semicolon output fields per RFC 4180
+    ToggleQuoteFields,
+    /// Type the text written around every converted item
+    SetItemWrap,
+    /// Cycle writing the converted items as a Python or JavaScript list literal
+    CycleLiteral,
+    /// Toggle writing JSON objects or rows with a header as a Markdown table
+    ToggleMarkdownTable,
+    /// Convert the input row by row, changing only the field separator
+    ToggleTableMode,
+    /// Ask for the column widths of a fixed-width source
+    SetFixedWidths,
+    /// Cycle key-value extraction: keys, values, columns, off
+    CycleKeyValue,
+    /// Replace List 1 or List 2 with the converter output
+    SendToList(PanelId),
+    /// Restore an older (negative) or newer conversion from the converter history
+    ConvertHistory(isize),
+    /// Replace the Convert input with the previewed JSON repair
+    AcceptJsonRepair,
+    /// Switch to the delimiter suggested after a load or paste parsed into one item
+    AcceptDelimiter,
+    /// Cycle how JSON arrays mixing objects and other values are converted
+    CycleJsonStrategy,
+    /// Ask for a JSON path whose values are extracted instead of whole objects
+    SetJsonPath,
+    /// Toggle expanding nested JSON values into `parent.child` columns
+    ToggleFlattenJson,
+    /// Analyze the Analysis tab's source list
+    Analyze,
+    /// Switch the list the Analysis tab examines
+    CycleAnalysisSource,
+    /// Join or zip List 1 with List 2 using the Merge tab settings
+    Join,
+    /// Switch the Merge tab join type
+    CycleJoinType,
+    /// Move the List 1 key column (negative is left)
+    ShiftList1Key(i16),
+    /// Move the List 2 key column (negative is left)
+    ShiftList2Key(i16),
+    /// Switch the Merge tab field separator
+    CycleJoinSeparator,
+    /// Copy the active panel to the clipboard
+    Copy,
+    /// Paste the clipboard into the active editor
+    Paste,
+    /// Copy a Markdown summary of the last comparison
+    CopySummary,
+    /// Export a Markdown report of the last comparison
+    ExportReport,
+    /// Save the lists, options and results as a comparison bundle
+    ExportBundle,
+    /// Open a comparison bundle and replay it
+    ImportBundle,
+    /// Move the editor cursor
+    Move(Motion),
+}
+
+impl Action {
+    /// Whether the action applies to the current tab, panel and state
+    pub fn is_available(&self, app: &App) -> bool {
+        let results = (app.active_tab == Tab::Results).then_some(&app.results_tab);
+        match self {
+            Action::Load | Action::Paste | Action::EnterInsert => app.is_writable_panel(),
+            Action::ToggleReadOnly | Action::SaveReference => app.is_editable_panel(),
+            Action::ReplaceRegex => app.is_writable_panel(),
+            Action::OpenReferences => app.is_writable_panel(),
+            Action::Move(motion) => {
+                app.is_editable_panel()
+                    || ((results.is_some_and(|r| r.view != ResultsView::Unified)
+                        || matches!(app.active_tab, Tab::Analysis | Tab::Merge))
+                        && matches!(
+                            motion,
+                            Motion::Down | Motion::Up | Motion::Top | Motion::Bottom
+                        ))
+            }
+            Action::SortAsc
+            | Action::SortDesc
+            | Action::TrimDedup
+            | Action::CountFrequencies
+            | Action::KeepDuplicates(_)
+            | Action::FilterRegex
+            | Action::RemoveStopwords
+            | Action::StripLogPrefixes => app.is_list_tab() && app.is_writable_panel(),
+            Action::FocusPanel(panel) => app.can_focus_panel(*panel),
+            Action::Compare
+            | Action::Validate(_)
+            | Action::CompareKeyed
+            | Action::CycleKeyedPreset => app.active_tab == Tab::Input,
+            Action::FilterList1(_) => {
+                app.active_tab == Tab::Input && !app.is_read_only(PanelId::List1)
+            }
+            Action::Undo => app
+                .undo_panel()
+                .is_some_and(|panel| !app.is_read_only(panel)),
+            Action::Redo => app
+                .redo_panel()
+                .is_some_and(|panel| !app.is_read_only(panel)),
+            Action::ToggleDiffView => results.is_some(),
+            Action::RefreshResults => results.is_some_and(|r| r.is_stale()),
+            Action::ToggleTableView => results.is_some_and(|r| {
+                r.view == ResultsView::Verdict
+                    || (r.view != ResultsView::Unified && r.record_separator.is_some())
+            }),
+            Action::CycleResultSort => results.is_some_and(|r| {
+                matches!(r.view, ResultsView::Grid | ResultsView::Differences)
+                    && r.compare_results.is_some()
+            }),
+            Action::ToggleHideEmpty | Action::ResizeGridColumns(_) | Action::ResizeGridRows(_) => {
+                results.is_some_and(|r| r.view == ResultsView::Grid)
+            }
+            Action::WidenColumns | Action::NarrowColumns | Action::AutoFitColumns => {
+                results.is_some_and(|r| r.table_separator().is_some())
+            }
+            Action::Convert
+            | Action::CycleSourceDelimiter
+            | Action::CycleTargetDelimiter
+            | Action::ToggleAlignedPreview
+            | Action::ToggleQuoteFields
+            | Action::SetItemWrap
+            | Action::CycleLiteral
+            | Action::ToggleMarkdownTable
+            | Action::ToggleTableMode
+            | Action::SetFixedWidths
+            | Action::CycleKeyValue
+            | Action::SendToList(_)
+            | Action::ConvertHistory(_) => app.active_tab == Tab::Convert,
+            Action::CycleJsonStrategy | Action::SetJsonPath | Action::ToggleFlattenJson => {
+                app.active_tab == Tab::Convert && app.convert_tab.source_delimiter.is_structured()
+            }
+            Action::AcceptJsonRepair => {
+                app.active_tab == Tab::Convert && app.convert_tab.pending_repair.is_some()
+            }
+            Action::AcceptDelimiter => {
+                if app.active_tab == Tab::Convert {
+                    app.convert_tab.suggested_source.is_some()
+                } else {
+                    app.suggested_delimiter.is_some()
+                }
+            }
+            Action::Analyze | Action::CycleAnalysisSource => app.active_tab == Tab::Analysis,
+            Action::Join | Action::CycleJoinType | Action::CycleJoinSeparator => {
+                app.active_tab == Tab::Merge
+            }
+            Action::ShiftList1Key(_) | Action::ShiftList2Key(_) => {
+                app.active_tab == Tab::Merge && app.merge_tab.join_type.uses_keys()
+            }
+            Action::CopySummary | Action::ExportReport => app.results_tab.compare_results.is_some(),
+            Action::ExportBundle => matches!(app.active_tab, Tab::Input | Tab::Results),
+            Action::ToggleWatch => {
+                matches!(app.active_tab, Tab::Input | Tab::Results)
+                    && (app.watch.is_some()
+                        || (!app.is_read_only(PanelId::List1) && !app.is_read_only(PanelId::List2)))
+            }
+            Action::ImportBundle | Action::LoadGitRevisions => {
+                app.active_tab == Tab::Input
+                    && !app.is_read_only(PanelId::List1)
+                    && !app.is_read_only(PanelId::List2)
+            }
+            _ => true,
+        }
+    }
+}
+
+/// How a binding matches a key event
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Key {
+    /// A key regardless of modifiers
+    Plain(KeyCode),
+    /// Alt + a number key
+    AltNumber(u8),
+    /// Alt + Shift + a number key
+    AltShiftNumber(u8),
+    /// Ctrl (Linux) or Cmd (macOS) + a character
+    Command(char),
+    /// Shift + a function key
+    Shift(KeyCode),
+    /// Shift+Tab
+    BackTab,
+    /// A plain digit key selecting a panel
+    PanelNumber(u8),
+}
+
+impl Key {
+    fn matches(&self, key_event: &KeyEvent) -> bool {
+        match *self {
+            // Ctrl+letter is a command of its own, never the plain letter
+            Key::Plain(code @ KeyCode::Char(_)) => {
+                is_key(key_event, code) && !key_event.modifiers.contains(KeyModifiers::CONTROL)
+            }
+            Key::Plain(code) => is_key(key_event, code),
+            Key::AltNumber(n) => is_alt_number(key_event, n),
+            Key::AltShiftNumber(n) => is_alt_shift_number(key_event, n),
+            Key::Command(c) => is_copy_paste_key(key_event, KeyCode::Char(c)),
+            Key::Shift(code) => is_shift_key(key_event, code),
+            Key::BackTab => is_back_tab(key_event),
+            Key::PanelNumber(n) => is_panel_number(key_event, n),
+        }
+    }
+}
+
+/// A key bound to an action
+#[derive(Debug, Clone, Copy)]
+pub struct Binding {
+    /// Key that triggers the action
+    pub key: Key,
+    /// Required editor mode (`None` means any mode)
+    pub mode: Option<Mode>,
+    /// Action to run
+    pub action: Action,
+    /// Key label shown to the user
+    pub label: &'static str,
+    /// Short description shown in hints
+    pub description: Text,
+    /// Whether the binding is listed in the INFO hints
+    pub hint: bool,
+}
+
+const fn bind(
+    key: Key,
+    mode: Option<Mode>,
+    action: Action,
+    label: &'static str,
+    description: Text,
+    hint: bool,
+) -> Binding {
+    Binding {
+        key,
+        mode,
+        action,
+        label,
+        description,
+        hint,
+    }
+}
+
+const NORMAL: Option<Mode> = Some(Mode::Normal);
+const INSERT: Option<Mode> = Some(Mode::Insert);
+const ANY: Option<Mode> = None;
+
+const COPY_LABEL: &str = if cfg!(target_os = "macos") {
+    "Cmd+C"
+} else {
+    "Ctrl+C"
+};
+const PASTE_LABEL: &str = if cfg!(target_os = "macos") {
+    "Cmd+V"
+} else {
+    "Ctrl+V"
+};
+const UNDO_LABEL: &str = if cfg!(target_os = "macos") {
+    "Cmd+Z"
+} else {
+    "Ctrl+Z"
+};
+const REDO_LABEL: &str = if cfg!(target_os = "macos") {
+    "Cmd+Y"
+} else {
+    "Ctrl+Y"
+};
+const DUPLICATES_LABEL: &str = if cfg!(target_os = "macos") {
+    "Cmd+D"
+} else {
+    "Ctrl+D"
+};
+
+/// All key bindings, in priority order (first available match wins)
+#[rustfmt::skip]
+pub const BINDINGS: &[Binding] = &[
+    bind(Key::Plain(KeyCode::Esc), INSERT, Action::ExitInsert, "Esc", Text::HintNormal, true),
+    bind(Key::Plain(KeyCode::Esc), NORMAL, Action::Quit, "Esc", Text::HintQuit, false),
+    bind(Key::Plain(KeyCode::Char('?')), ANY, Action::ToggleHelp, "?", Text::HintHelp, true),
+    bind(Key::AltNumber(1), ANY, Action::GoToTab(Tab::Input), "Alt+1", Text::TabInput, false),
+    bind(Key::AltNumber(2), ANY, Action::GoToTab(Tab::Results), "Alt+2", Text::TabResults, false),
+    bind(Key::AltNumber(3), ANY, Action::GoToTab(Tab::Convert), "Alt+3", Text::TabConvert, false),
+    bind(Key::AltNumber(4), ANY, Action::GoToTab(Tab::List), "Alt+4", Text::TabList, false),
+    bind(Key::AltNumber(5), ANY, Action::GoToTab(Tab::Analysis), "Alt+5", Text::TabAnalysis, false),
+    bind(Key::AltNumber(6), ANY, Action::GoToTab(Tab::Merge), "Alt+6", Text::TabMerge, false),
+    bind(Key::AltShiftNumber(1), ANY, Action::SwitchWorkspace(0), "Alt+Shift+1", Text::HintWorkspace, false),
+    bind(Key::AltShiftNumber(2), ANY, Action::SwitchWorkspace(1), "Alt+Shift+2", Text::HintWorkspace, false),
+    bind(Key::AltShiftNumber(3), ANY, Action::SwitchWorkspace(2), "Alt+Shift+3", Text::HintWorkspace, false),
+    bind(Key::AltShiftNumber(4), ANY, Action::SwitchWorkspace(3), "Alt+Shift+4", Text::HintWorkspace, false),
+    bind(Key::BackTab, ANY, Action::PreviousPanel, "Shift+Tab", Text::HintPreviousPanel, false),
+    bind(Key::Plain(KeyCode::Tab), ANY, Action::NextPanel, "Tab", Text::HintNextPanel, true),
+    bind(Key::PanelNumber(1), NORMAL, Action::FocusPanel(0), "1", Text::HintFocusPanel, false),
+    bind(Key::PanelNumber(2), NORMAL, Action::FocusPanel(1), "2", Text::HintFocusPanel, false),
+    bind(Key::PanelNumber(3), NORMAL, Action::FocusPanel(2), "3", Text::HintFocusPanel, false),
+    bind(Key::PanelNumber(4), NORMAL, Action::FocusPanel(3), "4", Text::HintFocusPanel, false),
+    bind(Key::Plain(KeyCode::F(1)), ANY, Action::Save, "F1", Text::HintSave, true),
+    bind(Key::Plain(KeyCode::F(2)), ANY, Action::Load, "F2", Text::HintLoad, true),
+    bind(Key::Plain(KeyCode::F(3)), ANY, Action::ToggleCase, "F3", Text::HintCase, false),
+    bind(Key::Plain(KeyCode::F(4)), ANY, Action::ToggleTrim, "F4", Text::HintTrim, false),
+    bind(Key::Plain(KeyCode::F(5)), ANY, Action::CycleDelimiter, "F5", Text::HintDelim, true),
+    bind(Key::Plain(KeyCode::Char(':')), NORMAL, Action::SetCustomDelimiter, ":", Text::HintCustomDelimiter, false),
+    bind(Key::Plain(KeyCode::F(6)), ANY, Action::SortAsc, "F6", Text::HintSortAsc, true),
+    bind(Key::Plain(KeyCode::F(7)), ANY, Action::SortDesc, "F7", Text::HintSortDesc, true),
+    // Before F8, which matches whatever the modifiers
+    bind(Key::Shift(KeyCode::F(8)), ANY, Action::CountFrequencies, "Shift+F8", Text::HintFrequencies, false),
+    bind(Key::Plain(KeyCode::F(8)), ANY, Action::TrimDedup, "F8", Text::HintDedup, true),
+    bind(Key::Plain(KeyCode::F(9)), ANY, Action::ToggleInfo, "F9", Text::HintHideInfo, false),
+    bind(Key::Plain(KeyCode::F(10)), ANY, Action::CycleSourceDelimiter, "F10", Text::HintCycleSrc, true),
+    bind(Key::Plain(KeyCode::F(11)), ANY, Action::CycleTargetDelimiter, "F11", Text::HintCycleDst, true),
+    bind(Key::Plain(KeyCode::F(12)), ANY, Action::Compare, "F12", Text::HintCompare, true),
+    bind(Key::Plain(KeyCode::F(12)), ANY, Action::ToggleDiffView, "F12", Text::HintToggleView, true),
+    bind(Key::Plain(KeyCode::F(12)), ANY, Action::Convert, "F12", Text::HintConvert, true),
+    bind(Key::Plain(KeyCode::F(12)), ANY, Action::Analyze, "F12", Text::HintAnalyze, true),
+    bind(Key::Plain(KeyCode::F(12)), ANY, Action::Join, "F12", Text::HintJoin, true),
+    bind(Key::Command('v'), ANY, Action::Paste, PASTE_LABEL, Text::HintPaste, true),
+    bind(Key::Command('c'), ANY, Action::Copy, COPY_LABEL, Text::HintCopy, true),
+    bind(Key::Command('z'), NORMAL, Action::Undo, UNDO_LABEL, Text::HintUndo, false),
+    bind(Key::Command('y'), NORMAL, Action::Redo, REDO_LABEL, Text::HintRedo, true),
+    bind(Key::Command('d'), NORMAL, Action::KeepDuplicates(true), DUPLICATES_LABEL, Text::HintDuplicates, false),
+    bind(Key::Plain(KeyCode::Char('i')), NORMAL, Action::EnterInsert, "i", Text::HintInsert, true),
+    bind(Key::Plain(KeyCode::Char('h')), NORMAL, Action::Move(Motion::Left), "h", Text::HintLeft, false),
+    bind(Key::Plain(KeyCode::Char('j')), NORMAL, Action::Move(Motion::Down), "j", Text::HintDown, false),
+    bind(Key::Plain(KeyCode::Char('k')), NORMAL, Action::Move(Motion::Up), "k", Text::HintUp, false),
+    bind(Key::Plain(KeyCode::Char('l')), NORMAL, Action::Move(Motion::Right), "l", Text::HintRight, false),
+    bind(Key::Plain(KeyCode::Char('w')), NORMAL, Action::Move(Motion::WordForward), "w", Text::HintWordForward, false),
+    bind(Key::Plain(KeyCode::Char('b')), NORMAL, Action::Move(Motion::WordBack), "b", Text::HintWordBack, false),
+    bind(Key::Plain(KeyCode::Char('0')), NORMAL, Action::Move(Motion::LineStart), "0", Text::HintLineStart, false),
+    bind(Key::Plain(KeyCode::Char('$')), NORMAL, Action::Move(Motion::LineEnd), "$", Text::HintLineEnd, false),
+    bind(Key::Plain(KeyCode::Char('g')), NORMAL, Action::Move(Motion::Top), "g", Text::HintTop, false),
+    bind(Key::Plain(KeyCode::Char('G')), NORMAL, Action::Move(Motion::Bottom), "G", Text::HintBottom, false),
+    bind(Key::Plain(KeyCode::Down), NORMAL, Action::Move(Motion::Down), "↓", Text::HintDown, false),
+    bind(Key::Plain(KeyCode::Up), NORMAL, Action::Move(Motion::Up), "↑", Text::HintUp, false),
+    bind(Key::Plain(KeyCode::Char('y')), NORMAL, Action::CopySummary, "y", Text::HintCopySummary, true),
+    bind(Key::Plain(KeyCode::Char('m')), NORMAL, Action::ExportReport, "m", Text::HintReport, true),
+    bind(Key::Plain(KeyCode::Char('e')), NORMAL, Action::ExportBundle, "e", Text::HintExportBundle, false),
+    bind(Key::Plain(KeyCode::Char('E')), NORMAL, Action::ImportBundle, "E", Text::HintImportBundle, false),
+    bind(Key::Plain(KeyCode::Char('+')), NORMAL, Action::GrowInfo, "+", Text::HintGrowInfo, false),
+    bind(Key::Plain(KeyCode::Char('-')), NORMAL, Action::ShrinkInfo, "-", Text::HintShrinkInfo, false),
+    bind(Key::Plain(KeyCode::Char('R')), NORMAL, Action::ToggleReadOnly, "R", Text::HintReadOnly, false),
+    bind(Key::Plain(KeyCode::Char('V')), NORMAL, Action::Validate(VerdictMode::Exact), "V", Text::HintValidate, false),
+    bind(Key::Plain(KeyCode::Char('X')), NORMAL, Action::Validate(VerdictMode::Pattern), "X", Text::HintValidatePatterns, false),
+    bind(Key::Plain(KeyCode::Char('Z')), NORMAL, Action::CompareKeyed, "Z", Text::HintCompareKeyed, false),
+    bind(Key::Plain(KeyCode::Char('z')), NORMAL, Action::CycleKeyedPreset, "z", Text::HintCycleKeyedPreset, false),
+    bind(Key::Plain(KeyCode::Char('B')), NORMAL, Action::FilterList1(ListFilter::Remove), "B", Text::HintRemoveListed, false),
+    bind(Key::Plain(KeyCode::Char('I')), NORMAL, Action::FilterList1(ListFilter::Keep), "I", Text::HintKeepListed, false),
+    bind(Key::Plain(KeyCode::Char('/')), NORMAL, Action::FilterRegex, "/", Text::HintFilterRegex, false),
+    bind(Key::Plain(KeyCode::Char('&')), NORMAL, Action::ReplaceRegex, "&", Text::HintReplaceRegex, false),
+    bind(Key::Plain(KeyCode::Char('N')), NORMAL, Action::RemoveStopwords, "N", Text::HintStopwords, false),
+    bind(Key::Plain(KeyCode::Char('d')), NORMAL, Action::KeepDuplicates(false), "d", Text::HintDuplicates, false),
+    bind(Key::Plain(KeyCode::Char('S')), NORMAL, Action::StripLogPrefixes, "S", Text::HintLogPrefixes, false),
+    bind(Key::Plain(KeyCode::Char('u')), NORMAL, Action::Undo, "u", Text::HintUndo, true),
+    bind(Key::Plain(KeyCode::Char('Q')), NORMAL, Action::CycleTrimSet, "Q", Text::HintTrimSet, false),
+    bind(Key::Plain(KeyCode::Char('C')), NORMAL, Action::ToggleFoldTypography, "C", Text::HintFoldTypography, false),
+    bind(Key::Plain(KeyCode::Char('Y')), NORMAL, Action::TogglePreserveOrder, "Y", Text::HintPreserveOrder, false),
+    bind(Key::Plain(KeyCode::Char('c')), NORMAL, Action::CycleNormalizePreset, "c", Text::HintNormalizePreset, false),
+    bind(Key::Plain(KeyCode::Char('#')), NORMAL, Action::SelectCompareColumn, "#", Text::HintCompareColumn, false),
+    bind(Key::Plain(KeyCode::Char('%')), NORMAL, Action::SetTolerance, "%", Text::HintTolerance, false),
+    bind(Key::Plain(KeyCode::Char('J')), NORMAL, Action::CycleLineEnding, "J", Text::HintLineEnding, false),
+    bind(Key::Plain(KeyCode::Char('F')), NORMAL, Action::CycleEncoding, "F", Text::HintEncoding, false),
+    bind(Key::Plain(KeyCode::Char(',')), NORMAL, Action::ToggleEmptyItems, ",", Text::HintEmptyItems, false),
+    bind(Key::Plain(KeyCode::Char('~')), NORMAL, Action::ToggleTrimOnPaste, "~", Text::HintTrimOnPaste, false),
+    bind(Key::Plain(KeyCode::Char('U')), NORMAL, Action::ToggleUsage, "U", Text::HintUsage, false),
+    bind(Key::Plain(KeyCode::Char('r')), NORMAL, Action::RefreshResults, "r", Text::HintRefresh, true),
+    bind(Key::Plain(KeyCode::Char('T')), NORMAL, Action::ToggleTableView, "T", Text::HintTableView, true),
+    bind(Key::Plain(KeyCode::Char('T')), NORMAL, Action::ToggleTableMode, "T", Text::HintTableMode, true),
+    bind(Key::Plain(KeyCode::Char('|')), NORMAL, Action::SetFixedWidths, "|", Text::HintFixedWidths, true),
+    bind(Key::Plain(KeyCode::Char('=')), NORMAL, Action::CycleKeyValue, "=", Text::HintKeyValue, true),
+    bind(Key::Plain(KeyCode::Char('!')), NORMAL, Action::SendToList(PanelId::List1), "!", Text::HintSendToLists, false),
+    bind(Key::Plain(KeyCode::Char('@')), NORMAL, Action::SendToList(PanelId::List2), "! @", Text::HintSendToLists, true),
+    bind(Key::Plain(KeyCode::Char('o')), NORMAL, Action::CycleJsonStrategy, "o", Text::HintJsonStrategy, true),
+    bind(Key::Plain(KeyCode::Char('x')), NORMAL, Action::SetJsonPath, "x", Text::HintJsonPath, true),
+    bind(Key::Plain(KeyCode::Char('f')), NORMAL, Action::ToggleFlattenJson, "f", Text::HintFlattenJson, true),
+    bind(Key::Plain(KeyCode::Char('a')), NORMAL, Action::AcceptJsonRepair, "a", Text::HintAcceptRepair, true),
+    bind(Key::Plain(KeyCode::Char('a')), NORMAL, Action::AcceptDelimiter, "a", Text::HintAcceptDelimiter, true),
+    bind(Key::Plain(KeyCode::Char('p')), NORMAL, Action::ConvertHistory(-1), "p", Text::HintConvertHistory, false),
+    bind(Key::Plain(KeyCode::Char('n')), NORMAL, Action::ConvertHistory(1), "p n", Text::HintConvertHistory, true),
+    bind(Key::Plain(KeyCode::Char('>')), NORMAL, Action::WidenColumns, ">", Text::HintWidenColumns, true),
+    bind(Key::Plain(KeyCode::Char('<')), NORMAL, Action::NarrowColumns, "<", Text::HintNarrowColumns, true),
+    bind(Key::Plain(KeyCode::Char('=')), NORMAL, Action::AutoFitColumns, "=", Text::HintAutoFit, false),
+    bind(Key::Plain(KeyCode::Char('E')), NORMAL, Action::ToggleHideEmpty, "E", Text::HintHideEmpty, true),
+    bind(Key::Plain(KeyCode::Char('s')), NORMAL, Action::CycleResultSort, "s", Text::HintResultSort, true),
+    bind(Key::Plain(KeyCode::Char('[')), NORMAL, Action::ResizeGridColumns(-1), "[", Text::HintGridLeft, false),
+    bind(Key::Plain(KeyCode::Char(']')), NORMAL, Action::ResizeGridColumns(1), "]", Text::HintGridRight, false),
+    bind(Key::Plain(KeyCode::Char('{')), NORMAL, Action::ResizeGridRows(-1), "{", Text::HintGridUp, false),
+    bind(Key::Plain(KeyCode::Char('}')), NORMAL, Action::ResizeGridRows(1), "}", Text::HintGridDown, false),
+    bind(Key::Plain(KeyCode::Char('s')), NORMAL, Action::CycleAnalysisSource, "s", Text::HintAnalysisSource, true),
+    bind(Key::Plain(KeyCode::Char('t')), NORMAL, Action::CycleJoinType, "t", Text::HintJoinType, true),
+    bind(Key::Plain(KeyCode::Char('[')), NORMAL, Action::ShiftList1Key(-1), "[", Text::HintList1Key, false),
+    bind(Key::Plain(KeyCode::Char(']')), NORMAL, Action::ShiftList1Key(1), "[ ]", Text::HintList1Key, true),
+    bind(Key::Plain(KeyCode::Char('{')), NORMAL, Action::ShiftList2Key(-1), "{", Text::HintList2Key, false),
+    bind(Key::Plain(KeyCode::Char('}')), NORMAL, Action::ShiftList2Key(1), "{ }", Text::HintList2Key, true),
+    bind(Key::Plain(KeyCode::Char('s')), NORMAL, Action::CycleJoinSeparator, "s", Text::HintJoinSeparator, true),
+    bind(Key::Plain(KeyCode::Char('P')), NORMAL, Action::ToggleAlignedPreview, "P", Text::HintAlignedPreview, true),
+    bind(Key::Plain(KeyCode::Char('"')), NORMAL, Action::ToggleQuoteFields, "\"", Text::HintQuoteFields, true),
+    bind(Key::Plain(KeyCode::Char('\'')), NORMAL, Action::SetItemWrap, "'", Text::HintItemWrap, true),
+    bind(Key::Plain(KeyCode::Char('(')), NORMAL, Action::CycleLiteral, "(", Text::HintLiteral, true),
+    bind(Key::Plain(KeyCode::Char('v')), NORMAL, Action::ToggleMarkdownTable, "v", Text::HintMarkdownTable, true),
+    bind(Key::Plain(KeyCode::Char('A')), NORMAL, Action::ToggleAutoCompare, "A", Text::HintAutoCompare, false),
+    bind(Key::Plain(KeyCode::Char('L')), NORMAL, Action::LoadAndCompare, "L", Text::HintLoadCompare, false),
+    bind(Key::Plain(KeyCode::Char('M')), NORMAL, Action::ToggleWatch, "M", Text::HintWatch, false),
+    bind(Key::Plain(KeyCode::Char('H')), NORMAL, Action::LoadGitRevisions, "H", Text::HintGitRevisions, false),
+    bind(Key::Plain(KeyCode::Char('O')), NORMAL, Action::OpenReferences, "O", Text::HintReferences, false),
+    bind(Key::Plain(KeyCode::Char('K')), NORMAL, Action::SaveReference, "K", Text::HintSaveReference, false),
+    bind(Key::Plain(KeyCode::Char('W')), NORMAL, Action::SaveWorkspace, "W", Text::HintSaveWorkspace, false),
+    bind(Key::Plain(KeyCode::Char('D')), NORMAL, Action::ShowDiagnostics, "D", Text::HintDiagnostics, false),
+];
+
+impl Binding {
+    /// Whether the binding applies in the app's current mode and context
+    pub fn is_active(&self, app: &App) -> bool {
+        self.mode.is_none_or(|mode| mode == app.mode) && self.action.is_available(app)
+    }
+}
+
+/// Resolve a key event to the first binding that is active in the current context
+pub fn resolve(app: &App, key_event: &KeyEvent) -> Option<Action> {
+    BINDINGS
+        .iter()
+        .find(|b| b.key.matches(key_event) && b.is_active(app))
+        .map(|b| b.action)
+}
+
+/// Build the hint line for the current tab, panel and mode from the registry
+pub fn context_hints(app: &App) -> String {
+    BINDINGS
+        .iter()
+        .filter(|b| b.hint && b.is_active(app))
+        .map(|b| format!("{}: {}", b.label, tr(b.description)))
+        .collect::<Vec<_>>()
+        .join(" | ")
+}
@@ -0,0 +1,864 @@
+/// Application state and main event loop supporting six tabs:
+/// Input (lists + summary), Results (diff panels), Convert (delimiter conversion),
+/// List (full-screen single list editing), Analysis (single list analytics) and
+/// Merge (joining the two lists).
+pub mod modals;
+pub mod notify;
+pub mod tabs;
+pub mod task;
+pub mod undo;
+pub mod watch;
+pub mod workspace;
+
+pub use modals::*;
+pub use notify::*;
+pub use tabs::*;
+pub use task::*;
+pub use undo::*;
+pub use watch::*;
+pub use workspace::*;
+
+use crate::clipboard::Clipboard;
+use crate::config::{Config, Paths};
+use crate::i18n::{tr, Text};
+use crate::numbers::NumberFormat;
+use crate::operations::{ColumnSelector, CompareOptions, KeyedPreset, Occurrence, Tolerance};
+use crate::parser::{
+    detect_delimiter, suggest_delimiter, Delimiter, EmptyItems, Encoding, LineEnding,
+};
+use std::env;
+use std::io;
+use std::time::Duration;
+use tui_textarea::TextArea;
+
+/// Default height of the INFO panel (including borders)
+pub const DEFAULT_INFO_HEIGHT: u16 = 4;
+/// Smallest visible INFO panel height (borders plus one line)
+pub const MIN_INFO_HEIGHT: u16 = 3;
+/// Largest INFO panel height allowed when resizing
+pub const MAX_INFO_HEIGHT: u16 = 12;
+
+/// Default combined item count above which F12 first shows a sampled estimate
+pub const DEFAULT_SAMPLE_ABOVE: usize = 100_000;
+/// Approximate number of items per list used for sampled estimates
+pub const SAMPLE_SIZE: usize = 10_000;
+
+/// Default input file size (MiB) above which load & compare switches to the disk-backed mode
+pub const DEFAULT_SPILL_ABOVE_MB: u64 = 256;
+
+/// Number of key events kept by the diagnostics key tester
+pub const KEY_LOG_LIMIT: usize = 10;
+
+/// Editor modes for Vim-like interaction
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Mode {
+    /// Navigate or use shortcuts without typing
+    Normal,
+    /// Type text into the active panel
+    Insert,
+}
+
+/// Editable panels that publish change notifications
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PanelId {
+    List1,
+    List2,
+    ConvertInput,
+    SingleList,
+}
+
+/// Outcome of inspecting text about to fill an empty editor for its delimiter
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DelimiterDetection {
+    /// The detected delimiter replaced the current one
+    Applied(Delimiter),
+    /// Other editors sharing the delimiter hold text, so it is only offered
+    Suggested(Delimiter),
+}
+
+/// Notifications published when application state changes
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AppEvent {
+    /// The text of an editable panel changed
+    PanelEdited(PanelId),
+    /// The list delimiter changed, so parsed items may differ
+    DelimiterChanged,
+    /// Case sensitivity or trimming for comparisons changed
+    CompareOptionsChanged,
+}
+
+/// Callback run for every published event (see [`App::subscribe`])
+pub type Subscriber = fn(&mut App, AppEvent) -> Result<(), io::Error>;
+
+/// Item counts kept up to date by a [`Subscriber`] instead of being recomputed every frame
+#[derive(Debug, Clone, Copy, Default)]
+pub struct LiveCounts {
+    /// Items in List 1
+    pub list1: usize,
+    /// Items in List 2
+    pub list2: usize,
+    /// Items in the List tab
+    pub single_list: usize,
+    /// Unique items in the List tab
+    pub single_list_unique: usize,
+}
+
+/// Main application state
+pub struct App {
+    /// Lists and focused panel of the Input tab
+    pub input_tab: InputTab,
+    /// Comparison results and their view settings
+    pub results_tab: ResultsTab,
+    /// Converter input, output and delimiters
+    pub convert_tab: ConvertTab,
+    /// Analytics of one list
+    pub analysis_tab: AnalysisTab,
+    /// Join settings and result of the two lists
+    pub merge_tab: MergeTab,
+    /// Single list text area (List tab, its only panel)
+    pub single_list: TextArea<'static>,
+    /// Currently selected delimiter
+    pub delimiter: Delimiter,
+    /// Delimiter detected in the last paste or load, until cycled by hand
+    pub detected_delimiter: Option<Delimiter>,
+    /// Delimiter offered after a paste or load, for `a` to switch to
+    pub suggested_delimiter: Option<Delimiter>,
+    /// Options that control list comparison
+    pub compare_options: CompareOptions,
+    /// How List 1 and List 2 are read when compared by key
+    pub keyed_preset: KeyedPreset,
+    /// Column of delimited rows compared instead of the whole rows
+    pub compare_column: Option<ColumnSelector>,
+    /// Currently active tab
+    pub active_tab: Tab,
+    /// Results text to display (summary for Tab 1)
+    pub results: Vec<String>,
+    /// Whether the application should exit
+    pub should_quit: bool,
+    /// Whether the help modal is being displayed
+    pub show_help: bool,
+    /// Clipboard instance for persistent selection on Linux
+    pub clipboard: Option<Clipboard>,
+    /// Current editor mode
+    pub mode: Mode,
+    /// Height of the INFO panel when visible
+    pub info_height: u16,
+    /// Whether the INFO panel is displayed
+    pub show_info: bool,
+    /// How counts are rendered in titles, INFO messages and reports
+    pub number_format: NumberFormat,
+    /// Whether the status bar shows item counts and memory usage
+    pub show_usage: bool,
+    /// Whether the diagnostics modal is being displayed
+    pub show_diagnostics: bool,
+    /// Reference list picker, when open
+    pub reference_picker: Option<ReferencePicker>,
+    /// Binary input waiting for the user to pick a view, when one was detected
+    pub binary_picker: Option<BinaryPicker>,
+    /// Text prompt, when open
+    pub prompt: Option<Prompt>,
+    /// Environment details captured when the diagnostics modal was opened
+    pub diagnostics: Vec<(Text, String)>,
+    /// Most recent key events seen by the key tester (newest last)
+    pub key_log: Vec<String>,
+    /// Whether comparisons rerun automatically when the lists change
+    pub auto_compare: bool,
+    /// Whether empty items (as in `a,,b`) are kept or dropped when parsing lists
+    pub empty_items: EmptyItems,
+    /// Whether pasted items are trimmed and trailing delimiters dropped
+    pub trim_on_paste: bool,
+    /// Whether pasting or loading into an empty editor detects its delimiter
+    pub auto_delimiter: bool,
+    /// Item length above which loads and pastes warn (0 disables)
+    pub max_item_length: usize,
+    /// Whether long items are clipped with an ellipsis in result panels
+    pub truncate_long_items: bool,
+    /// Noise values removed by the stopword operation (from the config file)
+    pub stopwords: Vec<String>,
+    /// Named `(name, regex)` presets stripped from the start of log lines
+    pub log_presets: Vec<(String, String)>,
+    /// Working directory files are loaded from and saved to, and the app's own directories
+    pub paths: Paths,
+    /// Whether every copy is also appended to the clipboard history file in the state directory
+    pub clipboard_history: bool,
+    /// Combined item count above which comparing first shows a sampled estimate (0 disables)
+    pub sample_above: usize,
+    /// Whether the sampled estimate was shown for the current inputs
+    pub sample_previewed: bool,
+    /// Combined item count from which comparing runs on a worker thread (0 always does)
+    pub background_above: usize,
+    /// Comparison or load running on a worker thread
+    pub task: Option<BackgroundTask>,
+    /// File size in bytes above which load & compare runs on disk (0 disables)
+    pub spill_above: u64,
+    /// Time between checks of watched files (`LIST_UTILS_WATCH_SECS`)
+    pub watch_interval: Duration,
+    /// How the end of a slow operation is signaled
+    pub notice: Notice,
+    /// Run time from which an operation counts as slow
+    pub notice_after: Duration,
+    /// Whether the bell rings after the next draw
+    pub ring_bell: bool,
+    /// Whether the next draw is inverted as a flash notice
+    pub flash: bool,
+    /// Files compared again when they change, while watch mode is on
+    pub watch: Option<Watch>,
+    /// Item counts refreshed on change notifications
+    pub live_counts: LiveCounts,
+    /// Editors locked against edits (e.g. reference lists loaded from disk)
+    pub read_only: Vec<PanelId>,
+    /// Most snapshots kept on each of the undo and redo stacks (0 disables undo)
+    pub undo_depth: usize,
+    /// Bytes of snapshots kept on each of the undo and redo stacks (0 for no limit)
+    pub undo_budget: usize,
+    /// Editor snapshots restored by undo, most recent last
+    pub undo_stack: Vec<Snapshot>,
+    /// Editor snapshots put back by redo, most recently undone last
+    pub redo_stack: Vec<Snapshot>,
+    /// Line ending of the file each editor was last loaded from
+    pub loaded_line_endings: Vec<(PanelId, LineEnding)>,
+    /// Line ending for saved files (`None` keeps the loaded file's, LF otherwise)
+    pub save_line_ending: Option<LineEnding>,
+    /// Text encoding of saved files
+    pub save_encoding: Encoding,
+    /// Index of the active workspace
+    pub workspace: usize,
+    /// Parked sessions, indexed by workspace (`None` for the active one and unopened ones)
+    workspaces: Vec<Option<Workspace>>,
+    /// Callbacks notified of published events
+    subscribers: Vec<Subscriber>,
+    /// Events waiting for the next dispatch
+    pending_events: Vec<AppEvent>,
+}
+
+impl App {
+    /// Create a new application instance
+    pub fn new() -> Self {
+        Self::with_config(Config::load())
+    }
+
+    /// Create an application instance from given preferences
+    pub fn with_config(config: Config) -> Self {
+        Self {
+            input_tab: InputTab::default(),
+            results_tab: ResultsTab {
+                grid_layout: config.grid,
+                ..ResultsTab::default()
+            },
+            convert_tab: ConvertTab::default(),
+            analysis_tab: AnalysisTab::default(),
+            merge_tab: MergeTab::default(),
+            single_list: TextArea::default(),
+            delimiter: Delimiter::Newline,
+            detected_delimiter: None,
+            suggested_delimiter: None,
+            compare_options: CompareOptions {
+                trim_set: config.trim,
+                fold_typography: config.fold_typography,
+                smart_sort: config.smart_sort,
+                ..CompareOptions::default()
+            },
+            active_tab: Tab::Input,
+            results: vec![tr(Text::Welcome).to_string(), tr(Text::Ready).to_string()],
+            should_quit: false,
+            show_help: false,
+            clipboard: Clipboard::new().ok(),
+            mode: Mode::Normal,
+            info_height: DEFAULT_INFO_HEIGHT,
+            show_info: true,
+            number_format: NumberFormat::from_env(),
+            show_usage: false,
+            show_diagnostics: false,
+            reference_picker: None,
+            binary_picker: None,
+            prompt: None,
+            diagnostics: Vec::new(),
+            key_log: Vec::new(),
+            auto_compare: false,
+            empty_items: if config.collapse_empty {
+                EmptyItems::Collapse
+            } else {
+                EmptyItems::Keep
+            },
+            trim_on_paste: config.trim_on_paste,
+            auto_delimiter: config.auto_delimiter,
+            max_item_length: config.max_item_length,
+            truncate_long_items: config.truncate_long_items,
+            stopwords: config.stopwords,
+            log_presets: config.log_presets,
+            paths: Paths::from_env(),
+            clipboard_history: config.clipboard_history,
+            sample_above: env::var("LIST_UTILS_SAMPLE_ABOVE")
+                .ok()
+                .and_then(|v| v.trim().parse().ok())
+                .unwrap_or(DEFAULT_SAMPLE_ABOVE),
+            sample_previewed: false,
+            background_above: env::var("LIST_UTILS_BACKGROUND_ABOVE")
+                .ok()
+                .and_then(|v| v.trim().parse().ok())
+                .unwrap_or(DEFAULT_BACKGROUND_ABOVE),
+            task: None,
+            spill_above: env::var("LIST_UTILS_SPILL_ABOVE_MB")
+                .ok()
+                .and_then(|v| v.trim().parse::<u64>().ok())
+                .unwrap_or(DEFAULT_SPILL_ABOVE_MB)
+                * 1024
+                * 1024,
+            watch_interval: Duration::from_secs(
+                env::var("LIST_UTILS_WATCH_SECS")
+                    .ok()
+                    .and_then(|v| v.trim().parse::<u64>().ok())
+                    .unwrap_or(DEFAULT_WATCH_SECS)
+                    .max(1),
+            ),
+            notice: config.notice,
+            notice_after: Duration::from_secs(config.notice_after.into()),
+            ring_bell: false,
+            flash: false,
+            watch: None,
+            live_counts: LiveCounts::default(),
+            read_only: Vec::new(),
+            undo_depth: config.undo_depth.into(),
+            undo_budget: usize::from(config.undo_memory_mb) * 1024 * 1024,
+            undo_stack: Vec::new(),
+            redo_stack: Vec::new(),
+            loaded_line_endings: Vec::new(),
+            save_line_ending: None,
+            save_encoding: Encoding::Utf8,
+            keyed_preset: KeyedPreset::default(),
+            compare_column: None,
+            workspace: 0,
+            workspaces: std::iter::repeat_with(|| None)
+                .take(WORKSPACE_COUNT)
+                .collect(),
+            subscribers: Vec::new(),
+            pending_events: Vec::new(),
+        }
+        .with_info_height_from_env()
+    }
+
+    /// Apply `LIST_UTILS_INFO_HEIGHT` (0 hides the INFO panel)
+    fn with_info_height_from_env(mut self) -> Self {
+        if let Some(height) = env::var("LIST_UTILS_INFO_HEIGHT")
+            .ok()
+            .and_then(|v| v.trim().parse::<u16>().ok())
+        {
+            if height == 0 {
+                self.show_info = false;
+            } else {
+                self.info_height = height.clamp(MIN_INFO_HEIGHT, MAX_INFO_HEIGHT);
+            }
+        }
+        self
+    }
+
+    /// Get the currently active text area (only for editable panels)
+    pub fn active_textarea(&mut self) -> Option<&mut TextArea<'static>> {
+        let panel = self.active_panel_id()?;
+        Some(self.textarea(panel))
+    }
+
+    /// Text area of an editable panel
+    pub fn textarea(&mut self, panel: PanelId) -> &mut TextArea<'static> {
+        match panel {
+            PanelId::List1 => &mut self.input_tab.list1,
+            PanelId::List2 => &mut self.input_tab.list2,
+            PanelId::ConvertInput => &mut self.convert_tab.input,
+            PanelId::SingleList => &mut self.single_list,
+        }
+    }
+
+    /// Delimiter the items of an editable panel are parsed with
+    pub fn panel_delimiter(&self, panel: PanelId) -> Delimiter {
+        match panel {
+            PanelId::ConvertInput => self.convert_tab.source_delimiter,
+            _ => self.delimiter,
+        }
+    }
+
+    /// Identify the active panel if it is editable
+    pub fn active_panel_id(&self) -> Option<PanelId> {
+        match self.active_tab {
+            Tab::Input => match self.input_tab.panel {
+                InputPanel::List1 => Some(PanelId::List1),
+                InputPanel::List2 => Some(PanelId::List2),
+                InputPanel::Info => None,
+            },
+            Tab::Convert => {
+                (self.convert_tab.panel == ConvertPanel::Input).then_some(PanelId::ConvertInput)
+            }
+            Tab::List => Some(PanelId::SingleList),
+            Tab::Results | Tab::Analysis | Tab::Merge => None,
+        }
+    }
+
+    /// Register a callback for published events
+    pub fn subscribe(&mut self, subscriber: Subscriber) {
+        self.subscribers.push(subscriber);
+    }
+
+    /// Queue an event for the next dispatch (duplicates are coalesced)
+    pub fn publish(&mut self, event: AppEvent) {
+        if !self.pending_events.contains(&event) {
+            self.pending_events.push(event);
+        }
+    }
+
+    /// Publish an edit notification for the active panel, if editable
+    pub fn publish_active_panel_edited(&mut self) {
+        if let Some(panel) = self.active_panel_id() {
+            self.publish(AppEvent::PanelEdited(panel));
+        }
+    }
+
+    /// Deliver queued events to every subscriber
+    ///
+    /// Events published by subscribers are delivered on the next dispatch.
+    pub fn dispatch_events(&mut self) -> Result<(), io::Error> {
+        let events = std::mem::take(&mut self.pending_events);
+        let subscribers = self.subscribers.clone();
+        for event in events {
+            for subscriber in &subscribers {
+                subscriber(self, event)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Whether the active panel is an editor (its text may still be locked)
+    pub fn is_editable_panel(&self) -> bool {
+        self.active_panel_id().is_some()
+    }
+
+    /// Whether the active panel accepts typing, pasting, loading and list operations
+    pub fn is_writable_panel(&self) -> bool {
+        self.active_panel_id()
+            .is_some_and(|panel| !self.is_read_only(panel))
+    }
+
+    /// Whether edits to an editor are blocked
+    pub fn is_read_only(&self, panel: PanelId) -> bool {
+        self.read_only.contains(&panel)
+    }
+
+    /// Lock or unlock the active editor; returns the panel and its new state
+    pub fn toggle_read_only(&mut self) -> Option<(PanelId, bool)> {
+        let panel = self.active_panel_id()?;
+        let locked = !self.is_read_only(panel);
+        if locked {
+            self.read_only.push(panel);
+        } else {
+            self.read_only.retain(|p| *p != panel);
+        }
+        Some((panel, locked))
+    }
+
+    /// Whether the active tab supports single-list operations (sort, dedup)
+    pub fn is_list_tab(&self) -> bool {
+        matches!(self.active_tab, Tab::Input | Tab::List)
+    }
+
+    /// Number of panels in the current tab
+    pub fn panel_count(&self) -> usize {
+        match self.active_tab {
+            Tab::Input => InputPanel::ALL.len(),
+            Tab::Results => self.results_tab.panels().len(),
+            Tab::Convert => ConvertPanel::ALL.len(),
+            Tab::List => 1,
+            Tab::Analysis => AnalysisPanel::ALL.len(),
+            Tab::Merge => MergePanel::ALL.len(),
+        }
+    }
+
+    /// Position of the focused panel within the current tab
+    pub fn active_panel_index(&self) -> usize {
+        match self.active_tab {
+            Tab::Input => self.input_tab.panel as usize,
+            Tab::Results => self.results_tab.panel.index(),
+            Tab::Convert => self.convert_tab.panel as usize,
+            Tab::List => 0,
+            Tab::Analysis => self.analysis_tab.panel.index(),
+            Tab::Merge => self.merge_tab.panel.index(),
+        }
+    }
+
+    /// Switch to the next panel within the current tab, wrapping around
+    pub fn switch_panel(&mut self) {
+        self.step_panel(1);
+    }
+
+    /// Switch to the previous panel within the current tab, wrapping around
+    pub fn switch_panel_back(&mut self) {
+        self.step_panel(self.panel_count() - 1);
+    }
+
+    /// Advance the active panel by `offset` (modulo the panel count)
+    fn step_panel(&mut self, offset: usize) {
+        let next = (self.active_panel_index() + offset) % self.panel_count();
+        match self.active_tab {
+            Tab::Input => self.input_tab.panel = InputPanel::ALL[next],
+            Tab::Results => self.results_tab.step_panel(offset),
+            Tab::Convert => self.convert_tab.panel = ConvertPanel::ALL[next],
+            Tab::List => {}
+            Tab::Analysis => self.analysis_tab.panel = AnalysisPanel::ALL[next],
+            Tab::Merge => self.merge_tab.panel = MergePanel::ALL[next],
+        }
+    }
+
+    /// Whether the panel at `panel` in the current tab exists and is shown
+    pub fn can_focus_panel(&self, panel: usize) -> bool {
+        match self.active_tab {
+            Tab::Results => ResultsPanel::ALL
+                .get(panel)
+                .is_some_and(|p| self.results_tab.is_reachable(*p)),
+            _ => panel < self.panel_count(),
+        }
+    }
+
+    /// Focus a panel of the current tab by position (ignored if out of range or collapsed)
+    pub fn focus_panel(&mut self, panel: usize) {
+        if !self.can_focus_panel(panel) {
+            return;
+        }
+        match self.active_tab {
+            Tab::Input => self.input_tab.panel = InputPanel::ALL[panel],
+            Tab::Results => self.results_tab.panel = ResultsPanel::ALL[panel],
+            Tab::Convert => self.convert_tab.panel = ConvertPanel::ALL[panel],
+            Tab::List => {}
+            Tab::Analysis => self.analysis_tab.panel = AnalysisPanel::ALL[panel],
+            Tab::Merge => self.merge_tab.panel = MergePanel::ALL[panel],
+        }
+    }
+
+    /// Go to a specific tab, restoring the panel that was focused when it was left
+    ///
+    /// Editors keep their own cursor and scroll position and read-only panels
+    /// keep their selected row, so only a Results panel hidden in the meantime
+    /// (e.g. by `E`) needs moving.
+    pub fn go_to_tab(&mut self, tab: Tab) {
+        self.active_tab = tab;
+        if tab == Tab::Results {
+            self.results_tab.ensure_visible_panel();
+        }
+    }
+
+    /// Cycle to the next delimiter
+    pub fn cycle_delimiter(&mut self) {
+        self.delimiter = self.delimiter.next();
+        self.detected_delimiter = None;
+        self.suggested_delimiter = None;
+        self.publish(AppEvent::DelimiterChanged);
+    }
+
+    /// Offer a delimiter for text loaded or pasted into `panel` that parsed into a single item
+    ///
+    /// Returns the suggestion with the number of times it occurs.
+    pub fn suggest_panel_delimiter(
+        &mut self,
+        panel: PanelId,
+        text: &str,
+    ) -> Option<(Delimiter, usize)> {
+        let suggestion = suggest_delimiter(text, self.panel_delimiter(panel));
+        let delimiter = suggestion.map(|(delimiter, _)| delimiter);
+        if panel == PanelId::ConvertInput {
+            self.convert_tab.suggested_source = delimiter;
+        } else {
+            self.suggested_delimiter = delimiter;
+        }
+        suggestion
+    }
+
+    /// Switch to the delimiter offered for the current tab, returning it
+    pub fn accept_suggested_delimiter(&mut self) -> Option<Delimiter> {
+        if self.active_tab == Tab::Convert {
+            let delimiter = self.convert_tab.suggested_source.take()?;
+            self.convert_tab.source_delimiter = delimiter;
+            Some(delimiter)
+        } else {
+            let delimiter = self.suggested_delimiter.take()?;
+            self.delimiter = delimiter;
+            self.publish(AppEvent::DelimiterChanged);
+            Some(delimiter)
+        }
+    }
+
+    /// Switch the delimiter of an empty editor to the one detected in text about to fill it
+    ///
+    /// JSON is only picked for the converter input, and a custom delimiter is
+    /// never replaced. List 1, List 2 and the List tab share one delimiter, so
+    /// it is only switched while all three are empty; otherwise the detected
+    /// one is offered for `a` instead, as switching would re-split the others.
+    /// Returns nothing when the current delimiter stays and nothing is offered.
+    pub fn detect_panel_delimiter(
+        &mut self,
+        panel: PanelId,
+        text: &str,
+    ) -> Option<DelimiterDetection> {
+        let current = self.panel_delimiter(panel);
+        if !self.auto_delimiter
+            || matches!(current, Delimiter::Custom(_))
+            || self.textarea(panel).lines().iter().any(|l| !l.is_empty())
+        {
+            return None;
+        }
+        let detected = detect_delimiter(text)?;
+        if panel == PanelId::ConvertInput {
+            self.convert_tab.source_delimiter = detected;
+            self.convert_tab.detected_source = Some(detected);
+            return (detected != current).then_some(DelimiterDetection::Applied(detected));
+        }
+        // Outside the Convert tab JSON Lines are plain lines
+        let detected = match detected {
+            Delimiter::Json => return None,
+            Delimiter::JsonLines => Delimiter::Newline,
+            detected => detected,
+        };
+        let shared_in_use = [PanelId::List1, PanelId::List2, PanelId::SingleList]
+            .into_iter()
+            .any(|other| self.textarea(other).lines().iter().any(|l| !l.is_empty()));
+        if shared_in_use {
+            return (detected != current).then_some(DelimiterDetection::Suggested(detected));
+        }
+        self.delimiter = detected;
+        self.detected_delimiter = Some(detected);
+        if detected == current {
+            return None;
+        }
+        self.publish(AppEvent::DelimiterChanged);
+        Some(DelimiterDetection::Applied(detected))
+    }
+
+    /// Toggle case sensitivity for comparisons
+    pub fn toggle_case_sensitivity(&mut self) {
+        self.compare_options.case_sensitive = !self.compare_options.case_sensitive;
+        self.publish(AppEvent::CompareOptionsChanged);
+    }
+
+    /// Toggle trimming behavior for comparisons
+    pub fn toggle_trim_spaces(&mut self) {
+        self.compare_options.trim_spaces = !self.compare_options.trim_spaces;
+        self.publish(AppEvent::CompareOptionsChanged);
+    }
+
+    /// Whether a modal (help, diagnostics, a picker or a prompt) has the keyboard
+    pub fn has_modal(&self) -> bool {
+        self.show_help
+            || self.show_diagnostics
+            || self.reference_picker.is_some()
+            || self.binary_picker.is_some()
+            || self.prompt.is_some()
+    }
+
+    /// Length at which result panels clip items, if clipping is on
+    pub fn display_item_limit(&self) -> Option<usize> {
+        (self.truncate_long_items && self.max_item_length > 0).then_some(self.max_item_length)
+    }
+
+    /// Switch between keeping and dropping empty items when parsing lists
+    pub fn toggle_empty_items(&mut self) {
+        self.empty_items = match self.empty_items {
+            EmptyItems::Keep => EmptyItems::Collapse,
+            EmptyItems::Collapse => EmptyItems::Keep,
+        };
+        self.publish(AppEvent::DelimiterChanged);
+    }
+
+    /// Switch to the next normalization preset for comparisons
+    pub fn cycle_normalize_preset(&mut self) {
+        self.compare_options.normalize = self.compare_options.normalize.next();
+        self.publish(AppEvent::CompareOptionsChanged);
+    }
+
+    /// Compare `column` of delimited rows, or whole rows with `None`
+    pub fn set_compare_column(&mut self, column: Option<ColumnSelector>) {
+        self.compare_column = column;
+        self.publish(AppEvent::CompareOptionsChanged);
+    }
+
+    /// Signal the end of an operation that ran for `elapsed`, if that was slow
+    pub fn notify_finished(&mut self, elapsed: Duration) {
+        if elapsed < self.notice_after {
+            return;
+        }
+        match self.notice {
+            Notice::Off => {}
+            Notice::Bell => self.ring_bell = true,
+            Notice::Flash => self.flash = true,
+        }
+    }
+
+    /// Match numbers within `tolerance`, or exactly with `None`
+    pub fn set_tolerance(&mut self, tolerance: Option<Tolerance>) {
+        self.compare_options.tolerance = tolerance;
+        self.publish(AppEvent::CompareOptionsChanged);
+    }
+
+    /// Toggle keeping comparison results in input order instead of sorting them
+    pub fn toggle_preserve_order(&mut self) {
+        self.compare_options.preserve_order = !self.compare_options.preserve_order;
+        self.publish(AppEvent::CompareOptionsChanged);
+    }
+
+    /// Toggle folding of typographic characters to ASCII for comparisons
+    pub fn toggle_fold_typography(&mut self) {
+        self.compare_options.fold_typography = !self.compare_options.fold_typography;
+        self.publish(AppEvent::CompareOptionsChanged);
+    }
+
+    /// Switch the line ending used when saving: auto -> LF -> CRLF
+    pub fn cycle_save_line_ending(&mut self) {
+        self.save_line_ending = match self.save_line_ending {
+            None => Some(LineEnding::Lf),
+            Some(LineEnding::Lf) => Some(LineEnding::Crlf),
+            Some(LineEnding::Crlf) => None,
+        };
+    }
+
+    /// Remember the line ending of the file just loaded into `panel` (`None` if it had no line breaks)
+    pub fn remember_line_ending(&mut self, panel: PanelId, ending: Option<LineEnding>) {
+        self.loaded_line_endings.retain(|(p, _)| *p != panel);
+        if let Some(ending) = ending {
+            self.loaded_line_endings.push((panel, ending));
+        }
+    }
+
+    /// Line ending to save a panel with: the chosen one, else the one its file was loaded with
+    pub fn line_ending_for(&self, panel: Option<PanelId>) -> LineEnding {
+        self.save_line_ending
+            .or_else(|| {
+                self.loaded_line_endings
+                    .iter()
+                    .find(|(p, _)| Some(*p) == panel)
+                    .map(|(_, ending)| *ending)
+            })
+            .unwrap_or_default()
+    }
+
+    /// Switch to the next set of characters trimmed along with the spaces
+    pub fn cycle_trim_set(&mut self) {
+        self.compare_options.trim_set = self.compare_options.trim_set.next_preset();
+        self.publish(AppEvent::CompareOptionsChanged);
+    }
+
+    /// Toggle help modal visibility
+    pub fn toggle_help(&mut self) {
+        self.show_help = !self.show_help;
+    }
+
+    /// Show or hide the INFO panel
+    pub fn toggle_info_panel(&mut self) {
+        self.show_info = !self.show_info;
+    }
+
+    /// Grow or shrink the INFO panel, keeping it within the allowed range
+    pub fn resize_info_panel(&mut self, delta: i16) {
+        let height = self.info_height as i16 + delta;
+        self.info_height = height.clamp(MIN_INFO_HEIGHT as i16, MAX_INFO_HEIGHT as i16) as u16;
+        self.show_info = true;
+    }
+
+    /// Effective INFO panel height for layout (0 when hidden)
+    pub fn info_panel_height(&self) -> u16 {
+        if self.show_info {
+            self.info_height
+        } else {
+            0
+        }
+    }
+
+    /// Record a key in the diagnostics key tester, keeping the last few
+    pub fn record_key(&mut self, description: String) {
+        self.key_log.push(description);
+        if self.key_log.len() > KEY_LOG_LIMIT {
+            self.key_log.remove(0);
+        }
+    }
+
+    /// Whether comparing should first show a sampled estimate for the current inputs
+    pub fn needs_sample_preview(&self) -> bool {
+        self.sample_above > 0
+            && !self.sample_previewed
+            && self.live_counts.list1 + self.live_counts.list2 >= self.sample_above
+    }
+
+    /// Turn automatic re-comparison on list changes on or off
+    pub fn toggle_auto_compare(&mut self) {
+        self.auto_compare = !self.auto_compare;
+    }
+
+    /// Show or hide the usage segment of the status bar
+    pub fn toggle_usage(&mut self) {
+        self.show_usage = !self.show_usage;
+    }
+
+    /// Approximate heap memory held by the lists, converter (with its history), results, analysis, join and undo snapshots, in bytes
+    ///
+    /// Counts string contents plus per-string overhead; allocator slack is ignored.
+    pub fn approximate_memory(&self) -> usize {
+        fn strings_size(items: &[String]) -> usize {
+            items
+                .iter()
+                .map(|s| s.len() + std::mem::size_of::<String>())
+                .sum()
+        }
+
+        let editors = [
+            &self.input_tab.list1,
+            &self.input_tab.list2,
+            &self.convert_tab.input,
+            &self.single_list,
+        ]
+        .iter()
+        .map(|textarea| strings_size(textarea.lines()))
+        .sum::<usize>();
+
+        let results = self.results_tab.compare_results.as_ref().map_or(0, |r| {
+            strings_size(&r.only_in_first)
+                + strings_size(&r.only_in_second)
+                + strings_size(&r.intersection)
+                + strings_size(&r.union)
+                + r.occurrences
+                    .keys()
+                    .map(|item| item.len() + std::mem::size_of::<(String, Occurrence)>())
+                    .sum::<usize>()
+        }) + self
+            .results_tab
+            .sorted
+            .iter()
+            .flatten()
+            .map(|items| strings_size(items))
+            .sum::<usize>();
+
+        let analysis = self
+            .analysis_tab
+            .lines
+            .iter()
+            .map(|lines| strings_size(lines))
+            .sum::<usize>();
+
+        editors
+            + results
+            + analysis
+            + strings_size(&self.merge_tab.settings)
+            + strings_size(&self.merge_tab.rows)
+            + strings_size(&self.convert_tab.output_items)
+            + self.convert_tab.output_serialized.len()
+            + self
+                .convert_tab
+                .history
+                .iter()
+                .map(|snapshot| {
+                    strings_size(&snapshot.input)
+                        + strings_size(&snapshot.output_items)
+                        + snapshot.output_serialized.len()
+                })
+                .sum::<usize>()
+            + strings_size(&self.results)
+            + self
+                .undo_stack
+                .iter()
+                .chain(&self.redo_stack)
+                .map(Snapshot::size)
+                .sum::<usize>()
+    }
+}
@@ -0,0 +1,158 @@
+//! State of the modals that take over the keyboard: pickers and text prompts
+use super::PanelId;
+use crate::i18n::Text;
+use crate::operations::ListFilter;
+use crate::parser::{BinaryReason, BinaryView};
+use std::path::PathBuf;
+use tui_textarea::TextArea;
+
+/// Picker listing the stored reference lists
+pub struct ReferencePicker {
+    /// Reference list names, in display order
+    pub names: Vec<String>,
+    /// Highlighted row
+    pub selected: usize,
+    /// Editor the chosen list is loaded into
+    pub target: PanelId,
+}
+
+impl ReferencePicker {
+    /// Move the highlight by `delta` rows, stopping at both ends
+    pub fn step(&mut self, delta: isize) {
+        let last = self.names.len().saturating_sub(1);
+        self.selected = self.selected.saturating_add_signed(delta).min(last);
+    }
+
+    /// Name of the highlighted list
+    pub fn selected_name(&self) -> Option<&str> {
+        self.names.get(self.selected).map(String::as_str)
+    }
+}
+
+/// Where binary input waiting for a decision came from
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BinaryOrigin {
+    /// A file loaded into the editor, replacing its content
+    File(PathBuf),
+    /// A paste, inserted at the cursor
+    Paste,
+}
+
+/// Choices offered for binary input, in display order (`None` aborts)
+pub const BINARY_CHOICES: [Option<BinaryView>; 3] =
+    [Some(BinaryView::Hex), Some(BinaryView::Lossy), None];
+
+/// Binary input held back until the user picks a view or aborts
+pub struct BinaryPicker {
+    /// Where the data came from
+    pub origin: BinaryOrigin,
+    /// The raw data
+    pub bytes: Vec<u8>,
+    /// Why it was taken for binary
+    pub reason: BinaryReason,
+    /// Highlighted entry of [`BINARY_CHOICES`]
+    pub selected: usize,
+    /// Editor the data goes into
+    pub target: PanelId,
+}
+
+impl BinaryPicker {
+    /// Move the highlight by `delta` rows, stopping at both ends
+    pub fn step(&mut self, delta: isize) {
+        let last = BINARY_CHOICES.len() - 1;
+        self.selected = self.selected.saturating_add_signed(delta).min(last);
+    }
+
+    /// The highlighted choice (`None` aborts)
+    pub fn choice(&self) -> Option<BinaryView> {
+        BINARY_CHOICES[self.selected]
+    }
+}
+
+/// What a text prompt's answer is used for
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PromptKind {
+    /// Name under which an editor is saved to the reference library
+    SaveReference(PanelId),
+    /// Column widths of the fixed-width Convert source
+    FixedWidths,
+    /// Path of the values extracted from a JSON Convert source
+    JsonPath,
+    /// Prefix and suffix written around every converted item
+    ItemWrap,
+    /// Custom delimiter string
+    CustomDelimiter(DelimiterSlot),
+    /// File a comparison bundle is saved to
+    ExportBundle,
+    /// File the Markdown report of the last comparison is saved to
+    ExportReport,
+    /// Comparison bundle to replay
+    ImportBundle,
+    /// File and the two git revisions loaded into List 1 and List 2
+    GitRevisions,
+    /// Column of delimited rows that is compared
+    CompareColumn,
+    /// Tolerance within which numbers match
+    Tolerance,
+    /// Regular expression the items of an editor are kept or dropped by
+    RegexFilter(PanelId, ListFilter),
+    /// Regular expression searched for in the lines of an editor
+    ReplaceFind(PanelId),
+    /// Replacement for the matches of the pattern, previewed while typed
+    ReplaceWith(PanelId, String),
+}
+
+/// Which delimiter a custom delimiter replaces
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DelimiterSlot {
+    /// The delimiter of List 1, List 2 and the List tab
+    Lists,
+    /// The Convert source delimiter
+    ConvertSource,
+    /// The Convert target delimiter
+    ConvertTarget,
+}
+
+impl DelimiterSlot {
+    /// Label used in the prompt title
+    pub fn name(self) -> Text {
+        match self {
+            DelimiterSlot::Lists => Text::DelimiterSlotLists,
+            DelimiterSlot::ConvertSource => Text::DelimiterSlotSource,
+            DelimiterSlot::ConvertTarget => Text::DelimiterSlotTarget,
+        }
+    }
+}
+
+/// Single-line text prompt
+pub struct Prompt {
+    /// What the answer is for
+    pub kind: PromptKind,
+    /// Text typed so far
+    pub input: TextArea<'static>,
+}
+
+impl Prompt {
+    /// Open a prompt pre-filled with `initial`
+    pub fn new(kind: PromptKind, initial: &str) -> Self {
+        let mut input = TextArea::default();
+        input.insert_str(initial);
+        Self { kind, input }
+    }
+
+    /// The answer typed so far
+    pub fn value(&self) -> String {
+        self.input.lines().concat()
+    }
+
+    /// Switch a regex filter prompt between keeping and dropping the matches
+    pub fn toggle_filter(&mut self) {
+        if let PromptKind::RegexFilter(panel, filter) = self.kind {
+            let filter = match filter {
+                ListFilter::Keep => ListFilter::Remove,
+                ListFilter::Remove => ListFilter::Keep,
+            };
+            self.kind = PromptKind::RegexFilter(panel, filter);
+        }
+    }
+}
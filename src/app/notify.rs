@@ -0,0 +1,45 @@
+//! Notice that a slow operation finished, for when the terminal is out of sight
+//!
+//! Operations run on the event loop, so a huge comparison blocks the screen
+//! until it is done; with a notice set in the config file, anything that took
+//! longer than a few seconds ends with the terminal bell or a brief flash.
+use std::time::Duration;
+
+/// Default seconds an operation runs before its end is notified (`notify_after`)
+pub const DEFAULT_NOTIFY_AFTER_SECS: u16 = 5;
+
+/// How long the flash notice keeps the screen inverted
+pub const FLASH_DURATION: Duration = Duration::from_millis(150);
+
+/// How the end of a slow operation is signaled
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Notice {
+    /// Not at all
+    #[default]
+    Off,
+    /// With the terminal bell, which tmux and most terminals can relay
+    Bell,
+    /// By inverting the screen for a moment
+    Flash,
+}
+
+impl Notice {
+    /// Name used in the config file
+    pub fn name(self) -> &'static str {
+        match self {
+            Notice::Off => "off",
+            Notice::Bell => "bell",
+            Notice::Flash => "flash",
+        }
+    }
+
+    /// Notice for a name written by [`Notice::name`]
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name.trim() {
+            "off" => Some(Notice::Off),
+            "bell" => Some(Notice::Bell),
+            "flash" => Some(Notice::Flash),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,928 @@
+//! Per-tab state: each tab owns its editors, outputs and focused panel
+//!
+//! Panels are identified by per-tab enums rather than raw indices, so a new
+//! tab only has to describe its own panels.
+use super::PanelId;
+use crate::operations::{
+    sort_results, CompareResult, JoinType, KeyedReport, ResultSort, VerdictReport, KEYED_SEPARATOR,
+    VERDICT_SEPARATOR,
+};
+use crate::parser::{
+    align_columns, column_widths, looks_like_header, Delimiter, ItemWrap, JsonArrayStrategy,
+    JsonPath, KeyValueOutput, LiteralTarget, NestedJson, RECORD_SEPARATORS,
+};
+use crate::ui::GridLayout;
+use tui_textarea::TextArea;
+
+/// Column width used when switching table columns from auto-fit to a fixed width
+pub const DEFAULT_TABLE_COLUMN_WIDTH: u16 = 12;
+/// Narrowest fixed table column
+pub const MIN_TABLE_COLUMN_WIDTH: u16 = 3;
+/// Widest fixed table column
+pub const MAX_TABLE_COLUMN_WIDTH: u16 = 60;
+
+/// Top-level tabs, in tab bar order
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Tab {
+    /// List 1, List 2 and the INFO summary
+    #[default]
+    Input,
+    /// Comparison result panels
+    Results,
+    /// Delimiter conversion
+    Convert,
+    /// Full-screen single list editing
+    List,
+    /// Frequencies, statistics and validation of one list
+    Analysis,
+    /// Join, zip and merge List 1 with List 2
+    Merge,
+}
+
+impl Tab {
+    /// Position in the tab bar
+    pub fn index(self) -> usize {
+        self as usize
+    }
+}
+
+/// Panels of the Input tab
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum InputPanel {
+    #[default]
+    List1,
+    List2,
+    /// INFO panel with the last messages
+    Info,
+}
+
+impl InputPanel {
+    /// Panels in focus order
+    pub const ALL: [InputPanel; 3] = [InputPanel::List1, InputPanel::List2, InputPanel::Info];
+}
+
+/// Result categories of the Results tab, in grid order
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ResultsPanel {
+    #[default]
+    OnlyInList1,
+    OnlyInList2,
+    Intersection,
+    Union,
+}
+
+impl ResultsPanel {
+    /// Panels in focus order
+    pub const ALL: [ResultsPanel; 4] = [
+        ResultsPanel::OnlyInList1,
+        ResultsPanel::OnlyInList2,
+        ResultsPanel::Intersection,
+        ResultsPanel::Union,
+    ];
+
+    /// Position in the grid (also indexes per-panel state such as the selection)
+    pub fn index(self) -> usize {
+        self as usize
+    }
+}
+
+/// Panels of the Convert tab
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ConvertPanel {
+    #[default]
+    Input,
+    Output,
+}
+
+impl ConvertPanel {
+    /// Panels in focus order
+    pub const ALL: [ConvertPanel; 2] = [ConvertPanel::Input, ConvertPanel::Output];
+}
+
+/// Panels of the Analysis tab, in grid order
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum AnalysisPanel {
+    /// Summary statistics
+    #[default]
+    Stats,
+    /// Every distinct item with its count
+    Frequencies,
+    /// Items that occur more than once
+    Duplicates,
+    /// Problems found in individual items
+    Validation,
+}
+
+impl AnalysisPanel {
+    /// Panels in focus order
+    pub const ALL: [AnalysisPanel; 4] = [
+        AnalysisPanel::Stats,
+        AnalysisPanel::Frequencies,
+        AnalysisPanel::Duplicates,
+        AnalysisPanel::Validation,
+    ];
+
+    /// Position in the grid (also indexes per-panel state such as the selection)
+    pub fn index(self) -> usize {
+        self as usize
+    }
+}
+
+/// List examined by the Analysis tab
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum AnalysisSource {
+    List1,
+    List2,
+    /// The List tab editor
+    #[default]
+    SingleList,
+}
+
+impl AnalysisSource {
+    /// The source `s` switches to next
+    pub fn next(self) -> Self {
+        match self {
+            AnalysisSource::List1 => AnalysisSource::List2,
+            AnalysisSource::List2 => AnalysisSource::SingleList,
+            AnalysisSource::SingleList => AnalysisSource::List1,
+        }
+    }
+
+    /// Editor holding the source list
+    pub fn panel_id(self) -> PanelId {
+        match self {
+            AnalysisSource::List1 => PanelId::List1,
+            AnalysisSource::List2 => PanelId::List2,
+            AnalysisSource::SingleList => PanelId::SingleList,
+        }
+    }
+}
+
+/// Panels of the Merge tab
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MergePanel {
+    /// Join type, key columns, separator and a preview of the first rows
+    #[default]
+    Settings,
+    /// Rows of the last join
+    Result,
+}
+
+impl MergePanel {
+    /// Panels in focus order
+    pub const ALL: [MergePanel; 2] = [MergePanel::Settings, MergePanel::Result];
+
+    /// Position in focus order (also indexes per-panel state such as the selection)
+    pub fn index(self) -> usize {
+        self as usize
+    }
+}
+
+/// How the Results tab lays out the result categories
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ResultsView {
+    /// All four categories in a resizable grid
+    #[default]
+    Grid,
+    /// A single unified diff
+    Unified,
+    /// Only the two difference panels, full height
+    Differences,
+    /// Pass/fail report of List 1 against a reference list (entered by validating)
+    Verdict,
+    /// Missing keys and changed values of two keyed files (entered by a keyed comparison)
+    Keyed,
+}
+
+impl ResultsView {
+    /// The view F12 switches to next
+    pub fn next(self) -> Self {
+        match self {
+            ResultsView::Grid => ResultsView::Unified,
+            ResultsView::Unified => ResultsView::Differences,
+            ResultsView::Differences | ResultsView::Verdict | ResultsView::Keyed => {
+                ResultsView::Grid
+            }
+        }
+    }
+}
+
+/// State of the Input tab
+#[derive(Default)]
+pub struct InputTab {
+    /// First list text area
+    pub list1: TextArea<'static>,
+    /// Second list text area
+    pub list2: TextArea<'static>,
+    /// Focused panel
+    pub panel: InputPanel,
+}
+
+/// State of the Results tab
+pub struct ResultsTab {
+    /// Detailed results of the last comparison
+    pub compare_results: Option<CompareResult>,
+    /// Whether the inputs changed since `compare_results` was computed
+    pub stale: bool,
+    /// Selected row in each result panel, indexed by [`ResultsPanel::index`]
+    pub selection: [usize; 4],
+    /// Current layout of the result categories
+    pub view: ResultsView,
+    /// Focused panel
+    pub panel: ResultsPanel,
+    /// Field separator shared by the compared items, when they are records
+    pub record_separator: Option<char>,
+    /// Header row of the compared records (the first item of both lists), when it looks like one
+    pub record_header: Option<String>,
+    /// Whether record-style results are rendered as tables
+    pub table_view: bool,
+    /// Fixed table column width (0 fits each column to its content)
+    pub table_column_width: u16,
+    /// Column widths of the table rendering, kept by [`ResultsTab::refresh_table_columns`]
+    pub table_columns: Option<TableColumns>,
+    /// Split ratios of the results grid (persisted in the config file)
+    pub grid_layout: GridLayout,
+    /// Whether result categories without items are collapsed in the grid
+    pub hide_empty_panels: bool,
+    /// Pass/fail report of the last validation against List 2
+    pub verdict: Option<VerdictReport>,
+    /// Whether the inputs changed since `verdict` was computed
+    pub verdict_stale: bool,
+    /// Report of the last keyed comparison of List 1 and List 2
+    pub keyed: Option<KeyedReport>,
+    /// Whether the inputs changed since `keyed` was computed
+    pub keyed_stale: bool,
+    /// Display order of each result panel, indexed by [`ResultsPanel::index`]
+    pub sort: [ResultSort; 4],
+    /// Items of each panel in its display order (`None` for the stored order),
+    /// kept in step by [`ResultsTab::set_compare_results`]
+    pub sorted: [Option<Vec<String>>; 4],
+}
+
+/// Widest field of each table column over all items of each result panel
+pub struct TableColumns {
+    /// View and separator the widths were measured for
+    view: ResultsView,
+    separator: char,
+    /// Widths indexed by [`ResultsPanel::index`]
+    widths: [Vec<usize>; 4],
+}
+
+impl Default for ResultsTab {
+    fn default() -> Self {
+        Self {
+            compare_results: None,
+            stale: false,
+            selection: [0; 4],
+            view: ResultsView::Grid,
+            panel: ResultsPanel::OnlyInList1,
+            record_separator: None,
+            record_header: None,
+            table_view: true,
+            table_column_width: 0,
+            table_columns: None,
+            grid_layout: GridLayout::default(),
+            hide_empty_panels: false,
+            verdict: None,
+            verdict_stale: false,
+            keyed: None,
+            keyed_stale: false,
+            sort: [ResultSort::Smart; 4],
+            sorted: Default::default(),
+        }
+    }
+}
+
+impl ResultsTab {
+    /// Panels reachable in the current view (the Differences view only shows the first two)
+    ///
+    /// The Verdict view has a single panel, which borrows the first slot; the
+    /// Keyed view borrows the first three.
+    pub fn panels(&self) -> &'static [ResultsPanel] {
+        match self.view {
+            ResultsView::Differences => &ResultsPanel::ALL[..2],
+            ResultsView::Verdict => &ResultsPanel::ALL[..1],
+            ResultsView::Keyed => &ResultsPanel::ALL[..3],
+            _ => &ResultsPanel::ALL,
+        }
+    }
+
+    /// Items shown in a result panel in the current view
+    ///
+    /// In the Verdict view the (single) panel holds the verdict rows, and in
+    /// the Keyed view the panels hold the keyed report.
+    pub fn items(&self, panel: ResultsPanel) -> &[String] {
+        match self.view {
+            ResultsView::Verdict => self.verdict_rows(),
+            ResultsView::Keyed => self.keyed_rows(panel),
+            _ => self.category_items(panel),
+        }
+    }
+
+    /// Rows of the last keyed report in a panel slot (empty before the first keyed comparison)
+    ///
+    /// The slots of the first two categories hold the keys missing in List 2
+    /// and in List 1, and the intersection slot holds the changed values.
+    pub fn keyed_rows(&self, panel: ResultsPanel) -> &[String] {
+        let Some(report) = self.keyed.as_ref() else {
+            return &[];
+        };
+        match panel {
+            ResultsPanel::OnlyInList1 => &report.missing_in_second,
+            ResultsPanel::OnlyInList2 => &report.missing_in_first,
+            ResultsPanel::Intersection => &report.changed,
+            ResultsPanel::Union => &[],
+        }
+    }
+
+    /// Rows of the last verdict report (empty before the first validation)
+    pub fn verdict_rows(&self) -> &[String] {
+        self.verdict.as_ref().map_or(&[], |report| &report.rows)
+    }
+
+    /// Items of a result category in the panel's display order (empty before the first comparison)
+    pub fn category_items(&self, panel: ResultsPanel) -> &[String] {
+        if let Some(sorted) = &self.sorted[panel.index()] {
+            return sorted;
+        }
+        self.stored_items(panel)
+    }
+
+    /// Items of a result category as stored by the comparison
+    fn stored_items(&self, panel: ResultsPanel) -> &[String] {
+        let Some(result) = self.compare_results.as_ref() else {
+            return &[];
+        };
+        match panel {
+            ResultsPanel::OnlyInList1 => &result.only_in_first,
+            ResultsPanel::OnlyInList2 => &result.only_in_second,
+            ResultsPanel::Intersection => &result.intersection,
+            ResultsPanel::Union => &result.union,
+        }
+    }
+
+    /// Which result panels are shown in the grid, indexed by [`ResultsPanel::index`]
+    ///
+    /// Empty categories are hidden in the grid view when `hide_empty_panels` is
+    /// on, unless that would hide every panel.
+    pub fn visible_panels(&self) -> [bool; 4] {
+        if self.view == ResultsView::Keyed {
+            return [true, true, true, false];
+        }
+        if !self.hide_empty_panels || self.view != ResultsView::Grid {
+            return [true; 4];
+        }
+        let visible = ResultsPanel::ALL.map(|panel| !self.items(panel).is_empty());
+        if visible.contains(&true) {
+            visible
+        } else {
+            [true; 4]
+        }
+    }
+
+    /// Whether a panel can be focused in the current view
+    pub fn is_reachable(&self, panel: ResultsPanel) -> bool {
+        self.panels().contains(&panel) && self.visible_panels()[panel.index()]
+    }
+
+    /// Advance the focused panel by `offset`, wrapping around and skipping hidden panels
+    pub fn step_panel(&mut self, offset: usize) {
+        let panels = self.panels();
+        let mut position = panels.iter().position(|p| *p == self.panel).unwrap_or(0);
+        for _ in 0..panels.len() {
+            position = (position + offset) % panels.len();
+            self.panel = panels[position];
+            if self.is_reachable(self.panel) {
+                break;
+            }
+        }
+    }
+
+    /// Replace the comparison results, listing them in each panel's display order
+    ///
+    /// The header row is taken from the new results, split by `record_separator`.
+    pub fn set_compare_results(&mut self, result: Option<CompareResult>) {
+        self.record_header = result
+            .as_ref()
+            .and_then(CompareResult::first_shared_item)
+            .zip(self.record_separator)
+            .filter(|(item, separator)| looks_like_header(item, *separator))
+            .map(|(item, _)| item.to_string());
+        self.compare_results = result;
+        self.table_columns = None;
+        for panel in ResultsPanel::ALL {
+            self.apply_sort(panel);
+        }
+    }
+
+    /// Switch a panel to the next display order, returning the new order
+    pub fn cycle_sort(&mut self, panel: ResultsPanel) -> ResultSort {
+        let sort = &mut self.sort[panel.index()];
+        *sort = sort.next();
+        let sort = *sort;
+        self.apply_sort(panel);
+        self.selection[panel.index()] = 0;
+        sort
+    }
+
+    fn apply_sort(&mut self, panel: ResultsPanel) {
+        let sorted = self.compare_results.as_ref().and_then(|result| {
+            sort_results(
+                self.stored_items(panel),
+                self.sort[panel.index()],
+                &result.occurrences,
+            )
+        });
+        self.sorted[panel.index()] = sorted;
+    }
+
+    /// Move focus off a panel the current view hides
+    pub fn ensure_visible_panel(&mut self) {
+        if !self.panels().contains(&self.panel) {
+            self.panel = self.panels()[0];
+        }
+        if !self.is_reachable(self.panel) {
+            self.step_panel(1);
+        }
+    }
+
+    /// Select a row in the focused panel, clamped to its items
+    pub fn select(&mut self, index: usize) {
+        let last = self.items(self.panel).len().saturating_sub(1);
+        self.selection[self.panel.index()] = index.min(last);
+    }
+
+    /// Selected row of the focused panel
+    pub fn selected(&self) -> usize {
+        self.selection[self.panel.index()]
+    }
+
+    /// Whether the results shown in the current view are outdated
+    pub fn is_stale(&self) -> bool {
+        match self.view {
+            ResultsView::Verdict => self.verdict_stale,
+            ResultsView::Keyed => self.keyed_stale,
+            _ => self.stale,
+        }
+    }
+
+    /// Show a fresh verdict report in the Verdict view
+    pub fn show_verdict(&mut self, report: VerdictReport) {
+        self.verdict = Some(report);
+        self.verdict_stale = false;
+        self.table_columns = None;
+        self.view = ResultsView::Verdict;
+        self.selection[0] = 0;
+        self.ensure_visible_panel();
+    }
+
+    /// Show a fresh keyed report in the Keyed view
+    pub fn show_keyed(&mut self, report: KeyedReport) {
+        self.keyed = Some(report);
+        self.keyed_stale = false;
+        self.table_columns = None;
+        self.view = ResultsView::Keyed;
+        self.selection = [0; 4];
+        self.ensure_visible_panel();
+    }
+
+    /// Cycle the result view modes (Grid -> Unified Diff -> Differences)
+    pub fn toggle_view(&mut self) {
+        match self.view {
+            // The verdict rows borrowed the first panel's selection
+            ResultsView::Verdict => self.selection[0] = 0,
+            ResultsView::Keyed => self.selection = [0; 4],
+            _ => {}
+        }
+        self.view = self.view.next();
+        self.ensure_visible_panel();
+    }
+
+    /// Collapse or restore empty result panels
+    pub fn toggle_hide_empty_panels(&mut self) {
+        self.hide_empty_panels = !self.hide_empty_panels;
+        self.ensure_visible_panel();
+    }
+
+    /// Separator to render results as a table with, if records were detected and the table view is on
+    ///
+    /// Verdict and keyed rows are always records.
+    pub fn table_separator(&self) -> Option<char> {
+        match self.view {
+            ResultsView::Verdict => return self.table_view.then_some(VERDICT_SEPARATOR),
+            ResultsView::Keyed => return self.table_view.then_some(KEYED_SEPARATOR),
+            _ => {}
+        }
+        self.record_separator
+            .filter(|_| self.table_view && self.view != ResultsView::Unified)
+    }
+
+    /// Header row of the table, for the category views when one was detected
+    pub fn table_header(&self) -> Option<&str> {
+        match self.view {
+            ResultsView::Verdict | ResultsView::Keyed => None,
+            _ => self.record_header.as_deref(),
+        }
+    }
+
+    /// Measure the table columns of every panel, unless the current view was measured already
+    ///
+    /// Widths span all items rather than the rows on screen, so columns keep
+    /// their width while scrolling.
+    pub fn refresh_table_columns(&mut self) {
+        let Some(separator) = self.table_separator() else {
+            return;
+        };
+        if self
+            .table_columns
+            .as_ref()
+            .is_some_and(|columns| columns.view == self.view && columns.separator == separator)
+        {
+            return;
+        }
+        let widths = ResultsPanel::ALL.map(|panel| column_widths(self.items(panel), separator));
+        self.table_columns = Some(TableColumns {
+            view: self.view,
+            separator,
+            widths,
+        });
+    }
+
+    /// Column widths of a panel's table as last measured by [`ResultsTab::refresh_table_columns`]
+    pub fn table_column_widths(&self, panel: ResultsPanel) -> &[usize] {
+        self.table_columns
+            .as_ref()
+            .map_or(&[], |columns| &columns.widths[panel.index()])
+    }
+
+    /// Switch between table and raw line rendering of record-style results
+    pub fn toggle_table_view(&mut self) {
+        self.table_view = !self.table_view;
+    }
+
+    /// Widen or narrow table columns, switching from auto-fit to a fixed width
+    pub fn resize_table_columns(&mut self, delta: i16) {
+        let current = if self.table_column_width == 0 {
+            DEFAULT_TABLE_COLUMN_WIDTH
+        } else {
+            self.table_column_width
+        };
+        let width = current as i16 + delta;
+        self.table_column_width =
+            width.clamp(MIN_TABLE_COLUMN_WIDTH as i16, MAX_TABLE_COLUMN_WIDTH as i16) as u16;
+    }
+
+    /// Go back to fitting each table column to its content
+    pub fn auto_fit_table_columns(&mut self) {
+        self.table_column_width = 0;
+    }
+
+    /// Move the column split of the grid row holding the focused panel
+    pub fn resize_grid_columns(&mut self, direction: i16) {
+        self.grid_layout
+            .resize_columns(self.panel.index(), direction);
+    }
+
+    /// Move the split between the top and bottom grid rows
+    pub fn resize_grid_rows(&mut self, direction: i16) {
+        self.grid_layout.resize_rows(direction);
+    }
+}
+
+/// Most conversions kept in the converter history; the oldest is dropped first
+pub const CONVERT_HISTORY_LIMIT: usize = 10;
+
+/// Input and output of one conversion
+#[derive(Debug, Clone)]
+pub struct ConvertSnapshot {
+    /// Input lines that were converted
+    pub input: Vec<String>,
+    /// Output items
+    pub output_items: Vec<String>,
+    /// Serialized output
+    pub output_serialized: String,
+    /// Number of items produced
+    pub item_count: usize,
+}
+/// State of the Convert tab
+pub struct ConvertTab {
+    /// Converter input text area
+    pub input: TextArea<'static>,
+    /// Converter output items (displayed as lines)
+    pub output_items: Vec<String>,
+    /// Serialized converter output with target delimiter (for saving)
+    pub output_serialized: String,
+    /// Number of items produced by the last conversion
+    pub item_count: usize,
+    /// Whether the output panel pads columns into an aligned preview
+    pub aligned_preview: bool,
+    /// Output items padded into columns, kept by [`ConvertTab::refresh_aligned_preview`]
+    /// (`None` when the aligned preview does not apply)
+    pub aligned_output: Option<Vec<String>>,
+    /// Whether comma and semicolon output quotes fields per RFC 4180
+    pub quote_fields: bool,
+    /// Text written around every converted list item
+    pub wrap: ItemWrap,
+    /// Language the items are written as a list literal for (`None` joins them with the target)
+    pub literal: Option<LiteralTarget>,
+    /// Whether the output is a Markdown table of the input records, overriding the target
+    pub markdown: bool,
+    /// Whether input lines are kept as rows and only the field separator is converted
+    pub table_mode: bool,
+    /// Column widths slicing the input rows into fields (`None` unless fixed-width)
+    pub fixed_widths: Option<Vec<usize>>,
+    /// What to extract from `key=value` / `key: value` lines (`None` unless key-value mode)
+    pub key_value: Option<KeyValueOutput>,
+    /// Delimiter the input is parsed with
+    pub source_delimiter: Delimiter,
+    /// Source delimiter detected in the last paste or load, until cycled by hand
+    pub detected_source: Option<Delimiter>,
+    /// Source delimiter offered after a paste or load parsed into a single item
+    pub suggested_source: Option<Delimiter>,
+    /// Delimiter the output is written with
+    pub target_delimiter: Delimiter,
+    /// How JSON arrays mixing objects and other values are converted
+    pub json_strategy: JsonArrayStrategy,
+    /// Path of the values extracted from a JSON source (`None` converts whole objects)
+    pub json_path: Option<JsonPath>,
+    /// How objects and arrays nested in JSON objects are written in CSV rows
+    pub nested_json: NestedJson,
+    /// Repaired JSON awaiting acceptance (`None` unless the last conversion repaired its input)
+    pub pending_repair: Option<String>,
+    /// INFO lines summarizing the fields of the last JSON input
+    pub json_summary: Vec<String>,
+    /// Previous conversions, oldest first
+    pub history: Vec<ConvertSnapshot>,
+    /// Index in `history` of the conversion shown
+    pub history_pos: usize,
+    /// Focused panel
+    pub panel: ConvertPanel,
+}
+
+impl Default for ConvertTab {
+    fn default() -> Self {
+        Self {
+            input: TextArea::default(),
+            output_items: Vec::new(),
+            output_serialized: String::new(),
+            item_count: 0,
+            aligned_preview: false,
+            aligned_output: None,
+            quote_fields: false,
+            wrap: ItemWrap::default(),
+            literal: None,
+            markdown: false,
+            table_mode: false,
+            fixed_widths: None,
+            key_value: None,
+            json_strategy: JsonArrayStrategy::default(),
+            json_path: None,
+            nested_json: NestedJson::default(),
+            pending_repair: None,
+            json_summary: Vec::new(),
+            history: Vec::new(),
+            history_pos: 0,
+            source_delimiter: Delimiter::Newline,
+            detected_source: None,
+            suggested_source: None,
+            target_delimiter: Delimiter::Comma,
+            panel: ConvertPanel::Input,
+        }
+    }
+}
+
+impl ConvertTab {
+    /// Cycle the source delimiter
+    pub fn cycle_source_delimiter(&mut self) {
+        self.source_delimiter = self.source_delimiter.next();
+        self.detected_source = None;
+        self.suggested_source = None;
+    }
+
+    /// Cycle the target delimiter
+    pub fn cycle_target_delimiter(&mut self) {
+        self.target_delimiter = self.target_delimiter.next();
+        self.refresh_aligned_preview();
+    }
+
+    /// Name of what the output is written as: a Markdown table, the list literal or the target delimiter
+    pub fn target_name(&self) -> &str {
+        if self.markdown {
+            return "Markdown table";
+        }
+        match self.literal {
+            Some(literal) => literal.display_name(),
+            None => self.target_delimiter.display_name(),
+        }
+    }
+
+    /// Discard the converter output
+    pub fn clear_output(&mut self) {
+        self.output_items.clear();
+        self.output_serialized.clear();
+        self.item_count = 0;
+        self.aligned_output = None;
+        self.pending_repair = None;
+        self.json_summary.clear();
+    }
+
+    /// Remember a successful conversion of `input` and its current output
+    pub fn record_history(&mut self, input: Vec<String>) {
+        if self.output_items.is_empty() {
+            return;
+        }
+        if self.history.len() == CONVERT_HISTORY_LIMIT {
+            self.history.remove(0);
+        }
+        self.history.push(ConvertSnapshot {
+            input,
+            output_items: self.output_items.clone(),
+            output_serialized: self.output_serialized.clone(),
+            item_count: self.item_count,
+        });
+        self.history_pos = self.history.len() - 1;
+    }
+
+    /// Move `delta` conversions through the history (negative is older)
+    ///
+    /// Returns the conversion to restore, or `None` at either end.
+    pub fn step_history(&mut self, delta: isize) -> Option<&ConvertSnapshot> {
+        let pos = self.history_pos.checked_add_signed(delta)?;
+        let snapshot = self.history.get(pos)?;
+        self.history_pos = pos;
+        Some(snapshot)
+    }
+
+    /// Switch the output between raw rows and an aligned preview
+    pub fn toggle_aligned_preview(&mut self) {
+        self.aligned_preview = !self.aligned_preview;
+        self.refresh_aligned_preview();
+    }
+
+    /// Pad the output into `aligned_output` once, rather than on every frame
+    ///
+    /// Called whenever the output, the target delimiter or the toggle changes.
+    pub fn refresh_aligned_preview(&mut self) {
+        self.aligned_output = self
+            .aligned_preview_separator()
+            .map(|separator| align_columns(&self.output_items, separator));
+    }
+
+    /// Switch RFC 4180 quoting of the output fields on or off
+    pub fn toggle_quote_fields(&mut self) {
+        self.quote_fields = !self.quote_fields;
+    }
+
+    /// Switch between flattening the input into items and converting it row by row
+    pub fn toggle_table_mode(&mut self) {
+        self.table_mode = !self.table_mode;
+    }
+
+    /// Whether fields are quoted for the current target delimiter
+    ///
+    /// Only comma and semicolon output is CSV, so other targets are never quoted.
+    /// The quoted CSV delimiters always quote.
+    pub fn quotes_output(&self) -> bool {
+        self.target_delimiter.is_quoted()
+            || (self.quote_fields
+                && matches!(
+                    self.target_delimiter,
+                    Delimiter::Comma | Delimiter::Semicolon
+                ))
+    }
+
+    /// Separator to align the output on, if the aligned preview applies
+    ///
+    /// Only multi-row tab, comma or semicolon output is aligned.
+    pub fn aligned_preview_separator(&self) -> Option<char> {
+        let applies = self.aligned_preview
+            && self.output_items.len() > 1
+            && matches!(
+                self.target_delimiter,
+                Delimiter::Tab | Delimiter::Comma | Delimiter::Semicolon
+            );
+        applies.then(|| self.target_delimiter.as_char())
+    }
+}
+
+/// State of the Analysis tab
+#[derive(Default)]
+pub struct AnalysisTab {
+    /// List being analyzed
+    pub source: AnalysisSource,
+    /// Rendered lines of each panel, indexed by [`AnalysisPanel::index`]
+    pub lines: [Vec<String>; 4],
+    /// Number of items in the last analysis (`None` before the first one)
+    pub item_count: Option<usize>,
+    /// Whether the source changed since the last analysis
+    pub stale: bool,
+    /// Selected row in each panel, indexed by [`AnalysisPanel::index`]
+    pub selection: [usize; 4],
+    /// Focused panel
+    pub panel: AnalysisPanel,
+}
+
+impl AnalysisTab {
+    /// Lines of a panel
+    pub fn lines(&self, panel: AnalysisPanel) -> &[String] {
+        &self.lines[panel.index()]
+    }
+
+    /// Switch to the next source list
+    pub fn cycle_source(&mut self) {
+        self.source = self.source.next();
+        self.stale = self.item_count.is_some();
+    }
+
+    /// Select a row in the focused panel, clamped to its lines
+    pub fn select(&mut self, index: usize) {
+        let last = self.lines(self.panel).len().saturating_sub(1);
+        self.selection[self.panel.index()] = index.min(last);
+    }
+
+    /// Selected row of the focused panel
+    pub fn selected(&self) -> usize {
+        self.selection[self.panel.index()]
+    }
+}
+
+/// State of the Merge tab
+#[derive(Default)]
+pub struct MergeTab {
+    /// How the rows of both lists are combined
+    pub join_type: JoinType,
+    /// Key column of List 1 (0-based)
+    pub left_key: usize,
+    /// Key column of List 2 (0-based)
+    pub right_key: usize,
+    /// Field separator (`None` detects it from List 1)
+    pub separator: Option<char>,
+    /// Settings and preview lines shown in the Settings panel
+    pub settings: Vec<String>,
+    /// Rows of the last join
+    pub rows: Vec<String>,
+    /// Number of rows in the last join (`None` before the first one)
+    pub row_count: Option<usize>,
+    /// Whether the lists or settings changed since the last join
+    pub stale: bool,
+    /// Selected row in each panel, indexed by [`MergePanel::index`]
+    pub selection: [usize; 2],
+    /// Focused panel
+    pub panel: MergePanel,
+}
+
+impl MergeTab {
+    /// Lines of a panel
+    pub fn lines(&self, panel: MergePanel) -> &[String] {
+        match panel {
+            MergePanel::Settings => &self.settings,
+            MergePanel::Result => &self.rows,
+        }
+    }
+
+    /// Switch to the next join type
+    pub fn cycle_join_type(&mut self) {
+        self.join_type = self.join_type.next();
+        self.mark_stale();
+    }
+
+    /// Move the List 1 (`left`) or List 2 key column by `delta`, stopping at the first column
+    pub fn shift_key(&mut self, left: bool, delta: i16) {
+        let key = if left {
+            &mut self.left_key
+        } else {
+            &mut self.right_key
+        };
+        *key = key.saturating_add_signed(delta as isize);
+        self.mark_stale();
+    }
+
+    /// Switch to the next field separator: auto-detect, then each record separator
+    pub fn cycle_separator(&mut self) {
+        self.separator = match self.separator {
+            None => Some(RECORD_SEPARATORS[0]),
+            Some(sep) => RECORD_SEPARATORS
+                .iter()
+                .position(|&s| s == sep)
+                .and_then(|i| RECORD_SEPARATORS.get(i + 1))
+                .copied(),
+        };
+        self.mark_stale();
+    }
+
+    /// Flag the last join as outdated, if there is one
+    pub fn mark_stale(&mut self) {
+        self.stale = self.row_count.is_some();
+    }
+
+    /// Select a row in the focused panel, clamped to its lines
+    pub fn select(&mut self, index: usize) {
+        let last = self.lines(self.panel).len().saturating_sub(1);
+        self.selection[self.panel.index()] = index.min(last);
+    }
+
+    /// Selected row of the focused panel
+    pub fn selected(&self) -> usize {
+        self.selection[self.panel.index()]
+    }
+}
@@ -0,0 +1,181 @@
+//! Background tasks: long comparisons, sampled estimates and file loads run on a worker thread
+//!
+//! The worker reports its progress over a channel and checks a cancel flag
+//! between steps, so the UI keeps drawing (and `Esc` stays responsive) while
+//! it runs. The output is applied to the app on the main thread once the
+//! worker sends it.
+use super::PanelId;
+use crate::i18n::Text;
+use crate::operations::{CompareResult, SampleEstimate};
+use std::fs::File;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{self, Receiver, Sender, TryRecvError};
+use std::sync::Arc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// Default combined item count from which F12 compares (or samples) on a worker thread
+/// (`LIST_UTILS_BACKGROUND_ABOVE`)
+pub const DEFAULT_BACKGROUND_ABOVE: usize = 50_000;
+/// File size in bytes from which a load reads the file on a worker thread
+pub const BACKGROUND_LOAD_BYTES: u64 = 8 * 1024 * 1024;
+/// How often the screen is redrawn while a task runs
+pub const TASK_REFRESH: Duration = Duration::from_millis(200);
+/// Bytes read by a background load between progress reports
+const LOAD_CHUNK: usize = 1024 * 1024;
+
+/// What a finished task hands back to the main thread
+#[derive(Debug)]
+pub enum TaskOutput {
+    /// List 1 compared with List 2
+    Compare {
+        result: Box<CompareResult>,
+        /// Rows lacking the compare column
+        missing: usize,
+        /// Items rejected by the normalization preset
+        invalid: usize,
+    },
+    /// Overlap of List 1 and List 2 estimated on a sample
+    Sample {
+        estimate: SampleEstimate,
+        /// Items in both lists together
+        total: usize,
+    },
+    /// The bytes of a file read for an editor, or why reading failed
+    Load {
+        panel: PanelId,
+        path: PathBuf,
+        bytes: io::Result<Vec<u8>>,
+    },
+}
+
+/// Message sent by the worker
+enum TaskUpdate {
+    Progress(u8),
+    Done(TaskOutput),
+}
+
+/// Handle given to the worker for reporting progress and noticing cancellation
+pub struct Progress {
+    updates: Sender<TaskUpdate>,
+    cancel: Arc<AtomicBool>,
+}
+
+impl Progress {
+    /// Report that `done` of `total` steps are finished, returning whether to go on
+    pub fn report(&self, done: usize, total: usize) -> bool {
+        let percent = (done.min(total) * 100).checked_div(total).unwrap_or(100);
+        // The receiver is gone once the task was cancelled
+        let _ = self.updates.send(TaskUpdate::Progress(percent as u8));
+        !self.cancelled()
+    }
+
+    /// Whether the task was cancelled
+    pub fn cancelled(&self) -> bool {
+        self.cancel.load(Ordering::Relaxed)
+    }
+}
+
+/// State of a task after [`BackgroundTask::poll`]
+#[derive(Debug)]
+pub enum TaskState {
+    /// Still working
+    Running,
+    /// Finished with its output
+    Done(TaskOutput),
+    /// Stopped without output (cancelled, or the worker failed)
+    Stopped,
+}
+
+/// A task running on a worker thread
+pub struct BackgroundTask {
+    /// What the task does, shown in the status bar
+    pub name: Text,
+    started: Instant,
+    percent: u8,
+    cancel: Arc<AtomicBool>,
+    updates: Receiver<TaskUpdate>,
+}
+
+impl BackgroundTask {
+    /// Run `work` on a new thread; it returns `None` when it stopped early
+    pub fn spawn<F>(name: Text, work: F) -> Self
+    where
+        F: FnOnce(&Progress) -> Option<TaskOutput> + Send + 'static,
+    {
+        let (sender, updates) = mpsc::channel();
+        let cancel = Arc::new(AtomicBool::new(false));
+        let progress = Progress {
+            updates: sender,
+            cancel: Arc::clone(&cancel),
+        };
+        thread::spawn(move || {
+            if let Some(output) = work(&progress) {
+                let _ = progress.updates.send(TaskUpdate::Done(output));
+            }
+        });
+        Self {
+            name,
+            started: Instant::now(),
+            percent: 0,
+            cancel,
+            updates,
+        }
+    }
+
+    /// Take the progress reported so far, and the output once the worker sent it
+    pub fn poll(&mut self) -> TaskState {
+        loop {
+            match self.updates.try_recv() {
+                Ok(TaskUpdate::Progress(percent)) => self.percent = percent,
+                Ok(TaskUpdate::Done(output)) => return TaskState::Done(output),
+                Err(TryRecvError::Empty) => return TaskState::Running,
+                Err(TryRecvError::Disconnected) => return TaskState::Stopped,
+            }
+        }
+    }
+
+    /// Ask the worker to stop at its next step
+    pub fn cancel(&self) {
+        self.cancel.store(true, Ordering::Relaxed);
+    }
+
+    /// Percent of the work reported done
+    pub fn percent(&self) -> u8 {
+        self.percent
+    }
+
+    /// Time since the task started
+    pub fn elapsed(&self) -> Duration {
+        self.started.elapsed()
+    }
+}
+
+/// Read the file at `path` of `size` bytes, reporting the bytes read so far
+///
+/// Returns `None` when the task was cancelled.
+pub fn read_with_progress(
+    path: &Path,
+    size: u64,
+    progress: &Progress,
+) -> Option<io::Result<Vec<u8>>> {
+    let mut file = match File::open(path) {
+        Ok(file) => file,
+        Err(err) => return Some(Err(err)),
+    };
+    let mut bytes = Vec::with_capacity(size as usize);
+    let mut chunk = vec![0; LOAD_CHUNK];
+    loop {
+        match file.read(&mut chunk) {
+            Ok(0) => return Some(Ok(bytes)),
+            Ok(read) => bytes.extend_from_slice(&chunk[..read]),
+            Err(err) if err.kind() == io::ErrorKind::Interrupted => continue,
+            Err(err) => return Some(Err(err)),
+        }
+        if !progress.report(bytes.len(), size as usize) {
+            return None;
+        }
+    }
+}
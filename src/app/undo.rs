@@ -0,0 +1,119 @@
+//! Snapshots of editors taken before an operation rewrites them
+//!
+//! Operations that replace an editor's text call [`App::save_undo`] first;
+//! `u` or Ctrl+Z restores the most recent snapshot and Ctrl+Y puts the undone
+//! change back.
+//!
+//! Each stack keeps at most `undo_depth` snapshots and `undo_budget` bytes of
+//! text; the oldest snapshots are dropped first, so undoing a few sorts of a
+//! huge list cannot hold many copies of it.
+use super::{App, AppEvent, PanelId};
+
+/// Snapshots kept per workspace unless the config file sets `undo_depth`
+pub const DEFAULT_UNDO_DEPTH: u16 = 20;
+
+/// Megabytes of snapshots kept per workspace unless the config file sets `undo_memory_mb`
+pub const DEFAULT_UNDO_MEMORY_MB: u16 = 64;
+
+/// Text of an editor before an operation replaced it
+#[derive(Debug, Clone)]
+pub struct Snapshot {
+    /// Editor the text belongs to
+    pub panel: PanelId,
+    /// Lines of the editor
+    pub lines: Vec<String>,
+}
+
+impl Snapshot {
+    /// Approximate heap memory held by the lines, in bytes
+    pub fn size(&self) -> usize {
+        self.lines
+            .iter()
+            .map(|line| line.len() + std::mem::size_of::<String>())
+            .sum()
+    }
+}
+
+/// Push `snapshot` onto `stack`, then drop the oldest snapshots until at most
+/// `depth` are left and they fit in `budget` bytes (0 for no budget)
+///
+/// The newest snapshot is kept even when it alone is over the budget, so the
+/// last change can always be undone unless `depth` is 0.
+fn push_limited(stack: &mut Vec<Snapshot>, snapshot: Snapshot, depth: usize, budget: usize) {
+    stack.push(snapshot);
+    let mut size: usize = stack.iter().map(Snapshot::size).sum();
+    let mut evicted = 0;
+    while stack.len() - evicted > depth
+        || (budget > 0 && size > budget && stack.len() - evicted > 1)
+    {
+        size -= stack[evicted].size();
+        evicted += 1;
+    }
+    stack.drain(..evicted);
+}
+
+impl App {
+    /// Remember the current text of `panel` so the next change to it can be undone
+    ///
+    /// A new change makes the undone ones impossible to redo.
+    pub fn save_undo(&mut self, panel: PanelId) {
+        let snapshot = self.snapshot(panel);
+        push_limited(
+            &mut self.undo_stack,
+            snapshot,
+            self.undo_depth,
+            self.undo_budget,
+        );
+        self.redo_stack.clear();
+    }
+
+    /// Editor the next undo would restore
+    pub fn undo_panel(&self) -> Option<PanelId> {
+        self.undo_stack.last().map(|snapshot| snapshot.panel)
+    }
+
+    /// Editor the next redo would restore
+    pub fn redo_panel(&self) -> Option<PanelId> {
+        self.redo_stack.last().map(|snapshot| snapshot.panel)
+    }
+
+    /// Restore the most recent snapshot, returning the editor it was restored into
+    pub fn undo(&mut self) -> Option<PanelId> {
+        let snapshot = self.undo_stack.pop()?;
+        let current = self.snapshot(snapshot.panel);
+        push_limited(
+            &mut self.redo_stack,
+            current,
+            self.undo_depth,
+            self.undo_budget,
+        );
+        Some(self.restore(snapshot))
+    }
+
+    /// Put back the most recently undone change, returning the editor it was restored into
+    pub fn redo(&mut self) -> Option<PanelId> {
+        let snapshot = self.redo_stack.pop()?;
+        let current = self.snapshot(snapshot.panel);
+        push_limited(
+            &mut self.undo_stack,
+            current,
+            self.undo_depth,
+            self.undo_budget,
+        );
+        Some(self.restore(snapshot))
+    }
+
+    fn snapshot(&mut self, panel: PanelId) -> Snapshot {
+        let lines = self.textarea(panel).lines().to_vec();
+        Snapshot { panel, lines }
+    }
+
+    fn restore(&mut self, Snapshot { panel, lines }: Snapshot) -> PanelId {
+        let textarea = self.textarea(panel);
+        textarea.select_all();
+        textarea.cut();
+        textarea.insert_str(lines.join("\n"));
+        self.publish(AppEvent::PanelEdited(panel));
+        panel
+    }
+}
@@ -0,0 +1,97 @@
+//! Watch mode: compare two files on disk again whenever they change
+//!
+//! The files are checked every few seconds; when either modification time
+//! moved they are loaded and compared again, and a change counter records
+//! whether the results differ from the previous run.
+use crate::operations::CompareResult;
+use std::collections::hash_map::DefaultHasher;
+use std::fs;
+use std::hash::{Hash, Hasher};
+use std::path::PathBuf;
+use std::time::{Duration, Instant, SystemTime};
+
+/// Default seconds between checks of the watched files (`LIST_UTILS_WATCH_SECS`)
+pub const DEFAULT_WATCH_SECS: u64 = 5;
+
+/// A pair of files compared again when they change
+#[derive(Debug, Clone)]
+pub struct Watch {
+    /// File loaded into List 1
+    pub first: PathBuf,
+    /// File loaded into List 2
+    pub second: PathBuf,
+    /// Time between checks
+    pub interval: Duration,
+    /// Runs whose results differed from the run before
+    pub changes: usize,
+    /// When the results last changed
+    pub changed_at: Option<SystemTime>,
+    /// Whether the results changed since the last key press
+    pub unseen: bool,
+    /// Modification times of the files at the last run
+    modified: [Option<SystemTime>; 2],
+    /// When the files are checked next
+    next_check: Instant,
+    /// Hash of the differences found by the last run
+    fingerprint: Option<u64>,
+}
+
+impl Watch {
+    /// Watch `first` and `second`, taking their current state as already compared
+    pub fn new(first: PathBuf, second: PathBuf, interval: Duration) -> Self {
+        let mut watch = Self {
+            first,
+            second,
+            interval,
+            changes: 0,
+            changed_at: None,
+            unseen: false,
+            modified: [None, None],
+            next_check: Instant::now() + interval,
+            fingerprint: None,
+        };
+        watch.modified = watch.modification_times();
+        watch
+    }
+
+    fn modification_times(&self) -> [Option<SystemTime>; 2] {
+        [&self.first, &self.second]
+            .map(|path| fs::metadata(path).and_then(|meta| meta.modified()).ok())
+    }
+
+    /// Time left until the next check
+    pub fn timeout(&self, now: Instant) -> Duration {
+        self.next_check.saturating_duration_since(now)
+    }
+
+    /// Whether the interval elapsed and either file changed since the last run
+    pub fn due(&mut self, now: Instant) -> bool {
+        if now < self.next_check {
+            return false;
+        }
+        self.next_check = now + self.interval;
+        let modified = self.modification_times();
+        if modified == self.modified {
+            return false;
+        }
+        self.modified = modified;
+        true
+    }
+
+    /// Record the results of a run, returning whether they differ from the previous run
+    pub fn record(&mut self, result: &CompareResult) -> bool {
+        let mut hasher = DefaultHasher::new();
+        result.only_in_first.hash(&mut hasher);
+        result.only_in_second.hash(&mut hasher);
+        result.intersection.hash(&mut hasher);
+        let fingerprint = hasher.finish();
+        let changed = self.fingerprint.is_some_and(|last| last != fingerprint);
+        self.fingerprint = Some(fingerprint);
+        if changed {
+            self.changes += 1;
+            self.changed_at = Some(SystemTime::now());
+            self.unseen = true;
+        }
+        changed
+    }
+}
@@ -0,0 +1,118 @@
+//! Independent comparison sessions switched with Alt+Shift+number
+//!
+//! The active session lives directly in [`App`]'s fields so the rest of the
+//! code never has to know about workspaces; switching parks those fields in a
+//! [`Workspace`] and restores another one in their place.
+use super::{
+    AnalysisTab, App, ConvertTab, InputTab, LiveCounts, MergeTab, PanelId, ResultsTab, Snapshot,
+    Tab, Watch,
+};
+use crate::operations::CompareOptions;
+use crate::parser::{Delimiter, LineEnding};
+use std::mem;
+use tui_textarea::TextArea;
+
+/// Number of workspaces (Alt+Shift+1 to Alt+Shift+4)
+pub const WORKSPACE_COUNT: usize = 4;
+
+/// Lists, results and settings of one parked session
+pub struct Workspace {
+    pub input_tab: InputTab,
+    pub results_tab: ResultsTab,
+    pub convert_tab: ConvertTab,
+    pub analysis_tab: AnalysisTab,
+    pub merge_tab: MergeTab,
+    pub single_list: TextArea<'static>,
+    pub delimiter: Delimiter,
+    pub compare_options: CompareOptions,
+    pub active_tab: Tab,
+    pub results: Vec<String>,
+    pub live_counts: LiveCounts,
+    pub read_only: Vec<PanelId>,
+    pub undo_stack: Vec<Snapshot>,
+    pub redo_stack: Vec<Snapshot>,
+    pub loaded_line_endings: Vec<(PanelId, LineEnding)>,
+    pub sample_previewed: bool,
+    pub watch: Option<Watch>,
+}
+
+impl Default for Workspace {
+    fn default() -> Self {
+        Self {
+            input_tab: InputTab::default(),
+            results_tab: ResultsTab::default(),
+            convert_tab: ConvertTab::default(),
+            analysis_tab: AnalysisTab::default(),
+            merge_tab: MergeTab::default(),
+            single_list: TextArea::default(),
+            delimiter: Delimiter::Newline,
+            compare_options: CompareOptions::default(),
+            active_tab: Tab::Input,
+            results: Vec::new(),
+            live_counts: LiveCounts::default(),
+            read_only: Vec::new(),
+            undo_stack: Vec::new(),
+            redo_stack: Vec::new(),
+            loaded_line_endings: Vec::new(),
+            sample_previewed: false,
+            watch: None,
+        }
+    }
+}
+
+impl App {
+    /// Park the active session and restore workspace `index` (0-based)
+    ///
+    /// A workspace that was never opened starts empty. The results grid layout
+    /// and the normalization preferences (trimmed characters, typographic
+    /// folding, normalization preset, numeric tolerance, smart sort) are not session state, so they carry over.
+    /// Returns `false` if `index` is out of range or already active.
+    pub fn switch_workspace(&mut self, index: usize) -> bool {
+        if index >= WORKSPACE_COUNT || index == self.workspace {
+            return false;
+        }
+
+        let mut next = self.workspaces[index].take().unwrap_or_else(|| Workspace {
+            compare_options: CompareOptions {
+                trim_set: self.compare_options.trim_set,
+                fold_typography: self.compare_options.fold_typography,
+                normalize: self.compare_options.normalize,
+                tolerance: self.compare_options.tolerance,
+                smart_sort: self.compare_options.smart_sort,
+                ..CompareOptions::default()
+            },
+            ..Workspace::default()
+        });
+        next.results_tab.grid_layout = self.results_tab.grid_layout;
+        let previous = Workspace {
+            input_tab: mem::replace(&mut self.input_tab, next.input_tab),
+            results_tab: mem::replace(&mut self.results_tab, next.results_tab),
+            convert_tab: mem::replace(&mut self.convert_tab, next.convert_tab),
+            analysis_tab: mem::replace(&mut self.analysis_tab, next.analysis_tab),
+            merge_tab: mem::replace(&mut self.merge_tab, next.merge_tab),
+            single_list: mem::replace(&mut self.single_list, next.single_list),
+            delimiter: mem::replace(&mut self.delimiter, next.delimiter),
+            compare_options: mem::replace(&mut self.compare_options, next.compare_options),
+            active_tab: mem::replace(&mut self.active_tab, next.active_tab),
+            results: mem::replace(&mut self.results, next.results),
+            live_counts: mem::replace(&mut self.live_counts, next.live_counts),
+            read_only: mem::replace(&mut self.read_only, next.read_only),
+            undo_stack: mem::replace(&mut self.undo_stack, next.undo_stack),
+            redo_stack: mem::replace(&mut self.redo_stack, next.redo_stack),
+            loaded_line_endings: mem::replace(
+                &mut self.loaded_line_endings,
+                next.loaded_line_endings,
+            ),
+            sample_previewed: mem::replace(&mut self.sample_previewed, next.sample_previewed),
+            watch: mem::replace(&mut self.watch, next.watch),
+        };
+        self.workspaces[self.workspace] = Some(previous);
+        self.workspace = index;
+        true
+    }
+
+    /// Whether workspace `index` holds a session (the active one always does)
+    pub fn is_workspace_open(&self, index: usize) -> bool {
+        index == self.workspace || self.workspaces.get(index).is_some_and(Option::is_some)
+    }
+}
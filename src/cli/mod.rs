@@ -0,0 +1,662 @@
+//! Non-interactive subcommands for shell scripts
+//!
+//! `list-utils compare a.txt b.txt --delim ,` runs the same comparison as F12
+//! and prints the results to stdout instead of starting the TUI. Exit codes
+//! follow `diff`: 0 when the lists hold the same items, 1 when they differ and
+//! 2 for usage or I/O errors. `diff-bundles` exits with 1 when the newer
+//! bundle has differences the older one did not.
+use crate::operations::{
+    compare_lists, detect_field_separator, process_single_list, CompareOptions, CompareResult,
+};
+use crate::parser::{
+    delimited_rows, extract_json_path, json_array_lines, parse_list_with, parse_structured_to_list,
+    quote_csv_field, rows_to_json_objects, Delimiter, EmptyItems, JsonArrayStrategy, JsonPath,
+    NestedJson,
+};
+use crate::report::{diff_bundles, ComparisonBundle, DiffSide};
+use std::fs;
+use std::io::{self, Read, Write};
+
+/// Exit code for usage and I/O errors
+const EXIT_ERROR: i32 = 2;
+
+pub const USAGE: &str = "\
+Usage:
+  list-utils                                  start the interactive UI
+  list-utils compare FILE1 FILE2 [options]    compare two lists
+  list-utils list FILE [options]              trim, dedup or sort one list
+  list-utils convert FILE [options]           change the delimiter of a list
+  list-utils diff-bundles OLD NEW             differences resolved and new
+                                              between two .lcmp bundles
+
+A FILE of - reads standard input. Items are printed one per line, except
+for convert.
+
+Options:
+  --delim D           delimiter of the input lists (default newline)
+  --collapse-empty    drop empty items
+  --case-sensitive    compare: match case
+  --no-trim           compare: keep surrounding spaces
+  --keep-order        compare: print items in input order instead of sorted
+  --only SECTION      compare: print only first, second, both or union
+  --trim              list: trim spaces
+  --dedup             list: remove duplicates
+  --sort asc|desc     list: sort (numerically when all items are numbers)
+  --from D            convert: source delimiter, json, jsonl or yaml (default newline)
+  --to D              convert: target delimiter, or json / jsonl to turn rows
+                      with a header into objects (default comma)
+  --quote             convert: quote CSV fields (RFC 4180)
+  --flatten           convert: expand nested JSON objects and arrays into
+                      parent.child columns
+  --path P            convert: only the values at a JSON path such as
+                      $.items[*].id (or a top-level key) of a JSON source
+
+Delimiters: newline (\\n), tab (\\t), comma (,), semicolon (;), and csv or
+csv; for comma or semicolon separated values with quoted fields.";
+
+/// Result section printed by `compare --only`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Section {
+    OnlyFirst,
+    OnlySecond,
+    Both,
+    Union,
+}
+
+/// Sort order for `list --sort`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SortOrder {
+    Asc,
+    Desc,
+}
+
+/// A parsed command line
+#[derive(Debug)]
+enum Command {
+    Help,
+    Compare {
+        first: String,
+        second: String,
+        options: CompareOptions,
+        only: Option<Section>,
+    },
+    List {
+        file: String,
+        trim: bool,
+        dedup: bool,
+        sort: Option<SortOrder>,
+    },
+    Convert {
+        file: String,
+        from: Delimiter,
+        to: Delimiter,
+        quote: bool,
+        nested: NestedJson,
+        path: Option<JsonPath>,
+    },
+    DiffBundles {
+        old: String,
+        new: String,
+    },
+}
+
+/// Flags shared by every subcommand plus the command itself
+#[derive(Debug)]
+struct Invocation {
+    command: Command,
+    delimiter: Delimiter,
+    empty: EmptyItems,
+}
+
+/// Run the subcommand named by the arguments (without the program name)
+///
+/// # Returns
+/// `None` when there are no arguments and the TUI should start, otherwise the
+/// process exit code.
+pub fn run(args: &[String]) -> Option<i32> {
+    if args.is_empty() {
+        return None;
+    }
+    let code = match parse_args(args) {
+        Ok(invocation) => match execute(&invocation, &mut io::stdout().lock()) {
+            Ok(code) => code,
+            // The reader went away (`| head`); nothing left to report
+            Err(CliError::Io(err)) if err.kind() == io::ErrorKind::BrokenPipe => 0,
+            Err(err) => {
+                eprintln!("list-utils: {}", err);
+                EXIT_ERROR
+            }
+        },
+        Err(message) => {
+            eprintln!("list-utils: {}\n\n{}", message, USAGE);
+            EXIT_ERROR
+        }
+    };
+    Some(code)
+}
+
+/// Errors while running a parsed command
+#[derive(Debug)]
+enum CliError {
+    /// A list could not be read
+    Read(String, io::Error),
+    /// JSON input could not be parsed
+    Json(String),
+    /// YAML input could not be parsed
+    Yaml(String),
+    /// Rows converted to JSON have no field separator in their header
+    NoFieldSeparator(String),
+    /// A comparison bundle could not be parsed
+    Bundle(String, String),
+    /// Writing the output failed
+    Io(io::Error),
+}
+
+impl std::fmt::Display for CliError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CliError::Read(path, err) => write!(f, "{}: {}", path, err),
+            CliError::Json(err) => write!(f, "invalid JSON: {}", err),
+            CliError::Yaml(err) => write!(f, "invalid YAML: {}", err),
+            CliError::NoFieldSeparator(path) => {
+                write!(f, "{}: no tab, |, ; or , in the header row", path)
+            }
+            CliError::Bundle(path, err) => write!(f, "{}: invalid bundle: {}", path, err),
+            CliError::Io(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+impl From<io::Error> for CliError {
+    fn from(err: io::Error) -> Self {
+        CliError::Io(err)
+    }
+}
+
+fn delimiter_arg(value: &str) -> Result<Delimiter, String> {
+    Delimiter::from_name(value).ok_or_else(|| format!("unknown delimiter '{}'", value))
+}
+
+fn parse_args(args: &[String]) -> Result<Invocation, String> {
+    let mut args = args.iter();
+    let name = args.next().map(String::as_str).unwrap_or_default();
+    if matches!(name, "help" | "-h" | "--help") {
+        return Ok(Invocation {
+            command: Command::Help,
+            delimiter: Delimiter::Newline,
+            empty: EmptyItems::Keep,
+        });
+    }
+    if !matches!(name, "compare" | "list" | "convert" | "diff-bundles") {
+        return Err(format!("unknown command '{}'", name));
+    }
+
+    let mut files = Vec::new();
+    let mut delimiter = Delimiter::Newline;
+    let mut empty = EmptyItems::Keep;
+    let mut options = CompareOptions::default();
+    let mut only = None;
+    let (mut trim, mut dedup, mut sort) = (false, false, None);
+    let (mut from, mut to, mut quote) = (None, Delimiter::Comma, false);
+    let (mut nested, mut path) = (NestedJson::Raw, None);
+
+    while let Some(arg) = args.next() {
+        let mut value = || {
+            args.next()
+                .map(String::as_str)
+                .ok_or_else(|| format!("{} needs a value", arg))
+        };
+        match arg.as_str() {
+            "--delim" => delimiter = delimiter_arg(value()?)?,
+            "--collapse-empty" => empty = EmptyItems::Collapse,
+            "--case-sensitive" => options.case_sensitive = true,
+            "--no-trim" => options.trim_spaces = false,
+            "--keep-order" => options.preserve_order = true,
+            "--only" => {
+                only = Some(match value()? {
+                    "first" => Section::OnlyFirst,
+                    "second" => Section::OnlySecond,
+                    "both" => Section::Both,
+                    "union" => Section::Union,
+                    other => return Err(format!("unknown section '{}'", other)),
+                })
+            }
+            "--trim" => trim = true,
+            "--dedup" => dedup = true,
+            "--sort" => {
+                sort = Some(match value()? {
+                    "asc" => SortOrder::Asc,
+                    "desc" => SortOrder::Desc,
+                    other => return Err(format!("unknown sort order '{}'", other)),
+                })
+            }
+            "--from" => {
+                let source = delimiter_arg(value()?)?;
+                if source == Delimiter::Yaml && !cfg!(feature = "yaml") {
+                    return Err(
+                        "YAML support is not built in (rebuild with --features yaml)".to_string(),
+                    );
+                }
+                from = Some(source);
+            }
+            "--to" => {
+                to = delimiter_arg(value()?)?;
+                if to == Delimiter::Yaml {
+                    return Err("YAML is only supported as a source".to_string());
+                }
+            }
+            "--quote" => quote = true,
+            "--flatten" => nested = NestedJson::Flatten,
+            "--path" => {
+                path = Some(
+                    JsonPath::parse(value()?).map_err(|err| format!("invalid --path: {}", err))?,
+                )
+            }
+            flag if flag.starts_with("--") => return Err(format!("unknown option '{}'", flag)),
+            file => files.push(file.to_string()),
+        }
+    }
+
+    if delimiter == Delimiter::Json {
+        return Err("--delim json is not supported, use convert --from json".to_string());
+    }
+    if to.is_structured() && from.is_some_and(|from| from.is_structured()) {
+        return Err("JSON targets need a delimited source".to_string());
+    }
+    if path.is_some() && !from.is_some_and(|from| from.is_structured()) {
+        return Err("--path needs --from json, jsonl or yaml".to_string());
+    }
+    let expected = if matches!(name, "compare" | "diff-bundles") {
+        2
+    } else {
+        1
+    };
+    if files.len() != expected {
+        return Err(format!(
+            "{} expects {} file{}",
+            name,
+            expected,
+            if expected == 1 { "" } else { "s" }
+        ));
+    }
+    let mut files = files.into_iter();
+    let mut file = || files.next().unwrap_or_default();
+    let command = match name {
+        "compare" => Command::Compare {
+            first: file(),
+            second: file(),
+            options,
+            only,
+        },
+        "list" => Command::List {
+            file: file(),
+            trim,
+            dedup,
+            sort,
+        },
+        "diff-bundles" => Command::DiffBundles {
+            old: file(),
+            new: file(),
+        },
+        _ => Command::Convert {
+            file: file(),
+            from: from.unwrap_or(delimiter),
+            to,
+            quote,
+            nested,
+            path,
+        },
+    };
+    Ok(Invocation {
+        command,
+        delimiter,
+        empty,
+    })
+}
+
+/// Read a file, or standard input for `-`
+fn read_input(path: &str) -> Result<String, CliError> {
+    let read = if path == "-" {
+        let mut text = String::new();
+        io::stdin().read_to_string(&mut text).map(|_| text)
+    } else {
+        fs::read_to_string(path)
+    };
+    read.map_err(|err| CliError::Read(path.to_string(), err))
+}
+
+/// Parse a file the way the editors do: lines joined with the delimiter, then split
+fn read_list(path: &str, delimiter: Delimiter, empty: EmptyItems) -> Result<Vec<String>, CliError> {
+    let text = read_input(path)?;
+    let joined = text.lines().collect::<Vec<_>>().join(delimiter.as_str());
+    Ok(parse_list_with(&joined, delimiter, empty))
+}
+
+/// Items joined with `target`, quoted as CSV fields when `quote` is set
+fn join_items(items: &[String], target: char, quote: bool) -> String {
+    items
+        .iter()
+        .map(|item| {
+            if quote {
+                quote_csv_field(item, target)
+            } else {
+                item.into()
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(&target.to_string())
+}
+
+fn write_items(out: &mut impl Write, items: &[String]) -> io::Result<()> {
+    for item in items {
+        writeln!(out, "{}", item)?;
+    }
+    Ok(())
+}
+
+fn write_compare(
+    out: &mut impl Write,
+    result: &CompareResult,
+    first: &str,
+    second: &str,
+    only: Option<Section>,
+) -> io::Result<()> {
+    if let Some(only) = only {
+        let items = match only {
+            Section::OnlyFirst => &result.only_in_first,
+            Section::OnlySecond => &result.only_in_second,
+            Section::Both => &result.intersection,
+            Section::Union => &result.union,
+        };
+        return write_items(out, items);
+    }
+    let sections = [
+        (format!("Only in {}", first), &result.only_in_first),
+        (format!("Only in {}", second), &result.only_in_second),
+        ("In both".to_string(), &result.intersection),
+    ];
+    for (index, (title, items)) in sections.iter().enumerate() {
+        if index > 0 {
+            writeln!(out)?;
+        }
+        writeln!(out, "{} ({}):", title, items.len())?;
+        write_items(out, items)?;
+    }
+    Ok(())
+}
+
+fn read_bundle(path: &str) -> Result<ComparisonBundle, CliError> {
+    let text = read_input(path)?;
+    ComparisonBundle::from_json(&text).map_err(|err| CliError::Bundle(path.to_string(), err))
+}
+
+/// Print the resolved, new and still open differences, `<` marking items only
+/// in List 1 and `>` items only in List 2
+fn write_bundle_diff(out: &mut impl Write, old: &str, new: &str) -> Result<i32, CliError> {
+    let diff = diff_bundles(&read_bundle(old)?, &read_bundle(new)?);
+    let sections = [
+        ("Resolved", &diff.resolved),
+        ("New", &diff.new),
+        ("Still open", &diff.open),
+    ];
+    for (index, (title, entries)) in sections.iter().enumerate() {
+        if index > 0 {
+            writeln!(out)?;
+        }
+        writeln!(out, "{} ({}):", title, entries.len())?;
+        for (side, item) in entries.iter() {
+            let marker = match side {
+                DiffSide::First => '<',
+                DiffSide::Second => '>',
+            };
+            writeln!(out, "{} {}", marker, item)?;
+        }
+    }
+    Ok(i32::from(!diff.new.is_empty()))
+}
+
+/// Run a parsed command, returning the exit code
+fn execute(invocation: &Invocation, out: &mut impl Write) -> Result<i32, CliError> {
+    let Invocation {
+        command,
+        delimiter,
+        empty,
+    } = invocation;
+    match command {
+        Command::Help => {
+            writeln!(out, "{}", USAGE)?;
+            Ok(0)
+        }
+        Command::Compare {
+            first,
+            second,
+            options,
+            only,
+        } => {
+            let list1 = read_list(first, *delimiter, *empty)?;
+            let list2 = read_list(second, *delimiter, *empty)?;
+            let result = compare_lists(&list1, &list2, *options);
+            write_compare(out, &result, first, second, *only)?;
+            let differ = !result.only_in_first.is_empty() || !result.only_in_second.is_empty();
+            Ok(i32::from(differ))
+        }
+        Command::List {
+            file,
+            trim,
+            dedup,
+            sort,
+        } => {
+            let items = read_list(file, *delimiter, *empty)?;
+            let result = process_single_list(
+                &items,
+                *trim,
+                *dedup,
+                *sort == Some(SortOrder::Asc),
+                *sort == Some(SortOrder::Desc),
+            );
+            write_items(out, &result.items)?;
+            Ok(0)
+        }
+        Command::Convert {
+            file,
+            from,
+            to,
+            quote,
+            nested,
+            path,
+        } => {
+            let quote =
+                to.is_quoted() || (*quote && matches!(to, Delimiter::Comma | Delimiter::Semicolon));
+            let target = to.as_char();
+            let output = if to.is_structured() {
+                // Delimited rows with a header become one object each
+                let text = read_input(file)?;
+                let lines: Vec<&str> = text.lines().collect();
+                let separator = match from {
+                    Delimiter::Newline => detect_field_separator(&lines),
+                    from => from.field_separator(),
+                }
+                .ok_or_else(|| CliError::NoFieldSeparator(file.clone()))?;
+                let objects = rows_to_json_objects(&delimited_rows(&text, separator));
+                match to {
+                    Delimiter::Json => json_array_lines(&objects),
+                    _ => objects,
+                }
+                .join("\n")
+            } else if from.is_structured() {
+                let text = read_input(file)?;
+                let error = |err| match from {
+                    Delimiter::Yaml => CliError::Yaml(err),
+                    _ => CliError::Json(err),
+                };
+                let (rows, json) = parse_structured_to_list(
+                    &text,
+                    *from,
+                    target,
+                    quote,
+                    JsonArrayStrategy::default(),
+                    *nested,
+                )
+                .map_err(error)?;
+                match path {
+                    // Extracted values are plain items, joined like a list
+                    Some(path) => join_items(
+                        &extract_json_path(&json, *from, path).map_err(error)?,
+                        target,
+                        quote,
+                    ),
+                    None => rows.join("\n"),
+                }
+            } else {
+                join_items(&read_list(file, *from, *empty)?, target, quote)
+            };
+            writeln!(out, "{}", output)?;
+            Ok(0)
+        }
+        Command::DiffBundles { old, new } => write_bundle_diff(out, old, new),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    static RUNS: AtomicUsize = AtomicUsize::new(0);
+
+    fn args(line: &str) -> Vec<String> {
+        line.split_whitespace().map(String::from).collect()
+    }
+
+    /// Run a command line against files written to a temp dir, returning the exit code and stdout
+    fn run_with(files: &[(&str, &str)], line: &str) -> (i32, String) {
+        let dir = std::env::temp_dir().join(format!(
+            "list-utils-cli-{}-{}",
+            std::process::id(),
+            RUNS.fetch_add(1, Ordering::Relaxed)
+        ));
+        fs::create_dir_all(&dir).unwrap();
+        for (name, content) in files {
+            fs::write(dir.join(name), content).unwrap();
+        }
+        let line = line.replace("@", &format!("{}/", dir.display()));
+        let mut out = Vec::new();
+        let code = execute(&parse_args(&args(&line)).unwrap(), &mut out).unwrap();
+        fs::remove_dir_all(dir).unwrap();
+        (code, String::from_utf8(out).unwrap())
+    }
+
+    #[test]
+    fn test_parse_args_rejects_bad_usage() {
+        assert!(parse_args(&args("frobnicate a")).is_err());
+        assert!(parse_args(&args("compare a")).is_err());
+        assert!(parse_args(&args("compare a b --delim pipe")).is_err());
+        assert!(parse_args(&args("compare a b --only")).is_err());
+        assert!(parse_args(&args("list a --bogus")).is_err());
+        assert!(parse_args(&args("convert a --to yaml")).is_err());
+        assert_eq!(
+            parse_args(&args("convert a --from yaml")).is_ok(),
+            cfg!(feature = "yaml")
+        );
+        assert!(parse_args(&args("convert a --to json --from jsonl")).is_err());
+        assert!(parse_args(&args("convert a --path id")).is_err());
+        assert!(parse_args(&args("convert a --from json --path $.a[")).is_err());
+        assert!(matches!(
+            parse_args(&args("--help")).unwrap().command,
+            Command::Help
+        ));
+    }
+
+    #[test]
+    fn test_compare_prints_sections_and_exit_code() {
+        let files = [
+            ("a", "apple\nBanana\ncherry\n"),
+            ("b", "banana\ncherry\ndate"),
+        ];
+        let (code, out) = run_with(&files, "compare @a @b");
+        assert_eq!(code, 1);
+        assert!(out.starts_with("Only in "));
+        assert!(out.contains("a (1):\napple\n\nOnly in "));
+        assert!(out.ends_with("b (1):\ndate\n\nIn both (2):\nBanana\ncherry\n"));
+
+        let files = [("a", "apple,Banana\ncherry"), ("b", "banana , cherry,date")];
+        let (_, out) = run_with(&files, "compare @a @b --delim , --only second");
+        assert_eq!(out, "date\n");
+        let (_, out) = run_with(
+            &files,
+            "compare @a @b --delim , --case-sensitive --only first",
+        );
+        assert_eq!(out, "Banana\napple\n");
+
+        let same = [("a", "x\ny"), ("b", "y\nx\n")];
+        assert_eq!(
+            run_with(&same, "compare @a @b --only union"),
+            (0, "x\ny\n".to_string())
+        );
+    }
+
+    #[test]
+    fn test_list_and_convert() {
+        let files = [("l", "b\n a\nb\n10\n")];
+        let (code, out) = run_with(&files, "list @l --trim --dedup --sort asc");
+        assert_eq!(code, 0);
+        assert_eq!(out, "10\na\nb\n");
+
+        let files = [("c", "x\ny,z\n")];
+        let (_, out) = run_with(&files, "convert @c --to ; --quote");
+        assert_eq!(out, "x;y,z\n");
+        let (_, out) = run_with(&files, "convert @c --to , --quote");
+        assert_eq!(out, "x,\"y,z\"\n");
+
+        let files = [("j", r#"[{"id":1},{"id":2}]"#)];
+        let (_, out) = run_with(&files, "convert @j --from json");
+        assert_eq!(out, "id\n1\n2\n");
+
+        let files = [("j", "{\"id\":1}\n{\"id\":2,\"ok\":true}\n")];
+        let (_, out) = run_with(&files, "convert @j --from jsonl --to ;");
+        assert_eq!(out, "id;ok\n1;\n2;true\n");
+
+        if cfg!(feature = "yaml") {
+            let files = [("y", "- id: 1\n- id: 2\n")];
+            let (_, out) = run_with(&files, "convert @y --from yaml");
+            assert_eq!(out, "id\n1\n2\n");
+            let (_, out) = run_with(&files, "convert @y --from yaml --path $[*].id --to ;");
+            assert_eq!(out, "1;2\n");
+        }
+
+        let files = [("n", r#"[{"id":1,"user":{"name":"Ann","tags":["a","b"]}}]"#)];
+        let (_, out) = run_with(&files, "convert @n --from json --flatten");
+        assert_eq!(out, "id,user.name,user.tags.0,user.tags.1\n1,Ann,a,b\n");
+
+        let files = [("t", "id\tname\n1\tAnn\n")];
+        let (_, out) = run_with(&files, "convert @t --to jsonl");
+        assert_eq!(out, "{\"id\": 1, \"name\": \"Ann\"}\n");
+    }
+
+    #[test]
+    fn test_diff_bundles() {
+        let bundle = |list1: &[&str], list2: &[&str]| {
+            let lines = |items: &[&str]| items.iter().map(|s| s.to_string()).collect::<Vec<_>>();
+            ComparisonBundle::new(
+                &lines(list1),
+                &lines(list2),
+                Delimiter::Newline,
+                EmptyItems::Keep,
+                CompareOptions::default(),
+                None,
+            )
+            .to_json()
+        };
+        let old = bundle(&["a", "b"], &["c"]);
+        let new = bundle(&["b"], &["d"]);
+        let files = [("old.lcmp", old.as_str()), ("new.lcmp", new.as_str())];
+        let (code, out) = run_with(&files, "diff-bundles @old.lcmp @new.lcmp");
+        assert_eq!(code, 1);
+        assert_eq!(
+            out,
+            "Resolved (2):\n< a\n> c\n\nNew (1):\n> d\n\nStill open (1):\n< b\n"
+        );
+        let (code, _) = run_with(&files, "diff-bundles @new.lcmp @new.lcmp");
+        assert_eq!(code, 0);
+    }
+}
@@ -0,0 +1,54 @@
+//! Copies mirrored into a history file, so text copied earlier in a session can
+//! be recovered after the clipboard has been overwritten
+//!
+//! Each copy is appended as a `--- <time> · <what> ---` header line followed
+//! by the copied text and a blank line.
+use crate::report::format_timestamp;
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::path::Path;
+use std::time::SystemTime;
+
+/// Name of the history file in the state directory
+pub const CLIPBOARD_HISTORY_FILE: &str = "clipboard_history.txt";
+
+/// Append `text`, copied from `what` at `time`, to the history file at `path`
+///
+/// The file and its directory are created when missing.
+pub fn append_history(path: &Path, what: &str, text: &str, time: SystemTime) -> io::Result<()> {
+    if let Some(dir) = path.parent().filter(|dir| !dir.as_os_str().is_empty()) {
+        fs::create_dir_all(dir)?;
+    }
+    let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+    let text = text.strip_suffix('\n').unwrap_or(text);
+    write!(
+        file,
+        "--- {} · {} ---\n{}\n\n",
+        format_timestamp(time),
+        what,
+        text
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+    use std::process;
+    use std::time::{Duration, UNIX_EPOCH};
+
+    #[test]
+    fn test_append_history() {
+        let dir = env::temp_dir().join(format!("list-utils-clipboard-{}", process::id()));
+        let path = dir.join(CLIPBOARD_HISTORY_FILE);
+        let time = UNIX_EPOCH + Duration::from_secs(86_400);
+        append_history(&path, "List 1", "a\nb\n", time).unwrap();
+        append_history(&path, "Summary", "| x |", time).unwrap();
+        assert_eq!(
+            fs::read_to_string(&path).unwrap(),
+            "--- 1970-01-02 00:00:00 UTC · List 1 ---\na\nb\n\n\
+             --- 1970-01-02 00:00:00 UTC · Summary ---\n| x |\n\n"
+        );
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -1,9 +1,45 @@
 //! Clipboard operations using arboard with platform-specific fallbacks
-use arboard::Clipboard;
+//!
+//! Without the `clipboard` feature arboard is left out and every copy and
+//! paste goes through the platform tools.
+pub mod history;
+
+pub use history::*;
+
+#[cfg(feature = "clipboard")]
+pub use arboard::Clipboard;
+use std::env;
 use std::io;
 use std::io::Write;
 use std::process::{Command, Stdio};
 
+/// Stand-in for the arboard clipboard in builds without the `clipboard` feature
+///
+/// It can never be created, so the platform tools are always used.
+#[cfg(not(feature = "clipboard"))]
+pub enum Clipboard {}
+
+#[cfg(not(feature = "clipboard"))]
+impl Clipboard {
+    /// Always fails: arboard is not built in
+    pub fn new() -> Result<Self, io::Error> {
+        Err(no_arboard())
+    }
+
+    fn set_text(&mut self, _text: &str) -> Result<(), io::Error> {
+        match *self {}
+    }
+
+    fn get_text(&mut self) -> Result<String, io::Error> {
+        match *self {}
+    }
+}
+
+#[cfg(not(feature = "clipboard"))]
+fn no_arboard() -> io::Error {
+    io::Error::other("built without the clipboard feature")
+}
+
 /// Copy text to the system clipboard
 ///
 /// # Arguments
@@ -13,17 +49,17 @@ pub fn copy_to_clipboard(clipboard: Option<&mut Clipboard>, text: &str) -> Resul
     let result = match clipboard {
         Some(cb) => cb
             .set_text(text)
-            .map_err(|e| io::Error::new(io::ErrorKind::Other, format!("Failed to copy: {}", e))),
+            .map_err(|e| io::Error::other(format!("Failed to copy: {}", e))),
         None => copy_with_arboard(text),
     };
 
     if let Err(primary_err) = result {
         // Try platform-specific fallback if arboard is unavailable
         copy_with_platform_tool(text).map_err(|fallback_err| {
-            io::Error::new(
-                io::ErrorKind::Other,
-                format!("{}; fallback failed: {}", primary_err, fallback_err),
-            )
+            io::Error::other(format!(
+                "{}; fallback failed: {}",
+                primary_err, fallback_err
+            ))
         })?;
     }
 
@@ -38,47 +74,98 @@ pub fn get_from_clipboard(clipboard: Option<&mut Clipboard>) -> Result<String, i
     let result = match clipboard {
         Some(cb) => cb
             .get_text()
-            .map_err(|e| io::Error::new(io::ErrorKind::Other, format!("Failed to paste: {}", e))),
+            .map_err(|e| io::Error::other(format!("Failed to paste: {}", e))),
         None => paste_with_arboard(),
     };
 
     match result {
         Ok(text) => Ok(text),
         Err(primary_err) => paste_with_platform_tool().map_err(|fallback_err| {
-            io::Error::new(
-                io::ErrorKind::Other,
-                format!("{}; fallback failed: {}", primary_err, fallback_err),
-            )
+            io::Error::other(format!(
+                "{}; fallback failed: {}",
+                primary_err, fallback_err
+            ))
         }),
     }
 }
 
+/// Describe the clipboard backend in use, for diagnostics
+///
+/// # Arguments
+/// * `clipboard` - Persistent clipboard instance, if it could be created
+pub fn backend_description(clipboard: Option<&Clipboard>) -> String {
+    let platform = if cfg!(target_os = "macos") {
+        "NSPasteboard"
+    } else if cfg!(windows) {
+        "Win32"
+    } else if env::var_os("WAYLAND_DISPLAY").is_some() {
+        "Wayland"
+    } else if env::var_os("DISPLAY").is_some() {
+        "X11"
+    } else {
+        "no display"
+    };
+
+    if clipboard.is_some() {
+        return format!("arboard ({})", platform);
+    }
+
+    let tools: Vec<&str> = FALLBACK_TOOLS
+        .iter()
+        .copied()
+        .filter(|tool| is_on_path(tool))
+        .collect();
+    if tools.is_empty() {
+        format!("unavailable ({}, no fallback tools)", platform)
+    } else {
+        format!("fallback: {} ({})", tools.join(", "), platform)
+    }
+}
+
+/// Command-line tools tried when arboard is unavailable
+#[cfg(target_os = "macos")]
+const FALLBACK_TOOLS: &[&str] = &["pbcopy", "pbpaste"];
+#[cfg(target_os = "linux")]
+const FALLBACK_TOOLS: &[&str] = &["wl-copy", "xclip", "xsel"];
+#[cfg(not(any(target_os = "macos", target_os = "linux")))]
+const FALLBACK_TOOLS: &[&str] = &[];
+
+fn is_on_path(cmd: &str) -> bool {
+    env::var_os("PATH")
+        .map(|paths| env::split_paths(&paths).any(|dir| dir.join(cmd).is_file()))
+        .unwrap_or(false)
+}
+
+#[cfg(not(feature = "clipboard"))]
+fn copy_with_arboard(_text: &str) -> Result<(), io::Error> {
+    Err(no_arboard())
+}
+
+#[cfg(not(feature = "clipboard"))]
+fn paste_with_arboard() -> Result<String, io::Error> {
+    Err(no_arboard())
+}
+
+#[cfg(feature = "clipboard")]
 fn copy_with_arboard(text: &str) -> Result<(), io::Error> {
-    let mut clipboard = Clipboard::new().map_err(|e| {
-        io::Error::new(
-            io::ErrorKind::Other,
-            format!("Failed to initialize clipboard: {}", e),
-        )
-    })?;
+    let mut clipboard = Clipboard::new()
+        .map_err(|e| io::Error::other(format!("Failed to initialize clipboard: {}", e)))?;
 
     clipboard
         .set_text(text)
-        .map_err(|e| io::Error::new(io::ErrorKind::Other, format!("Failed to copy: {}", e)))?;
+        .map_err(|e| io::Error::other(format!("Failed to copy: {}", e)))?;
 
     Ok(())
 }
 
+#[cfg(feature = "clipboard")]
 fn paste_with_arboard() -> Result<String, io::Error> {
-    let mut clipboard = Clipboard::new().map_err(|e| {
-        io::Error::new(
-            io::ErrorKind::Other,
-            format!("Failed to initialize clipboard: {}", e),
-        )
-    })?;
+    let mut clipboard = Clipboard::new()
+        .map_err(|e| io::Error::other(format!("Failed to initialize clipboard: {}", e)))?;
 
     clipboard
         .get_text()
-        .map_err(|e| io::Error::new(io::ErrorKind::Other, format!("Failed to paste: {}", e)))
+        .map_err(|e| io::Error::other(format!("Failed to paste: {}", e)))
 }
 
 #[cfg(target_os = "macos")]
@@ -108,10 +195,7 @@ fn copy_with_platform_tool(text: &str) -> Result<(), io::Error> {
     }
 
     Err(last_err.unwrap_or_else(|| {
-        io::Error::new(
-            io::ErrorKind::Other,
-            "No clipboard command available (tried wl-copy, xclip, xsel)",
-        )
+        io::Error::other("No clipboard command available (tried wl-copy, xclip, xsel)")
     }))
 }
 
@@ -132,10 +216,7 @@ fn paste_with_platform_tool() -> Result<String, io::Error> {
     }
 
     Err(last_err.unwrap_or_else(|| {
-        io::Error::new(
-            io::ErrorKind::Other,
-            "No clipboard command available (tried wl-paste, xclip, xsel)",
-        )
+        io::Error::other("No clipboard command available (tried wl-paste, xclip, xsel)")
     }))
 }
 
@@ -160,30 +241,27 @@ fn run_copy_command(cmd: &str, args: &[&str], text: &str) -> Result<(), io::Erro
         .args(args)
         .stdin(Stdio::piped())
         .spawn()
-        .map_err(|e| io::Error::new(io::ErrorKind::Other, format!("{} failed: {}", cmd, e)))?;
+        .map_err(|e| io::Error::other(format!("{} failed: {}", cmd, e)))?;
 
     if let Some(stdin) = child.stdin.as_mut() {
-        stdin.write_all(text.as_bytes()).map_err(|e| {
-            io::Error::new(io::ErrorKind::Other, format!("{} stdin failed: {}", cmd, e))
-        })?;
+        stdin
+            .write_all(text.as_bytes())
+            .map_err(|e| io::Error::other(format!("{} stdin failed: {}", cmd, e)))?;
     } else {
-        return Err(io::Error::new(
-            io::ErrorKind::Other,
-            format!("{}: stdin not available", cmd),
-        ));
+        return Err(io::Error::other(format!("{}: stdin not available", cmd)));
     }
 
     let status = child
         .wait()
-        .map_err(|e| io::Error::new(io::ErrorKind::Other, format!("{} wait failed: {}", cmd, e)))?;
+        .map_err(|e| io::Error::other(format!("{} wait failed: {}", cmd, e)))?;
 
     if status.success() {
         Ok(())
     } else {
-        Err(io::Error::new(
-            io::ErrorKind::Other,
-            format!("{} exited with status {}", cmd, status),
-        ))
+        Err(io::Error::other(format!(
+            "{} exited with status {}",
+            cmd, status
+        )))
     }
 }
 
@@ -191,19 +269,15 @@ fn run_paste_command(cmd: &str, args: &[&str]) -> Result<String, io::Error> {
     let output = Command::new(cmd)
         .args(args)
         .output()
-        .map_err(|e| io::Error::new(io::ErrorKind::Other, format!("{} failed: {}", cmd, e)))?;
+        .map_err(|e| io::Error::other(format!("{} failed: {}", cmd, e)))?;
 
     if output.status.success() {
-        String::from_utf8(output.stdout).map_err(|e| {
-            io::Error::new(
-                io::ErrorKind::Other,
-                format!("{} output was not UTF-8: {}", cmd, e),
-            )
-        })
+        String::from_utf8(output.stdout)
+            .map_err(|e| io::Error::other(format!("{} output was not UTF-8: {}", cmd, e)))
     } else {
-        Err(io::Error::new(
-            io::ErrorKind::Other,
-            format!("{} exited with status {}", cmd, output.status),
-        ))
+        Err(io::Error::other(format!(
+            "{} exited with status {}",
+            cmd, output.status
+        )))
     }
 }
@@ -0,0 +1,337 @@
+//! Persistent user preferences stored as a small `key=value` file
+//!
+//! The file lives at `LIST_UTILS_CONFIG` if set, otherwise at `config` in the
+//! config directory of [`Paths`] (`$XDG_CONFIG_HOME/list-utils`, falling back
+//! to `~/.config`). Unknown keys and malformed lines are ignored so older
+//! files keep working.
+pub mod paths;
+pub mod references;
+
+pub use paths::*;
+
+use crate::app::{Notice, DEFAULT_NOTIFY_AFTER_SECS, DEFAULT_UNDO_DEPTH, DEFAULT_UNDO_MEMORY_MB};
+use crate::operations::{NumberPlacement, SmartSort, TieBreak, TrimSet, DEFAULT_LOG_PRESETS};
+use crate::ui::GridLayout;
+use std::env;
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+
+/// Noise values removed by the stopword operation unless the config file lists its own
+pub const DEFAULT_STOPWORDS: [&str; 10] = [
+    "n/a",
+    "na",
+    "null",
+    "none",
+    "nil",
+    "nan",
+    "undefined",
+    "-",
+    "--",
+    "?",
+];
+
+/// Item length above which loads and pastes warn unless the config file sets its own
+pub const DEFAULT_MAX_ITEM_LENGTH: usize = 2000;
+
+/// User preferences that survive restarts
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Config {
+    /// Split ratios of the results grid
+    pub grid: GridLayout,
+    /// Items removed by the stopword operation (compared case-insensitively)
+    pub stopwords: Vec<String>,
+    /// Characters trimmed from items along with the spaces
+    pub trim: TrimSet,
+    /// Whether comparisons fold curly quotes, dashes and special spaces to ASCII
+    pub fold_typography: bool,
+    /// Whether empty items (as in `a,,b`) are dropped when parsing lists
+    pub collapse_empty: bool,
+    /// Whether pasted items are trimmed and trailing delimiters dropped
+    pub trim_on_paste: bool,
+    /// Whether pasting or loading into an empty editor detects its delimiter
+    pub auto_delimiter: bool,
+    /// Item length (in characters) above which a load or paste warns (0 disables)
+    pub max_item_length: usize,
+    /// Whether items over `max_item_length` are clipped with an ellipsis in result panels
+    pub truncate_long_items: bool,
+    /// Named `(name, regex)` presets stripped from the start of log lines
+    pub log_presets: Vec<(String, String)>,
+    /// How comparison results are sorted: numbers before or after text, and ties
+    pub smart_sort: SmartSort,
+    /// How the end of a slow operation is signaled
+    pub notice: Notice,
+    /// Seconds an operation runs before its end is signaled
+    pub notice_after: u16,
+    /// Snapshots kept for undo and for redo (0 disables undo)
+    pub undo_depth: u16,
+    /// Megabytes of snapshots kept for undo and for redo (0 for no limit)
+    pub undo_memory_mb: u16,
+    /// Whether every copy is also appended to the clipboard history file
+    pub clipboard_history: bool,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            grid: GridLayout::default(),
+            stopwords: DEFAULT_STOPWORDS.map(str::to_string).to_vec(),
+            trim: TrimSet::default(),
+            fold_typography: false,
+            collapse_empty: false,
+            trim_on_paste: false,
+            auto_delimiter: true,
+            max_item_length: DEFAULT_MAX_ITEM_LENGTH,
+            truncate_long_items: true,
+            log_presets: DEFAULT_LOG_PRESETS
+                .map(|(name, pattern)| (name.to_string(), pattern.to_string()))
+                .to_vec(),
+            smart_sort: SmartSort::default(),
+            notice: Notice::Off,
+            notice_after: DEFAULT_NOTIFY_AFTER_SECS,
+            undo_depth: DEFAULT_UNDO_DEPTH,
+            undo_memory_mb: DEFAULT_UNDO_MEMORY_MB,
+            clipboard_history: false,
+        }
+    }
+}
+
+impl Config {
+    /// Location of the config file, if one can be determined
+    pub fn path() -> Option<PathBuf> {
+        if let Some(path) = env::var_os("LIST_UTILS_CONFIG").filter(|p| !p.is_empty()) {
+            return Some(PathBuf::from(path));
+        }
+        Some(Paths::from_env().config?.join("config"))
+    }
+
+    /// Load the config file, falling back to defaults when it is missing or unreadable
+    pub fn load() -> Self {
+        Self::path()
+            .and_then(|path| fs::read_to_string(path).ok())
+            .map(|text| Self::parse(&text))
+            .unwrap_or_default()
+    }
+
+    /// Write the config file, creating its directory if needed
+    pub fn save(&self) -> Result<(), io::Error> {
+        let path = Self::path().ok_or_else(|| {
+            io::Error::other("No config location (set LIST_UTILS_CONFIG or HOME)")
+        })?;
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        fs::write(path, self.serialize())
+    }
+
+    /// Parse `key=value` lines on top of the defaults
+    pub fn parse(text: &str) -> Self {
+        let mut config = Self::default();
+        for line in text.lines() {
+            let Some((key, value)) = line.split_once('=') else {
+                continue;
+            };
+            match key.trim() {
+                "stopwords" => {
+                    config.stopwords = parse_stopwords(value);
+                    continue;
+                }
+                "max_item_length" => {
+                    if let Ok(length) = value.trim().parse() {
+                        config.max_item_length = length;
+                    }
+                    continue;
+                }
+                "sort_numbers" => {
+                    if let Some(numbers) = NumberPlacement::from_name(value) {
+                        config.smart_sort.numbers = numbers;
+                    }
+                    continue;
+                }
+                "notify" => {
+                    if let Some(notice) = Notice::from_name(value) {
+                        config.notice = notice;
+                    }
+                    continue;
+                }
+                "sort_ties" => {
+                    if let Some(ties) = TieBreak::from_name(value) {
+                        config.smart_sort.ties = ties;
+                    }
+                    continue;
+                }
+                "trim_chars" => {
+                    config.trim = config.trim.with_custom(value);
+                    continue;
+                }
+                key if key.starts_with(LOG_PRESET_PREFIX) => {
+                    set_log_preset(
+                        &mut config.log_presets,
+                        &key[LOG_PRESET_PREFIX.len()..],
+                        value,
+                    );
+                    continue;
+                }
+                _ => {}
+            }
+            let Ok(value) = value.trim().parse::<u16>() else {
+                continue;
+            };
+            match key.trim() {
+                "grid_rows" => config.grid.rows = value.min(100),
+                "grid_top" => config.grid.top = value.min(100),
+                "grid_bottom" => config.grid.bottom = value.min(100),
+                "trim_quotes" => config.trim.quotes = value != 0,
+                "trim_brackets" => config.trim.brackets = value != 0,
+                "trim_punctuation" => config.trim.punctuation = value != 0,
+                "fold_typography" => config.fold_typography = value != 0,
+                "collapse_empty" => config.collapse_empty = value != 0,
+                "trim_on_paste" => config.trim_on_paste = value != 0,
+                "auto_delimiter" => config.auto_delimiter = value != 0,
+                "truncate_long_items" => config.truncate_long_items = value != 0,
+                "notify_after" => config.notice_after = value,
+                "undo_depth" => config.undo_depth = value,
+                "undo_memory_mb" => config.undo_memory_mb = value,
+                "clipboard_history" => config.clipboard_history = value != 0,
+                _ => {}
+            }
+        }
+        config
+    }
+
+    /// Render the config as `key=value` lines
+    pub fn serialize(&self) -> String {
+        // Removed built-in presets are written empty so they stay removed
+        let removed = DEFAULT_LOG_PRESETS
+            .iter()
+            .filter(|(name, _)| !self.log_presets.iter().any(|(kept, _)| kept == name))
+            .map(|(name, _)| (*name, ""));
+        let presets: String = removed
+            .chain(
+                self.log_presets
+                    .iter()
+                    .map(|(name, pattern)| (name.as_str(), pattern.as_str())),
+            )
+            .map(|(name, pattern)| format!("{}{}={}\n", LOG_PRESET_PREFIX, name, pattern))
+            .collect();
+        format!(
+            "grid_rows={}\ngrid_top={}\ngrid_bottom={}\nstopwords={}\n\
+             trim_quotes={}\ntrim_brackets={}\ntrim_punctuation={}\ntrim_chars={}\n\
+             fold_typography={}\ncollapse_empty={}\ntrim_on_paste={}\n\
+             auto_delimiter={}\nmax_item_length={}\ntruncate_long_items={}\n\
+             sort_numbers={}\nsort_ties={}\nnotify={}\nnotify_after={}\n\
+             undo_depth={}\nundo_memory_mb={}\nclipboard_history={}\n{}",
+            self.grid.rows,
+            self.grid.top,
+            self.grid.bottom,
+            self.stopwords.join(","),
+            u8::from(self.trim.quotes),
+            u8::from(self.trim.brackets),
+            u8::from(self.trim.punctuation),
+            self.trim.custom().iter().collect::<String>(),
+            u8::from(self.fold_typography),
+            u8::from(self.collapse_empty),
+            u8::from(self.trim_on_paste),
+            u8::from(self.auto_delimiter),
+            self.max_item_length,
+            u8::from(self.truncate_long_items),
+            self.smart_sort.numbers.name(),
+            self.smart_sort.ties.name(),
+            self.notice.name(),
+            self.notice_after,
+            self.undo_depth,
+            self.undo_memory_mb,
+            u8::from(self.clipboard_history),
+            presets
+        )
+    }
+}
+
+/// Key prefix of log prefix presets (`log_preset.<name>=<regex>`)
+const LOG_PRESET_PREFIX: &str = "log_preset.";
+
+/// Replace or add the preset `name`; an empty pattern removes it
+fn set_log_preset(presets: &mut Vec<(String, String)>, name: &str, pattern: &str) {
+    let (name, pattern) = (name.trim(), pattern.trim());
+    if name.is_empty() {
+        return;
+    }
+    presets.retain(|(existing, _)| existing != name);
+    if !pattern.is_empty() {
+        presets.push((name.to_string(), pattern.to_string()));
+    }
+}
+
+/// Comma-separated stopwords, trimmed, with empty entries dropped
+fn parse_stopwords(value: &str) -> Vec<String> {
+    value
+        .split(',')
+        .map(str::trim)
+        .filter(|word| !word.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_roundtrip() {
+        let config = Config {
+            grid: GridLayout {
+                rows: 60,
+                top: 50,
+                bottom: 0,
+            },
+            stopwords: vec!["n/a".to_string(), "unknown".to_string()],
+            trim: TrimSet::new(true, false, true).with_custom("#*"),
+            fold_typography: true,
+            collapse_empty: true,
+            trim_on_paste: true,
+            auto_delimiter: false,
+            max_item_length: 100_000,
+            truncate_long_items: false,
+            log_presets: vec![("date".to_string(), r"\d{4}-\d{2}-\d{2}".to_string())],
+            smart_sort: SmartSort {
+                numbers: NumberPlacement::Last,
+                ties: TieBreak::Input,
+            },
+            notice: Notice::Flash,
+            notice_after: 30,
+            undo_depth: 5,
+            undo_memory_mb: 0,
+            clipboard_history: true,
+        };
+        assert_eq!(Config::parse(&config.serialize()), config);
+    }
+
+    #[test]
+    fn test_parse_stopwords() {
+        assert_eq!(Config::parse("").stopwords, Config::default().stopwords);
+        assert_eq!(
+            Config::parse("stopwords= TBD, ,--\n").stopwords,
+            vec!["TBD", "--"]
+        );
+        assert!(Config::parse("stopwords=\n").stopwords.is_empty());
+    }
+
+    #[test]
+    fn test_parse_log_presets() {
+        let config = Config::parse("log_preset.level=\nlog_preset.pid=pid=\\d+\n");
+        let names: Vec<&str> = config
+            .log_presets
+            .iter()
+            .map(|(name, _)| name.as_str())
+            .collect();
+        assert_eq!(names, vec!["timestamp", "thread", "pid"]);
+        assert_eq!(config.log_presets[2].1, r"pid=\d+");
+    }
+
+    #[test]
+    fn test_parse_ignores_garbage() {
+        let config = Config::parse("# comment\ngrid_rows = 70\ngrid_top=abc\nother=1\n");
+        assert_eq!(config.grid.rows, 70);
+        assert_eq!(config.grid.top, GridLayout::default().top);
+    }
+}
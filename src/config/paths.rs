@@ -0,0 +1,180 @@
+//! Directories the app keeps its own files in, per the XDG base directory spec
+//!
+//! Files the user works with (panel files, reports, bundles) stay in the
+//! working directory. The app's own files are split by kind: preferences in
+//! the config directory, reference lists and workspace snapshots in the data
+//! directory, the temporary runs of disk-backed comparisons in the cache
+//! directory and the clipboard history in the state directory.
+//!
+//! The `XDG_*_HOME` variables win on every platform. Otherwise Linux and other
+//! Unix systems use the XDG defaults under `~`, macOS the `~/Library` folders
+//! (but `~/.config` for the config file, like most command-line tools) and
+//! Windows `%APPDATA%` and `%LOCALAPPDATA%`.
+use std::env;
+use std::ffi::OsString;
+use std::path::PathBuf;
+
+/// Name of the app's directory inside each base directory
+pub const APP_DIR: &str = "list-utils";
+
+/// Operating system family, which decides the default base directories
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Platform {
+    /// Linux and other Unix systems
+    Unix,
+    /// macOS
+    MacOs,
+    /// Windows
+    Windows,
+}
+
+impl Platform {
+    /// The platform the app was built for
+    pub const CURRENT: Platform = if cfg!(target_os = "macos") {
+        Platform::MacOs
+    } else if cfg!(windows) {
+        Platform::Windows
+    } else {
+        Platform::Unix
+    };
+}
+
+/// Where the app reads and writes files
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Paths {
+    /// Panel files, reports and bundles (`LIST_UTILS_DIR`, default the current directory)
+    pub work: PathBuf,
+    /// Preferences, if a home directory can be determined
+    pub config: Option<PathBuf>,
+    /// Reference lists and workspace snapshots
+    pub data: PathBuf,
+    /// Temporary runs of disk-backed comparisons
+    pub cache: PathBuf,
+    /// Clipboard history
+    pub state: PathBuf,
+}
+
+impl Paths {
+    /// Directories for this platform, from the environment
+    pub fn from_env() -> Self {
+        Self::resolve(|name| env::var_os(name), Platform::CURRENT)
+    }
+
+    /// Every directory set to `work`, as when no home directory is known
+    pub fn within(work: PathBuf) -> Self {
+        Self {
+            config: None,
+            data: work.clone(),
+            cache: work.clone(),
+            state: work.clone(),
+            work,
+        }
+    }
+
+    /// Directories for `platform`, reading environment variables through `var`
+    ///
+    /// Data, cache and state fall back to the working directory when no base
+    /// directory can be determined, as everything was kept there before.
+    pub fn resolve(var: impl Fn(&str) -> Option<OsString>, platform: Platform) -> Self {
+        // The spec asks for relative values to be ignored
+        let dir = |name: &str| {
+            var(name)
+                .map(PathBuf::from)
+                .filter(|path| path.is_absolute())
+        };
+        let home = dir("HOME");
+        let under_home = |parts: &[&str]| {
+            home.as_ref().map(|home| {
+                parts
+                    .iter()
+                    .fold(home.clone(), |path, part| path.join(part))
+            })
+        };
+        let (config, data, cache, state) = match platform {
+            Platform::Unix => (
+                under_home(&[".config"]),
+                under_home(&[".local", "share"]),
+                under_home(&[".cache"]),
+                under_home(&[".local", "state"]),
+            ),
+            Platform::MacOs => (
+                under_home(&[".config"]),
+                under_home(&["Library", "Application Support"]),
+                under_home(&["Library", "Caches"]),
+                under_home(&["Library", "Application Support"]),
+            ),
+            Platform::Windows => (
+                dir("APPDATA"),
+                dir("APPDATA"),
+                dir("LOCALAPPDATA"),
+                dir("LOCALAPPDATA"),
+            ),
+        };
+        let base = |xdg: &str, default: Option<PathBuf>| {
+            dir(xdg).or(default).map(|base| base.join(APP_DIR))
+        };
+        let fallback = Self::within(
+            var("LIST_UTILS_DIR")
+                .filter(|path| !path.is_empty())
+                .map_or_else(|| PathBuf::from("."), PathBuf::from),
+        );
+        Self {
+            work: fallback.work,
+            config: base("XDG_CONFIG_HOME", config),
+            data: base("XDG_DATA_HOME", data).unwrap_or(fallback.data),
+            cache: base("XDG_CACHE_HOME", cache).unwrap_or(fallback.cache),
+            state: base("XDG_STATE_HOME", state).unwrap_or(fallback.state),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::Path;
+
+    fn resolve(vars: &[(&str, &str)], platform: Platform) -> Paths {
+        Paths::resolve(
+            |name| {
+                vars.iter()
+                    .find(|(var, _)| *var == name)
+                    .map(|(_, value)| OsString::from(value))
+            },
+            platform,
+        )
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_resolve_paths() {
+        let paths = resolve(&[("HOME", "/home/ana")], Platform::Unix);
+        assert_eq!(paths.work, Path::new("."));
+        assert_eq!(
+            paths.config.as_deref(),
+            Some(Path::new("/home/ana/.config/list-utils"))
+        );
+        assert_eq!(paths.data, Path::new("/home/ana/.local/share/list-utils"));
+        assert_eq!(paths.cache, Path::new("/home/ana/.cache/list-utils"));
+        assert_eq!(paths.state, Path::new("/home/ana/.local/state/list-utils"));
+
+        let paths = resolve(
+            &[
+                ("HOME", "/Users/ana"),
+                ("XDG_CACHE_HOME", "/tmp/cache"),
+                ("XDG_DATA_HOME", "relative/ignored"),
+                ("LIST_UTILS_DIR", "/srv/lists"),
+            ],
+            Platform::MacOs,
+        );
+        assert_eq!(paths.work, Path::new("/srv/lists"));
+        assert_eq!(
+            paths.data,
+            Path::new("/Users/ana/Library/Application Support/list-utils")
+        );
+        assert_eq!(paths.cache, Path::new("/tmp/cache/list-utils"));
+
+        // Without a home everything stays in the working directory
+        let paths = resolve(&[("LIST_UTILS_DIR", "/srv/lists")], Platform::Unix);
+        assert_eq!(paths, Paths::within(PathBuf::from("/srv/lists")));
+    }
+}
@@ -0,0 +1,113 @@
+//! Library of named reference lists stored in the data directory
+//!
+//! Each list is a plain `<name>.txt` file in a `references` directory of the
+//! data directory, so lists can also be added or edited outside the app.
+use super::{Config, Paths};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// Extension of reference list files
+const EXTENSION: &str = "txt";
+
+/// Name of the directory holding the reference lists
+const LIBRARY_DIR: &str = "references";
+
+/// Directory holding the reference lists
+///
+/// Lists used to live beside the config file; that directory is still used
+/// while it exists and the data directory has no library yet.
+pub fn library_dir(paths: &Paths) -> PathBuf {
+    let dir = paths.data.join(LIBRARY_DIR);
+    let legacy = Config::path()
+        .and_then(|path| Some(path.parent()?.join(LIBRARY_DIR)))
+        .filter(|legacy| legacy.is_dir());
+    match legacy {
+        Some(legacy) if !dir.exists() => legacy,
+        _ => dir,
+    }
+}
+
+/// Whether `name` can be used as a reference list name (and file stem)
+pub fn is_valid_name(name: &str) -> bool {
+    !name.trim().is_empty()
+        && !name.starts_with('.')
+        && !name.contains(['/', '\\'])
+        && !name.chars().any(char::is_control)
+}
+
+/// Names of the stored reference lists, sorted case-insensitively
+///
+/// A missing directory simply means the library is empty.
+pub fn reference_names(dir: &Path) -> Result<Vec<String>, io::Error> {
+    let entries = match fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(err) => return Err(err),
+    };
+    let mut names: Vec<String> = entries
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.path())
+        .filter(|path| path.is_file() && path.extension().is_some_and(|ext| ext == EXTENSION))
+        .filter_map(|path| Some(path.file_stem()?.to_str()?.to_string()))
+        .collect();
+    names.sort_by_key(|name| name.to_lowercase());
+    Ok(names)
+}
+
+/// File holding the reference list `name`
+pub fn reference_path(dir: &Path, name: &str) -> PathBuf {
+    dir.join(format!("{}.{}", name.trim(), EXTENSION))
+}
+
+/// Read the reference list `name`
+pub fn load_reference(dir: &Path, name: &str) -> Result<String, io::Error> {
+    fs::read_to_string(reference_path(dir, name))
+}
+
+/// Store `content` as the reference list `name`, replacing any list of that name
+pub fn save_reference(dir: &Path, name: &str, content: &str) -> Result<PathBuf, io::Error> {
+    if !is_valid_name(name) {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!("invalid reference name: {:?}", name),
+        ));
+    }
+    fs::create_dir_all(dir)?;
+    let path = reference_path(dir, name);
+    fs::write(&path, content)?;
+    Ok(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{env, process};
+
+    #[test]
+    fn test_save_list_and_load() {
+        let dir = env::temp_dir().join(format!("list-utils-references-{}", process::id()));
+        assert_eq!(reference_names(&dir).unwrap(), Vec::<String>::new());
+
+        save_reference(&dir, "country codes", "AR\nES\nUS").unwrap();
+        save_reference(&dir, "Allowed", "a\nb").unwrap();
+        fs::write(dir.join("notes.md"), "ignored").unwrap();
+
+        assert_eq!(
+            reference_names(&dir).unwrap(),
+            vec!["Allowed", "country codes"]
+        );
+        assert_eq!(load_reference(&dir, "country codes").unwrap(), "AR\nES\nUS");
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_invalid_names() {
+        assert!(is_valid_name("iso-3166"));
+        for name in ["", "  ", ".hidden", "../escape", "a\\b", "tab\there"] {
+            assert!(!is_valid_name(name), "{:?}", name);
+        }
+        let dir = env::temp_dir();
+        assert!(save_reference(&dir, "../x", "").is_err());
+    }
+}
@@ -0,0 +1,928 @@
+//! Headless driver feeding synthetic input events through the app loop
+//!
+//! Events go through the same `handle_event` path as the terminal, without
+//! `crossterm::event::read`, and every event is followed by a draw on a
+//! `TestBackend`. Tests script keys and pastes, then check the app state or
+//! the rendered screen.
+use super::{draw, handle_event, subscribe_all};
+use crate::app::App;
+use crate::config::{Config, Paths};
+use crate::events::InputEvent;
+use crate::i18n::{force_lang, Lang};
+use crate::numbers::NumberFormat;
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use ratatui::{backend::TestBackend, buffer::Buffer, Terminal};
+use std::path::PathBuf;
+use std::thread;
+use std::time::Duration;
+
+/// Terminal size the screens are rendered at
+pub const WIDTH: u16 = 100;
+pub const HEIGHT: u16 = 30;
+
+/// App with default preferences, English texts and no clipboard, independent of the environment
+pub fn test_app() -> App {
+    force_lang(Lang::En);
+    let mut app = App::with_config(Config::default());
+    app.number_format = NumberFormat::default();
+    app.paths = Paths::within(PathBuf::from("."));
+    app.clipboard = None;
+    subscribe_all(&mut app);
+    app
+}
+
+/// Text of every row of a rendered screen, trailing spaces trimmed
+pub fn screen_text(buffer: &Buffer) -> String {
+    let area = buffer.area;
+    (area.top()..area.bottom())
+        .map(|y| {
+            let row: String = (area.left()..area.right())
+                .map(|x| buffer[(x, y)].symbol())
+                .collect();
+            format!("{}\n", row.trim_end())
+        })
+        .collect()
+}
+
+/// App driven by scripted events
+pub struct Driver {
+    /// The driven app, open for inspection and setup
+    pub app: App,
+    terminal: Terminal<TestBackend>,
+}
+
+impl Driver {
+    /// Driver around [`test_app`], drawn once like the first frame of the loop
+    pub fn new() -> Self {
+        Self::with_app(test_app())
+    }
+
+    /// Driver around a prepared app
+    pub fn with_app(app: App) -> Self {
+        let terminal = Terminal::new(TestBackend::new(WIDTH, HEIGHT)).unwrap();
+        let mut driver = Self { app, terminal };
+        driver.draw();
+        driver
+    }
+
+    fn draw(&mut self) {
+        let app = &mut self.app;
+        self.terminal.draw(|f| draw(f, app)).unwrap();
+    }
+
+    /// Handle one event as the loop would, then redraw
+    ///
+    /// # Panics
+    /// Panics if handling the event fails.
+    pub fn send(&mut self, event: InputEvent) -> &mut Self {
+        handle_event(&mut self.app, event).unwrap();
+        self.draw();
+        self
+    }
+
+    /// Press a key without modifiers
+    pub fn key(&mut self, code: KeyCode) -> &mut Self {
+        self.press(code, KeyModifiers::NONE)
+    }
+
+    /// Press a key with modifiers
+    pub fn press(&mut self, code: KeyCode, modifiers: KeyModifiers) -> &mut Self {
+        self.send(InputEvent::Key(KeyEvent::new(code, modifiers)))
+    }
+
+    /// Type text one key at a time (`\n` presses Enter)
+    pub fn type_text(&mut self, text: &str) -> &mut Self {
+        for c in text.chars() {
+            match c {
+                '\n' => self.key(KeyCode::Enter),
+                c => self.key(KeyCode::Char(c)),
+            };
+        }
+        self
+    }
+
+    /// Paste text as the terminal does with bracketed paste
+    pub fn paste(&mut self, text: &str) -> &mut Self {
+        self.send(InputEvent::Paste(text.to_string()))
+    }
+
+    /// Tick until the background task finished, as the loop does while it runs
+    pub fn finish_task(&mut self) -> &mut Self {
+        while self.app.task.is_some() {
+            thread::sleep(Duration::from_millis(1));
+            self.send(InputEvent::Tick);
+        }
+        self
+    }
+
+    /// Text of the last drawn screen
+    pub fn screen(&self) -> String {
+        screen_text(self.terminal.backend().buffer())
+    }
+}
+
+mod tests {
+    use super::*;
+    use crate::app::{BackgroundTask, Mode, Notice, PanelId, ResultsPanel};
+    use crate::i18n::Text;
+    use crate::operations::Tolerance;
+    use crate::panels::PanelContent;
+    use crate::parser::Delimiter;
+    use std::fs;
+    use std::time::Duration;
+    use tui_textarea::TextArea;
+
+    fn lines(driver: &mut Driver, panel: PanelId) -> Vec<String> {
+        driver.app.textarea(panel).lines().to_vec()
+    }
+
+    #[test]
+    fn test_paste_compare_export() {
+        let dir = std::env::temp_dir().join(format!("list-utils-e2e-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let mut driver = Driver::new();
+        driver.app.paths = Paths::within(dir.clone());
+
+        driver
+            .paste("apple\nbanana\ncherry")
+            .key(KeyCode::Tab)
+            .paste("banana\ncherry\ndate")
+            .key(KeyCode::F(12));
+        let result = driver.app.results_tab.compare_results.as_ref().unwrap();
+        assert_eq!(result.only_in_first, vec!["apple"]);
+        assert_eq!(result.only_in_second, vec!["date"]);
+        assert!(driver.screen().contains("Results  (2Δ)"));
+
+        driver.key(KeyCode::Char('m')).key(KeyCode::Enter);
+        let report = fs::read_to_string(dir.join("comparison_report.md")).unwrap();
+        assert!(report.contains("apple"));
+
+        // Another name keeps the first report, and gets the extension when it has none
+        driver.key(KeyCode::Char('m'));
+        driver.app.prompt.as_mut().unwrap().input.select_all();
+        driver.type_text("before_fix\n");
+        assert!(fs::read_to_string(dir.join("before_fix.md"))
+            .unwrap()
+            .contains("apple"));
+        assert!(dir.join("comparison_report.md").exists());
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_typing_in_insert_mode() {
+        let mut driver = Driver::new();
+        driver.key(KeyCode::Char('i')).type_text("one\ntwo");
+        assert_eq!(driver.app.mode, Mode::Insert);
+        driver.key(KeyCode::Esc);
+        assert_eq!(driver.app.mode, Mode::Normal);
+        assert_eq!(lines(&mut driver, PanelId::List1), vec!["one", "two"]);
+        assert!(driver.screen().contains("│two"));
+    }
+
+    #[test]
+    fn test_trim_on_paste() {
+        let mut driver = Driver::new();
+        driver.key(KeyCode::Char('~')).paste("  a \n b\n\n");
+        assert!(driver.app.trim_on_paste);
+        assert_eq!(lines(&mut driver, PanelId::List1), vec!["a", "b"]);
+    }
+
+    #[test]
+    fn test_email_normalize_preset() {
+        let mut driver = Driver::new();
+        driver
+            .paste("John Doe <J.Doe@Example.com>\nann+news@x.com\nnot an email")
+            .key(KeyCode::Tab)
+            .paste("j.doe@example.com\nann@x.com")
+            .key(KeyCode::Char('c'))
+            .key(KeyCode::Char('c'));
+        assert_eq!(driver.app.results, vec!["Normalize items: email-no-tags"]);
+        driver.key(KeyCode::F(12));
+        let result = driver.app.results_tab.compare_results.as_ref().unwrap();
+        assert_eq!(result.intersection.len(), 2);
+        assert_eq!(result.only_in_first.len(), 1);
+        assert!(result.only_in_second.is_empty());
+        assert!(driver.app.results.contains(
+            &"1 items are not valid for the email-no-tags preset and were compared as typed"
+                .to_string()
+        ));
+    }
+
+    #[test]
+    fn test_keep_input_order() {
+        let mut driver = Driver::new();
+        driver
+            .paste("c\na\nb")
+            .key(KeyCode::Tab)
+            .paste("b\nz")
+            .key(KeyCode::Char('Y'));
+        assert_eq!(driver.app.results, vec!["Results in input order ON"]);
+        driver.key(KeyCode::F(12));
+        let result = driver.app.results_tab.compare_results.as_ref().unwrap();
+        assert_eq!(result.only_in_first, vec!["c", "a"]);
+        assert_eq!(result.union, vec!["c", "a", "b", "z"]);
+        driver.press(KeyCode::Char('2'), KeyModifiers::ALT);
+        assert!(driver.screen().contains("Order: input"));
+    }
+
+    #[test]
+    fn test_compare_column_by_header() {
+        let mut driver = Driver::new();
+        driver
+            .paste("id,email,name\n1,a@x.com,Ann\n2,b@x.com,Bo")
+            .key(KeyCode::Tab)
+            .paste("email\tid\nb@x.com\t9\nc@x.com\t8")
+            .key(KeyCode::Char('#'))
+            .type_text("phone\n")
+            .key(KeyCode::F(12));
+        assert_eq!(
+            driver.app.results,
+            vec!["No header named phone in the first row"]
+        );
+
+        driver.key(KeyCode::Char('#'));
+        driver.app.prompt.as_mut().unwrap().input.select_all();
+        driver.type_text("Email\n");
+        assert_eq!(driver.app.results, vec!["Compare column Email"]);
+        driver.key(KeyCode::F(12));
+        let result = driver.app.results_tab.compare_results.as_ref().unwrap();
+        assert_eq!(result.only_in_first, vec!["a@x.com"]);
+        assert_eq!(result.only_in_second, vec!["c@x.com"]);
+        assert_eq!(result.intersection, vec!["b@x.com"]);
+    }
+
+    #[test]
+    fn test_tolerance_matches_rounded_numbers() {
+        let mut driver = Driver::new();
+        driver
+            .paste("10.00\n20")
+            .key(KeyCode::Tab)
+            .paste("10.001\n30")
+            .key(KeyCode::Char('%'))
+            .type_text("abc\n");
+        assert_eq!(
+            driver.app.results,
+            vec!["Not a tolerance: abc (use e.g. 0.01 or 1%)"]
+        );
+
+        driver.key(KeyCode::Char('%')).type_text("0.01\n");
+        assert_eq!(
+            driver.app.compare_options.tolerance,
+            Some(Tolerance::Absolute(0.01))
+        );
+        driver.key(KeyCode::F(12));
+        let result = driver.app.results_tab.compare_results.as_ref().unwrap();
+        assert_eq!(result.intersection, vec!["10.00"]);
+        assert_eq!(result.only_in_first, vec!["20"]);
+        assert_eq!(result.only_in_second, vec!["30"]);
+        assert!(driver
+            .app
+            .results
+            .contains(&"  10.00 ≈ 10.001 (Δ +0.001)".to_string()));
+    }
+
+    #[test]
+    fn test_result_panel_sort_order() {
+        let mut driver = Driver::new();
+        driver
+            .paste("pear\n10\nApple\n9\npear")
+            .key(KeyCode::Tab)
+            .paste("kiwi\npear")
+            .key(KeyCode::F(12))
+            .press(KeyCode::Char('2'), KeyModifiers::ALT)
+            .key(KeyCode::Char('4'))
+            .key(KeyCode::Char('s'));
+        let results_tab = &driver.app.results_tab;
+        assert_eq!(
+            results_tab.category_items(ResultsPanel::Union),
+            vec!["pear", "10", "Apple", "9", "kiwi"]
+        );
+        assert_eq!(
+            results_tab.category_items(ResultsPanel::OnlyInList1),
+            vec!["9", "10", "Apple"]
+        );
+        // The stored results keep the smart order
+        let result = results_tab.compare_results.as_ref().unwrap();
+        assert_eq!(result.union, vec!["9", "10", "Apple", "kiwi", "pear"]);
+        assert_eq!(driver.app.results, vec!["Union sorted by original order"]);
+        assert!(driver.screen().contains("original order"));
+
+        // The order carries over to the next comparison
+        driver
+            .press(KeyCode::Char('1'), KeyModifiers::ALT)
+            .key(KeyCode::F(12));
+        assert_eq!(
+            driver.app.results_tab.category_items(ResultsPanel::Union)[0],
+            "pear"
+        );
+    }
+
+    #[test]
+    fn test_result_table_uses_header_row() {
+        let mut driver = Driver::new();
+        let rows: String = (1..=20).map(|n| format!("\n{},user{}", n, n)).collect();
+        driver
+            .paste(&format!("id,name{}", rows))
+            .key(KeyCode::Tab)
+            .paste(&format!("id,name{}\n21,someone with a long name", rows))
+            .key(KeyCode::F(12));
+        let results_tab = &driver.app.results_tab;
+        assert_eq!(results_tab.record_header.as_deref(), Some("id,name"));
+        // Measured over all items, not only the rows on screen
+        assert_eq!(
+            results_tab.table_column_widths(ResultsPanel::Union),
+            &[2, 24]
+        );
+        let screen = driver.screen();
+        assert!(screen.contains("id  name"));
+        assert!(!screen.contains("Column 1"));
+    }
+
+    #[test]
+    fn test_paste_detects_delimiter() {
+        let mut driver = Driver::new();
+        driver.paste("a,b,c");
+        assert_eq!(driver.app.delimiter, Delimiter::Comma);
+        assert!(driver.screen().contains("Delim: , (auto)"));
+
+        // Only empty editors are inspected
+        driver.paste("\nd;e");
+        assert_eq!(driver.app.delimiter, Delimiter::Comma);
+
+        driver.key(KeyCode::F(5));
+        assert_eq!(driver.app.detected_delimiter, None);
+        assert!(!driver.screen().contains("(auto)"));
+    }
+
+    #[test]
+    fn test_detected_delimiter_spares_other_lists() {
+        let mut driver = Driver::new();
+        driver.key(KeyCode::Tab).paste("Smith, John\nDoe, Jane");
+        assert_eq!(driver.app.delimiter, Delimiter::Newline);
+        assert_eq!(driver.app.input_tab.list2.lines().len(), 2);
+
+        // List 2 already holds text, so the delimiter detected in List 1 is only offered
+        driver.key(KeyCode::BackTab).paste("a;b;c");
+        assert_eq!(driver.app.delimiter, Delimiter::Newline);
+        assert_eq!(driver.app.suggested_delimiter, Some(Delimiter::Semicolon));
+        assert!(driver.app.results[0].starts_with("Detected delimiter ;"));
+        assert!(driver.screen().contains("(a: switch to ;)"));
+
+        driver.key(KeyCode::Char('a'));
+        assert_eq!(driver.app.delimiter, Delimiter::Semicolon);
+        assert!(!driver.screen().contains("switch to"));
+    }
+
+    #[test]
+    fn test_delimiter_mismatch_offers_a_fix() {
+        let mut driver = Driver::new();
+        driver.app.auto_delimiter = false;
+        driver.paste(&vec!["id"; 200].join(","));
+        assert_eq!(driver.app.suggested_delimiter, Some(Delimiter::Comma));
+        assert!(driver.app.results[0].contains(", occurs 199 times"));
+
+        driver.key(KeyCode::Char('a'));
+        assert_eq!(driver.app.delimiter, Delimiter::Comma);
+        assert_eq!(driver.app.suggested_delimiter, None);
+        assert_eq!(driver.app.results, vec!["Delimiter switched to ,"]);
+    }
+
+    #[test]
+    fn test_convert_item_wrap() {
+        let mut driver = Driver::new();
+        driver.press(KeyCode::Char('3'), KeyModifiers::ALT);
+        driver.paste("a\nb").key(KeyCode::Char('\''));
+        assert!(driver.screen().contains("Wrap each item"));
+        driver.type_text("'\n").key(KeyCode::F(12));
+        assert_eq!(driver.app.convert_tab.output_serialized, "'a','b'");
+
+        driver.key(KeyCode::Char('\''));
+        driver.app.prompt.as_mut().unwrap().input.select_all();
+        driver.type_text("<li>{}</li>\n");
+        assert_eq!(
+            driver.app.results,
+            vec!["Converted items wrapped as <li>…</li> (press F12 to convert again)"]
+        );
+    }
+
+    #[test]
+    fn test_convert_list_literal() {
+        let mut driver = Driver::new();
+        driver.press(KeyCode::Char('3'), KeyModifiers::ALT);
+        driver.paste("it's\nb").key(KeyCode::Char('('));
+        driver.key(KeyCode::F(12));
+        assert_eq!(driver.app.convert_tab.output_serialized, "['it\\'s', 'b']");
+        assert_eq!(
+            driver.app.results,
+            vec!["Converted 2 item(s) to Python list"]
+        );
+        driver.key(KeyCode::Char('(')).key(KeyCode::Char('('));
+        assert_eq!(driver.app.convert_tab.literal, None);
+    }
+
+    #[test]
+    fn test_convert_markdown_table() {
+        let mut driver = Driver::new();
+        driver.press(KeyCode::Char('3'), KeyModifiers::ALT);
+        driver
+            .paste(r#"[{"name": "Ann", "qty": 3}, {"name": "Bo|b", "qty": 12}]"#)
+            .key(KeyCode::Char('v'))
+            .key(KeyCode::F(12));
+        assert_eq!(
+            driver.app.convert_tab.output_items,
+            vec![
+                "| name  | qty |",
+                "| ----- | --: |",
+                "| Ann   |   3 |",
+                "| Bo\\|b |  12 |",
+            ]
+        );
+        assert_eq!(
+            driver.app.results,
+            vec!["Converted 2 item(s) to Markdown table"]
+        );
+
+        let mut driver = Driver::new();
+        driver.press(KeyCode::Char('3'), KeyModifiers::ALT);
+        driver
+            .paste("plain\nwords")
+            .key(KeyCode::Char('v'))
+            .key(KeyCode::F(12));
+        assert_eq!(
+            driver.app.results,
+            vec!["Markdown tables need JSON objects or delimited rows with a header"]
+        );
+    }
+
+    #[test]
+    fn test_aligned_preview_follows_output() {
+        let mut driver = Driver::new();
+        driver.press(KeyCode::Char('3'), KeyModifiers::ALT);
+        driver.app.convert_tab.table_mode = true;
+        driver
+            .paste("id,name\n100,東京")
+            .key(KeyCode::F(12))
+            .key(KeyCode::Esc)
+            .key(KeyCode::Char('P'));
+        assert_eq!(
+            driver.app.convert_tab.aligned_output,
+            Some(vec!["id , name".to_string(), "100, 東京".to_string()])
+        );
+        assert!(driver.screen().contains("100, 東京"));
+
+        // A new conversion refreshes the padded rows
+        driver.app.convert_tab.input = TextArea::from(["id,name", "100,東京", "7,x"]);
+        driver.key(KeyCode::F(12));
+        assert_eq!(
+            driver.app.convert_tab.aligned_output.as_ref().unwrap()[2],
+            "7  , x"
+        );
+        driver.key(KeyCode::Char('P'));
+        assert_eq!(driver.app.convert_tab.aligned_output, None);
+    }
+
+    #[test]
+    fn test_convert_rows_to_json() {
+        let mut driver = Driver::new();
+        driver.press(KeyCode::Char('3'), KeyModifiers::ALT);
+        driver.paste("id,name,active\n1,\"Doe, J\",true\n2,,false");
+        driver.app.convert_tab.target_delimiter = Delimiter::Json;
+        driver.key(KeyCode::F(12));
+        assert_eq!(
+            driver.app.convert_tab.output_items,
+            vec![
+                "[",
+                r#"  {"id": 1, "name": "Doe, J", "active": true},"#,
+                r#"  {"id": 2, "name": null, "active": false}"#,
+                "]",
+            ]
+        );
+        assert_eq!(driver.app.results, vec!["Converted 2 item(s) to JSON"]);
+    }
+
+    #[test]
+    fn test_undo_redo_sort_and_dedup() {
+        let mut driver = Driver::new();
+        driver.paste("b\na\nb").key(KeyCode::F(6));
+        let list1 = |driver: &Driver| driver.app.input_tab.list1.lines().join(",");
+        assert_eq!(list1(&driver), "a,b,b");
+
+        driver.press(KeyCode::Char('z'), KeyModifiers::CONTROL);
+        assert_eq!(list1(&driver), "b,a,b");
+        assert_eq!(driver.app.results, vec!["Undid the last change to List 1"]);
+        driver.press(KeyCode::Char('y'), KeyModifiers::CONTROL);
+        assert_eq!(list1(&driver), "a,b,b");
+
+        // A new change drops what could be redone
+        driver.key(KeyCode::F(8)).key(KeyCode::Char('u'));
+        assert_eq!(list1(&driver), "a,b,b");
+        assert_eq!(driver.app.redo_panel(), Some(PanelId::List1));
+        driver.key(KeyCode::F(7));
+        assert_eq!(list1(&driver), "b,b,a");
+        assert_eq!(driver.app.redo_panel(), None);
+
+        // The paste itself can be undone too
+        driver.key(KeyCode::Char('u')).key(KeyCode::Char('u'));
+        assert_eq!(list1(&driver), "b,a,b");
+        driver.key(KeyCode::Char('u'));
+        assert_eq!(list1(&driver), "");
+    }
+
+    #[test]
+    fn test_undo_depth_and_budget() {
+        let mut driver = Driver::new();
+        driver.app.undo_depth = 2;
+        driver
+            .paste("b\na\nb")
+            .key(KeyCode::F(6))
+            .key(KeyCode::F(7));
+        let list1 = |driver: &Driver| driver.app.input_tab.list1.lines().join(",");
+        // The paste was evicted to make room for the sorts
+        assert_eq!(driver.app.undo_stack.len(), 2);
+        assert_eq!(driver.app.undo_stack[0].lines, vec!["b", "a", "b"]);
+
+        // Only the newest snapshot fits in the budget, but it is always kept
+        driver.app.undo_budget = 1;
+        driver.key(KeyCode::F(8));
+        assert_eq!(driver.app.undo_stack.len(), 1);
+        driver.key(KeyCode::Char('u')).key(KeyCode::Char('u'));
+        assert_eq!(list1(&driver), "b,b,a");
+
+        driver.app.undo_depth = 0;
+        driver.key(KeyCode::F(6));
+        assert!(driver.app.undo_stack.is_empty());
+    }
+
+    #[test]
+    fn test_count_frequencies() {
+        let mut driver = Driver::new();
+        driver.paste("b\na\nb\nc\na\nb");
+        driver.press(KeyCode::F(8), KeyModifiers::SHIFT);
+        assert_eq!(driver.app.input_tab.list1.lines(), ["b\t3", "a\t2", "c\t1"]);
+        assert_eq!(
+            driver.app.results,
+            vec!["Counted 6 items: 3 distinct, most frequent first (u to undo)"]
+        );
+        // Terminals that send F20 for Shift+F8 count too
+        driver.key(KeyCode::Char('u')).key(KeyCode::F(20));
+        assert_eq!(driver.app.input_tab.list1.lines()[0], "b\t3");
+    }
+
+    #[test]
+    fn test_keep_duplicates() {
+        let mut driver = Driver::new();
+        driver.paste("x\nb\na\nb\na\na").key(KeyCode::Char('d'));
+        assert_eq!(driver.app.input_tab.list1.lines(), ["a", "b"]);
+        assert_eq!(
+            driver.app.results,
+            vec!["Kept 2 duplicated items (5 occurrences), dropped 1 unique (u to undo)"]
+        );
+
+        driver.key(KeyCode::Char('u'));
+        driver.press(KeyCode::Char('d'), KeyModifiers::CONTROL);
+        assert_eq!(driver.app.input_tab.list1.lines(), ["a\t3", "b\t2"]);
+
+        // Nothing repeats any more
+        driver.key(KeyCode::Char('d'));
+        assert_eq!(driver.app.input_tab.list1.lines(), ["a\t3", "b\t2"]);
+        assert_eq!(
+            driver.app.results,
+            vec!["No duplicates among 2 items, list left as is"]
+        );
+    }
+
+    #[test]
+    fn test_filter_regex() {
+        let mut driver = Driver::new();
+        driver.paste("ERR-1\nok\nERR-22\nwarn");
+        driver.key(KeyCode::Char('/')).type_text(r"^ERR-\d+$");
+        driver.key(KeyCode::Enter);
+        assert_eq!(driver.app.input_tab.list1.lines(), ["ERR-1", "ERR-22"]);
+        assert_eq!(
+            driver.app.results,
+            vec![r"Kept 2 items matching ^ERR-\d+$, dropped 2 (u to undo)"]
+        );
+
+        // Tab switches the prompt to dropping the matches
+        driver.key(KeyCode::Char('u')).key(KeyCode::Char('/'));
+        driver
+            .type_text("ERR")
+            .key(KeyCode::Tab)
+            .key(KeyCode::Enter);
+        assert_eq!(driver.app.input_tab.list1.lines(), ["ok", "warn"]);
+
+        driver
+            .key(KeyCode::Char('/'))
+            .type_text("(")
+            .key(KeyCode::Enter);
+        assert!(driver.app.results[0].starts_with("Invalid regular expression"));
+        assert_eq!(driver.app.input_tab.list1.lines(), ["ok", "warn"]);
+    }
+
+    #[test]
+    fn test_replace_regex() {
+        let mut driver = Driver::new();
+        driver.paste("id=7 name=ann\nid=12\nnone");
+        driver.key(KeyCode::Char('&')).type_text(r"id=(\d+)");
+        driver.key(KeyCode::Enter);
+        assert_eq!(
+            driver.app.results,
+            vec!["2 of 3 lines of List 1 would change (Enter applies, Esc cancels)"]
+        );
+        // The preview follows the replacement as it is typed
+        driver.type_text("id=$1");
+        assert!(driver.app.results[0].starts_with("0 of 3 lines"));
+        driver.type_text(";");
+        assert!(driver.app.results[0].starts_with("2 of 3 lines"));
+        driver.key(KeyCode::Enter);
+        assert_eq!(
+            driver.app.input_tab.list1.lines(),
+            ["id=7; name=ann", "id=12;", "none"]
+        );
+        assert_eq!(
+            driver.app.results,
+            vec![r"Replaced the matches of id=(\d+) in 2 of 3 lines (u to undo)"]
+        );
+
+        driver.key(KeyCode::Char('u'));
+        assert_eq!(
+            driver.app.input_tab.list1.lines(),
+            ["id=7 name=ann", "id=12", "none"]
+        );
+        driver
+            .key(KeyCode::Char('&'))
+            .type_text("x")
+            .key(KeyCode::Enter)
+            .type_text("y")
+            .key(KeyCode::Enter);
+        assert_eq!(driver.app.results, vec!["No line changes when replacing x"]);
+
+        driver
+            .key(KeyCode::Char('&'))
+            .type_text("(")
+            .key(KeyCode::Enter);
+        assert!(driver.app.results[0].starts_with("Invalid regular expression"));
+        assert!(driver.app.prompt.is_none());
+    }
+
+    #[test]
+    fn test_slow_operation_notice() {
+        let mut driver = Driver::new();
+        driver.app.notice = Notice::Bell;
+        driver.app.notice_after = Duration::ZERO;
+        driver.paste("a\nb").key(KeyCode::Tab).paste("b");
+        driver.app.ring_bell = false;
+        driver.key(KeyCode::F(12));
+        assert!(driver.app.ring_bell);
+        assert!(!driver.app.flash);
+
+        driver.app.ring_bell = false;
+        driver.app.notice = Notice::Flash;
+        driver.key(KeyCode::F(12));
+        assert!(driver.app.flash && !driver.app.ring_bell);
+
+        // Quick operations stay quiet
+        driver.app.flash = false;
+        driver.app.notice_after = Duration::from_secs(3600);
+        driver.key(KeyCode::F(12));
+        assert!(!driver.app.flash);
+    }
+
+    #[test]
+    fn test_convert_json_path() {
+        let mut driver = Driver::new();
+        driver.press(KeyCode::Char('3'), KeyModifiers::ALT);
+        driver
+            .paste(r#"{"items": [{"id": 7, "tag": "a"}, {"id": "x9"}, {"tag": "b"}]}"#)
+            .key(KeyCode::Char('x'));
+        assert!(driver.screen().contains("JSON path"));
+        driver.type_text("$.items[*].id\n");
+        assert!(driver.screen().contains("Path: $.items[*].id"));
+        driver.key(KeyCode::F(12));
+        assert_eq!(driver.app.convert_tab.output_items, vec!["7,x9"]);
+
+        driver.key(KeyCode::Char('x'));
+        for _ in 0.."$.items[*].id".len() {
+            driver.key(KeyCode::Backspace);
+        }
+        driver.type_text("missing\n").key(KeyCode::F(12));
+        assert_eq!(
+            driver.app.results,
+            vec!["JSON path missing matched nothing"]
+        );
+        assert!(driver.app.convert_tab.output_items.is_empty());
+    }
+
+    #[test]
+    fn test_custom_delimiter() {
+        let mut driver = Driver::new();
+        driver.key(KeyCode::Char(':'));
+        assert!(driver.screen().contains("List delimiter"));
+        driver.type_text("||\n").paste("a,1||b,2||c");
+        assert_eq!(driver.app.delimiter.display_name(), "||");
+        assert!(driver.screen().contains("Delim: ||"));
+        driver.key(KeyCode::Tab).paste("b,2").key(KeyCode::F(12));
+        let result = driver.app.results_tab.compare_results.as_ref().unwrap();
+        assert_eq!(result.only_in_first, vec!["a,1", "c"]);
+
+        driver.press(KeyCode::Char('3'), KeyModifiers::ALT);
+        driver.key(KeyCode::Char(':')).type_text("::\n");
+        driver.paste("x::y::z").key(KeyCode::Tab);
+        driver.key(KeyCode::Char(':')).type_text(" | \n");
+        driver.key(KeyCode::F(12));
+        assert_eq!(driver.app.convert_tab.output_serialized, "x | y | z");
+
+        // Records need a single-character separator
+        driver.key(KeyCode::Char('T')).key(KeyCode::F(12));
+        assert!(driver.app.results[0].starts_with(" |  cannot separate fields"));
+    }
+
+    #[test]
+    fn test_keyed_env_comparison() {
+        let mut driver = Driver::new();
+        driver
+            .paste("# app\nexport HOST=db1\nPORT=5432\nDEBUG=1")
+            .key(KeyCode::Tab)
+            .paste("HOST=db1\nPORT=\"6543\"\nLOG=info")
+            .key(KeyCode::Char('Z'));
+        assert_eq!(driver.app.active_tab, crate::app::Tab::Results);
+        let report = driver.app.results_tab.keyed.as_ref().unwrap();
+        assert_eq!(report.missing_in_second, vec!["DEBUG\t1"]);
+        assert_eq!(report.missing_in_first, vec!["LOG\tinfo"]);
+        assert_eq!(report.changed, vec!["PORT\t5432\t6543"]);
+        let screen = driver.screen();
+        assert!(screen.contains("Missing in List 2 (1 items)"));
+        assert!(screen.contains("Changed values (1 items)"));
+        assert!(screen.contains("(.env) | 1 unchanged"));
+
+        driver.key(KeyCode::Tab).key(KeyCode::Tab);
+        let panel = PanelContent::active(&driver.app).unwrap();
+        assert_eq!(panel, PanelContent::ChangedValues);
+        assert_eq!(panel.content(&driver.app), "PORT\t5432\t6543");
+    }
+
+    #[test]
+    fn test_keyed_requirements_comparison() {
+        let mut driver = Driver::new();
+        driver
+            .paste("Django==4.2\nrequests>=2.0\nsix==1.16")
+            .key(KeyCode::Tab)
+            .paste("django==5.0\nrequests>=2.0\nurllib3==2.2")
+            .key(KeyCode::Char('z'));
+        assert_eq!(
+            driver.app.results,
+            vec!["Compare by key as requirements.txt"]
+        );
+        driver.key(KeyCode::Char('Z'));
+        let screen = driver.screen();
+        assert!(screen.contains("Removed (1 items)"));
+        assert!(screen.contains("Added (1 items)"));
+        assert!(screen.contains("Version changed (1 items)"));
+        assert_eq!(
+            driver.app.results_tab.keyed.as_ref().unwrap().changed,
+            vec!["django\t4.2\t5.0"]
+        );
+    }
+
+    #[test]
+    fn test_bundle_export_and_replay() {
+        let dir = std::env::temp_dir().join(format!("list-utils-bundle-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let mut driver = Driver::new();
+        driver.app.paths = Paths::within(dir.clone());
+        driver
+            .paste("Apple;pear")
+            .key(KeyCode::Tab)
+            .paste("apple;plum")
+            .key(KeyCode::F(3))
+            .key(KeyCode::F(12));
+        let recorded = driver.app.results_tab.compare_results.clone().unwrap();
+        driver.key(KeyCode::Char('e')).type_text("\n");
+        assert!(dir.join("comparison.lcmp").exists());
+
+        let mut replay = Driver::new();
+        replay.app.paths = Paths::within(dir.clone());
+        replay.key(KeyCode::Char('E')).type_text("\n");
+        assert_eq!(replay.app.delimiter, Delimiter::Semicolon);
+        assert_eq!(lines(&mut replay, PanelId::List2), vec!["apple;plum"]);
+        let replayed = replay.app.results_tab.compare_results.as_ref().unwrap();
+        assert_eq!(replayed.intersection, recorded.intersection);
+        let secs =
+            |at: std::time::SystemTime| at.duration_since(std::time::UNIX_EPOCH).unwrap().as_secs();
+        assert_eq!(secs(replayed.compared_at), secs(recorded.compared_at));
+        assert!(!replay.app.results_tab.stale);
+        assert!(replay.app.results[0].starts_with("Replayed the comparison bundle"));
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_watch_compares_changed_files() {
+        let dir = std::env::temp_dir().join(format!("list-utils-watch-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("list1.txt"), "a\nb").unwrap();
+        fs::write(dir.join("list2.txt"), "b").unwrap();
+        let mut driver = Driver::new();
+        driver.app.paths = Paths::within(dir.clone());
+        driver.app.watch_interval = std::time::Duration::ZERO;
+        driver.key(KeyCode::Char('M'));
+        assert!(driver.app.watch.is_some());
+        assert!(driver.screen().contains("Watch 0s |"));
+
+        // Unchanged files are not compared again
+        driver.send(InputEvent::Tick);
+        assert_eq!(driver.app.watch.as_ref().unwrap().changes, 0);
+
+        fs::write(dir.join("list2.txt"), "b\nc").unwrap();
+        let later = std::time::SystemTime::now() + std::time::Duration::from_secs(10);
+        fs::File::options()
+            .write(true)
+            .open(dir.join("list2.txt"))
+            .unwrap()
+            .set_modified(later)
+            .unwrap();
+        driver.send(InputEvent::Tick);
+        let result = driver.app.results_tab.compare_results.as_ref().unwrap();
+        assert_eq!(result.only_in_second, vec!["c"]);
+        assert!(!driver.app.results_tab.stale);
+        assert!(driver.app.results[0].starts_with("Results changed at"));
+        assert!(driver.app.watch.as_ref().unwrap().unseen);
+        assert!(driver.screen().contains("Watch 0s (1Δ)"));
+
+        driver.key(KeyCode::Char('M'));
+        assert!(driver.app.watch.is_none());
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_background_compare_shows_progress() {
+        let mut driver = Driver::new();
+        driver.app.background_above = 0;
+        driver.paste("a\nb").key(KeyCode::Tab).paste("b\nc");
+        driver.key(KeyCode::F(12));
+        assert!(driver.screen().contains("Comparing 0% 0s (Esc cancels) |"));
+
+        driver.finish_task();
+        let result = driver.app.results_tab.compare_results.as_ref().unwrap();
+        assert_eq!(result.intersection, vec!["b"]);
+        assert_eq!(driver.app.active_tab, crate::app::Tab::Results);
+        assert!(!driver.screen().contains("Esc cancels"));
+    }
+
+    #[test]
+    fn test_background_sample_then_full_run() {
+        let mut driver = Driver::new();
+        driver.app.background_above = 0;
+        driver.app.sample_above = 1;
+        driver.paste("a\nb").key(KeyCode::Tab).paste("b\nc");
+        driver.key(KeyCode::F(12));
+        assert!(driver.screen().contains("Sampling 0% 0s (Esc cancels) |"));
+
+        driver.finish_task();
+        assert_eq!(
+            driver.app.results[1],
+            "Large lists (4 items): press F12 again to run the full comparison"
+        );
+        assert!(driver.app.results_tab.compare_results.is_none());
+
+        driver.key(KeyCode::F(12));
+        assert!(driver.screen().contains("Comparing"));
+        driver.finish_task();
+        let result = driver.app.results_tab.compare_results.as_ref().unwrap();
+        assert_eq!(result.intersection, vec!["b"]);
+    }
+
+    #[test]
+    fn test_background_task_can_be_cancelled() {
+        let mut driver = Driver::new();
+        driver.app.task = Some(BackgroundTask::spawn(Text::TaskLoading, |progress| {
+            while progress.report(1, 4) {
+                thread::sleep(Duration::from_millis(1));
+            }
+            None
+        }));
+        driver.app.mode = Mode::Insert;
+        while driver.app.task.as_ref().unwrap().percent() == 0 {
+            thread::sleep(Duration::from_millis(1));
+            driver.send(InputEvent::Tick);
+        }
+        assert!(driver.screen().contains("Loading 25% 0s (Esc cancels) |"));
+        driver.key(KeyCode::F(2));
+        assert!(driver.app.results[0].starts_with("Loading is still running"));
+
+        driver.key(KeyCode::Esc);
+        assert!(driver.app.task.is_none());
+        assert_eq!(driver.app.results, vec!["Cancelled: Loading"]);
+        // Esc went to the task, not to the editor
+        assert_eq!(driver.app.mode, Mode::Insert);
+    }
+
+    #[test]
+    fn test_binary_paste_can_be_aborted() {
+        let mut driver = Driver::new();
+        driver.paste("a\0b");
+        assert!(driver.app.binary_picker.is_some());
+        driver.key(KeyCode::Esc);
+        assert!(driver.app.binary_picker.is_none());
+        assert_eq!(lines(&mut driver, PanelId::List1), vec![""]);
+    }
+}
@@ -1,6 +1,7 @@
 //! Event handling for keyboard and mouse input
-use crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind, MouseEvent};
+use crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers, MouseEvent};
 use std::io;
+use std::time::Duration;
 
 /// Represents different types of input events
 #[derive(Debug, Clone)]
@@ -9,8 +10,12 @@ pub enum InputEvent {
     Key(KeyEvent),
     /// Mouse event
     Mouse(MouseEvent),
+    /// Text pasted into the terminal (bracketed paste)
+    Paste(String),
     /// Terminal resize
     Resize((), ()),
+    /// No input arrived before the timeout of [`poll_event`]
+    Tick,
 }
 
 /// Read the next event from the terminal
@@ -18,14 +23,38 @@ pub enum InputEvent {
 /// # Returns
 /// An InputEvent or an error
 pub fn read_event() -> Result<InputEvent, io::Error> {
-    match event::read()? {
+    loop {
+        // Ignore release events and others, read again
+        if let Some(event) = input_event(event::read()?) {
+            return Ok(event);
+        }
+    }
+}
+
+fn input_event(event: Event) -> Option<InputEvent> {
+    match event {
         Event::Key(key_event) if key_event.kind == KeyEventKind::Press => {
-            Ok(InputEvent::Key(key_event))
+            Some(InputEvent::Key(key_event))
         }
-        Event::Mouse(mouse_event) => Ok(InputEvent::Mouse(mouse_event)),
-        Event::Resize(_width, _height) => Ok(InputEvent::Resize((), ())),
-        _ => read_event(), // Ignore release events and others, read again
+        Event::Mouse(mouse_event) => Some(InputEvent::Mouse(mouse_event)),
+        Event::Paste(text) => Some(InputEvent::Paste(text)),
+        Event::Resize(_width, _height) => Some(InputEvent::Resize((), ())),
+        _ => None,
+    }
+}
+
+/// Read the next event, giving up with [`InputEvent::Tick`] after `timeout`
+///
+/// Without a timeout this blocks like [`read_event`]. Ignored events (key
+/// releases and the like) also give a tick rather than waiting again.
+pub fn poll_event(timeout: Option<Duration>) -> Result<InputEvent, io::Error> {
+    let Some(timeout) = timeout else {
+        return read_event();
+    };
+    if !event::poll(timeout)? {
+        return Ok(InputEvent::Tick);
     }
+    Ok(input_event(event::read()?).unwrap_or(InputEvent::Tick))
 }
 
 /// Check if a key event matches a specific key code
@@ -33,6 +62,32 @@ pub fn is_key(key_event: &KeyEvent, code: KeyCode) -> bool {
     key_event.code == code
 }
 
+/// Check if Shift is held with a function key `code` (e.g. Shift+F8)
+/// Some terminals report Shift+F1 to Shift+F12 as F13 to F24 instead
+pub fn is_shift_key(key_event: &KeyEvent, code: KeyCode) -> bool {
+    match (key_event.code, code) {
+        (KeyCode::F(reported), KeyCode::F(n)) if reported == n + 12 => true,
+        _ => key_event.code == code && key_event.modifiers.contains(KeyModifiers::SHIFT),
+    }
+}
+
+/// Check if the key cycles panels backwards (Shift+Tab)
+/// Most terminals report BackTab, some send Tab with the Shift modifier instead
+pub fn is_back_tab(key_event: &KeyEvent) -> bool {
+    key_event.code == KeyCode::BackTab
+        || (key_event.code == KeyCode::Tab && key_event.modifiers.contains(KeyModifiers::SHIFT))
+}
+
+/// Check if a plain digit key (no Ctrl/Alt) selects panel `number` (1-9)
+pub fn is_panel_number(key_event: &KeyEvent, number: u8) -> bool {
+    let modified = key_event
+        .modifiers
+        .intersects(KeyModifiers::CONTROL | KeyModifiers::ALT);
+    !modified
+        && (1..=9).contains(&number)
+        && key_event.code == KeyCode::Char((b'0' + number) as char)
+}
+
 /// Check if Ctrl (Linux) or Command/Super/Meta (macOS) is pressed with a key
 /// This handles cross-platform copy/paste shortcuts
 /// Note: On macOS, Command key may be reported as SUPER or META depending on terminal
@@ -46,12 +101,13 @@ pub fn is_copy_paste_key(key_event: &KeyEvent, code: KeyCode) -> bool {
 /// Check if Alt/Meta is pressed with a numeric key (1-9)
 /// Alternative to Ctrl+number for tab navigation
 pub fn is_alt_number(key_event: &KeyEvent, number: u8) -> bool {
-    if number < 1 || number > 9 {
+    if !(1..=9).contains(&number) {
         return false;
     }
 
     let has_alt = key_event.modifiers.contains(event::KeyModifiers::ALT);
-    if !has_alt {
+    let has_shift = key_event.modifiers.contains(event::KeyModifiers::SHIFT);
+    if !has_alt || has_shift {
         return false;
     }
 
@@ -64,3 +120,44 @@ pub fn is_alt_number(key_event: &KeyEvent, number: u8) -> bool {
 
     false
 }
+
+/// Symbols produced by Shift+1 to Shift+9 on a US layout
+const SHIFTED_DIGITS: [char; 9] = ['!', '@', '#', '$', '%', '^', '&', '*', '('];
+
+/// Check if Alt+Shift is pressed with a numeric key (1-9)
+/// Most terminals report the shifted symbol (e.g. `!` for 1) rather than the digit
+pub fn is_alt_shift_number(key_event: &KeyEvent, number: u8) -> bool {
+    if !(1..=9).contains(&number) {
+        return false;
+    }
+
+    let has_alt = key_event.modifiers.contains(event::KeyModifiers::ALT);
+    let has_shift = key_event.modifiers.contains(event::KeyModifiers::SHIFT);
+    match key_event.code {
+        KeyCode::Char(c) if c == (b'0' + number) as char => has_alt && has_shift,
+        KeyCode::Char(c) => has_alt && c == SHIFTED_DIGITS[number as usize - 1],
+        _ => false,
+    }
+}
+
+/// Describe a key event as the terminal reported it (e.g. `Ctrl+Alt+F(5)`)
+///
+/// Used by the diagnostics key tester, so it shows raw codes rather than labels.
+pub fn describe_key(key_event: &KeyEvent) -> String {
+    let modifiers = [
+        (KeyModifiers::CONTROL, "Ctrl"),
+        (KeyModifiers::ALT, "Alt"),
+        (KeyModifiers::SHIFT, "Shift"),
+        (KeyModifiers::SUPER, "Super"),
+        (KeyModifiers::META, "Meta"),
+        (KeyModifiers::HYPER, "Hyper"),
+    ];
+
+    let mut parts: Vec<String> = modifiers
+        .iter()
+        .filter(|(flag, _)| key_event.modifiers.contains(*flag))
+        .map(|(_, name)| name.to_string())
+        .collect();
+    parts.push(format!("{:?}", key_event.code));
+    parts.join("+")
+}
@@ -0,0 +1,134 @@
+//! Golden-file tests of the rendered screens
+//!
+//! Each test puts the app in a known state, draws it with the [`Driver`] and
+//! compares the text of the buffer with `tests/golden/<name>.txt`. Styles are
+//! not compared. Run with `UPDATE_GOLDEN=1` to rewrite the files after an
+//! intended layout change, then review the diff.
+use super::handle_action;
+use crate::actions::Action;
+use crate::app::{App, AppEvent, PanelId, Tab};
+use crate::driver::{test_app as app, Driver};
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+use std::time::{Duration, UNIX_EPOCH};
+use tui_textarea::TextArea;
+
+/// Replace an editor's content and let the subscribers react
+fn fill(app: &mut App, panel: PanelId, text: &str) {
+    *app.textarea(panel) = TextArea::from(text.lines());
+    app.publish(AppEvent::PanelEdited(panel));
+    app.dispatch_events().unwrap();
+}
+
+/// App with two small overlapping lists
+fn app_with_lists() -> App {
+    let mut app = app();
+    fill(&mut app, PanelId::List1, "apple\nbanana\ncherry\nbanana");
+    fill(&mut app, PanelId::List2, "banana\ncherry\ndate");
+    app
+}
+
+/// Run an action as if its key had been pressed
+fn act(app: &mut App, action: Action) {
+    handle_action(app, action).unwrap();
+    app.dispatch_events().unwrap();
+}
+
+/// Compare the lists, pinning the comparison time so the header is stable
+fn compare(app: &mut App) {
+    act(app, Action::Compare);
+    if let Some(result) = app.results_tab.compare_results.as_mut() {
+        result.compared_at = UNIX_EPOCH + Duration::from_secs(1_700_000_000);
+    }
+}
+
+/// Compare the rendered screen with its golden file (or rewrite it with `UPDATE_GOLDEN=1`)
+fn assert_golden(name: &str, app: App) {
+    let path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("tests")
+        .join("golden")
+        .join(format!("{}.txt", name));
+    let screen = Driver::with_app(app).screen();
+    if env::var_os("UPDATE_GOLDEN").is_some() {
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(&path, &screen).unwrap();
+        return;
+    }
+    let expected = fs::read_to_string(&path).unwrap_or_else(|err| {
+        panic!(
+            "{}: {} (run with UPDATE_GOLDEN=1 to create it)",
+            path.display(),
+            err
+        )
+    });
+    assert!(
+        screen == expected,
+        "screen differs from {} (run with UPDATE_GOLDEN=1 to accept it)\n--- expected\n{}--- rendered\n{}",
+        path.display(),
+        expected,
+        screen
+    );
+}
+
+#[test]
+fn test_input_tab_empty() {
+    assert_golden("input_empty", app());
+}
+
+#[test]
+fn test_input_tab_compared() {
+    let mut app = app_with_lists();
+    compare(&mut app);
+    app.active_tab = Tab::Input;
+    assert_golden("input_compared", app);
+}
+
+#[test]
+fn test_results_tab() {
+    let mut app = app_with_lists();
+    compare(&mut app);
+    app.active_tab = Tab::Results;
+    assert_golden("results", app);
+}
+
+#[test]
+fn test_convert_tab() {
+    let mut app = app();
+    app.active_tab = Tab::Convert;
+    fill(&mut app, PanelId::ConvertInput, "red\ngreen\nblue");
+    act(&mut app, Action::Convert);
+    assert_golden("convert", app);
+}
+
+#[test]
+fn test_list_tab() {
+    let mut app = app();
+    app.active_tab = Tab::List;
+    fill(&mut app, PanelId::SingleList, "b\na\nb\nc");
+    assert_golden("list", app);
+}
+
+#[test]
+fn test_analysis_tab() {
+    let mut app = app();
+    fill(&mut app, PanelId::SingleList, "b\na\nb\nc\n\nb");
+    app.active_tab = Tab::Analysis;
+    act(&mut app, Action::Analyze);
+    assert_golden("analysis", app);
+}
+
+#[test]
+fn test_merge_tab() {
+    let mut app = app_with_lists();
+    app.active_tab = Tab::Merge;
+    act(&mut app, Action::Join);
+    assert_golden("merge", app);
+}
+
+#[test]
+fn test_help_modal() {
+    let mut app = app();
+    app.show_help = true;
+    assert_golden("help", app);
+}
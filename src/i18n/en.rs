@@ -0,0 +1,560 @@
+//! English strings
+use super::Text;
+
+/// Look up a English string
+pub fn text(key: Text) -> &'static str {
+    match key {
+        Text::TabInput => " Input ",
+        Text::TabResults => " Results ",
+        Text::TabConvert => " Convert ",
+        Text::TabList => " List ",
+        Text::TabAnalysis => " Analysis ",
+        Text::TabMerge => " Merge ",
+        Text::WorkspaceLabel => " Workspace {}/{} ",
+        Text::TitleList1 => "LIST 1",
+        Text::TitleList2 => "LIST 2",
+        Text::TitleList => "LIST",
+        Text::TitleConvertInput => "CONVERT INPUT",
+        Text::TitleConvertOutput => "CONVERT OUTPUT",
+        Text::TitleInfo => "INFO",
+        Text::TitleUnifiedDiff => " Unified Diff (- L1, + L2) ",
+        Text::TitleHelp => " Help - Keyboard Shortcuts ",
+        Text::TitleDiagnostics => " Diagnostics ",
+        Text::TitleReferences => " Reference Lists ",
+        Text::TitleBinary => " {} looks binary ({}) ",
+        Text::TitleSaveReference => " Save {} as reference named ",
+        Text::TitleFixedWidths => "Column widths (e.g. 10,8,12; empty turns fixed-width off)",
+        Text::TitleJsonPath => "JSON path (e.g. $.items[*].id or id; empty converts whole objects again)",
+        Text::TitleItemWrap => "Wrap each item: text for both sides (e.g. \" or `), or PREFIX{}SUFFIX (empty for none)",
+        Text::TitleCustomDelimiter => "{} delimiter (any text, e.g. || or ::)",
+        Text::TitleExportBundle => "Save the comparison bundle as (relative to the working directory)",
+        Text::TitleExportReport => "Save the Markdown report as (relative to the working directory)",
+        Text::TitleImportBundle => "Open a comparison bundle (relative to the working directory)",
+        Text::TitleGitRevisions => "Git file and revisions: path [rev1] [rev2] (default HEAD~1 HEAD)",
+        Text::TitleCompareColumn => "Column to compare: number or header name (empty compares whole rows)",
+        Text::TitleTolerance => "Numeric tolerance: absolute (0.01) or relative (1%) (empty for exact matching)",
+        Text::TitleRegexDrop => "Drop items of {} matching the regex (Tab: keep them instead)",
+        Text::TitleReplaceFind => "Find in {} (regex)",
+        Text::TitleReplaceWith => "Replace /{}/ in {} with ($1 for groups)",
+        Text::TitleRegexKeep => "Keep items of {} matching the regex (Tab: drop them instead)",
+        Text::List1 => "List 1",
+        Text::List2 => "List 2",
+        Text::Results => "Results",
+        Text::SingleList => "Single List",
+        Text::AnalysisStats => "Stats",
+        Text::AnalysisFrequencies => "Frequencies",
+        Text::AnalysisDuplicates => "Duplicates",
+        Text::AnalysisValidation => "Validation",
+        Text::MergeSettings => "Join Settings",
+        Text::MergeResult => "Joined Rows",
+        Text::Verdict => "Verdict",
+        Text::VerdictTitle => "Verdict ({} pass, {} fail)",
+        Text::MissingInList2 => "Missing in List 2",
+        Text::MissingInList1 => "Missing in List 1",
+        Text::ChangedValues => "Changed values",
+        Text::PackagesRemoved => "Removed",
+        Text::PackagesAdded => "Added",
+        Text::VersionChanged => "Version changed",
+        Text::DifferentTargets => "Different targets",
+        Text::AmountsDiffer => "Amounts differ",
+        Text::JoinInner => "Inner",
+        Text::JoinLeft => "Left",
+        Text::JoinRight => "Right",
+        Text::JoinFull => "Full",
+        Text::JoinZip => "Zip",
+        Text::ConvertInput => "Convert Input",
+        Text::ConvertOutput => "Convert Output",
+        Text::OnlyInList1 => "Only in List 1",
+        Text::OnlyInList2 => "Only in List 2",
+        Text::Intersection => "Intersection",
+        Text::Union => "Union",
+        Text::UnifiedDiff => "Unified Diff",
+        Text::ColumnHeader => "Column {}",
+        Text::ItemsCount => "{} ({} items)",
+        Text::PanelPosition => "[{}/{}] {} ({} items)",
+        Text::ListStats => "List: {} items ({} unique) | Delim: {}",
+        Text::ConvertDelims => "Src: [ ({}) ] | Dst: [ ({}) ]",
+        Text::ConvertResult => "Result: {} items | Dst: {}",
+        Text::AnalysisInfo => "Source: {} | Delim: {}",
+        Text::MergeInfo => "Join: {} | Separator: {} | Delim: {}",
+        Text::StatItems => "Items: {} ({} unique)",
+        Text::StatDuplicated => "Duplicated items: {}",
+        Text::StatEmpty => "Empty items: {}",
+        Text::StatLength => "Length: {} to {} characters (mean {})",
+        Text::StatNumericRange => "Min: {} | Max: {} | Sum: {}",
+        Text::StatNumericCenter => "Mean: {} | Median: {}",
+        Text::FindingLine => "Item {}: {} — \"{}\"",
+        Text::IssueEmpty => "empty",
+        Text::IssueWhitespace => "leading or trailing whitespace",
+        Text::IssueControl => "control characters",
+        Text::IssueCaseVariant => "differs only in case from an earlier item",
+        Text::ConvertInputLabel => "Convert Input (Src {})",
+        Text::ConvertOutputLabel => "Convert Output (Dst {}) [{} items]",
+        Text::ResultsHeader => "Compared {} | Case sensitive: {} | Trim: {} | Delim: {}",
+        Text::InputOrderHeader => "Order: input",
+        Text::ResultsStale => "STALE: inputs changed since this comparison, press r to refresh",
+        Text::VerdictHeader => "List 1 checked against List 2 ({}) | Case sensitive: {} | Trim: {}",
+        Text::KeyedHeader => "List 1 and List 2 compared by key ({}) | {} unchanged",
+        Text::VerdictExact => "exact match",
+        Text::VerdictPattern => "patterns",
+        Text::BadgeDifferences => "{}Δ",
+        Text::ItemPosition => "item {} of {}",
+        Text::ResultSortChanged => "{} sorted by {}",
+        Text::ModeNormal => " NORMAL ",
+        Text::ModeInsert => " INSERT ",
+        Text::StatusShortcuts => "Alt+1-6: Tabs | Tab: Next | {}",
+        Text::StatusHelp => "?: Help | Esc",
+        Text::StatusDelim => "Delim: {}",
+        Text::StatusConvertDelims => "Src: {} | Dst: {}",
+        Text::StatusDetected => "(auto)",
+        Text::StatusSuggested => "(a: switch to {})",
+        Text::StatusWatch => "Watch {}s",
+        Text::StatusWatchChanges => "Watch {}s ({}Δ)",
+        Text::StatusTask => "{} {}% {}s (Esc cancels)",
+        Text::StatusConverter => "Converter",
+        Text::StatusUsage => "Items: {} | Mem: ~{}",
+        Text::StatusUndo => "Undo {}/{}",
+        Text::Welcome => "Welcome to List Utils! Press ? for help.",
+        Text::WorkspaceOpened => "Workspace {}: new session with its own lists and results",
+        Text::Ready => "Ready to process lists.",
+        Text::On => "ON",
+        Text::Off => "OFF",
+        Text::CaseSensitivity => "Case sensitivity {}",
+        Text::TrimSpaces => "Trim spaces {}",
+        Text::TrimSetChanged => "Trimmed characters: {}",
+        Text::SpacesOnly => "spaces only",
+        Text::FoldTypography => "Typographic characters to ASCII {}",
+        Text::PreserveOrder => "Results in input order {}",
+        Text::NormalizePresetChanged => "Normalize items: {}",
+        Text::CompareColumn => "Compare column {}",
+        Text::ToleranceSet => "Numbers match within {} (press F12 to compare again)",
+        Text::ToleranceOff => "Numbers must match exactly",
+        Text::InvalidTolerance => "Not a tolerance: {} (use e.g. 0.01 or 1%)",
+        Text::NearMatches => "{} numbers matched within {} (largest difference {}):",
+        Text::NearMatchesMore => "  … and {} more",
+        Text::CompareWholeRows => "Compare whole rows",
+        Text::ColumnNoSeparator => "Column {}: the rows have no tab, |, ; or , to split on",
+        Text::ColumnUnknownHeader => "No header named {} in the first row",
+        Text::ColumnMissing => "{} rows have no column {} and were skipped",
+        Text::PresetInvalid => "{} items are not valid for the {} preset and were compared as typed",
+        Text::CollapseEmpty => "Drop empty items between consecutive delimiters: {}",
+        Text::TrimOnPaste => "Trim pasted items and drop trailing delimiters: {}",
+        Text::LineEndingChanged => "Line endings on save: {}",
+        Text::LineEndingAuto => "auto (as loaded, LF for new files)",
+        Text::EncodingChanged => "Encoding on save: {}",
+        Text::AutoCompare => "Auto-compare: {}",
+        Text::SourceDelimiter => "Source delimiter: {}",
+        Text::TargetDelimiter => "Target delimiter: {}",
+        Text::DiffMode => "Diff mode: {}",
+        Text::UnifiedView => "Unified View",
+        Text::GridView => "Grid View",
+        Text::DifferencesView => "Differences View",
+        Text::VerdictView => "Verdict View",
+        Text::KeyedView => "Keyed View",
+        Text::TableView => "Table view: {}",
+        Text::GridLayoutStatus => "Grid: top row {}% | top left {}% | bottom left {}%",
+        Text::HideEmptyPanels => "Hide empty panels: {}",
+        Text::AlignedPreview => "Aligned preview: {} (saved output stays unpadded)",
+        Text::QuoteFields => "Quoted CSV fields: {} (comma and semicolon output, press F12 to convert again)",
+        Text::ConvertRowByRow => "Row by row",
+        Text::ConvertFixedWidth => "Fixed width: {}",
+        Text::ConvertKeyValue => "Key-value: {}",
+        Text::ConvertJsonStrategy => "Arrays: {}",
+        Text::ConvertNestedJson => "Nested: {}",
+        Text::ConvertJsonPath => "Path: {}",
+        Text::TableNeedsFieldTarget => "Table mode and fixed-width sources need a tab, comma or semicolon target",
+        Text::FieldSeparatorTooLong => "{} cannot separate fields: pick a single-character delimiter",
+        Text::NoFieldSeparator => "No field separator shared by every row; choose a tab, comma or semicolon source",
+        Text::ConvertedTable => "Converted {} row(s) to {} fields",
+        Text::TableMode => "Table mode: {} (rows are kept, only the field separator is converted)",
+        Text::InvalidColumnWidths => "Invalid column widths: {} (use positive numbers such as 10,8,12)",
+        Text::InvalidJsonPath => "Invalid JSON path: {}",
+        Text::InvalidCustomDelimiter => "Invalid delimiter: type 1 to 16 bytes without line breaks",
+        Text::FixedWidthOff => "Fixed-width source off",
+        Text::ItemWrapOn => "Converted items wrapped as {} (press F12 to convert again)",
+        Text::ItemWrapOff => "Converted items not wrapped",
+        Text::LiteralOn => "Convert to a {} literal (press F12 to convert again)",
+        Text::LiteralOff => "Convert with the target delimiter",
+        Text::MarkdownTableOn => "Convert to a Markdown table (press F12 to convert again)",
+        Text::MarkdownTableOff => "Convert with the target delimiter",
+        Text::MarkdownNeedsHeader => "Markdown tables need JSON objects or delimited rows with a header",
+        Text::JsonNeedsHeader => "JSON output needs delimited rows with a header: choose a tab, comma or semicolon source",
+        Text::SentToList => "Sent {} converted item(s) to {}",
+        Text::NothingToSend => "Nothing to send: convert first (F12)",
+        Text::JsonRepairApplied => "Repaired JSON applied to the input (u to undo)",
+        Text::JsonRepairPreview => "Repaired JSON (press a to apply it to the input):",
+        Text::JsonStrategyChanged => "Mixed JSON arrays: {} (press F12 to convert again)",
+        Text::NestedJsonChanged => "Nested JSON values: {} (press F12 to convert again)",
+        Text::JsonSummary => "JSON: {} record(s), {} field(s), depth {}",
+        Text::NoNewerConversion => "No newer conversion in the history",
+        Text::NoOlderConversion => "No older conversion in the history",
+        Text::ConversionRestored => "Restored conversion {} of {}",
+        Text::KeyValueOff => "Key-value extraction off",
+        Text::KeyValueOn => "Key-value extraction: {} (press F12 to convert)",
+        Text::FixedWidthOn => "Fixed-width source with column widths {} (press F12 to convert)",
+        Text::JsonPathOn => "Extracting {} from JSON sources (press F12 to convert)",
+        Text::JsonPathOff => "JSON path off: objects are converted to rows again",
+        Text::JsonPathNoMatch => "JSON path {} matched nothing",
+        Text::ErrorPasting => "Error pasting: {}",
+        Text::ReadOnlyOn => "{} is now read-only (R to unlock)",
+        Text::ReadOnlyOff => "{} is editable again",
+        Text::ReadOnlyBlocked => "{} is read-only (R to unlock)",
+        Text::ErrorCopying => "Error copying: {}",
+        Text::Copied => "Copied {} to clipboard",
+        Text::SelectList => "Please select List 1 or List 2",
+        Text::NoItemsToProcess => "No items to process",
+        Text::TrimDedupDone => "Trim & Dedup: {} → {} items",
+        Text::NoItemsToSort => "No items to sort",
+        Text::SortedAsc => "Sorted ↑ {} items",
+        Text::SortedDesc => "Sorted ↓ {} items",
+        Text::BothListsEmpty => "Both lists are empty",
+        Text::CompareSummary => "Only L1: {} | Only L2: {} | Inter: {} | Union: {}",
+        Text::CompareRatios => {
+            "{} of List 1 found in List 2 | {} of List 2 found in List 1 | Overlap: {}"
+        }
+        Text::SampleEstimate => "Sample (1 in {}, {} items): ~{} of List 1 found in List 2 | ~{} of List 2 found in List 1 | Overlap: ~{}",
+        Text::SampleConfirm => "Large lists ({} items): press F12 again to run the full comparison",
+        Text::CompareComplete => "Compare complete. Details available in Results tab.",
+        Text::NoComparison => "No comparison yet. Press F12 on the Input tab first",
+        Text::CopiedSummary => "Copied comparison summary (Markdown) to clipboard",
+        Text::HistoryFailed => "Could not add the copy to {}: {}",
+        Text::HistorySummary => "Comparison summary",
+        Text::ExportedReport => "Exported Markdown report to {}",
+        Text::JsonError => "JSON Error: {}",
+        Text::YamlError => "YAML Error: {}",
+        Text::NothingToConvert => "Nothing to convert",
+        Text::Converted => "Converted {} item(s) to {}",
+        Text::Analyzed => "Analyzed {} items from {}: {} duplicated, {} issue(s)",
+        Text::NothingToAnalyze => "Nothing to analyze in {}",
+        Text::AnalysisSourceChanged => "Analysis source: {} (F12 to analyze)",
+        Text::Joined => "Joined List 1 and List 2 ({}): {} rows, {} matched, {} only in List 1, {} only in List 2",
+        Text::NothingToJoin => "Nothing to join: List 1 and List 2 are empty",
+        Text::Validated => "Checked {} items of List 1 against List 2 ({}): {} pass, {} fail",
+        Text::KeyedCompared => "Compared by key ({}): {} missing in List 2, {} missing in List 1, {} changed, {} unchanged",
+        Text::AmountTotals => "Totals: List 1 {}, List 2 {} | matched ids {} vs {} (difference {}) | only in List 1 {}, only in List 2 {}",
+        Text::PackagesCompared => "Compared packages ({}): {} removed, {} added, {} version changed, {} unchanged",
+        Text::NothingToValidate => "Nothing to check: List 1 or the reference List 2 is empty",
+        Text::NothingToCompareKeyed => "Nothing to compare: List 1 and List 2 hold no {} entries",
+        Text::KeyedParseError => "Could not read the lists as {}: {}",
+        Text::KeyedPresetChanged => "Compare by key as {}",
+        Text::FilterRemoved => "Removed {} items of List 1 found in List 2, {} left (u to undo)",
+        Text::FilterKept => "Kept {} items of List 1 found in List 2, removed {} (u to undo)",
+        Text::InvalidRegex => "Invalid regular expression: {}",
+        Text::RegexDropped => "Dropped {} items matching {}, {} left (u to undo)",
+        Text::ReplacePreview => "{} of {} lines of {} would change (Enter applies, Esc cancels)",
+        Text::RegexReplaced => "Replaced the matches of {} in {} of {} lines (u to undo)",
+        Text::NothingReplaced => "No line changes when replacing {}",
+        Text::RegexKept => "Kept {} items matching {}, dropped {} (u to undo)",
+        Text::NothingToFilter => "Nothing to filter: List 1 or List 2 is empty",
+        Text::Undone => "Undid the last change to {}",
+        Text::Redone => "Redid the last undone change to {}",
+        Text::StopwordsRemoved => "Removed {} stopwords and blank items, {} left (u to undo)",
+        Text::FrequenciesCounted => "Counted {} items: {} distinct, most frequent first (u to undo)",
+        Text::NoDuplicates => "No duplicates among {} items, list left as is",
+        Text::DuplicatesKept => "Kept {} duplicated items ({} occurrences), dropped {} unique (u to undo)",
+        Text::InvalidLogPreset => "Invalid log preset {}: {}",
+        Text::LogPrefixesStripped => "Stripped log prefixes: {} message(s) left (presets: {})",
+        Text::InvalidPattern => "Invalid pattern in List 2: {}",
+        Text::JoinTypeLine => "Join type: {}",
+        Text::JoinKeyLine => "{} key: column {} ({})",
+        Text::JoinKeyMissing => "{} key: column {} (not in the first record)",
+        Text::JoinKeysUnused => "Keys: not used by zip",
+        Text::JoinSeparatorLine => "Separator: {}",
+        Text::JoinSeparatorAuto => "auto ({})",
+        Text::JoinPreview => "Preview of the first {} items of each list:",
+        Text::SelectLoadable => "Select a loadable panel (List 1/2, Convert Input or List)",
+        Text::NoTargetFile => "No target file for this panel",
+        Text::NoActivePanel => "No active panel",
+        Text::Loaded => "Loaded {} item(s) from {}",
+        Text::TaskComparing => "Comparing",
+        Text::TaskLoading => "Loading",
+        Text::TaskSampling => "Sampling",
+        Text::TaskBusy => "{} is still running: wait for it or press Esc to cancel it",
+        Text::TaskCancelled => "Cancelled: {}",
+        Text::TaskFailed => "{} stopped without a result",
+        Text::DelimiterDetected => "Detected delimiter {} (press {} to change it)",
+        Text::DelimiterMismatch => "Everything parsed as one item but {} occurs {} times: press a to switch the delimiter to it",
+        Text::DelimiterSuggested => "Detected delimiter {}, but the other lists are parsed with {}: press a to switch all of them",
+        Text::DelimiterSwitched => "Delimiter switched to {}",
+        Text::BundleExported => "Comparison bundle saved to {}",
+        Text::BundleImported => "Replayed the comparison bundle {}",
+        Text::BundleMismatch => "The replayed results differ from the ones recorded in the bundle",
+        Text::InvalidBundle => "{} is not a valid comparison bundle: {}",
+        Text::InvalidGitRequest => "Type a file path and up to two revisions, e.g. ids.txt v1.0 HEAD",
+        Text::GitFailed => "Could not read {} from git: {}",
+        Text::GitBinary => "{} looks binary ({}) and was not loaded",
+        Text::DelimiterSlotLists => "List",
+        Text::DelimiterSlotSource => "Source",
+        Text::DelimiterSlotTarget => "Target",
+        Text::LongItems => "Warning: {} item(s) longer than {} characters; operations still use the full text",
+        Text::FailedToLoad => "Failed to load {}: {}",
+        Text::BinaryNulByte => "NUL byte at offset {}",
+        Text::BinaryInvalidUtf8 => "invalid UTF-8 at byte {}",
+        Text::BinaryControlChars => "{} control characters",
+        Text::BinaryViewHex => "Load a hex view",
+        Text::BinaryViewLossy => "Load as text, replacing invalid bytes",
+        Text::BinaryAbort => "Abort",
+        Text::BinaryAborted => "Binary input from {} was not loaded",
+        Text::BinaryLoadedHex => "Loaded a hex view of {} ({} bytes shown); the editor holds the dump, not the data",
+        Text::BinaryLoadedLossy => "Loaded {} as text with invalid bytes replaced by U+FFFD",
+        Text::BinaryRefused => "{} looks binary ({}); load it with F2 to pick a view",
+        Text::Clipboard => "clipboard",
+        Text::NoReferences => "No reference lists yet in {} (K saves the active list)",
+        Text::ReferenceLoaded => "Loaded reference '{}' into {} ({} item(s))",
+        Text::ReferenceSaved => "Saved {} as reference '{}' ({})",
+        Text::InvalidReferenceName => "Invalid reference name: '{}'",
+        Text::NothingToSave => "Nothing to save from {}",
+        Text::Saved => "Saved {} to {}",
+        Text::FailedToSave => "Failed to save {}: {}",
+        Text::NothingToSaveWorkspace => "All panels are empty, nothing to save",
+        Text::SavedWorkspace => "Saved {} files to {}",
+        Text::NoComparisonFiles => "No files to compare in {} (expected list1.txt and list2.txt)",
+        Text::LoadedPair => "Loaded {} into List 1 and {} into List 2",
+        Text::WatchOn => "Watching {} and {}: compared again when they change (checked every {}s, M stops)",
+        Text::WatchOff => "Watch mode off",
+        Text::WatchChanged => "Results changed at {} ({} changes since watching started)",
+        Text::SpillDone => "Compared {} and {} on disk (too large to load)",
+        Text::SpillWritten => "Results written to only_in_list1.txt, only_in_list2.txt, intersection.txt and union.txt in {}",
+        Text::SpillFailed => "Disk-backed compare failed: {}",
+        Text::DiagTerminalSize => "Terminal size",
+        Text::DiagTerm => "TERM",
+        Text::DiagColorTerm => "COLORTERM",
+        Text::DiagTermProgram => "Terminal program",
+        Text::DiagKeyboardEnhancement => "Enhanced keyboard protocol",
+        Text::DiagClipboard => "Clipboard backend",
+        Text::DiagLanguage => "Language",
+        Text::DiagParallel => "Parallel processing",
+        Text::DiagParallelOn => "yes, for lists of {}+ items",
+        Text::DiagWorkDir => "Working directory (LIST_UTILS_DIR)",
+        Text::DiagDataDir => "Data directory",
+        Text::DiagCacheDir => "Cache directory",
+        Text::DiagStateDir => "State directory",
+        Text::DiagClipboardHistory => "Clipboard history",
+        Text::DiagConfig => "Configuration",
+        Text::DiagConfigEnv => "environment variables: {}",
+        Text::DiagConfigFile => "Config file",
+        Text::DiagUnset => "(unset)",
+        Text::DiagYes => "yes",
+        Text::DiagNo => "no",
+        Text::DiagUnknown => "unknown",
+        Text::DiagKeyTester => "Key tester",
+        Text::DiagKeyPrompt => "Press any key to see how the terminal reports it",
+        Text::DiagUnbound => "(no action)",
+        Text::DiagClose => "Press Esc to close",
+        Text::PickerHint => " j/k: Select | Enter: Load | Esc: Close ",
+        Text::PromptHint => " Enter: Confirm | Esc: Cancel ",
+        Text::HintNormal => "Normal",
+        Text::HintQuit => "Quit",
+        Text::HintHelp => "Help",
+        Text::HintNextPanel => "Next Panel",
+        Text::HintWorkspace => "Workspace",
+        Text::HintPreviousPanel => "Previous Panel",
+        Text::HintFocusPanel => "Go to Panel",
+        Text::HintSave => "Save",
+        Text::HintLoad => "Load",
+        Text::HintCase => "Case",
+        Text::HintTrim => "Trim",
+        Text::HintTrimSet => "Trim chars",
+        Text::HintFoldTypography => "ASCII quotes",
+        Text::HintPreserveOrder => "Keep order",
+        Text::HintNormalizePreset => "Normalize",
+        Text::HintCompareColumn => "Column",
+        Text::HintTolerance => "Tolerance",
+        Text::HintLineEnding => "Line endings",
+        Text::HintEncoding => "Encoding",
+        Text::HintEmptyItems => "Empty items",
+        Text::HintTrimOnPaste => "Trim paste",
+        Text::HintDelim => "Delim",
+        Text::HintSortAsc => "Sort ↑",
+        Text::HintSortDesc => "Sort ↓",
+        Text::HintDedup => "Dedup",
+        Text::HintFrequencies => "Count",
+        Text::HintDuplicates => "Duplicates",
+        Text::HintHideInfo => "Hide INFO",
+        Text::HintCycleSrc => "Cycle Src",
+        Text::HintCycleDst => "Cycle Dst",
+        Text::HintCompare => "Compare",
+        Text::HintToggleView => "Toggle View",
+        Text::HintConvert => "Convert",
+        Text::HintAnalyze => "Analyze",
+        Text::HintAnalysisSource => "Source",
+        Text::HintJoin => "Join",
+        Text::HintJoinType => "Join type",
+        Text::HintList1Key => "List 1 key",
+        Text::HintList2Key => "List 2 key",
+        Text::HintJoinSeparator => "Separator",
+        Text::HintPaste => "Paste",
+        Text::HintCopy => "Copy",
+        Text::HintInsert => "Insert",
+        Text::HintLeft => "Left",
+        Text::HintDown => "Down",
+        Text::HintUp => "Up",
+        Text::HintRight => "Right",
+        Text::HintWordForward => "Word →",
+        Text::HintWordBack => "Word ←",
+        Text::HintLineStart => "Line Start",
+        Text::HintLineEnd => "Line End",
+        Text::HintTop => "Top",
+        Text::HintBottom => "Bottom",
+        Text::HintCopySummary => "Copy Summary",
+        Text::HintReport => "MD Report",
+        Text::HintGrowInfo => "Grow INFO",
+        Text::HintShrinkInfo => "Shrink INFO",
+        Text::HintUsage => "Usage",
+        Text::HintReadOnly => "Read-only",
+        Text::HintValidate => "Check vs List 2",
+        Text::HintCompareKeyed => "Compare by key",
+        Text::HintCycleKeyedPreset => "Key preset",
+        Text::HintValidatePatterns => "Check vs patterns",
+        Text::HintRemoveListed => "Remove List 2 items",
+        Text::HintKeepListed => "Keep List 2 items",
+        Text::HintFilterRegex => "Regex filter",
+        Text::HintReplaceRegex => "Find & replace",
+        Text::HintUndo => "Undo",
+        Text::HintRedo => "Redo",
+        Text::HintStopwords => "Drop noise",
+        Text::HintLogPrefixes => "Strip log prefixes",
+        Text::HintDiagnostics => "Diagnostics",
+        Text::HintReferences => "References",
+        Text::HintSaveReference => "Save reference",
+        Text::HintSaveWorkspace => "Save All",
+        Text::HintLoadCompare => "Load & Compare",
+        Text::HintWatch => "Watch files",
+        Text::HintGitRevisions => "Git revisions",
+        Text::HintAutoCompare => "Auto-compare",
+        Text::HintTableView => "Table",
+        Text::HintWidenColumns => "Wider",
+        Text::HintNarrowColumns => "Narrower",
+        Text::HintAutoFit => "Auto-fit",
+        Text::HintHideEmpty => "Hide Empty",
+        Text::HintResultSort => "Sort",
+        Text::HintGridLeft => "Split ←",
+        Text::HintGridRight => "Split →",
+        Text::HintGridUp => "Split ↑",
+        Text::HintGridDown => "Split ↓",
+        Text::HintRefresh => "Refresh",
+        Text::HintAlignedPreview => "Align",
+        Text::HintQuoteFields => "Quote",
+        Text::HintItemWrap => "Wrap",
+        Text::HintLiteral => "Py/JS",
+        Text::HintMarkdownTable => "Markdown",
+        Text::HintTableMode => "Table",
+        Text::HintFixedWidths => "Fixed width",
+        Text::HintCustomDelimiter => "Custom delimiter",
+        Text::HintExportBundle => "Export bundle",
+        Text::HintImportBundle => "Import bundle",
+        Text::HintKeyValue => "Key-value",
+        Text::HintSendToLists => "To List 1/2",
+        Text::HintConvertHistory => "History",
+        Text::HintAcceptRepair => "Apply repair",
+        Text::HintAcceptDelimiter => "Switch delimiter",
+        Text::HintJsonStrategy => "Mixed arrays",
+        Text::HintJsonPath => "Path",
+        Text::HintFlattenJson => "Flatten",
+        Text::HelpVimMode => "Vim Mode",
+        Text::HelpNavigation => "General Navigation",
+        Text::HelpDataOperations => "Data Operations",
+        Text::HelpConfiguration => "Configuration",
+        Text::HelpFilesClipboard => "Files & Clipboard",
+        Text::HelpConvertTab => "Convert Tab (Alt+3)",
+        Text::HelpResultsTab => "Results Tab (Alt+2)",
+        Text::HelpAnalysisTab => "Analysis Tab (Alt+5)",
+        Text::HelpMergeTab => "Merge Tab (Alt+6)",
+        Text::HelpClose => "Press any key or '?' to close",
+        Text::HelpInsert => "Enter INSERT mode to type",
+        Text::HelpNormal => "Back to NORMAL mode (from Insert)",
+        Text::HelpMoveCursor => "Move cursor (Normal mode)",
+        Text::HelpMoveWord => "Move Word Forward / Back",
+        Text::HelpMoveLine => "Move to Line Start / End",
+        Text::HelpMoveTopBottom => "Move to Top / Bottom of list",
+        Text::HelpSwitchTabs => "Switch between Tabs (Input, Results, Convert, List, Analysis, Merge)",
+        Text::HelpSwitchWorkspaces => "Switch between workspaces 1-4, each with its own lists, results and settings",
+        Text::HelpSwitchPanels => "Switch between panels",
+        Text::HelpPreviousPanel => "Switch to the previous panel",
+        Text::HelpFocusPanel => "Jump to a panel of the current tab (Normal mode)",
+        Text::HelpQuit => "Quit application / Close Help",
+        Text::HelpCycleDelimiter => "Cycle global delimiter",
+        Text::HelpCustomDelimiter => "Type a custom delimiter (source or target on the Convert tab)",
+        Text::HelpExportBundle => "Save both lists, the options and the results as a .lcmp bundle",
+        Text::HelpImportBundle => "Open a .lcmp bundle and replay its comparison",
+        Text::HelpSort => "Sort Ascending / Descending (replaces content)",
+        Text::HelpTrimDedup => "Trim spaces & Deduplicate (replaces content)",
+        Text::HelpFrequencies => "Count each item: item<TAB>count, most frequent first (replaces content)",
+        Text::HelpDuplicates => "Keep only items that occur more than once (Ctrl+D with counts)",
+        Text::HelpCompare => "Compare List 1 and List 2",
+        Text::HelpValidate => "Pass/fail each List 1 item against List 2: exact / regex patterns",
+        Text::HelpCompareKeyed => "Compare .env / properties files by key: missing keys and changed values",
+        Text::HelpCycleKeyedPreset => "Cycle the compare-by-key preset: .env, requirements.txt, package.json, Cargo.lock, hosts/DNS, id,amount",
+        Text::HelpFilterList1 => "Remove from / Keep in List 1 the items of List 2",
+        Text::HelpFilterRegex => "Keep the items matching a regex (Tab in the prompt: drop them)",
+        Text::HelpReplaceRegex => "Find and replace by regex, line by line ($1 for groups)",
+        Text::HelpStopwords => "Remove blank items and stopwords such as N/A, null, - (list set in the config file)",
+        Text::HelpLogPrefixes => "Strip log timestamps, levels and thread ids (log_preset.* in config)",
+        Text::HelpUndo => "Undo the last sort, dedup, filter, load or paste",
+        Text::HelpRedo => "Redo the last undone change",
+        Text::HelpToggleCase => "Toggle Case Sensitivity",
+        Text::HelpToggleTrim => "Toggle Trim Spaces",
+        Text::HelpTrimSet => "Cycle the characters trimmed with the spaces: quotes, brackets, trailing punctuation",
+        Text::HelpFoldTypography => "Toggle folding curly quotes, dashes and special spaces to ASCII",
+        Text::HelpPreserveOrder => "Toggle keeping results in the order items appear in the lists instead of sorting them",
+        Text::HelpNormalizePreset => "Cycle the normalization preset (none, email, email without +tags, URL, URL without tracking, URL without query, path)",
+        Text::HelpCompareColumn => "Compare a single column of CSV/TSV rows, by number or header name",
+        Text::HelpTolerance => "Match numbers within an absolute or relative tolerance, reporting the differences",
+        Text::HelpAutoCompare => "Toggle auto-compare when the lists change (Normal mode)",
+        Text::HelpToggleInfo => "Show / Hide INFO panel",
+        Text::HelpResizeInfo => "Grow / Shrink INFO panel (Normal mode)",
+        Text::HelpToggleUsage => "Show / Hide item count and memory usage (Normal mode)",
+        Text::HelpReadOnly => "Lock / Unlock the active list against edits (Normal mode)",
+        Text::HelpDiagnostics => "Diagnostics: terminal, clipboard and key tester (Normal mode)",
+        Text::HelpReferences => "Load a stored reference list into the active list (Normal mode)",
+        Text::HelpSaveReference => "Save the active list to the reference library (Normal mode)",
+        Text::HelpSaveLoad => "Save / Load active panel from file",
+        Text::HelpLineEnding => "Cycle line endings on save: auto (keep the loaded file) / LF / CRLF",
+        Text::HelpEncoding => "Cycle the encoding on save: UTF-8 / UTF-8 with BOM / UTF-16LE",
+        Text::HelpEmptyItems => "Keep or drop empty items (a,,b) when parsing lists",
+        Text::HelpTrimOnPaste => "Trim pasted items and drop trailing delimiters",
+        Text::HelpSaveWorkspace => {
+            "Save every non-empty panel to a timestamped folder (Normal mode)"
+        }
+        Text::HelpLoadCompare => "Load both lists from files and compare (Normal mode)",
+        Text::HelpWatch => "Watch both files and compare again when they change (Normal mode)",
+        Text::HelpGitRevisions => "Load a file at two git revisions into List 1 and List 2 (Normal mode)",
+        Text::HelpCopyPaste => "Copy panel / Paste into input",
+        Text::HelpCopySummary => "Copy comparison summary as Markdown (Normal mode)",
+        Text::HelpReport => "Export full Markdown report (Normal mode)",
+        Text::HelpCycleSource => "Cycle Source Delimiter (JSON support)",
+        Text::HelpCycleTarget => "Cycle Target Delimiter",
+        Text::HelpConvert => "Execute delimiter conversion",
+        Text::HelpAnalyze => "Analyze the source list: stats, frequencies, duplicates and validation",
+        Text::HelpAnalysisSource => "Cycle the analyzed list: List 1, List 2, List (Normal mode)",
+        Text::HelpJoin => "Join List 1 with List 2 into the Joined Rows panel",
+        Text::HelpJoinType => "Cycle the join type: inner, left, right, full, zip (Normal mode)",
+        Text::HelpJoinKeys => "Move the List 1 / List 2 key column (Normal mode)",
+        Text::HelpJoinSeparator => "Cycle the field separator: auto, tab, |, ;, , (Normal mode)",
+        Text::HelpAlignedPreview => "Aligned column preview of tab/CSV output (Normal mode)",
+        Text::HelpQuoteFields => "Quote CSV fields that hold the delimiter or quotes (RFC 4180)",
+        Text::HelpItemWrap => "Wrap each converted item in quotes, backticks or a PREFIX{}SUFFIX template",
+        Text::HelpLiteral => "Cycle list literal output: Python list, JS array, off",
+        Text::HelpMarkdownTable => "Toggle Markdown table output from JSON objects or CSV rows with a header",
+        Text::HelpTableMode => "Table mode: keep rows and convert only the field separator",
+        Text::HelpFixedWidths => "Fixed-width source: set or guess the column widths",
+        Text::HelpKeyValue => "Extract keys, values or columns from key=value lines",
+        Text::HelpSendToList => "Send the output to List 1 / List 2 on the Input tab",
+        Text::HelpConvertHistory => "Previous / next conversion from the history",
+        Text::HelpAcceptRepair => "Apply the previewed JSON repair to the input",
+        Text::HelpAcceptDelimiter => "Switch to the delimiter suggested after a load or paste",
+        Text::HelpJsonStrategy => "JSON source: union of keys / objects only / stringify",
+        Text::HelpJsonPath => "JSON source: extract only the values at a path such as $.items[*].id",
+        Text::HelpFlattenJson => "JSON source: expand nested objects and arrays into parent.child columns",
+        Text::HelpToggleView => "Cycle Grid, Unified Diff and Differences views",
+        Text::HelpSelectResult => "Move the selected row (Normal mode)",
+        Text::HelpRefresh => "Refresh stale results after the lists changed (Normal mode)",
+        Text::HelpTableView => "Show record results as a table or raw lines (Normal mode)",
+        Text::HelpColumnWidth => "Widen / Narrow / Auto-fit table columns (Normal mode)",
+        Text::HelpHideEmpty => "Hide / Show result panels without items (Normal mode)",
+        Text::HelpResultSort => "Cycle the order of the focused result panel: smart, original, alphabetic, numeric, length, frequency",
+        Text::HelpResizeGrid => {
+            "Move the split of the active grid row / between rows (Normal mode)"
+        }
+    }
+}
@@ -0,0 +1,574 @@
+//! Spanish strings
+use super::Text;
+
+/// Look up a Spanish string
+pub fn text(key: Text) -> &'static str {
+    match key {
+        Text::TabInput => " Entrada ",
+        Text::TabResults => " Resultados ",
+        Text::TabConvert => " Convertir ",
+        Text::TabList => " Lista ",
+        Text::TabAnalysis => " Análisis ",
+        Text::TabMerge => " Combinar ",
+        Text::WorkspaceLabel => " Espacio de trabajo {}/{} ",
+        Text::TitleList1 => "LISTA 1",
+        Text::TitleList2 => "LISTA 2",
+        Text::TitleList => "LISTA",
+        Text::TitleConvertInput => "ENTRADA A CONVERTIR",
+        Text::TitleConvertOutput => "SALIDA CONVERTIDA",
+        Text::TitleInfo => "INFO",
+        Text::TitleUnifiedDiff => " Diff unificado (- L1, + L2) ",
+        Text::TitleHelp => " Ayuda - Atajos de teclado ",
+        Text::TitleDiagnostics => " Diagnóstico ",
+        Text::TitleReferences => " Listas de referencia ",
+        Text::TitleBinary => " {} parece binario ({}) ",
+        Text::TitleSaveReference => " Guardar {} como referencia con el nombre ",
+        Text::TitleFixedWidths => "Anchos de columna (p. ej. 10,8,12; vacío desactiva el ancho fijo)",
+        Text::TitleJsonPath => "Ruta JSON (p. ej. $.items[*].id o id; vacío vuelve a convertir objetos completos)",
+        Text::TitleItemWrap => "Envolver cada elemento: texto para ambos lados (p. ej. \" o `), o PREFIJO{}SUFIJO (vacío para ninguno)",
+        Text::TitleCustomDelimiter => "Delimitador {} (cualquier texto, p. ej. || o ::)",
+        Text::TitleExportBundle => "Guardar el paquete de comparación como (relativo al directorio de trabajo)",
+        Text::TitleExportReport => "Guardar el informe Markdown como (relativo al directorio de trabajo)",
+        Text::TitleImportBundle => "Abrir un paquete de comparación (relativo al directorio de trabajo)",
+        Text::TitleGitRevisions => "Archivo y revisiones de git: ruta [rev1] [rev2] (por defecto HEAD~1 HEAD)",
+        Text::TitleCompareColumn => "Columna a comparar: número o nombre de cabecera (vacío compara filas completas)",
+        Text::TitleTolerance => "Tolerancia numérica: absoluta (0.01) o relativa (1%) (vacío para coincidencia exacta)",
+        Text::TitleRegexDrop => "Quitar los elementos de {} que coinciden con la regex (Tab: conservarlos)",
+        Text::TitleReplaceFind => "Buscar en {} (regex)",
+        Text::TitleReplaceWith => "Reemplazar /{}/ en {} por ($1 para grupos)",
+        Text::TitleRegexKeep => "Conservar los elementos de {} que coinciden con la regex (Tab: quitarlos)",
+        Text::List1 => "Lista 1",
+        Text::List2 => "Lista 2",
+        Text::Results => "Resultados",
+        Text::SingleList => "Lista única",
+        Text::AnalysisStats => "Estadísticas",
+        Text::AnalysisFrequencies => "Frecuencias",
+        Text::AnalysisDuplicates => "Duplicados",
+        Text::AnalysisValidation => "Validación",
+        Text::MergeSettings => "Configuración de la unión",
+        Text::MergeResult => "Filas unidas",
+        Text::Verdict => "Veredicto",
+        Text::VerdictTitle => "Veredicto ({} aprobados, {} fallidos)",
+        Text::MissingInList2 => "Faltan en Lista 2",
+        Text::MissingInList1 => "Faltan en Lista 1",
+        Text::ChangedValues => "Valores distintos",
+        Text::PackagesRemoved => "Eliminados",
+        Text::PackagesAdded => "Agregados",
+        Text::VersionChanged => "Versión distinta",
+        Text::DifferentTargets => "Destinos distintos",
+        Text::AmountsDiffer => "Importes distintos",
+        Text::JoinInner => "Interna",
+        Text::JoinLeft => "Izquierda",
+        Text::JoinRight => "Derecha",
+        Text::JoinFull => "Completa",
+        Text::JoinZip => "Zip",
+        Text::ConvertInput => "Entrada de conversión",
+        Text::ConvertOutput => "Salida de conversión",
+        Text::OnlyInList1 => "Solo en Lista 1",
+        Text::OnlyInList2 => "Solo en Lista 2",
+        Text::Intersection => "Intersección",
+        Text::Union => "Unión",
+        Text::UnifiedDiff => "Diff unificado",
+        Text::ColumnHeader => "Columna {}",
+        Text::ItemsCount => "{} ({} elementos)",
+        Text::PanelPosition => "[{}/{}] {} ({} elementos)",
+        Text::ListStats => "Lista: {} elementos ({} únicos) | Delim: {}",
+        Text::ConvertDelims => "Origen: [ ({}) ] | Destino: [ ({}) ]",
+        Text::ConvertResult => "Resultado: {} elementos | Destino: {}",
+        Text::AnalysisInfo => "Origen: {} | Delim: {}",
+        Text::MergeInfo => "Unión: {} | Separador: {} | Delim: {}",
+        Text::StatItems => "Elementos: {} ({} únicos)",
+        Text::StatDuplicated => "Elementos duplicados: {}",
+        Text::StatEmpty => "Elementos vacíos: {}",
+        Text::StatLength => "Longitud: {} a {} caracteres (media {})",
+        Text::StatNumericRange => "Mín: {} | Máx: {} | Suma: {}",
+        Text::StatNumericCenter => "Media: {} | Mediana: {}",
+        Text::FindingLine => "Elemento {}: {} — \"{}\"",
+        Text::IssueEmpty => "vacío",
+        Text::IssueWhitespace => "espacios al inicio o al final",
+        Text::IssueControl => "caracteres de control",
+        Text::IssueCaseVariant => "difiere solo en mayúsculas de un elemento anterior",
+        Text::ConvertInputLabel => "Entrada de conversión (Origen {})",
+        Text::ConvertOutputLabel => "Salida de conversión (Destino {}) [{} elementos]",
+        Text::ResultsHeader => {
+            "Comparado {} | Distinguir mayúsculas: {} | Recortar: {} | Delim: {}"
+        }
+        Text::InputOrderHeader => "Orden: entrada",
+        Text::ResultsStale => "DESACTUALIZADO: las entradas cambiaron desde esta comparación, presiona r para actualizar",
+        Text::VerdictHeader => "Lista 1 verificada contra Lista 2 ({}) | Distinguir mayúsculas: {} | Recortar: {}",
+        Text::KeyedHeader => "Lista 1 y Lista 2 comparadas por clave ({}) | {} sin cambios",
+        Text::VerdictExact => "coincidencia exacta",
+        Text::VerdictPattern => "patrones",
+        Text::BadgeDifferences => "{}Δ",
+        Text::ItemPosition => "elemento {} de {}",
+        Text::ResultSortChanged => "{} ordenado por {}",
+        Text::ModeNormal => " NORMAL ",
+        Text::ModeInsert => " INSERTAR ",
+        Text::StatusShortcuts => "Alt+1-6: Pestañas | Tab: Siguiente | {}",
+        Text::StatusHelp => "?: Ayuda | Esc",
+        Text::StatusDelim => "Delim: {}",
+        Text::StatusConvertDelims => "Origen: {} | Destino: {}",
+        Text::StatusDetected => "(auto)",
+        Text::StatusSuggested => "(a: cambiar a {})",
+        Text::StatusWatch => "Vigilancia {}s",
+        Text::StatusWatchChanges => "Vigilancia {}s ({}Δ)",
+        Text::StatusTask => "{} {}% {}s (Esc cancela)",
+        Text::StatusConverter => "Conversor",
+        Text::StatusUsage => "Elementos: {} | Mem: ~{}",
+        Text::StatusUndo => "Deshacer {}/{}",
+        Text::Welcome => "¡Bienvenido a List Utils! Presiona ? para ver la ayuda.",
+        Text::WorkspaceOpened => "Espacio de trabajo {}: nueva sesión con sus propias listas y resultados",
+        Text::Ready => "Listo para procesar listas.",
+        Text::On => "SÍ",
+        Text::Off => "NO",
+        Text::CaseSensitivity => "Distinguir mayúsculas: {}",
+        Text::TrimSpaces => "Recortar espacios: {}",
+        Text::TrimSetChanged => "Caracteres recortados: {}",
+        Text::SpacesOnly => "solo espacios",
+        Text::FoldTypography => "Caracteres tipográficos a ASCII {}",
+        Text::PreserveOrder => "Resultados en el orden de entrada {}",
+        Text::NormalizePresetChanged => "Normalizar elementos: {}",
+        Text::CompareColumn => "Comparar columna {}",
+        Text::ToleranceSet => "Los números coinciden con una tolerancia de {} (pulsa F12 para comparar de nuevo)",
+        Text::ToleranceOff => "Los números deben coincidir exactamente",
+        Text::InvalidTolerance => "No es una tolerancia: {} (usa p. ej. 0.01 o 1%)",
+        Text::NearMatches => "{} números coincidieron con una tolerancia de {} (mayor diferencia {}):",
+        Text::NearMatchesMore => "  … y {} más",
+        Text::CompareWholeRows => "Comparar filas completas",
+        Text::ColumnNoSeparator => "Columna {}: las filas no tienen tabulador, |, ; ni , para separar",
+        Text::ColumnUnknownHeader => "No hay ninguna cabecera llamada {} en la primera fila",
+        Text::ColumnMissing => "{} filas no tienen la columna {} y se omitieron",
+        Text::PresetInvalid => "{} elementos no son válidos para el preset {} y se compararon tal cual",
+        Text::CollapseEmpty => "Descartar elementos vacíos entre delimitadores consecutivos: {}",
+        Text::TrimOnPaste => "Recortar elementos pegados y quitar delimitadores finales: {}",
+        Text::LineEndingChanged => "Fin de línea al guardar: {}",
+        Text::LineEndingAuto => "auto (como se cargó, LF para archivos nuevos)",
+        Text::EncodingChanged => "Codificación al guardar: {}",
+        Text::AutoCompare => "Comparación automática: {}",
+        Text::SourceDelimiter => "Delimitador de origen: {}",
+        Text::TargetDelimiter => "Delimitador de destino: {}",
+        Text::DiffMode => "Modo diff: {}",
+        Text::UnifiedView => "Vista unificada",
+        Text::GridView => "Vista de grilla",
+        Text::DifferencesView => "Vista de diferencias",
+        Text::VerdictView => "Vista de veredicto",
+        Text::KeyedView => "Vista por clave",
+        Text::TableView => "Vista de tabla: {}",
+        Text::GridLayoutStatus => "Grilla: fila superior {}% | arriba izq. {}% | abajo izq. {}%",
+        Text::HideEmptyPanels => "Ocultar paneles vacíos: {}",
+        Text::AlignedPreview => "Vista alineada: {} (la salida guardada no se rellena)",
+        Text::QuoteFields => "Campos CSV entrecomillados: {} (salida con coma o punto y coma, presiona F12 para convertir de nuevo)",
+        Text::ConvertRowByRow => "Fila por fila",
+        Text::ConvertFixedWidth => "Ancho fijo: {}",
+        Text::ConvertKeyValue => "Clave-valor: {}",
+        Text::ConvertJsonStrategy => "Arrays: {}",
+        Text::ConvertNestedJson => "Anidados: {}",
+        Text::ConvertJsonPath => "Ruta: {}",
+        Text::TableNeedsFieldTarget => "El modo tabla y el origen de ancho fijo necesitan un destino tab, coma o punto y coma",
+        Text::FieldSeparatorTooLong => "{} no puede separar campos: elige un delimitador de un solo carácter",
+        Text::NoFieldSeparator => "Ningún separador de campos es común a todas las filas; elige un origen tab, coma o punto y coma",
+        Text::ConvertedTable => "{} fila(s) convertidas a campos {}",
+        Text::TableMode => "Modo tabla: {} (se mantienen las filas, solo se convierte el separador de campos)",
+        Text::InvalidColumnWidths => "Anchos de columna no válidos: {} (usa números positivos como 10,8,12)",
+        Text::InvalidJsonPath => "Ruta JSON no válida: {}",
+        Text::InvalidCustomDelimiter => "Delimitador no válido: escribe de 1 a 16 bytes sin saltos de línea",
+        Text::FixedWidthOff => "Origen de ancho fijo desactivado",
+        Text::ItemWrapOn => "Elementos convertidos envueltos como {} (pulsa F12 para convertir de nuevo)",
+        Text::ItemWrapOff => "Elementos convertidos sin envolver",
+        Text::LiteralOn => "Convertir a un literal {} (pulsa F12 para convertir de nuevo)",
+        Text::LiteralOff => "Convertir con el delimitador de destino",
+        Text::MarkdownTableOn => "Convertir a una tabla Markdown (pulsa F12 para convertir de nuevo)",
+        Text::MarkdownTableOff => "Convertir con el delimitador de destino",
+        Text::MarkdownNeedsHeader => "Las tablas Markdown necesitan objetos JSON o filas delimitadas con encabezado",
+        Text::JsonNeedsHeader => "La salida JSON necesita filas delimitadas con encabezado: elige un origen de tabulador, coma o punto y coma",
+        Text::SentToList => "{} elemento(s) convertidos enviados a {}",
+        Text::NothingToSend => "Nada para enviar: convierte primero (F12)",
+        Text::JsonRepairApplied => "JSON reparado aplicado a la entrada (u para deshacer)",
+        Text::JsonRepairPreview => "JSON reparado (presiona a para aplicarlo a la entrada):",
+        Text::JsonStrategyChanged => "Arrays JSON mixtos: {} (presiona F12 para convertir de nuevo)",
+        Text::NestedJsonChanged => "Valores JSON anidados: {} (presiona F12 para convertir de nuevo)",
+        Text::JsonSummary => "JSON: {} registro(s), {} campo(s), profundidad {}",
+        Text::NoNewerConversion => "No hay conversiones más recientes en el historial",
+        Text::NoOlderConversion => "No hay conversiones anteriores en el historial",
+        Text::ConversionRestored => "Conversión {} de {} restaurada",
+        Text::KeyValueOff => "Extracción clave-valor desactivada",
+        Text::KeyValueOn => "Extracción clave-valor: {} (presiona F12 para convertir)",
+        Text::FixedWidthOn => "Origen de ancho fijo con anchos de columna {} (presiona F12 para convertir)",
+        Text::JsonPathOn => "Extrayendo {} de orígenes JSON (presiona F12 para convertir)",
+        Text::JsonPathOff => "Ruta JSON desactivada: los objetos vuelven a convertirse en filas",
+        Text::JsonPathNoMatch => "La ruta JSON {} no encontró nada",
+        Text::ErrorPasting => "Error al pegar: {}",
+        Text::ReadOnlyOn => "{} ahora es de solo lectura (R para desbloquear)",
+        Text::ReadOnlyOff => "{} vuelve a ser editable",
+        Text::ReadOnlyBlocked => "{} es de solo lectura (R para desbloquear)",
+        Text::ErrorCopying => "Error al copiar: {}",
+        Text::Copied => "{} copiado al portapapeles",
+        Text::SelectList => "Selecciona Lista 1 o Lista 2",
+        Text::NoItemsToProcess => "No hay elementos para procesar",
+        Text::TrimDedupDone => "Recortar y deduplicar: {} → {} elementos",
+        Text::NoItemsToSort => "No hay elementos para ordenar",
+        Text::SortedAsc => "Ordenados ↑ {} elementos",
+        Text::SortedDesc => "Ordenados ↓ {} elementos",
+        Text::BothListsEmpty => "Ambas listas están vacías",
+        Text::CompareSummary => "Solo L1: {} | Solo L2: {} | Inter: {} | Unión: {}",
+        Text::CompareRatios => "{} de Lista 1 está en Lista 2 | {} de Lista 2 está en Lista 1 | Superposición: {}",
+        Text::SampleEstimate => "Muestra (1 de cada {}, {} elementos): ~{} de Lista 1 está en Lista 2 | ~{} de Lista 2 está en Lista 1 | Superposición: ~{}",
+        Text::SampleConfirm => "Listas grandes ({} elementos): presiona F12 otra vez para la comparación completa",
+        Text::CompareComplete => "Comparación completa. Detalles en la pestaña Resultados.",
+        Text::NoComparison => "Todavía no hay comparación. Presiona F12 en la pestaña Entrada",
+        Text::CopiedSummary => "Resumen de la comparación (Markdown) copiado al portapapeles",
+        Text::HistoryFailed => "No se pudo agregar la copia a {}: {}",
+        Text::HistorySummary => "Resumen de la comparación",
+        Text::ExportedReport => "Reporte Markdown exportado a {}",
+        Text::JsonError => "Error de JSON: {}",
+        Text::YamlError => "Error de YAML: {}",
+        Text::NothingToConvert => "Nada para convertir",
+        Text::Converted => "{} elemento(s) convertidos a {}",
+        Text::Analyzed => "{} elementos analizados de {}: {} duplicados, {} problema(s)",
+        Text::NothingToAnalyze => "Nada para analizar en {}",
+        Text::AnalysisSourceChanged => "Origen del análisis: {} (F12 para analizar)",
+        Text::Joined => "Lista 1 y Lista 2 unidas ({}): {} filas, {} coincidencias, {} solo en Lista 1, {} solo en Lista 2",
+        Text::NothingToJoin => "Nada para unir: Lista 1 y Lista 2 están vacías",
+        Text::Validated => "{} elementos de Lista 1 verificados contra Lista 2 ({}): {} aprobados, {} fallidos",
+        Text::KeyedCompared => "Comparadas por clave ({}): {} faltan en Lista 2, {} faltan en Lista 1, {} distintos, {} sin cambios",
+        Text::AmountTotals => "Totales: Lista 1 {}, Lista 2 {} | ids coincidentes {} frente a {} (diferencia {}) | solo en Lista 1 {}, solo en Lista 2 {}",
+        Text::PackagesCompared => "Paquetes comparados ({}): {} eliminados, {} agregados, {} con versión distinta, {} sin cambios",
+        Text::NothingToValidate => "Nada para verificar: Lista 1 o la Lista 2 de referencia está vacía",
+        Text::NothingToCompareKeyed => "Nada para comparar: Lista 1 y Lista 2 no tienen entradas {}",
+        Text::KeyedParseError => "No se pudieron leer las listas como {}: {}",
+        Text::KeyedPresetChanged => "Comparar por clave como {}",
+        Text::FilterRemoved => "Se quitaron {} elementos de Lista 1 presentes en Lista 2, quedan {} (u para deshacer)",
+        Text::FilterKept => "Se conservaron {} elementos de Lista 1 presentes en Lista 2, se quitaron {} (u para deshacer)",
+        Text::InvalidRegex => "Expresión regular inválida: {}",
+        Text::RegexDropped => "Se quitaron {} elementos que coinciden con {}, quedan {} (u para deshacer)",
+        Text::ReplacePreview => "{} de {} líneas de {} cambiarían (Enter aplica, Esc cancela)",
+        Text::RegexReplaced => "Se reemplazaron las coincidencias de {} en {} de {} líneas (u para deshacer)",
+        Text::NothingReplaced => "Ninguna línea cambia al reemplazar {}",
+        Text::RegexKept => "Se conservaron {} elementos que coinciden con {}, se quitaron {} (u para deshacer)",
+        Text::NothingToFilter => "Nada para filtrar: Lista 1 o Lista 2 está vacía",
+        Text::Undone => "Se deshizo el último cambio en {}",
+        Text::Redone => "Se rehizo el último cambio deshecho en {}",
+        Text::StopwordsRemoved => "Se quitaron {} palabras vacías y elementos en blanco, quedan {} (u para deshacer)",
+        Text::FrequenciesCounted => "Contados {} elementos: {} distintos, los más frecuentes primero (u para deshacer)",
+        Text::NoDuplicates => "Sin duplicados entre {} elementos, la lista queda igual",
+        Text::DuplicatesKept => "Se conservaron {} elementos duplicados ({} apariciones), se quitaron {} únicos (u para deshacer)",
+        Text::InvalidLogPreset => "Preset de log no válido {}: {}",
+        Text::LogPrefixesStripped => "Prefijos de log quitados: quedan {} mensaje(s) (presets: {})",
+        Text::InvalidPattern => "Patrón inválido en Lista 2: {}",
+        Text::JoinTypeLine => "Tipo de unión: {}",
+        Text::JoinKeyLine => "Clave de {}: columna {} ({})",
+        Text::JoinKeyMissing => "Clave de {}: columna {} (no está en el primer registro)",
+        Text::JoinKeysUnused => "Claves: no se usan al emparejar (zip)",
+        Text::JoinSeparatorLine => "Separador: {}",
+        Text::JoinSeparatorAuto => "auto ({})",
+        Text::JoinPreview => "Vista previa de los primeros {} elementos de cada lista:",
+        Text::SelectLoadable => {
+            "Selecciona un panel cargable (Lista 1/2, Entrada de conversión o Lista)"
+        }
+        Text::NoTargetFile => "Este panel no tiene archivo de destino",
+        Text::NoActivePanel => "No hay panel activo",
+        Text::Loaded => "{} elemento(s) cargados desde {}",
+        Text::TaskComparing => "Comparando",
+        Text::TaskLoading => "Cargando",
+        Text::TaskSampling => "Muestreando",
+        Text::TaskBusy => "{} sigue en curso: espera o presiona Esc para cancelarlo",
+        Text::TaskCancelled => "Cancelado: {}",
+        Text::TaskFailed => "{} terminó sin resultado",
+        Text::DelimiterDetected => "Delimitador detectado: {} (presiona {} para cambiarlo)",
+        Text::DelimiterMismatch => "Todo se leyó como un solo elemento pero {} aparece {} veces: presiona a para usarlo como delimitador",
+        Text::DelimiterSuggested => "Delimitador detectado {}, pero las otras listas se leen con {}: presiona a para cambiarlas todas",
+        Text::DelimiterSwitched => "Delimitador cambiado a {}",
+        Text::BundleExported => "Paquete de comparación guardado en {}",
+        Text::BundleImported => "Comparación del paquete {} reproducida",
+        Text::BundleMismatch => "Los resultados reproducidos difieren de los guardados en el paquete",
+        Text::InvalidBundle => "{} no es un paquete de comparación válido: {}",
+        Text::InvalidGitRequest => "Escriba la ruta de un archivo y hasta dos revisiones, p. ej. ids.txt v1.0 HEAD",
+        Text::GitFailed => "No se pudo leer {} desde git: {}",
+        Text::GitBinary => "{} parece binario ({}) y no se cargó",
+        Text::DelimiterSlotLists => "Lista",
+        Text::DelimiterSlotSource => "Origen",
+        Text::DelimiterSlotTarget => "Destino",
+        Text::LongItems => "Aviso: {} elemento(s) con más de {} caracteres; las operaciones usan el texto completo",
+        Text::FailedToLoad => "No se pudo cargar {}: {}",
+        Text::BinaryNulByte => "byte NUL en la posición {}",
+        Text::BinaryInvalidUtf8 => "UTF-8 no válido en el byte {}",
+        Text::BinaryControlChars => "{} caracteres de control",
+        Text::BinaryViewHex => "Cargar vista hexadecimal",
+        Text::BinaryViewLossy => "Cargar como texto, reemplazando los bytes no válidos",
+        Text::BinaryAbort => "Cancelar",
+        Text::BinaryAborted => "La entrada binaria de {} no se cargó",
+        Text::BinaryLoadedHex => "Vista hexadecimal de {} cargada ({} bytes mostrados); el editor contiene el volcado, no los datos",
+        Text::BinaryLoadedLossy => "{} cargado como texto con los bytes no válidos reemplazados por U+FFFD",
+        Text::BinaryRefused => "{} parece binario ({}); cárgalo con F2 para elegir una vista",
+        Text::Clipboard => "portapapeles",
+        Text::NoReferences => "Todavía no hay listas de referencia en {} (K guarda la lista activa)",
+        Text::ReferenceLoaded => "Referencia '{}' cargada en {} ({} elemento(s))",
+        Text::ReferenceSaved => "{} guardada como referencia '{}' ({})",
+        Text::InvalidReferenceName => "Nombre de referencia inválido: '{}'",
+        Text::NothingToSave => "Nada para guardar de {}",
+        Text::Saved => "{} guardado en {}",
+        Text::FailedToSave => "No se pudo guardar {}: {}",
+        Text::NothingToSaveWorkspace => "Todos los paneles están vacíos, nada para guardar",
+        Text::SavedWorkspace => "{} archivos guardados en {}",
+        Text::NoComparisonFiles => {
+            "No hay archivos para comparar en {} (se esperaba list1.txt y list2.txt)"
+        }
+        Text::LoadedPair => "{} cargado en Lista 1 y {} en Lista 2",
+        Text::WatchOn => "Vigilando {} y {}: se comparan de nuevo al cambiar (cada {}s, M detiene)",
+        Text::WatchOff => "Modo vigilancia desactivado",
+        Text::WatchChanged => "Los resultados cambiaron a las {} ({} cambios desde que empezó la vigilancia)",
+        Text::SpillDone => "Se compararon {} y {} en disco (demasiado grandes para cargar)",
+        Text::SpillWritten => "Resultados escritos en only_in_list1.txt, only_in_list2.txt, intersection.txt y union.txt en {}",
+        Text::SpillFailed => "Falló la comparación en disco: {}",
+        Text::DiagTerminalSize => "Tamaño de la terminal",
+        Text::DiagTerm => "TERM",
+        Text::DiagColorTerm => "COLORTERM",
+        Text::DiagTermProgram => "Programa de terminal",
+        Text::DiagKeyboardEnhancement => "Protocolo de teclado extendido",
+        Text::DiagClipboard => "Portapapeles",
+        Text::DiagLanguage => "Idioma",
+        Text::DiagParallel => "Procesamiento paralelo",
+        Text::DiagParallelOn => "sí, para listas de {}+ elementos",
+        Text::DiagWorkDir => "Directorio de trabajo (LIST_UTILS_DIR)",
+        Text::DiagDataDir => "Directorio de datos",
+        Text::DiagCacheDir => "Directorio de caché",
+        Text::DiagStateDir => "Directorio de estado",
+        Text::DiagClipboardHistory => "Historial del portapapeles",
+        Text::DiagConfig => "Configuración",
+        Text::DiagConfigEnv => "variables de entorno: {}",
+        Text::DiagConfigFile => "Archivo de configuración",
+        Text::DiagUnset => "(sin definir)",
+        Text::DiagYes => "sí",
+        Text::DiagNo => "no",
+        Text::DiagUnknown => "desconocido",
+        Text::DiagKeyTester => "Prueba de teclas",
+        Text::DiagKeyPrompt => "Presiona cualquier tecla para ver cómo la reporta la terminal",
+        Text::DiagUnbound => "(sin acción)",
+        Text::DiagClose => "Presiona Esc para cerrar",
+        Text::PickerHint => " j/k: Elegir | Enter: Cargar | Esc: Cerrar ",
+        Text::PromptHint => " Enter: Confirmar | Esc: Cancelar ",
+        Text::HintNormal => "Normal",
+        Text::HintQuit => "Salir",
+        Text::HintHelp => "Ayuda",
+        Text::HintNextPanel => "Siguiente panel",
+        Text::HintWorkspace => "Espacio de trabajo",
+        Text::HintPreviousPanel => "Panel anterior",
+        Text::HintFocusPanel => "Ir al panel",
+        Text::HintSave => "Guardar",
+        Text::HintLoad => "Cargar",
+        Text::HintCase => "Mayúsc.",
+        Text::HintTrim => "Recortar",
+        Text::HintTrimSet => "Recortar caracteres",
+        Text::HintFoldTypography => "Comillas ASCII",
+        Text::HintPreserveOrder => "Mantener orden",
+        Text::HintNormalizePreset => "Normalizar",
+        Text::HintCompareColumn => "Columna",
+        Text::HintTolerance => "Tolerancia",
+        Text::HintLineEnding => "Fin de línea",
+        Text::HintEncoding => "Codificación",
+        Text::HintEmptyItems => "Elementos vacíos",
+        Text::HintTrimOnPaste => "Recortar al pegar",
+        Text::HintDelim => "Delim",
+        Text::HintSortAsc => "Ordenar ↑",
+        Text::HintSortDesc => "Ordenar ↓",
+        Text::HintDedup => "Deduplicar",
+        Text::HintFrequencies => "Contar",
+        Text::HintDuplicates => "Duplicados",
+        Text::HintHideInfo => "Ocultar INFO",
+        Text::HintCycleSrc => "Cambiar origen",
+        Text::HintCycleDst => "Cambiar destino",
+        Text::HintCompare => "Comparar",
+        Text::HintToggleView => "Cambiar vista",
+        Text::HintConvert => "Convertir",
+        Text::HintAnalyze => "Analizar",
+        Text::HintAnalysisSource => "Origen",
+        Text::HintJoin => "Unir",
+        Text::HintJoinType => "Tipo de unión",
+        Text::HintList1Key => "Clave Lista 1",
+        Text::HintList2Key => "Clave Lista 2",
+        Text::HintJoinSeparator => "Separador",
+        Text::HintPaste => "Pegar",
+        Text::HintCopy => "Copiar",
+        Text::HintInsert => "Insertar",
+        Text::HintLeft => "Izquierda",
+        Text::HintDown => "Abajo",
+        Text::HintUp => "Arriba",
+        Text::HintRight => "Derecha",
+        Text::HintWordForward => "Palabra →",
+        Text::HintWordBack => "Palabra ←",
+        Text::HintLineStart => "Inicio de línea",
+        Text::HintLineEnd => "Fin de línea",
+        Text::HintTop => "Inicio",
+        Text::HintBottom => "Final",
+        Text::HintCopySummary => "Copiar resumen",
+        Text::HintReport => "Reporte MD",
+        Text::HintGrowInfo => "Agrandar INFO",
+        Text::HintShrinkInfo => "Achicar INFO",
+        Text::HintUsage => "Uso",
+        Text::HintReadOnly => "Solo lectura",
+        Text::HintValidate => "Verificar vs Lista 2",
+        Text::HintCompareKeyed => "Comparar por clave",
+        Text::HintCycleKeyedPreset => "Preset de clave",
+        Text::HintValidatePatterns => "Verificar vs patrones",
+        Text::HintRemoveListed => "Quitar elementos de Lista 2",
+        Text::HintKeepListed => "Conservar elementos de Lista 2",
+        Text::HintFilterRegex => "Filtro regex",
+        Text::HintReplaceRegex => "Buscar y reemplazar",
+        Text::HintUndo => "Deshacer",
+        Text::HintRedo => "Rehacer",
+        Text::HintStopwords => "Quitar ruido",
+        Text::HintLogPrefixes => "Quitar prefijos de log",
+        Text::HintDiagnostics => "Diagnóstico",
+        Text::HintReferences => "Referencias",
+        Text::HintSaveReference => "Guardar referencia",
+        Text::HintSaveWorkspace => "Guardar todo",
+        Text::HintLoadCompare => "Cargar y comparar",
+        Text::HintWatch => "Vigilar archivos",
+        Text::HintGitRevisions => "Revisiones de git",
+        Text::HintAutoCompare => "Comparación automática",
+        Text::HintTableView => "Tabla",
+        Text::HintWidenColumns => "Ensanchar",
+        Text::HintNarrowColumns => "Angostar",
+        Text::HintAutoFit => "Ajustar",
+        Text::HintHideEmpty => "Ocultar vacíos",
+        Text::HintResultSort => "Ordenar",
+        Text::HintGridLeft => "División ←",
+        Text::HintGridRight => "División →",
+        Text::HintGridUp => "División ↑",
+        Text::HintGridDown => "División ↓",
+        Text::HintRefresh => "Actualizar",
+        Text::HintAlignedPreview => "Alinear",
+        Text::HintQuoteFields => "Comillas",
+        Text::HintItemWrap => "Envolver",
+        Text::HintLiteral => "Py/JS",
+        Text::HintMarkdownTable => "Markdown",
+        Text::HintTableMode => "Tabla",
+        Text::HintFixedWidths => "Ancho fijo",
+        Text::HintCustomDelimiter => "Delimitador propio",
+        Text::HintExportBundle => "Exportar paquete",
+        Text::HintImportBundle => "Importar paquete",
+        Text::HintKeyValue => "Clave-valor",
+        Text::HintSendToLists => "A Lista 1/2",
+        Text::HintConvertHistory => "Historial",
+        Text::HintAcceptRepair => "Aplicar reparación",
+        Text::HintAcceptDelimiter => "Cambiar delimitador",
+        Text::HintJsonStrategy => "Arrays mixtos",
+        Text::HintJsonPath => "Ruta",
+        Text::HintFlattenJson => "Aplanar",
+        Text::HelpVimMode => "Modo Vim",
+        Text::HelpNavigation => "Navegación general",
+        Text::HelpDataOperations => "Operaciones de datos",
+        Text::HelpConfiguration => "Configuración",
+        Text::HelpFilesClipboard => "Archivos y portapapeles",
+        Text::HelpConvertTab => "Pestaña Convertir (Alt+3)",
+        Text::HelpResultsTab => "Pestaña Resultados (Alt+2)",
+        Text::HelpAnalysisTab => "Pestaña Análisis (Alt+5)",
+        Text::HelpMergeTab => "Pestaña Combinar (Alt+6)",
+        Text::HelpClose => "Presiona cualquier tecla o '?' para cerrar",
+        Text::HelpInsert => "Entrar en modo INSERTAR para escribir",
+        Text::HelpNormal => "Volver al modo NORMAL (desde Insertar)",
+        Text::HelpMoveCursor => "Mover el cursor (modo Normal)",
+        Text::HelpMoveWord => "Avanzar / Retroceder una palabra",
+        Text::HelpMoveLine => "Ir al inicio / fin de línea",
+        Text::HelpMoveTopBottom => "Ir al inicio / final de la lista",
+        Text::HelpSwitchTabs => "Cambiar de pestaña (Entrada, Resultados, Convertir, Lista, Análisis, Combinar)",
+        Text::HelpSwitchWorkspaces => "Cambiar entre los espacios de trabajo 1-4, cada uno con sus propias listas, resultados y opciones",
+        Text::HelpSwitchPanels => "Cambiar de panel",
+        Text::HelpPreviousPanel => "Cambiar al panel anterior",
+        Text::HelpFocusPanel => "Saltar a un panel de la pestaña actual (modo Normal)",
+        Text::HelpQuit => "Salir de la aplicación / Cerrar ayuda",
+        Text::HelpCycleDelimiter => "Cambiar el delimitador global",
+        Text::HelpCustomDelimiter => "Escribir un delimitador propio (origen o destino en la pestaña Convertir)",
+        Text::HelpExportBundle => "Guardar ambas listas, las opciones y los resultados como paquete .lcmp",
+        Text::HelpImportBundle => "Abrir un paquete .lcmp y reproducir su comparación",
+        Text::HelpSort => "Ordenar ascendente / descendente (reemplaza el contenido)",
+        Text::HelpTrimDedup => "Recortar espacios y deduplicar (reemplaza el contenido)",
+        Text::HelpFrequencies => "Contar cada elemento: elemento<TAB>cantidad, los más frecuentes primero (reemplaza el contenido)",
+        Text::HelpDuplicates => "Conservar solo los elementos repetidos (Ctrl+D con cantidades)",
+        Text::HelpCompare => "Comparar Lista 1 y Lista 2",
+        Text::HelpValidate => "Aprobar/fallar cada elemento de Lista 1 contra Lista 2: exacto / patrones regex",
+        Text::HelpCompareKeyed => "Comparar archivos .env / properties por clave: claves faltantes y valores distintos",
+        Text::HelpCycleKeyedPreset => "Cambiar el preset de comparación por clave: .env, requirements.txt, package.json, Cargo.lock, hosts/DNS, id,amount",
+        Text::HelpFilterList1 => "Quitar de / Conservar en Lista 1 los elementos de Lista 2",
+        Text::HelpFilterRegex => "Conservar los elementos que coinciden con una regex (Tab en el prompt: quitarlos)",
+        Text::HelpReplaceRegex => "Buscar y reemplazar con regex, línea a línea ($1 para grupos)",
+        Text::HelpStopwords => "Quitar elementos en blanco y palabras vacías como N/A, null, - (lista definida en el archivo de configuración)",
+        Text::HelpLogPrefixes => "Quitar fechas, niveles e ids de hilo de logs (log_preset.* en config)",
+        Text::HelpUndo => "Deshacer el último orden, deduplicado, filtro, carga o pegado",
+        Text::HelpRedo => "Rehacer el último cambio deshecho",
+        Text::HelpToggleCase => "Activar / desactivar distinción de mayúsculas",
+        Text::HelpToggleTrim => "Activar / desactivar recorte de espacios",
+        Text::HelpTrimSet => "Alternar los caracteres recortados con los espacios: comillas, corchetes, puntuación final",
+        Text::HelpFoldTypography => "Activar/desactivar la conversión de comillas tipográficas, guiones y espacios especiales a ASCII",
+        Text::HelpPreserveOrder => "Mantener los resultados en el orden de las listas en lugar de ordenarlos",
+        Text::HelpNormalizePreset => "Cambiar el preset de normalización (ninguno, email, email sin +etiquetas, URL, URL sin rastreo, URL sin consulta, ruta)",
+        Text::HelpCompareColumn => "Comparar una sola columna de filas CSV/TSV, por número o nombre de cabecera",
+        Text::HelpTolerance => "Hacer coincidir números con una tolerancia absoluta o relativa, informando las diferencias",
+        Text::HelpAutoCompare => {
+            "Activar / desactivar la comparación automática al cambiar las listas (modo Normal)"
+        }
+        Text::HelpToggleInfo => "Mostrar / Ocultar el panel INFO",
+        Text::HelpResizeInfo => "Agrandar / Achicar el panel INFO (modo Normal)",
+        Text::HelpToggleUsage => {
+            "Mostrar / Ocultar cantidad de elementos y uso de memoria (modo Normal)"
+        }
+        Text::HelpReadOnly => "Bloquear / Desbloquear la lista activa contra ediciones (modo Normal)",
+        Text::HelpDiagnostics => {
+            "Diagnóstico: terminal, portapapeles y prueba de teclas (modo Normal)"
+        }
+        Text::HelpReferences => "Cargar una lista de referencia guardada en la lista activa (modo Normal)",
+        Text::HelpSaveReference => "Guardar la lista activa en la biblioteca de referencias (modo Normal)",
+        Text::HelpSaveLoad => "Guardar / Cargar el panel activo desde archivo",
+        Text::HelpLineEnding => "Alternar fin de línea al guardar: auto (como el archivo cargado) / LF / CRLF",
+        Text::HelpEncoding => "Alternar la codificación al guardar: UTF-8 / UTF-8 con BOM / UTF-16LE",
+        Text::HelpEmptyItems => "Mantener o descartar elementos vacíos (a,,b) al leer listas",
+        Text::HelpTrimOnPaste => "Recortar elementos pegados y quitar delimitadores finales",
+        Text::HelpSaveWorkspace => {
+            "Guardar todos los paneles con contenido en una carpeta con fecha (modo Normal)"
+        }
+        Text::HelpLoadCompare => "Cargar ambas listas desde archivos y comparar (modo Normal)",
+        Text::HelpWatch => "Vigilar ambos archivos y comparar de nuevo al cambiar (modo Normal)",
+        Text::HelpGitRevisions => "Cargar un archivo en dos revisiones de git en Lista 1 y Lista 2 (modo Normal)",
+        Text::HelpCopyPaste => "Copiar panel / Pegar en la entrada",
+        Text::HelpCopySummary => "Copiar el resumen de la comparación en Markdown (modo Normal)",
+        Text::HelpReport => "Exportar el reporte Markdown completo (modo Normal)",
+        Text::HelpCycleSource => "Cambiar delimitador de origen (soporta JSON)",
+        Text::HelpCycleTarget => "Cambiar delimitador de destino",
+        Text::HelpConvert => "Ejecutar la conversión de delimitadores",
+        Text::HelpAnalyze => "Analizar la lista de origen: estadísticas, frecuencias, duplicados y validación",
+        Text::HelpAnalysisSource => "Cambiar la lista analizada: Lista 1, Lista 2, Lista (modo Normal)",
+        Text::HelpJoin => "Unir Lista 1 con Lista 2 en el panel Filas unidas",
+        Text::HelpJoinType => "Cambiar el tipo de unión: interna, izquierda, derecha, completa, zip (modo Normal)",
+        Text::HelpJoinKeys => "Mover la columna clave de Lista 1 / Lista 2 (modo Normal)",
+        Text::HelpJoinSeparator => "Cambiar el separador de campos: auto, tab, |, ;, , (modo Normal)",
+        Text::HelpAlignedPreview => {
+            "Vista previa con columnas alineadas para salida tab/CSV (modo Normal)"
+        }
+        Text::HelpQuoteFields => "Entrecomillar campos CSV con el delimitador o comillas (RFC 4180)",
+        Text::HelpItemWrap => "Envolver cada elemento convertido en comillas, acentos graves o una plantilla PREFIJO{}SUFIJO",
+        Text::HelpLiteral => "Cambiar la salida como literal: lista de Python, array de JS, desactivado",
+        Text::HelpMarkdownTable => "Activar la salida como tabla Markdown a partir de objetos JSON o filas CSV con encabezado",
+        Text::HelpTableMode => "Modo tabla: mantener filas y convertir solo el separador de campos",
+        Text::HelpFixedWidths => "Origen de ancho fijo: definir o adivinar los anchos de columna",
+        Text::HelpKeyValue => "Extraer claves, valores o columnas de líneas clave=valor",
+        Text::HelpSendToList => "Enviar la salida a Lista 1 / Lista 2 en la pestaña Entrada",
+        Text::HelpConvertHistory => "Conversión anterior / siguiente del historial",
+        Text::HelpAcceptRepair => "Aplicar a la entrada la reparación JSON previsualizada",
+        Text::HelpAcceptDelimiter => "Cambiar al delimitador sugerido tras cargar o pegar",
+        Text::HelpJsonStrategy => "Origen JSON: unión de claves / solo objetos / texto",
+        Text::HelpJsonPath => "Origen JSON: extraer solo los valores de una ruta como $.items[*].id",
+        Text::HelpFlattenJson => "Origen JSON: expandir objetos y arrays anidados en columnas padre.hijo",
+        Text::HelpToggleView => "Alternar entre vista de grilla, diff unificado y diferencias",
+        Text::HelpSelectResult => "Mover la fila seleccionada (modo Normal)",
+        Text::HelpRefresh => "Actualizar resultados desactualizados tras cambiar las listas (modo Normal)",
+        Text::HelpTableView => {
+            "Mostrar resultados con registros como tabla o como líneas (modo Normal)"
+        }
+        Text::HelpColumnWidth => {
+            "Ensanchar / Angostar / Ajustar columnas de la tabla (modo Normal)"
+        }
+        Text::HelpHideEmpty => "Ocultar / Mostrar paneles de resultados sin elementos (modo Normal)",
+        Text::HelpResultSort => "Cambiar el orden del panel de resultados enfocado: inteligente, original, alfabético, numérico, longitud, frecuencia",
+        Text::HelpResizeGrid => "Mover la división de la fila activa / entre filas de la grilla (modo Normal)",
+    }
+}
@@ -0,0 +1,710 @@
+//! Localization layer for user-facing strings
+//!
+//! Every string shown in the UI is looked up through a [`Text`] key. Each
+//! language bundle is an exhaustive `match`, so adding a key without
+//! translating it is a compile error.
+mod en;
+mod es;
+
+use std::env;
+use std::fmt::Display;
+use std::sync::OnceLock;
+
+/// Supported UI languages
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Lang {
+    /// English (default)
+    En,
+    /// Spanish
+    Es,
+}
+
+impl Lang {
+    /// Parse a locale string such as `es`, `es_AR.UTF-8` or `en-US`
+    pub fn from_locale(locale: &str) -> Option<Self> {
+        let code = locale
+            .split(['_', '-', '.'])
+            .next()
+            .unwrap_or("")
+            .to_ascii_lowercase();
+        match code.as_str() {
+            "en" => Some(Lang::En),
+            "es" => Some(Lang::Es),
+            _ => None,
+        }
+    }
+
+    /// Detect the language from `LIST_UTILS_LANG`, then `LC_ALL`, `LC_MESSAGES` and `LANG`
+    pub fn detect() -> Self {
+        ["LIST_UTILS_LANG", "LC_ALL", "LC_MESSAGES", "LANG"]
+            .iter()
+            .filter_map(|var| env::var(var).ok())
+            .find_map(|value| Lang::from_locale(&value))
+            .unwrap_or(Lang::En)
+    }
+}
+
+static LANG: OnceLock<Lang> = OnceLock::new();
+
+/// Language in use, detected from the environment on first access
+pub fn lang() -> Lang {
+    *LANG.get_or_init(Lang::detect)
+}
+
+/// Use `lang` instead of detecting it, so rendered screens do not depend on the environment
+///
+/// # Panics
+/// Panics if another language is already in use.
+#[cfg(test)]
+pub fn force_lang(lang: Lang) {
+    assert_eq!(
+        *LANG.get_or_init(|| lang),
+        lang,
+        "UI language already detected"
+    );
+}
+
+/// Translate a key into the active language
+pub fn tr(key: Text) -> &'static str {
+    tr_in(lang(), key)
+}
+
+/// Translate a key into a specific language
+pub fn tr_in(lang: Lang, key: Text) -> &'static str {
+    match lang {
+        Lang::En => en::text(key),
+        Lang::Es => es::text(key),
+    }
+}
+
+/// Translate a key and fill its `{}` placeholders in order
+pub fn trf(key: Text, args: &[&dyn Display]) -> String {
+    fill(tr(key), args)
+}
+
+/// Replace each `{}` in `template` with the next argument
+pub fn fill(template: &str, args: &[&dyn Display]) -> String {
+    let mut out = String::with_capacity(template.len());
+    let mut args = args.iter();
+    let mut rest = template;
+    while let Some(pos) = rest.find("{}") {
+        out.push_str(&rest[..pos]);
+        match args.next() {
+            Some(arg) => out.push_str(&arg.to_string()),
+            None => out.push_str("{}"),
+        }
+        rest = &rest[pos + 2..];
+    }
+    out.push_str(rest);
+    out
+}
+
+/// Translate an ON/OFF state
+pub fn on_off(flag: bool) -> &'static str {
+    if flag {
+        tr(Text::On)
+    } else {
+        tr(Text::Off)
+    }
+}
+
+/// Keys for every user-facing string
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Text {
+    // Tabs
+    TabInput,
+    TabResults,
+    TabConvert,
+    TabList,
+    TabAnalysis,
+    TabMerge,
+    WorkspaceLabel,
+
+    // Panel titles
+    TitleList1,
+    TitleList2,
+    TitleList,
+    TitleConvertInput,
+    TitleConvertOutput,
+    TitleInfo,
+    TitleUnifiedDiff,
+    TitleHelp,
+    TitleDiagnostics,
+    TitleReferences,
+    TitleBinary,
+    TitleSaveReference,
+    TitleFixedWidths,
+    TitleJsonPath,
+    TitleItemWrap,
+    TitleCustomDelimiter,
+    TitleExportBundle,
+    TitleExportReport,
+    TitleImportBundle,
+    TitleGitRevisions,
+    TitleCompareColumn,
+    TitleTolerance,
+    TitleRegexDrop,
+    TitleReplaceFind,
+    TitleReplaceWith,
+    TitleRegexKeep,
+
+    // Panel and category names
+    List1,
+    List2,
+    Results,
+    SingleList,
+    AnalysisStats,
+    AnalysisFrequencies,
+    AnalysisDuplicates,
+    AnalysisValidation,
+    MergeSettings,
+    MergeResult,
+    Verdict,
+    VerdictTitle,
+    MissingInList2,
+    MissingInList1,
+    ChangedValues,
+    PackagesRemoved,
+    PackagesAdded,
+    VersionChanged,
+    DifferentTargets,
+    AmountsDiffer,
+    JoinInner,
+    JoinLeft,
+    JoinRight,
+    JoinFull,
+    JoinZip,
+    ConvertInput,
+    ConvertOutput,
+    OnlyInList1,
+    OnlyInList2,
+    Intersection,
+    Union,
+    UnifiedDiff,
+    ColumnHeader,
+
+    // Titles and labels with placeholders
+    ItemsCount,
+    PanelPosition,
+    ListStats,
+    ConvertDelims,
+    ConvertResult,
+    AnalysisInfo,
+    MergeInfo,
+    StatItems,
+    StatDuplicated,
+    StatEmpty,
+    StatLength,
+    StatNumericRange,
+    StatNumericCenter,
+    FindingLine,
+    IssueEmpty,
+    IssueWhitespace,
+    IssueControl,
+    IssueCaseVariant,
+    ConvertInputLabel,
+    ConvertOutputLabel,
+    ResultsHeader,
+    InputOrderHeader,
+    ResultsStale,
+    VerdictHeader,
+    KeyedHeader,
+    VerdictExact,
+    VerdictPattern,
+    BadgeDifferences,
+    ItemPosition,
+    ResultSortChanged,
+
+    // Status bar
+    ModeNormal,
+    ModeInsert,
+    StatusShortcuts,
+    StatusHelp,
+    StatusDelim,
+    StatusConvertDelims,
+    StatusDetected,
+    StatusSuggested,
+    StatusWatch,
+    StatusWatchChanges,
+    StatusTask,
+    StatusConverter,
+    StatusUsage,
+    StatusUndo,
+
+    // Messages
+    Welcome,
+    WorkspaceOpened,
+    Ready,
+    On,
+    Off,
+    CaseSensitivity,
+    TrimSpaces,
+    TrimSetChanged,
+    SpacesOnly,
+    FoldTypography,
+    PreserveOrder,
+    NormalizePresetChanged,
+    CompareColumn,
+    ToleranceSet,
+    ToleranceOff,
+    InvalidTolerance,
+    NearMatches,
+    NearMatchesMore,
+    CompareWholeRows,
+    ColumnNoSeparator,
+    ColumnUnknownHeader,
+    ColumnMissing,
+    PresetInvalid,
+    CollapseEmpty,
+    TrimOnPaste,
+    LineEndingChanged,
+    LineEndingAuto,
+    EncodingChanged,
+    AutoCompare,
+    SourceDelimiter,
+    TargetDelimiter,
+    DiffMode,
+    UnifiedView,
+    GridView,
+    DifferencesView,
+    VerdictView,
+    KeyedView,
+    TableView,
+    GridLayoutStatus,
+    HideEmptyPanels,
+    AlignedPreview,
+    QuoteFields,
+    ConvertRowByRow,
+    ConvertFixedWidth,
+    ConvertKeyValue,
+    ConvertJsonStrategy,
+    ConvertNestedJson,
+    ConvertJsonPath,
+    TableNeedsFieldTarget,
+    FieldSeparatorTooLong,
+    NoFieldSeparator,
+    ConvertedTable,
+    TableMode,
+    InvalidColumnWidths,
+    InvalidJsonPath,
+    InvalidCustomDelimiter,
+    FixedWidthOff,
+    ItemWrapOn,
+    ItemWrapOff,
+    LiteralOn,
+    LiteralOff,
+    MarkdownTableOn,
+    MarkdownTableOff,
+    MarkdownNeedsHeader,
+    JsonNeedsHeader,
+    SentToList,
+    NothingToSend,
+    JsonRepairApplied,
+    JsonRepairPreview,
+    JsonStrategyChanged,
+    NestedJsonChanged,
+    JsonSummary,
+    NoNewerConversion,
+    NoOlderConversion,
+    ConversionRestored,
+    KeyValueOff,
+    KeyValueOn,
+    FixedWidthOn,
+    JsonPathOn,
+    JsonPathOff,
+    JsonPathNoMatch,
+    ErrorPasting,
+    ReadOnlyOn,
+    ReadOnlyOff,
+    ReadOnlyBlocked,
+    ErrorCopying,
+    Copied,
+    SelectList,
+    NoItemsToProcess,
+    TrimDedupDone,
+    NoItemsToSort,
+    SortedAsc,
+    SortedDesc,
+    BothListsEmpty,
+    CompareSummary,
+    CompareRatios,
+    SampleEstimate,
+    SampleConfirm,
+    CompareComplete,
+    NoComparison,
+    CopiedSummary,
+    HistoryFailed,
+    HistorySummary,
+    ExportedReport,
+    JsonError,
+    YamlError,
+    NothingToConvert,
+    Converted,
+    Analyzed,
+    NothingToAnalyze,
+    AnalysisSourceChanged,
+    Joined,
+    NothingToJoin,
+    Validated,
+    KeyedCompared,
+    AmountTotals,
+    PackagesCompared,
+    NothingToValidate,
+    NothingToCompareKeyed,
+    KeyedParseError,
+    KeyedPresetChanged,
+    FilterRemoved,
+    FilterKept,
+    InvalidRegex,
+    RegexDropped,
+    ReplacePreview,
+    RegexReplaced,
+    NothingReplaced,
+    RegexKept,
+    NothingToFilter,
+    Undone,
+    Redone,
+    StopwordsRemoved,
+    FrequenciesCounted,
+    NoDuplicates,
+    DuplicatesKept,
+    InvalidLogPreset,
+    LogPrefixesStripped,
+    InvalidPattern,
+    JoinTypeLine,
+    JoinKeyLine,
+    JoinKeyMissing,
+    JoinKeysUnused,
+    JoinSeparatorLine,
+    JoinSeparatorAuto,
+    JoinPreview,
+    SelectLoadable,
+    NoTargetFile,
+    NoActivePanel,
+    Loaded,
+    TaskComparing,
+    TaskLoading,
+    TaskSampling,
+    TaskBusy,
+    TaskCancelled,
+    TaskFailed,
+    DelimiterDetected,
+    DelimiterMismatch,
+    DelimiterSuggested,
+    DelimiterSwitched,
+    BundleExported,
+    BundleImported,
+    BundleMismatch,
+    InvalidBundle,
+    InvalidGitRequest,
+    GitFailed,
+    GitBinary,
+    DelimiterSlotLists,
+    DelimiterSlotSource,
+    DelimiterSlotTarget,
+    LongItems,
+    FailedToLoad,
+    BinaryNulByte,
+    BinaryInvalidUtf8,
+    BinaryControlChars,
+    BinaryViewHex,
+    BinaryViewLossy,
+    BinaryAbort,
+    BinaryAborted,
+    BinaryLoadedHex,
+    BinaryLoadedLossy,
+    BinaryRefused,
+    Clipboard,
+    NoReferences,
+    ReferenceLoaded,
+    ReferenceSaved,
+    InvalidReferenceName,
+    NothingToSave,
+    Saved,
+    FailedToSave,
+    NothingToSaveWorkspace,
+    SavedWorkspace,
+    NoComparisonFiles,
+    LoadedPair,
+    WatchOn,
+    WatchOff,
+    WatchChanged,
+    SpillDone,
+    SpillWritten,
+    SpillFailed,
+
+    // Diagnostics modal
+    DiagTerminalSize,
+    DiagTerm,
+    DiagColorTerm,
+    DiagTermProgram,
+    DiagKeyboardEnhancement,
+    DiagClipboard,
+    DiagLanguage,
+    DiagParallel,
+    DiagParallelOn,
+    DiagWorkDir,
+    DiagDataDir,
+    DiagCacheDir,
+    DiagStateDir,
+    DiagClipboardHistory,
+    DiagConfig,
+    DiagConfigEnv,
+    DiagConfigFile,
+    DiagUnset,
+    DiagYes,
+    DiagNo,
+    DiagUnknown,
+    DiagKeyTester,
+    DiagKeyPrompt,
+    DiagUnbound,
+    DiagClose,
+    PickerHint,
+    PromptHint,
+
+    // Hints (action descriptions)
+    HintNormal,
+    HintQuit,
+    HintHelp,
+    HintNextPanel,
+    HintWorkspace,
+    HintPreviousPanel,
+    HintFocusPanel,
+    HintSave,
+    HintLoad,
+    HintCase,
+    HintTrim,
+    HintTrimSet,
+    HintFoldTypography,
+    HintPreserveOrder,
+    HintNormalizePreset,
+    HintCompareColumn,
+    HintTolerance,
+    HintLineEnding,
+    HintEncoding,
+    HintEmptyItems,
+    HintTrimOnPaste,
+    HintDelim,
+    HintSortAsc,
+    HintSortDesc,
+    HintDedup,
+    HintFrequencies,
+    HintDuplicates,
+    HintHideInfo,
+    HintCycleSrc,
+    HintCycleDst,
+    HintCompare,
+    HintToggleView,
+    HintConvert,
+    HintAnalyze,
+    HintAnalysisSource,
+    HintJoin,
+    HintJoinType,
+    HintList1Key,
+    HintList2Key,
+    HintJoinSeparator,
+    HintPaste,
+    HintCopy,
+    HintInsert,
+    HintLeft,
+    HintDown,
+    HintUp,
+    HintRight,
+    HintWordForward,
+    HintWordBack,
+    HintLineStart,
+    HintLineEnd,
+    HintTop,
+    HintBottom,
+    HintCopySummary,
+    HintReport,
+    HintGrowInfo,
+    HintShrinkInfo,
+    HintUsage,
+    HintReadOnly,
+    HintValidate,
+    HintCompareKeyed,
+    HintCycleKeyedPreset,
+    HintValidatePatterns,
+    HintRemoveListed,
+    HintKeepListed,
+    HintFilterRegex,
+    HintReplaceRegex,
+    HintUndo,
+    HintRedo,
+    HintStopwords,
+    HintLogPrefixes,
+    HintDiagnostics,
+    HintReferences,
+    HintSaveReference,
+    HintSaveWorkspace,
+    HintLoadCompare,
+    HintWatch,
+    HintGitRevisions,
+    HintAutoCompare,
+    HintRefresh,
+    HintTableView,
+    HintWidenColumns,
+    HintNarrowColumns,
+    HintAutoFit,
+    HintHideEmpty,
+    HintResultSort,
+    HintGridLeft,
+    HintGridRight,
+    HintGridUp,
+    HintGridDown,
+    HintAlignedPreview,
+    HintQuoteFields,
+    HintItemWrap,
+    HintLiteral,
+    HintMarkdownTable,
+    HintTableMode,
+    HintFixedWidths,
+    HintCustomDelimiter,
+    HintExportBundle,
+    HintImportBundle,
+    HintKeyValue,
+    HintSendToLists,
+    HintConvertHistory,
+    HintAcceptRepair,
+    HintAcceptDelimiter,
+    HintJsonStrategy,
+    HintJsonPath,
+    HintFlattenJson,
+
+    // Help modal
+    HelpVimMode,
+    HelpNavigation,
+    HelpDataOperations,
+    HelpConfiguration,
+    HelpFilesClipboard,
+    HelpConvertTab,
+    HelpResultsTab,
+    HelpAnalysisTab,
+    HelpMergeTab,
+    HelpClose,
+    HelpInsert,
+    HelpNormal,
+    HelpMoveCursor,
+    HelpMoveWord,
+    HelpMoveLine,
+    HelpMoveTopBottom,
+    HelpSwitchTabs,
+    HelpSwitchWorkspaces,
+    HelpSwitchPanels,
+    HelpPreviousPanel,
+    HelpFocusPanel,
+    HelpQuit,
+    HelpCycleDelimiter,
+    HelpCustomDelimiter,
+    HelpExportBundle,
+    HelpImportBundle,
+    HelpSort,
+    HelpTrimDedup,
+    HelpFrequencies,
+    HelpDuplicates,
+    HelpCompare,
+    HelpValidate,
+    HelpCompareKeyed,
+    HelpCycleKeyedPreset,
+    HelpFilterList1,
+    HelpFilterRegex,
+    HelpReplaceRegex,
+    HelpStopwords,
+    HelpLogPrefixes,
+    HelpUndo,
+    HelpRedo,
+    HelpToggleCase,
+    HelpToggleTrim,
+    HelpTrimSet,
+    HelpFoldTypography,
+    HelpPreserveOrder,
+    HelpNormalizePreset,
+    HelpCompareColumn,
+    HelpTolerance,
+    HelpAutoCompare,
+    HelpToggleInfo,
+    HelpResizeInfo,
+    HelpToggleUsage,
+    HelpReadOnly,
+    HelpDiagnostics,
+    HelpReferences,
+    HelpSaveReference,
+    HelpSaveLoad,
+    HelpLineEnding,
+    HelpEncoding,
+    HelpEmptyItems,
+    HelpTrimOnPaste,
+    HelpSaveWorkspace,
+    HelpLoadCompare,
+    HelpWatch,
+    HelpGitRevisions,
+    HelpCopyPaste,
+    HelpCopySummary,
+    HelpReport,
+    HelpCycleSource,
+    HelpCycleTarget,
+    HelpConvert,
+    HelpAnalyze,
+    HelpAnalysisSource,
+    HelpJoin,
+    HelpJoinType,
+    HelpJoinKeys,
+    HelpJoinSeparator,
+    HelpAlignedPreview,
+    HelpQuoteFields,
+    HelpItemWrap,
+    HelpLiteral,
+    HelpMarkdownTable,
+    HelpTableMode,
+    HelpFixedWidths,
+    HelpKeyValue,
+    HelpSendToList,
+    HelpConvertHistory,
+    HelpAcceptRepair,
+    HelpAcceptDelimiter,
+    HelpJsonStrategy,
+    HelpJsonPath,
+    HelpFlattenJson,
+    HelpToggleView,
+    HelpSelectResult,
+    HelpRefresh,
+    HelpTableView,
+    HelpColumnWidth,
+    HelpHideEmpty,
+    HelpResultSort,
+    HelpResizeGrid,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_locale() {
+        assert_eq!(Lang::from_locale("es_AR.UTF-8"), Some(Lang::Es));
+        assert_eq!(Lang::from_locale("en-US"), Some(Lang::En));
+        assert_eq!(Lang::from_locale("ES"), Some(Lang::Es));
+        assert_eq!(Lang::from_locale("C.UTF-8"), None);
+    }
+
+    #[test]
+    fn test_fill_placeholders() {
+        assert_eq!(fill("{} of {}", &[&1, &"two"]), "1 of two");
+        assert_eq!(fill("missing {}", &[]), "missing {}");
+    }
+
+    #[test]
+    fn test_bundles_keep_placeholders() {
+        for key in [
+            Text::ItemsCount,
+            Text::Loaded,
+            Text::CompareSummary,
+            Text::ResultsHeader,
+        ] {
+            let en = tr_in(Lang::En, key).matches("{}").count();
+            let es = tr_in(Lang::Es, key).matches("{}").count();
+            assert_eq!(en, es, "{:?}", key);
+        }
+    }
+}
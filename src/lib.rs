@@ -1,3 +1,5 @@
 /// List Utils library
+pub mod numbers;
 pub mod operations;
 pub mod parser;
+pub mod report;
@@ -1,889 +1,4352 @@
 /// List Utils - Terminal UI application for manipulating and comparing lists
+mod actions;
 mod app;
+mod cli;
 mod clipboard;
+mod config;
+#[cfg(test)]
+mod driver;
 mod events;
+#[cfg(test)]
+mod golden;
+mod i18n;
+mod numbers;
 mod operations;
+mod panels;
 mod parser;
+mod report;
 mod ui;
 
-use app::{App, Mode};
+use app::{
+    read_with_progress, AnalysisPanel, AnalysisSource, App, AppEvent, BackgroundTask, BinaryOrigin,
+    BinaryPicker, ConvertPanel, ConvertTab, DelimiterDetection, DelimiterSlot, InputPanel,
+    MergePanel, Mode, PanelId, Prompt, PromptKind, ReferencePicker, ResultsPanel, ResultsTab,
+    ResultsView, Tab, TaskOutput, TaskState, Watch, BACKGROUND_LOAD_BYTES, BINARY_CHOICES,
+    FLASH_DURATION, SAMPLE_SIZE, TASK_REFRESH, WORKSPACE_COUNT,
+};
 use crossterm::{
-    event::{DisableMouseCapture, EnableMouseCapture, KeyCode},
+    event::{
+        DisableBracketedPaste, DisableMouseCapture, EnableBracketedPaste, EnableMouseCapture,
+        KeyCode, KeyEvent,
+    },
     execute,
     terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
 };
-use ratatui::{backend::CrosstermBackend, Terminal};
-use std::{env, fs, io, path::PathBuf};
+use ratatui::{
+    backend::CrosstermBackend,
+    style::{Modifier, Style},
+    Terminal,
+};
+use regex::Regex;
+use std::{
+    borrow::Cow,
+    env, fs,
+    io::{self, Write},
+    mem,
+    path::{Path, PathBuf},
+    time::{Instant, SystemTime},
+};
 use tui_textarea::{CursorMove, Input};
 
-use crate::events::{is_alt_number, is_copy_paste_key, is_key, read_event, InputEvent};
-use crate::operations::{compare_lists, process_single_list};
-use crate::parser::{parse_list, Delimiter};
+use crate::actions::{context_hints, resolve, Action, Motion};
+use crate::config::{references, Config};
+use crate::events::{describe_key, is_key, poll_event, InputEvent};
+use crate::i18n::{on_off, tr, trf, Text};
+use crate::numbers::NumberFormat;
+use crate::operations::{
+    analyze, check_against_reference, compare_files_external, compare_keyed, compare_lists,
+    compare_lists_with_progress, count_frequencies, count_items, count_replaced_lines,
+    detect_field_separator, extract_column, filter_by_list, filter_by_regex, format_amount,
+    frequency_lines, git_show, join_lists, keep_duplicates, parse_git_request, process_single_list,
+    remove_stopwords, replace_lines, sample_compare, sample_compare_with_progress,
+    strip_log_prefixes, AmountTotals, Analysis, ColumnError, ColumnSelector, CompareOptions,
+    CompareResult, Frequency, Issue, JoinOptions, JoinType, KeyedPreset, KeyedReport, ListFilter,
+    LogPreset, ResultSort, SampleEstimate, SpillConfig, SpillOutputs, Tolerance, VerdictMode,
+    VerdictReport,
+};
+use crate::panels::PanelContent;
+use crate::parser::{
+    convert_fixed_width, convert_table, delimited_rows, detect_binary, detect_fixed_widths,
+    detect_record_separator, extract_json_path, extract_key_values, hex_dump, is_long_item,
+    json_array_lines, lossy_text, parse_column_widths, parse_items_with, parse_list,
+    parse_list_with, quote_csv_field, rows_to_json_objects, structured_rows, summarize_json_text,
+    to_list_literal, to_markdown_table, trim_pasted, BinaryReason, BinaryView, CustomDelimiter,
+    Delimiter, EmptyItems, ItemWrap, JsonPath, JsonSummary, KeyValueOutput, LineEnding,
+    LiteralTarget, HEX_VIEW_LIMIT,
+};
+use crate::report::{format_timestamp, ComparisonBundle, BUNDLE_EXTENSION};
 use crate::ui::{
-    create_layout_with_tabs, create_results_grid, render_list_panel, render_result_list_panel,
-    render_results_panel, render_status_bar, render_tabs,
+    create_differences_layout, create_layout_with_tabs, create_results_grid, is_narrow,
+    render_list_panel, render_picker_modal, render_prompt_modal, render_result_list_panel,
+    render_result_table_panel, render_results_header, render_results_panel, render_status_bar,
+    render_tabs, split_header, GridLayout, TabMeta,
 };
 // Use statement removed
 
 fn main() -> Result<(), io::Error> {
+    // Subcommands print to stdout and never start the TUI
+    let args: Vec<String> = env::args().skip(1).collect();
+    if let Some(code) = cli::run(&args) {
+        std::process::exit(code);
+    }
+
     // Setup terminal
     enable_raw_mode()?;
     let mut stdout = io::stdout();
-    execute!(stdout, EnterAlternateScreen, EnableMouseCapture)?;
+    execute!(
+        stdout,
+        EnterAlternateScreen,
+        EnableMouseCapture,
+        EnableBracketedPaste
+    )?;
     let backend = CrosstermBackend::new(stdout);
     let mut terminal = Terminal::new(backend)?;
 
     // Create application
     let mut app = App::new();
+    subscribe_all(&mut app);
 
     // Main event loop
     loop {
-        terminal.draw(|f| {
-            let (tabs_area, list1_area, list2_area, results_area, status_area, content_area_tab2) =
-                create_layout_with_tabs(f.area());
+        terminal.draw(|f| draw(f, &mut app))?;
+        if mem::take(&mut app.ring_bell) {
+            let backend = terminal.backend_mut();
+            backend.write_all(b"\x07")?;
+            backend.flush()?;
+        }
 
-            // Render tabs
-            render_tabs(f, tabs_area, app.active_tab);
+        // Wake up for the next check of watched files, and to redraw the
+        // progress of a background task, even without input, or to draw the
+        // screen again after a flash
+        let timeout = if mem::take(&mut app.flash) {
+            Some(FLASH_DURATION)
+        } else {
+            [
+                app.watch
+                    .as_ref()
+                    .map(|watch| watch.timeout(Instant::now())),
+                app.task.as_ref().map(|_| TASK_REFRESH),
+            ]
+            .into_iter()
+            .flatten()
+            .min()
+        };
+        handle_event(&mut app, poll_event(timeout)?)?;
 
-            // Render content based on active tab
-            if app.active_tab == 0 {
-                // Tab 1: Input view
-                render_list_panel(
-                    f,
-                    list1_area,
-                    "LIST 1",
-                    &mut app.list1,
-                    app.active_panel == 0,
-                );
-                render_list_panel(
-                    f,
-                    list2_area,
-                    "LIST 2",
-                    &mut app.list2,
-                    app.active_panel == 1,
-                );
-                // Render INFO panel with dynamic hints
-                let info_hints = match app.active_panel {
-                    0 => vec![
-                        "List 1: Ctrl+C (Copy) | Ctrl+V (Paste) | F2 (Load)".to_string(),
-                        "Press F12 to Compare with List 2 | F5 (Delim)".to_string(),
-                    ],
-                    1 => vec![
-                        "List 2: Ctrl+C (Copy) | Ctrl+V (Paste) | F2 (Load)".to_string(),
-                        "Press F12 to Compare with List 1 | F5 (Delim)".to_string(),
-                    ],
-                    _ => {
-                        // Show current app results (success messages, stats) or default tips
-                        if !app.results.is_empty() && !app.results[0].contains("Welcome") {
-                            app.results.clone()
-                        } else {
-                            vec![
-                                "INFO: Compare: F9 | Sort: F6/F7 | Dedup: F8".to_string(),
-                                "Save: F1 | Load: F2 | Tab: Next Panel".to_string(),
-                            ]
-                        }
-                    }
-                };
-                render_results_panel(f, results_area, &info_hints, 0, app.active_panel == 2);
-            } else if app.active_tab == 1 {
-                // Tab 2: Results view
-                if app.diff_view_mode == 1 {
-                    // Unified Diff View
-                    if let Some(ref compare_results) = app.compare_results {
-                        crate::ui::render_unified_diff_panel(f, content_area_tab2, compare_results);
-                    } else {
-                        crate::ui::render_result_list_panel(
-                            f,
-                            content_area_tab2,
-                            "Unified Diff (0 items)",
-                            &[],
-                            false,
-                        );
-                    }
+        if app.should_quit {
+            break;
+        }
+    }
+
+    // Restore terminal
+    disable_raw_mode()?;
+    execute!(
+        terminal.backend_mut(),
+        LeaveAlternateScreen,
+        DisableMouseCapture,
+        DisableBracketedPaste
+    )?;
+    terminal.show_cursor()?;
+
+    Ok(())
+}
+
+/// Apply one input event to the app and deliver the events it published
+///
+/// An event that took long to handle (a huge comparison, a slow load) ends
+/// with the configured notice.
+fn handle_event(app: &mut App, event: InputEvent) -> Result<(), io::Error> {
+    // Output of a background task lands before the event is handled
+    poll_task(app);
+    let started = Instant::now();
+    match event {
+        InputEvent::Key(key_event) => {
+            // Any key acknowledges a change found by watch mode
+            if let Some(watch) = app.watch.as_mut() {
+                watch.unseen = false;
+            }
+            // Handle keyboard shortcuts
+            if app.show_help {
+                app.show_help = false;
+            } else if app.show_diagnostics {
+                // Key tester: log every key with the action it would trigger
+                if is_key(&key_event, KeyCode::Esc) {
+                    app.show_diagnostics = false;
                 } else {
-                    // Grid View: use split layout
-                    let (only_l1_area, only_l2_area, intersection_area, union_area) =
-                        create_results_grid(content_area_tab2);
-
-                    if let Some(ref compare_results) = app.compare_results {
-                        let only_l1_title = format!(
-                            "Only in List 1 ({} items)",
-                            compare_results.only_in_first.len()
-                        );
-                        let only_l2_title = format!(
-                            "Only in List 2 ({} items)",
-                            compare_results.only_in_second.len()
-                        );
-                        let intersection_title = format!(
-                            "Intersection ({} items)",
-                            compare_results.intersection.len()
-                        );
-                        let union_title = format!("Union ({} items)", compare_results.union.len());
-
-                        render_result_list_panel(
-                            f,
-                            only_l1_area,
-                            &only_l1_title,
-                            &compare_results.only_in_first,
-                            app.active_panel == 0,
-                        );
-                        render_result_list_panel(
-                            f,
-                            only_l2_area,
-                            &only_l2_title,
-                            &compare_results.only_in_second,
-                            app.active_panel == 1,
-                        );
-                        render_result_list_panel(
-                            f,
-                            intersection_area,
-                            &intersection_title,
-                            &compare_results.intersection,
-                            app.active_panel == 2,
-                        );
-                        render_result_list_panel(
-                            f,
-                            union_area,
-                            &union_title,
-                            &compare_results.union,
-                            app.active_panel == 3,
-                        );
-                    } else {
-                        // No results yet
-                        render_result_list_panel(
-                            f,
-                            only_l1_area,
-                            "Only in List 1 (0 items)",
-                            &[],
-                            app.active_panel == 0,
-                        );
-                        render_result_list_panel(
-                            f,
-                            only_l2_area,
-                            "Only in List 2 (0 items)",
-                            &[],
-                            app.active_panel == 1,
-                        );
-                        render_result_list_panel(
-                            f,
-                            intersection_area,
-                            "Intersection (0 items)",
-                            &[],
-                            app.active_panel == 2,
-                        );
-                        render_result_list_panel(
-                            f,
-                            union_area,
-                            "Union (0 items)",
-                            &[],
-                            app.active_panel == 3,
-                        );
-                    }
+                    let action = resolve(app, &key_event)
+                        .map(|action| format!("{:?}", action))
+                        .unwrap_or_else(|| tr(Text::DiagUnbound).to_string());
+                    app.record_key(format!("{} → {}", describe_key(&key_event), action));
+                }
+            } else if app.prompt.is_some() {
+                handle_prompt_key(app, key_event)?;
+            } else if app.reference_picker.is_some() {
+                handle_picker_key(app, key_event);
+            } else if app.binary_picker.is_some() {
+                handle_binary_picker_key(app, key_event);
+            } else if app.task.is_some() && is_key(&key_event, KeyCode::Esc) {
+                cancel_task(app);
+            } else if let Some(action) = resolve(app, &key_event) {
+                handle_action(app, action)?;
+            } else if app.mode == Mode::Insert && !app.is_writable_panel() {
+                // Locked panel reached in INSERT mode (e.g. with Tab): drop the key
+                if let Some(panel) = PanelContent::active(app).filter(|_| app.is_editable_panel()) {
+                    app.results = vec![trf(Text::ReadOnlyBlocked, &[&tr(panel.name())])];
+                }
+            } else if app.mode == Mode::Insert {
+                // Pass other keys to the active textarea only if in INSERT mode
+                let modified = app
+                    .active_textarea()
+                    .is_some_and(|textarea| textarea.input(Input::from(key_event)));
+                if modified {
+                    app.publish_active_panel_edited();
+                }
+            }
+        }
+        InputEvent::Mouse(mouse_event) => {
+            // Handle mouse events for textarea (list editing tabs only)
+            if app.is_list_tab() {
+                let modified = app
+                    .active_textarea()
+                    .is_some_and(|textarea| textarea.input(Input::from(mouse_event)));
+                if modified {
+                    app.publish_active_panel_edited();
                 }
-                // Render INFO panel for Results tab
-                let results_info = vec![
-                    "Results: Tab (Next Panel) | F12 (Toggle View: Diff/Grid)".to_string(),
-                    "F1 (Save Panel) | Alt+1 (Go back to inputs) | ?: Help".to_string(),
-                ];
-                render_results_panel(f, results_area, &results_info, 0, false);
+            }
+        }
+        InputEvent::Paste(text) => {
+            // Bracketed paste from the terminal goes where Ctrl+V would put it
+            if let Some(prompt) = app.prompt.as_mut() {
+                prompt.input.insert_str(&text);
+            } else if !app.has_modal() && app.is_writable_panel() {
+                handle_paste(app, text);
+            }
+        }
+        InputEvent::Resize(_, _) => {
+            // Terminal was resized, will be handled in next draw
+        }
+        InputEvent::Tick => watch_tick(app)?,
+    }
+
+    app.dispatch_events()?;
+    app.notify_finished(started.elapsed());
+    Ok(())
+}
+
+/// Register the handlers that keep derived state in sync with edits
+fn subscribe_all(app: &mut App) {
+    app.subscribe(refresh_live_counts);
+    app.subscribe(mark_results_stale);
+    app.subscribe(reset_sample_preview);
+    app.subscribe(auto_compare_on_change);
+    app.subscribe(mark_analysis_stale);
+    app.subscribe(refresh_merge_preview);
+}
+
+/// Draw the whole screen for the current state
+fn draw(f: &mut ratatui::Frame, app: &mut App) {
+    let (tabs_area, list1_area, list2_area, results_area, status_area, content_area_tab2) =
+        create_layout_with_tabs(f.area(), app.info_panel_height());
+
+    // Render tabs
+    let workspace = trf(
+        Text::WorkspaceLabel,
+        &[&(app.workspace + 1), &WORKSPACE_COUNT],
+    );
+    render_tabs(
+        f,
+        tabs_area,
+        app.active_tab.index(),
+        &tab_badges(app),
+        &workspace,
+    );
+
+    // Render content based on active tab
+    if app.active_tab == Tab::Input {
+        // Tab 1: Input view
+        let panel = app.input_tab.panel;
+        render_list_panel(
+            f,
+            list1_area,
+            tr(Text::TitleList1),
+            &mut app.input_tab.list1,
+            panel == InputPanel::List1,
+            app.read_only.contains(&PanelId::List1),
+        );
+        render_list_panel(
+            f,
+            list2_area,
+            tr(Text::TitleList2),
+            &mut app.input_tab.list2,
+            panel == InputPanel::List2,
+            app.read_only.contains(&PanelId::List2),
+        );
+        // Render INFO panel with hints derived from the key bindings
+        let info_hints = if panel == InputPanel::Info
+            && !app.results.is_empty()
+            && app.results[0] != tr(Text::Welcome)
+        {
+            // Show current app results (success messages, stats)
+            app.results.clone()
+        } else {
+            vec![context_hints(app)]
+        };
+        render_results_panel(f, results_area, &info_hints, 0, panel == InputPanel::Info);
+    } else if app.active_tab == Tab::Results {
+        // Tab 2: Results view, headed by the settings the results were produced with
+        app.results_tab.refresh_table_columns();
+        let results_tab = &app.results_tab;
+        let header = match results_tab.view {
+            ResultsView::Verdict => results_tab
+                .verdict
+                .as_ref()
+                .map(|report| (verdict_header(report), results_tab.verdict_stale)),
+            ResultsView::Keyed => results_tab
+                .keyed
+                .as_ref()
+                .map(|report| (keyed_header(app, report), results_tab.keyed_stale)),
+            _ => results_tab
+                .compare_results
+                .as_ref()
+                .map(|result| (results_header(result), results_tab.stale)),
+        };
+        let content_area_tab2 = match header {
+            Some((header, stale)) => {
+                let (header_area, body_area) = split_header(content_area_tab2);
+                render_results_header(f, header_area, &header, stale);
+                body_area
+            }
+            None => content_area_tab2,
+        };
+        if results_tab.view == ResultsView::Verdict {
+            // Verdict View: the pass/fail report as a single full-size panel
+            let (passed, failed) = results_tab
+                .verdict
+                .as_ref()
+                .map_or((0, 0), |report| (report.passed, report.failed));
+            let title = trf(
+                Text::VerdictTitle,
+                &[
+                    &app.number_format.count(passed),
+                    &app.number_format.count(failed),
+                ],
+            );
+            let panel = results_tab.panel;
+            render_result_panel(f, app, content_area_tab2, &title, panel);
+        } else if results_tab.view == ResultsView::Keyed {
+            // Keyed View: missing keys side by side above the changed values
+            let visible = results_tab.visible_panels();
+            let (missing_in_2_area, missing_in_1_area, changed_area, _) =
+                create_results_grid(content_area_tab2, results_tab.grid_layout.collapse(visible));
+            for (panel, area) in results_tab.panels().iter().zip([
+                missing_in_2_area,
+                missing_in_1_area,
+                changed_area,
+            ]) {
+                let title = trf(
+                    Text::ItemsCount,
+                    &[
+                        &tr(keyed_panel_name(results_tab.keyed.as_ref(), *panel)),
+                        &app.number_format.count(results_tab.items(*panel).len()),
+                    ],
+                );
+                render_result_panel(f, app, area, &title, *panel);
+            }
+        } else if results_tab.view == ResultsView::Unified {
+            // Unified Diff View
+            if let Some(ref compare_results) = results_tab.compare_results {
+                crate::ui::render_unified_diff_panel(f, content_area_tab2, compare_results);
             } else {
-                // Tab 3: Convert delimiters
-                render_list_panel(
+                crate::ui::render_result_list_panel(
                     f,
-                    list1_area,
-                    "CONVERT INPUT",
-                    &mut app.convert_input,
-                    app.active_panel == 0,
+                    content_area_tab2,
+                    &trf(Text::ItemsCount, &[&tr(Text::UnifiedDiff), &0]),
+                    &[],
+                    None,
+                    false,
+                    None,
                 );
+            }
+        } else {
+            let title = |panel: ResultsPanel| {
+                sorted_title(
+                    results_tab,
+                    panel,
+                    trf(
+                        Text::ItemsCount,
+                        &[
+                            &tr(PanelContent::from(panel).name()),
+                            &app.number_format.count(results_tab.items(panel).len()),
+                        ],
+                    ),
+                )
+            };
 
-                render_result_list_panel(
-                    f,
-                    list2_area,
-                    "CONVERT OUTPUT",
-                    &app.convert_output_items,
-                    app.active_panel == 1,
+            if results_tab.view == ResultsView::Differences {
+                // Differences View: only the two difference panels, full height
+                let (only_l1_area, only_l2_area) = create_differences_layout(content_area_tab2);
+                for (panel, area) in results_tab
+                    .panels()
+                    .iter()
+                    .zip([only_l1_area, only_l2_area])
+                {
+                    render_result_panel(f, app, area, &title(*panel), *panel);
+                }
+            } else if is_narrow(f.area()) {
+                // Narrow terminals: show only the active panel, cycled with Tab
+                let panel = results_tab.panel;
+                let title = sorted_title(
+                    results_tab,
+                    panel,
+                    trf(
+                        Text::PanelPosition,
+                        &[
+                            &(panel.index() + 1),
+                            &ResultsPanel::ALL.len(),
+                            &tr(PanelContent::from(panel).name()),
+                            &app.number_format.count(results_tab.items(panel).len()),
+                        ],
+                    ),
                 );
+                render_result_panel(f, app, content_area_tab2, &title, panel);
+            } else {
+                // Grid View: use split layout
+                let visible = results_tab.visible_panels();
+                let (only_l1_area, only_l2_area, intersection_area, union_area) =
+                    create_results_grid(
+                        content_area_tab2,
+                        results_tab.grid_layout.collapse(visible),
+                    );
+                let areas = [only_l1_area, only_l2_area, intersection_area, union_area];
 
-                let convert_info = match app.active_panel {
-                    0 => vec![
-                        format!(
-                            "Src: [ ({}) ] | Dst: [ ({}) ] | Convert: F12",
-                            app.convert_source_delimiter.display_name(),
-                            app.convert_target_delimiter.display_name()
-                        ),
-                        "Paste: Ctrl+V | Load: F2 | Cycle Src: F10".to_string(),
-                    ],
-                    _ => vec![
-                        format!(
-                            "Result: {} items | Dst: {}",
-                            app.convert_output_items.len(),
-                            app.convert_target_delimiter.display_name()
-                        ),
-                        "Copy: Ctrl+C | Save: F1 | Cycle Dst: F11".to_string(),
-                    ],
-                };
-                render_results_panel(f, results_area, &convert_info, 0, false);
+                for (panel, area) in ResultsPanel::ALL.into_iter().zip(areas) {
+                    if !visible[panel.index()] || area.is_empty() {
+                        continue;
+                    }
+                    render_result_panel(f, app, area, &title(panel), panel);
+                }
             }
+        }
+        // Render INFO panel for Results tab
+        let results_info = vec![context_hints(app)];
+        render_results_panel(f, results_area, &results_info, 0, false);
+    } else if app.active_tab == Tab::Analysis {
+        // Tab 5: Analysis of one list in a fixed 2x2 grid
+        let analysis = &app.analysis_tab;
+        let (stats_area, frequencies_area, duplicates_area, validation_area) =
+            create_results_grid(content_area_tab2, GridLayout::default());
+        let areas = [
+            stats_area,
+            frequencies_area,
+            duplicates_area,
+            validation_area,
+        ];
+        for (panel, area) in AnalysisPanel::ALL.into_iter().zip(areas) {
+            let lines = analysis.lines(panel);
+            let name = tr(PanelContent::from(panel).name());
+            let title = if panel == AnalysisPanel::Stats {
+                name.to_string()
+            } else {
+                trf(
+                    Text::ItemsCount,
+                    &[&name, &app.number_format.count(lines.len())],
+                )
+            };
+            let row = analysis.selection[panel.index()];
+            let position = (!lines.is_empty()).then(|| {
+                trf(
+                    Text::ItemPosition,
+                    &[
+                        &app.number_format.count(row + 1),
+                        &app.number_format.count(lines.len()),
+                    ],
+                )
+            });
+            render_result_list_panel(
+                f,
+                area,
+                &title,
+                lines,
+                position.as_deref().map(|label| (row, label)),
+                analysis.panel == panel,
+                app.display_item_limit(),
+            );
+        }
 
-            let active_panel_info = active_panel_label(&app);
-            let convert_delims = if app.active_tab == 2 {
-                Some((app.convert_source_delimiter, app.convert_target_delimiter))
+        let mut analysis_info = vec![
+            trf(
+                Text::AnalysisInfo,
+                &[
+                    &tr(analysis_source_name(analysis.source)),
+                    &app.delimiter.display_name(),
+                ],
+            ),
+            context_hints(app),
+        ];
+        analysis_info.extend(app.results.iter().cloned());
+        render_results_panel(f, results_area, &analysis_info, 0, false);
+    } else if app.active_tab == Tab::Merge {
+        // Tab 6: Join settings with a preview next to the joined rows
+        let merge = &app.merge_tab;
+        let (settings_area, result_area) = create_differences_layout(content_area_tab2);
+        for (panel, area) in MergePanel::ALL
+            .into_iter()
+            .zip([settings_area, result_area])
+        {
+            let lines = merge.lines(panel);
+            let name = tr(if panel == MergePanel::Settings {
+                Text::MergeSettings
             } else {
-                None
+                Text::MergeResult
+            });
+            let title = if panel == MergePanel::Settings {
+                name.to_string()
+            } else {
+                trf(
+                    Text::ItemsCount,
+                    &[&name, &app.number_format.count(lines.len())],
+                )
             };
-            render_status_bar(
+            let row = merge.selection[panel.index()];
+            let position = (!lines.is_empty()).then(|| {
+                trf(
+                    Text::ItemPosition,
+                    &[
+                        &app.number_format.count(row + 1),
+                        &app.number_format.count(lines.len()),
+                    ],
+                )
+            });
+            render_result_list_panel(
                 f,
-                status_area,
-                app.delimiter,
-                convert_delims,
-                app.active_tab,
-                active_panel_info.as_deref(),
-                app.mode,
+                area,
+                &title,
+                lines,
+                position.as_deref().map(|label| (row, label)),
+                merge.panel == panel,
+                app.display_item_limit(),
             );
+        }
 
-            if app.show_help {
-                crate::ui::render_help_modal(f);
-            }
-        })?;
-
-        // Handle events
-        match read_event()? {
-            InputEvent::Key(key_event) => {
-                // Handle keyboard shortcuts
-                if app.show_help {
-                    app.show_help = false;
-                } else if is_key(&key_event, KeyCode::Esc) {
-                    if app.mode == Mode::Insert {
-                        app.mode = Mode::Normal;
-                    } else {
-                        app.should_quit = true;
-                    }
-                } else if is_key(&key_event, KeyCode::Char('?')) {
-                    app.toggle_help();
-                } else if is_alt_number(&key_event, 1) {
-                    app.go_to_tab(0);
-                } else if is_alt_number(&key_event, 2) {
-                    app.go_to_tab(1);
-                } else if is_alt_number(&key_event, 3) {
-                    app.go_to_tab(2);
-                } else if is_key(&key_event, KeyCode::Tab) {
-                    app.switch_panel();
-                } else if is_key(&key_event, KeyCode::F(1)) {
-                    handle_save_to_file(&mut app)?;
-                } else if is_key(&key_event, KeyCode::F(2)) {
-                    handle_load_from_file(&mut app)?;
-                } else if is_key(&key_event, KeyCode::F(3)) {
-                    app.toggle_case_sensitivity();
-                    let state = if app.compare_options.case_sensitive {
-                        "ON"
-                    } else {
-                        "OFF"
-                    };
-                    app.results = vec![format!("Case sensitivity {}", state)];
-                } else if is_key(&key_event, KeyCode::F(4)) {
-                    app.toggle_trim_spaces();
-                    let state = if app.compare_options.trim_spaces {
-                        "ON"
+        let mut merge_info = vec![
+            trf(
+                Text::MergeInfo,
+                &[
+                    &tr(join_type_name(merge.join_type)),
+                    &join_separator_label(app),
+                    &app.delimiter.display_name(),
+                ],
+            ),
+            context_hints(app),
+        ];
+        merge_info.extend(app.results.iter().cloned());
+        render_results_panel(f, results_area, &merge_info, 0, false);
+    } else if app.active_tab == Tab::List {
+        // Tab 4: Full-screen single list
+        render_list_panel(
+            f,
+            content_area_tab2,
+            tr(Text::TitleList),
+            &mut app.single_list,
+            true,
+            app.read_only.contains(&PanelId::SingleList),
+        );
+
+        let mut list_info = vec![
+            trf(
+                Text::ListStats,
+                &[
+                    &app.number_format.count(app.live_counts.single_list),
+                    &app.number_format.count(app.live_counts.single_list_unique),
+                    &app.delimiter.display_name(),
+                ],
+            ),
+            context_hints(app),
+        ];
+        list_info.extend(app.results.iter().cloned());
+        render_results_panel(f, results_area, &list_info, 0, false);
+    } else {
+        // Tab 3: Convert delimiters
+        let panel = app.convert_tab.panel;
+        render_list_panel(
+            f,
+            list1_area,
+            tr(Text::TitleConvertInput),
+            &mut app.convert_tab.input,
+            panel == ConvertPanel::Input,
+            app.read_only.contains(&PanelId::ConvertInput),
+        );
+
+        // Aligned preview pads columns for display only
+        let convert = &app.convert_tab;
+        render_result_list_panel(
+            f,
+            list2_area,
+            tr(Text::TitleConvertOutput),
+            convert
+                .aligned_output
+                .as_deref()
+                .unwrap_or(&convert.output_items),
+            None,
+            panel == ConvertPanel::Output,
+            app.display_item_limit(),
+        );
+
+        let mut convert_info = vec![
+            match panel {
+                ConvertPanel::Input => {
+                    let delims = trf(
+                        Text::ConvertDelims,
+                        &[
+                            &convert.source_delimiter.display_name(),
+                            &convert.target_name(),
+                        ],
+                    );
+                    if let Some(output) = convert.key_value {
+                        format!(
+                            "{} | {}",
+                            delims,
+                            trf(Text::ConvertKeyValue, &[&output.display_name()])
+                        )
+                    } else if let Some(widths) = &convert.fixed_widths {
+                        format!(
+                            "{} | {}",
+                            delims,
+                            trf(Text::ConvertFixedWidth, &[&join_widths(widths)])
+                        )
+                    } else if convert.table_mode {
+                        format!("{} | {}", delims, tr(Text::ConvertRowByRow))
+                    } else if let Some(path) = convert
+                        .json_path
+                        .as_ref()
+                        .filter(|_| convert.source_delimiter.is_structured())
+                    {
+                        format!("{} | {}", delims, trf(Text::ConvertJsonPath, &[path]))
+                    } else if convert.source_delimiter.is_structured() {
+                        format!(
+                            "{} | {} | {}",
+                            delims,
+                            trf(
+                                Text::ConvertJsonStrategy,
+                                &[&convert.json_strategy.display_name()]
+                            ),
+                            trf(
+                                Text::ConvertNestedJson,
+                                &[&convert.nested_json.display_name()]
+                            )
+                        )
                     } else {
-                        "OFF"
-                    };
-                    app.results = vec![format!("Trim spaces {}", state)];
-                } else if is_key(&key_event, KeyCode::F(5)) {
-                    app.cycle_delimiter();
-                } else if is_key(&key_event, KeyCode::F(6)) {
-                    handle_sort_asc(&mut app)?;
-                } else if is_key(&key_event, KeyCode::F(7)) {
-                    handle_sort_desc(&mut app)?;
-                } else if is_key(&key_event, KeyCode::F(8)) {
-                    handle_trim_dedup(&mut app)?;
-                } else if is_key(&key_event, KeyCode::F(10)) {
-                    if app.active_tab == 2 {
-                        app.cycle_convert_source_delimiter();
-                        app.results = vec![format!(
-                            "Source delimiter: {}",
-                            app.convert_source_delimiter.display_name()
-                        )];
-                    }
-                } else if is_key(&key_event, KeyCode::F(11)) {
-                    if app.active_tab == 2 {
-                        app.cycle_convert_target_delimiter();
-                        app.results = vec![format!(
-                            "Target delimiter: {}",
-                            app.convert_target_delimiter.display_name()
-                        )];
-                    }
-                } else if is_key(&key_event, KeyCode::F(12)) {
-                    if app.active_tab == 0 {
-                        handle_compare_operations(&mut app)?;
-                    } else if app.active_tab == 1 {
-                        app.toggle_diff_view();
-                        let mode = if app.diff_view_mode == 1 {
-                            "Unified View"
-                        } else {
-                            "Grid View"
-                        };
-                        app.results = vec![format!("Diff mode: {}", mode)];
-                    } else if app.active_tab == 2 {
-                        handle_convert_operation(&mut app)?;
-                    }
-                } else if is_copy_paste_key(&key_event, KeyCode::Char('v')) {
-                    // Paste from clipboard
-                    if app.active_tab == 0 || (app.active_tab == 2 && app.active_panel == 0) {
-                        match crate::clipboard::get_from_clipboard(app.clipboard.as_mut()) {
-                            Ok(text) => {
-                                if let Some(textarea) = app.active_textarea() {
-                                    textarea.insert_str(&text);
-                                }
-                            }
-                            Err(e) => {
-                                app.results = vec![format!("Error pasting: {}", e)];
-                            }
-                        }
-                    }
-                } else if is_copy_paste_key(&key_event, KeyCode::Char('c')) {
-                    // Copy active panel to clipboard (Ctrl+C on Linux, Cmd+C on macOS)
-                    let (text, panel_name) = active_panel_content(&app);
-                    match crate::clipboard::copy_to_clipboard(app.clipboard.as_mut(), &text) {
-                        Ok(_) => {
-                            if app.active_tab == 0 && app.active_panel != 2 {
-                                app.results = vec![format!("Copied {} to clipboard", panel_name)];
-                            }
-                        }
-                        Err(e) => {
-                            app.results = vec![format!("Error copying: {}", e)];
-                        }
-                    }
-                } else if app.mode == Mode::Normal && is_key(&key_event, KeyCode::Char('i')) {
-                    app.mode = Mode::Insert;
-                } else if app.mode == Mode::Normal && is_key(&key_event, KeyCode::Char('h')) {
-                    if let Some(textarea) = app.active_textarea() {
-                        textarea.move_cursor(CursorMove::Back);
-                    }
-                } else if app.mode == Mode::Normal && is_key(&key_event, KeyCode::Char('j')) {
-                    if let Some(textarea) = app.active_textarea() {
-                        textarea.move_cursor(CursorMove::Down);
-                    }
-                } else if app.mode == Mode::Normal && is_key(&key_event, KeyCode::Char('k')) {
-                    if let Some(textarea) = app.active_textarea() {
-                        textarea.move_cursor(CursorMove::Up);
-                    }
-                } else if app.mode == Mode::Normal && is_key(&key_event, KeyCode::Char('l')) {
-                    if let Some(textarea) = app.active_textarea() {
-                        textarea.move_cursor(CursorMove::Forward);
-                    }
-                } else if app.mode == Mode::Normal && is_key(&key_event, KeyCode::Char('w')) {
-                    if let Some(textarea) = app.active_textarea() {
-                        textarea.move_cursor(CursorMove::WordForward);
-                    }
-                } else if app.mode == Mode::Normal && is_key(&key_event, KeyCode::Char('b')) {
-                    if let Some(textarea) = app.active_textarea() {
-                        textarea.move_cursor(CursorMove::WordBack);
-                    }
-                } else if app.mode == Mode::Normal && is_key(&key_event, KeyCode::Char('0')) {
-                    if let Some(textarea) = app.active_textarea() {
-                        textarea.move_cursor(CursorMove::Head);
-                    }
-                } else if app.mode == Mode::Normal && is_key(&key_event, KeyCode::Char('$')) {
-                    if let Some(textarea) = app.active_textarea() {
-                        textarea.move_cursor(CursorMove::End);
-                    }
-                } else if app.mode == Mode::Normal && is_key(&key_event, KeyCode::Char('g')) {
-                    if let Some(textarea) = app.active_textarea() {
-                        textarea.move_cursor(CursorMove::Top);
-                    }
-                } else if app.mode == Mode::Normal && is_key(&key_event, KeyCode::Char('G')) {
-                    if let Some(textarea) = app.active_textarea() {
-                        textarea.move_cursor(CursorMove::Bottom);
-                    }
-                } else {
-                    // Pass other keys to the active textarea (Tab 1 and converter input)
-                    // only if in INSERT mode
-                    if app.mode == Mode::Insert {
-                        if app.active_tab == 0 || (app.active_tab == 2 && app.active_panel == 0) {
-                            if let Some(textarea) = app.active_textarea() {
-                                let input = Input::from(key_event);
-                                textarea.input(input);
-                            }
-                        }
+                        delims
                     }
                 }
-            }
-            InputEvent::Mouse(mouse_event) => {
-                // Handle mouse events for textarea (only in Tab 1)
-                if app.active_tab == 0 {
-                    if let Some(textarea) = app.active_textarea() {
-                        let input = Input::from(mouse_event);
-                        textarea.input(input);
-                    }
-                }
-            }
-            InputEvent::Resize(_, _) => {
-                // Terminal was resized, will be handled in next draw
-            }
+                ConvertPanel::Output => trf(
+                    Text::ConvertResult,
+                    &[
+                        &app.number_format.count(convert.output_items.len()),
+                        &convert.target_name(),
+                    ],
+                ),
+            },
+            context_hints(app),
+        ];
+        convert_info.extend(app.results.iter().cloned());
+        convert_info.extend(convert.json_summary.iter().cloned());
+        if let Some(repaired) = &convert.pending_repair {
+            convert_info.push(tr(Text::JsonRepairPreview).to_string());
+            convert_info.extend(repaired.lines().map(String::from));
         }
+        render_results_panel(f, results_area, &convert_info, 0, false);
+    }
 
-        if app.should_quit {
-            break;
-        }
+    let active_panel_info = active_panel_label(app);
+    let usage_info = app.show_usage.then(|| usage_label(app));
+    let undo_info = (!app.undo_stack.is_empty() || !app.redo_stack.is_empty())
+        .then(|| trf(Text::StatusUndo, &[&app.undo_stack.len(), &app.undo_depth]));
+    let watch_info = app.watch.as_ref().map(|watch| {
+        let secs = watch.interval.as_secs();
+        let label = if watch.changes == 0 {
+            trf(Text::StatusWatch, &[&secs])
+        } else {
+            trf(
+                Text::StatusWatchChanges,
+                &[&secs, &app.number_format.count(watch.changes)],
+            )
+        };
+        (label, watch.unseen)
+    });
+    let task_info = app.task.as_ref().map(|task| {
+        trf(
+            Text::StatusTask,
+            &[&tr(task.name), &task.percent(), &task.elapsed().as_secs()],
+        )
+    });
+    let convert_delims = if app.active_tab == Tab::Convert {
+        Some((
+            app.convert_tab.source_delimiter,
+            app.convert_tab.target_delimiter,
+        ))
+    } else {
+        None
+    };
+    let (detected, suggested) = if app.active_tab == Tab::Convert {
+        let current = app.convert_tab.source_delimiter;
+        (
+            app.convert_tab.detected_source == Some(current),
+            app.convert_tab.suggested_source.filter(|&s| s != current),
+        )
+    } else {
+        (
+            app.detected_delimiter == Some(app.delimiter),
+            app.suggested_delimiter.filter(|&s| s != app.delimiter),
+        )
+    };
+    render_status_bar(
+        f,
+        status_area,
+        app.delimiter,
+        convert_delims,
+        detected,
+        suggested,
+        app.active_tab.index(),
+        active_panel_info.as_deref(),
+        usage_info.as_deref(),
+        undo_info.as_deref(),
+        watch_info
+            .as_ref()
+            .map(|(label, unseen)| (label.as_str(), *unseen)),
+        task_info.as_deref(),
+        app.mode,
+    );
+
+    if app.show_help {
+        crate::ui::render_help_modal(f);
+    }
+    if app.show_diagnostics {
+        crate::ui::render_diagnostics_modal(f, &app.diagnostics, &app.key_log);
+    }
+    if let Some(picker) = &app.reference_picker {
+        render_picker_modal(f, tr(Text::TitleReferences), &picker.names, picker.selected);
+    }
+    if let Some(picker) = &app.binary_picker {
+        let title = trf(
+            Text::TitleBinary,
+            &[
+                &binary_origin_label(&picker.origin),
+                &binary_reason(app, picker.reason),
+            ],
+        );
+        let choices: Vec<String> = BINARY_CHOICES
+            .iter()
+            .map(|choice| {
+                tr(match choice {
+                    Some(BinaryView::Hex) => Text::BinaryViewHex,
+                    Some(BinaryView::Lossy) => Text::BinaryViewLossy,
+                    None => Text::BinaryAbort,
+                })
+                .to_string()
+            })
+            .collect();
+        render_picker_modal(f, &title, &choices, picker.selected);
+    }
+    if let Some(prompt) = &mut app.prompt {
+        let title = match &prompt.kind {
+            PromptKind::SaveReference(panel) => trf(
+                Text::TitleSaveReference,
+                &[&tr(PanelContent::from(*panel).name())],
+            ),
+            PromptKind::FixedWidths => tr(Text::TitleFixedWidths).to_string(),
+            PromptKind::JsonPath => tr(Text::TitleJsonPath).to_string(),
+            PromptKind::ItemWrap => tr(Text::TitleItemWrap).to_string(),
+            PromptKind::CustomDelimiter(slot) => {
+                trf(Text::TitleCustomDelimiter, &[&tr(slot.name())])
+            }
+            PromptKind::ExportBundle => tr(Text::TitleExportBundle).to_string(),
+            PromptKind::ExportReport => tr(Text::TitleExportReport).to_string(),
+            PromptKind::ImportBundle => tr(Text::TitleImportBundle).to_string(),
+            PromptKind::GitRevisions => tr(Text::TitleGitRevisions).to_string(),
+            PromptKind::CompareColumn => tr(Text::TitleCompareColumn).to_string(),
+            PromptKind::Tolerance => tr(Text::TitleTolerance).to_string(),
+            PromptKind::RegexFilter(panel, filter) => trf(
+                match filter {
+                    ListFilter::Keep => Text::TitleRegexKeep,
+                    ListFilter::Remove => Text::TitleRegexDrop,
+                },
+                &[&tr(PanelContent::from(*panel).name())],
+            ),
+            PromptKind::ReplaceFind(panel) => trf(
+                Text::TitleReplaceFind,
+                &[&tr(PanelContent::from(*panel).name())],
+            ),
+            PromptKind::ReplaceWith(panel, pattern) => trf(
+                Text::TitleReplaceWith,
+                &[pattern, &tr(PanelContent::from(*panel).name())],
+            ),
+        };
+        render_prompt_modal(f, &title, &mut prompt.input);
+    }
+    if app.flash {
+        let area = f.area();
+        f.buffer_mut()
+            .set_style(area, Style::default().add_modifier(Modifier::REVERSED));
     }
+}
 
-    // Restore terminal
-    disable_raw_mode()?;
-    execute!(
-        terminal.backend_mut(),
-        LeaveAlternateScreen,
-        DisableMouseCapture
-    )?;
-    terminal.show_cursor()?;
+/// Badges for the tab bar: differences from the last compare, converted item count,
+/// List tab item count, analyzed item count and joined row count
+fn tab_badges(app: &App) -> [TabMeta; 6] {
+    let count = |n: usize| Some(app.number_format.count(n));
+    let results = app.results_tab.compare_results.as_ref().map(|r| {
+        trf(
+            Text::BadgeDifferences,
+            &[&app
+                .number_format
+                .count(r.only_in_first.len() + r.only_in_second.len())],
+        )
+    });
+    let converted = app.convert_tab.item_count;
+    let listed = app.live_counts.single_list;
 
-    Ok(())
+    [
+        TabMeta::default(),
+        TabMeta {
+            badge: results,
+            warning: app.results_tab.stale,
+        },
+        TabMeta {
+            badge: if converted > 0 {
+                count(converted)
+            } else {
+                None
+            },
+            warning: false,
+        },
+        TabMeta {
+            badge: if listed > 0 { count(listed) } else { None },
+            warning: false,
+        },
+        TabMeta {
+            badge: app.analysis_tab.item_count.and_then(count),
+            warning: app.analysis_tab.stale,
+        },
+        TabMeta {
+            badge: app.merge_tab.row_count.and_then(count),
+            warning: app.merge_tab.stale,
+        },
+    ]
 }
 
-/// Handle trim and dedup operation - replaces panel content
-fn handle_trim_dedup(app: &mut App) -> Result<(), io::Error> {
-    if app.active_tab != 0 {
-        return Ok(());
+/// Describe the options, delimiter and time a comparison was run with
+fn results_header(result: &CompareResult) -> String {
+    let header = trf(
+        Text::ResultsHeader,
+        &[
+            &crate::report::format_timestamp(result.compared_at),
+            &on_off(result.options.case_sensitive),
+            &trim_label(result.options),
+            &result.delimiter.display_name(),
+        ],
+    );
+    if result.options.preserve_order {
+        format!("{} | {}", header, tr(Text::InputOrderHeader))
+    } else {
+        header
     }
+}
 
-    let delimiter = app.delimiter;
-    let Some(textarea) = app.active_textarea() else {
-        app.results = vec!["Please select List 1 or List 2".to_string()];
-        return Ok(());
-    };
+/// "on"/"off" for trimming, followed by the extra trimmed characters when there are any
+fn trim_label(options: CompareOptions) -> String {
+    let state = on_off(options.trim_spaces);
+    if options.trim_spaces && !options.trim_set.is_empty() {
+        format!("{} + {}", state, options.trim_set.chars())
+    } else {
+        state.to_string()
+    }
+}
 
-    let active_text = join_lines_with_delimiter(textarea.lines(), delimiter);
-    let items = parse_list(&active_text, delimiter);
+/// Describe how and with which options List 1 was checked against List 2
+fn verdict_header(report: &VerdictReport) -> String {
+    trf(
+        Text::VerdictHeader,
+        &[
+            &tr(verdict_mode_name(report.mode)),
+            &on_off(report.options.case_sensitive),
+            &trim_label(report.options),
+        ],
+    )
+}
 
-    if items.is_empty() {
-        app.results = vec!["No items to process".to_string()];
-        return Ok(());
+/// Describe how List 1 and List 2 were compared by key
+fn keyed_header(app: &App, report: &KeyedReport) -> String {
+    let header = trf(
+        Text::KeyedHeader,
+        &[
+            &report.preset.display_name(),
+            &app.number_format.count(report.unchanged),
+        ],
+    );
+    match &report.totals {
+        Some(totals) => format!("{} | {}", header, amount_totals_line(totals)),
+        None => header,
     }
+}
 
-    // Count BEFORE processing to show original stats
-    let original_total = items.len();
-    let original_unique = items.iter().collect::<std::collections::HashSet<_>>().len();
+/// Totals of a reconciliation: per list, for the matched ids and for the unmatched ones
+fn amount_totals_line(totals: &AmountTotals) -> String {
+    trf(
+        Text::AmountTotals,
+        &[
+            &format_amount(totals.first()),
+            &format_amount(totals.second()),
+            &format_amount(totals.matched_first),
+            &format_amount(totals.matched_second),
+            &format_amount(totals.matched_second - totals.matched_first),
+            &format_amount(totals.only_first),
+            &format_amount(totals.only_second),
+        ],
+    )
+}
 
-    // Apply trim and dedup (no sorting)
-    let result = process_single_list(&items, true, true, false, false);
+/// Name of a Keyed view panel: lockfile panels hold removed, added and upgraded
+/// packages, the changed hosts/DNS entries point to different targets and the
+/// changed ids of a reconciliation have different amounts
+fn keyed_panel_name(report: Option<&KeyedReport>, panel: ResultsPanel) -> Text {
+    let preset = report.map(|report| report.preset).unwrap_or_default();
+    match panel {
+        ResultsPanel::OnlyInList1 if preset.is_lockfile() => Text::PackagesRemoved,
+        ResultsPanel::OnlyInList2 if preset.is_lockfile() => Text::PackagesAdded,
+        ResultsPanel::Intersection if preset.is_lockfile() => Text::VersionChanged,
+        ResultsPanel::Intersection if preset == KeyedPreset::Hosts => Text::DifferentTargets,
+        ResultsPanel::Intersection if preset == KeyedPreset::Amounts => Text::AmountsDiffer,
+        _ => PanelContent::keyed(panel).name(),
+    }
+}
 
-    // Replace panel content with processed items
-    let new_content: Vec<String> = result.items.clone();
-    textarea.select_all();
-    textarea.cut();
-    textarea.insert_str(&new_content.join("\n"));
+fn verdict_mode_name(mode: VerdictMode) -> Text {
+    match mode {
+        VerdictMode::Exact => Text::VerdictExact,
+        VerdictMode::Pattern => Text::VerdictPattern,
+    }
+}
 
-    // Show stats in results
-    app.results = vec![format!(
-        "Trim & Dedup: {} → {} items",
-        original_total, original_unique
-    )];
+/// "item N of M" for the selected row of a result panel
+fn result_position_label(app: &App, panel: ResultsPanel) -> Option<String> {
+    let total = app.results_tab.items(panel).len();
+    (total > 0).then(|| {
+        trf(
+            Text::ItemPosition,
+            &[
+                &app.number_format
+                    .count(app.results_tab.selection[panel.index()] + 1),
+                &app.number_format.count(total),
+            ],
+        )
+    })
+}
+
+/// Panel title naming the display order, unless the panel keeps the smart order
+fn sorted_title(results_tab: &ResultsTab, panel: ResultsPanel, title: String) -> String {
+    match results_tab.sort[panel.index()] {
+        ResultSort::Smart => title,
+        sort => format!("{} · {}", title, sort.display_name()),
+    }
+}
+
+/// Render one result category as a table when records were detected, as raw lines otherwise
+fn render_result_panel(
+    f: &mut ratatui::Frame,
+    app: &App,
+    area: ratatui::layout::Rect,
+    title: &str,
+    panel: ResultsPanel,
+) {
+    let results_tab = &app.results_tab;
+    let items = results_tab.items(panel);
+    let is_active = results_tab.panel == panel;
+    let position = result_position_label(app, panel);
+    let selection = position
+        .as_deref()
+        .map(|label| (results_tab.selection[panel.index()], label));
+    match results_tab.table_separator() {
+        Some(separator) => render_result_table_panel(
+            f,
+            area,
+            title,
+            items,
+            separator,
+            results_tab.table_header(),
+            results_tab.table_column_widths(panel),
+            results_tab.table_column_width,
+            selection,
+            is_active,
+        ),
+        None => render_result_list_panel(
+            f,
+            area,
+            title,
+            items,
+            selection,
+            is_active,
+            app.display_item_limit(),
+        ),
+    }
+}
+
+/// Run an action resolved from the key bindings registry
+fn handle_action(app: &mut App, action: Action) -> Result<(), io::Error> {
+    match action {
+        Action::Quit => app.should_quit = true,
+        Action::ExitInsert => app.mode = Mode::Normal,
+        Action::EnterInsert => app.mode = Mode::Insert,
+        Action::ToggleHelp => app.toggle_help(),
+        Action::GoToTab(tab) => {
+            app.go_to_tab(tab);
+            if tab == Tab::Merge {
+                refresh_merge_settings(app);
+            }
+        }
+        Action::SwitchWorkspace(index) => {
+            let opened = !app.is_workspace_open(index);
+            if app.switch_workspace(index) && opened {
+                app.results = vec![trf(Text::WorkspaceOpened, &[&(index + 1)])];
+            }
+        }
+        Action::NextPanel => app.switch_panel(),
+        Action::PreviousPanel => app.switch_panel_back(),
+        Action::FocusPanel(panel) => app.focus_panel(panel),
+        Action::Save => handle_save_to_file(app)?,
+        Action::Load => handle_load_from_file(app)?,
+        Action::SaveWorkspace => handle_save_workspace(app)?,
+        Action::ToggleEmptyItems => {
+            app.toggle_empty_items();
+            let state = on_off(app.empty_items == EmptyItems::Collapse);
+            app.results = vec![trf(Text::CollapseEmpty, &[&state])];
+        }
+        Action::ToggleTrimOnPaste => {
+            app.trim_on_paste = !app.trim_on_paste;
+            app.results = vec![trf(Text::TrimOnPaste, &[&on_off(app.trim_on_paste)])];
+        }
+        Action::CycleEncoding => {
+            app.save_encoding = app.save_encoding.next();
+            app.results = vec![trf(
+                Text::EncodingChanged,
+                &[&app.save_encoding.display_name()],
+            )];
+        }
+        Action::CycleLineEnding => {
+            app.cycle_save_line_ending();
+            let ending = app
+                .save_line_ending
+                .map_or(tr(Text::LineEndingAuto), LineEnding::display_name);
+            app.results = vec![trf(Text::LineEndingChanged, &[&ending])];
+        }
+        Action::OpenReferences => handle_open_references(app),
+        Action::SaveReference => {
+            if let Some(panel) = app.active_panel_id() {
+                app.prompt = Some(Prompt::new(PromptKind::SaveReference(panel), ""));
+            }
+        }
+        Action::FilterRegex => {
+            if let Some(panel) = app.active_panel_id() {
+                let kind = PromptKind::RegexFilter(panel, ListFilter::Keep);
+                app.prompt = Some(Prompt::new(kind, ""));
+            }
+        }
+        Action::ReplaceRegex => {
+            if let Some(panel) = app.active_panel_id() {
+                app.prompt = Some(Prompt::new(PromptKind::ReplaceFind(panel), ""));
+            }
+        }
+        Action::LoadAndCompare => handle_load_and_compare(app)?,
+        Action::ToggleCase => {
+            app.toggle_case_sensitivity();
+            let state = on_off(app.compare_options.case_sensitive);
+            app.results = vec![trf(Text::CaseSensitivity, &[&state])];
+        }
+        Action::ToggleTrim => {
+            app.toggle_trim_spaces();
+            let state = trim_label(app.compare_options);
+            app.results = vec![trf(Text::TrimSpaces, &[&state])];
+        }
+        Action::ToggleFoldTypography => {
+            app.toggle_fold_typography();
+            let state = on_off(app.compare_options.fold_typography);
+            app.results = vec![trf(Text::FoldTypography, &[&state])];
+        }
+        Action::TogglePreserveOrder => {
+            app.toggle_preserve_order();
+            let state = on_off(app.compare_options.preserve_order);
+            app.results = vec![trf(Text::PreserveOrder, &[&state])];
+        }
+        Action::CycleNormalizePreset => {
+            app.cycle_normalize_preset();
+            let name = app.compare_options.normalize.name();
+            app.results = vec![trf(Text::NormalizePresetChanged, &[&name])];
+        }
+        Action::CycleTrimSet => {
+            app.cycle_trim_set();
+            let chars = app.compare_options.trim_set.chars();
+            let chars = if chars.is_empty() {
+                tr(Text::SpacesOnly).to_string()
+            } else {
+                chars
+            };
+            app.results = vec![trf(Text::TrimSetChanged, &[&chars])];
+        }
+        Action::CycleDelimiter => app.cycle_delimiter(),
+        Action::ExportBundle | Action::ImportBundle => {
+            let kind = if action == Action::ExportBundle {
+                PromptKind::ExportBundle
+            } else {
+                PromptKind::ImportBundle
+            };
+            app.prompt = Some(Prompt::new(kind, DEFAULT_BUNDLE_FILE));
+        }
+        Action::SetCustomDelimiter => {
+            let slot = match (app.active_tab, app.convert_tab.panel) {
+                (Tab::Convert, ConvertPanel::Output) => DelimiterSlot::ConvertTarget,
+                (Tab::Convert, _) => DelimiterSlot::ConvertSource,
+                _ => DelimiterSlot::Lists,
+            };
+            // Pre-fill with the current delimiter when it is a custom one
+            let current = match slot {
+                DelimiterSlot::Lists => app.delimiter,
+                DelimiterSlot::ConvertSource => app.convert_tab.source_delimiter,
+                DelimiterSlot::ConvertTarget => app.convert_tab.target_delimiter,
+            };
+            let initial = match current {
+                Delimiter::Custom(custom) => custom.as_str().to_string(),
+                _ => String::new(),
+            };
+            app.prompt = Some(Prompt::new(PromptKind::CustomDelimiter(slot), &initial));
+        }
+        Action::SortAsc => handle_sort_asc(app)?,
+        Action::SortDesc => handle_sort_desc(app)?,
+        Action::TrimDedup => handle_trim_dedup(app)?,
+        Action::CountFrequencies => handle_count_frequencies(app),
+        Action::KeepDuplicates(counts) => handle_keep_duplicates(app, counts),
+        Action::RemoveStopwords => handle_remove_stopwords(app),
+        Action::StripLogPrefixes => handle_strip_log_prefixes(app),
+        Action::ToggleReadOnly => {
+            if let (Some((_, locked)), Some(panel)) =
+                (app.toggle_read_only(), PanelContent::active(app))
+            {
+                let message = if locked {
+                    Text::ReadOnlyOn
+                } else {
+                    Text::ReadOnlyOff
+                };
+                app.results = vec![trf(message, &[&tr(panel.name())])];
+            }
+        }
+        Action::ToggleInfo => app.toggle_info_panel(),
+        Action::GrowInfo => app.resize_info_panel(1),
+        Action::ToggleUsage => app.toggle_usage(),
+        Action::ToggleWatch => toggle_watch(app)?,
+        Action::LoadGitRevisions => {
+            app.prompt = Some(Prompt::new(PromptKind::GitRevisions, ""));
+        }
+        Action::SetTolerance => {
+            let current = app
+                .compare_options
+                .tolerance
+                .map_or(String::new(), |tolerance| tolerance.to_string());
+            app.prompt = Some(Prompt::new(PromptKind::Tolerance, &current));
+        }
+        Action::SelectCompareColumn => {
+            let current = app
+                .compare_column
+                .as_ref()
+                .map_or(String::new(), ToString::to_string);
+            app.prompt = Some(Prompt::new(PromptKind::CompareColumn, &current));
+        }
+        Action::ToggleAutoCompare => {
+            app.toggle_auto_compare();
+            app.results = vec![trf(Text::AutoCompare, &[&on_off(app.auto_compare)])];
+        }
+        Action::ShowDiagnostics => {
+            app.diagnostics = collect_diagnostics(app);
+            app.key_log.clear();
+            app.show_diagnostics = true;
+        }
+        Action::ShrinkInfo => app.resize_info_panel(-1),
+        Action::CycleSourceDelimiter => {
+            app.convert_tab.cycle_source_delimiter();
+            app.results = vec![trf(
+                Text::SourceDelimiter,
+                &[&app.convert_tab.source_delimiter.display_name()],
+            )];
+        }
+        Action::CycleTargetDelimiter => {
+            app.convert_tab.cycle_target_delimiter();
+            app.results = vec![trf(
+                Text::TargetDelimiter,
+                &[&app.convert_tab.target_delimiter.display_name()],
+            )];
+        }
+        Action::Compare => handle_compare_operations(app)?,
+        Action::Validate(mode) => {
+            if run_validation(app, mode) {
+                app.go_to_tab(Tab::Results);
+            }
+        }
+        Action::CompareKeyed => {
+            if run_keyed_comparison(app, app.keyed_preset) {
+                app.go_to_tab(Tab::Results);
+            }
+        }
+        Action::CycleKeyedPreset => {
+            app.keyed_preset = app.keyed_preset.next();
+            app.results = vec![trf(
+                Text::KeyedPresetChanged,
+                &[&app.keyed_preset.display_name()],
+            )];
+        }
+        Action::FilterList1(filter) => handle_filter_list1(app, filter),
+        Action::Undo => {
+            if let Some(panel) = app.undo() {
+                let name = tr(PanelContent::from(panel).name());
+                app.results = vec![trf(Text::Undone, &[&name])];
+            }
+        }
+        Action::Redo => {
+            if let Some(panel) = app.redo() {
+                let name = tr(PanelContent::from(panel).name());
+                app.results = vec![trf(Text::Redone, &[&name])];
+            }
+        }
+        Action::RefreshResults => {
+            let results_tab = &app.results_tab;
+            let verdict = results_tab.verdict.as_ref().map(|report| report.mode);
+            let keyed = results_tab.keyed.as_ref().map(|report| report.preset);
+            match (results_tab.view, verdict, keyed) {
+                (ResultsView::Verdict, Some(mode), _) => {
+                    run_validation(app, mode);
+                }
+                (ResultsView::Keyed, _, Some(preset)) => {
+                    run_keyed_comparison(app, preset);
+                }
+                _ => {
+                    run_comparison(app);
+                }
+            }
+        }
+        Action::ToggleDiffView => {
+            app.results_tab.toggle_view();
+            let mode = match app.results_tab.view {
+                ResultsView::Grid => tr(Text::GridView),
+                ResultsView::Unified => tr(Text::UnifiedView),
+                ResultsView::Differences => tr(Text::DifferencesView),
+                ResultsView::Verdict => tr(Text::VerdictView),
+                ResultsView::Keyed => tr(Text::KeyedView),
+            };
+            app.results = vec![trf(Text::DiffMode, &[&mode])];
+        }
+        Action::ToggleTableView => {
+            app.results_tab.toggle_table_view();
+            app.results = vec![trf(Text::TableView, &[&on_off(app.results_tab.table_view)])];
+        }
+        Action::WidenColumns => app.results_tab.resize_table_columns(2),
+        Action::NarrowColumns => app.results_tab.resize_table_columns(-2),
+        Action::AutoFitColumns => app.results_tab.auto_fit_table_columns(),
+        Action::CycleResultSort => {
+            let panel = app.results_tab.panel;
+            let sort = app.results_tab.cycle_sort(panel);
+            app.results = vec![trf(
+                Text::ResultSortChanged,
+                &[&tr(PanelContent::from(panel).name()), &sort.display_name()],
+            )];
+        }
+        Action::ToggleHideEmpty => {
+            app.results_tab.toggle_hide_empty_panels();
+            app.results = vec![trf(
+                Text::HideEmptyPanels,
+                &[&on_off(app.results_tab.hide_empty_panels)],
+            )];
+        }
+        Action::ResizeGridColumns(direction) => {
+            app.results_tab.resize_grid_columns(direction);
+            save_grid_layout(app);
+        }
+        Action::ResizeGridRows(direction) => {
+            app.results_tab.resize_grid_rows(direction);
+            save_grid_layout(app);
+        }
+        Action::Convert => {
+            let input = app.convert_tab.input.lines().to_vec();
+            handle_convert_operation(app)?;
+            app.convert_tab.refresh_aligned_preview();
+            app.convert_tab.record_history(input);
+        }
+        Action::ConvertHistory(delta) => handle_convert_history(app, delta),
+        Action::AcceptJsonRepair => handle_accept_json_repair(app),
+        Action::AcceptDelimiter => {
+            if let Some(delimiter) = app.accept_suggested_delimiter() {
+                app.results = vec![trf(Text::DelimiterSwitched, &[&delimiter.display_name()])];
+            }
+        }
+        Action::ToggleAlignedPreview => {
+            app.convert_tab.toggle_aligned_preview();
+            app.results = vec![trf(
+                Text::AlignedPreview,
+                &[&on_off(app.convert_tab.aligned_preview)],
+            )];
+        }
+        Action::SendToList(panel) => handle_send_to_list(app, panel),
+        Action::CycleJsonStrategy => {
+            app.convert_tab.json_strategy = app.convert_tab.json_strategy.next();
+            app.results = vec![trf(
+                Text::JsonStrategyChanged,
+                &[&app.convert_tab.json_strategy.display_name()],
+            )];
+        }
+        Action::ToggleFlattenJson => {
+            let convert = &mut app.convert_tab;
+            convert.nested_json = convert.nested_json.toggle();
+            app.results = vec![trf(
+                Text::NestedJsonChanged,
+                &[&convert.nested_json.display_name()],
+            )];
+        }
+        Action::SetJsonPath => {
+            let path = app.convert_tab.json_path.as_ref();
+            let text = path.map_or(String::new(), JsonPath::to_string);
+            app.prompt = Some(Prompt::new(PromptKind::JsonPath, &text));
+        }
+        Action::CycleKeyValue => {
+            app.convert_tab.key_value = KeyValueOutput::next(app.convert_tab.key_value);
+            app.results = vec![match app.convert_tab.key_value {
+                Some(output) => trf(Text::KeyValueOn, &[&output.display_name()]),
+                None => tr(Text::KeyValueOff).to_string(),
+            }];
+        }
+        Action::SetFixedWidths => {
+            // Pre-fill with the current widths, or a guess from the input
+            let widths = match &app.convert_tab.fixed_widths {
+                Some(widths) => widths.clone(),
+                None => detect_fixed_widths(app.convert_tab.input.lines()),
+            };
+            app.prompt = Some(Prompt::new(PromptKind::FixedWidths, &join_widths(&widths)));
+        }
+        Action::ToggleMarkdownTable => {
+            let convert = &mut app.convert_tab;
+            convert.markdown = !convert.markdown;
+            convert.literal = None;
+            app.results = vec![match convert.markdown {
+                true => tr(Text::MarkdownTableOn).to_string(),
+                false => tr(Text::MarkdownTableOff).to_string(),
+            }];
+        }
+        Action::CycleLiteral => {
+            let convert = &mut app.convert_tab;
+            convert.literal = LiteralTarget::next(convert.literal);
+            convert.markdown = false;
+            app.results = vec![match convert.literal {
+                Some(literal) => trf(Text::LiteralOn, &[&literal.display_name()]),
+                None => tr(Text::LiteralOff).to_string(),
+            }];
+        }
+        Action::SetItemWrap => {
+            let spec = app.convert_tab.wrap.spec();
+            app.prompt = Some(Prompt::new(PromptKind::ItemWrap, &spec));
+        }
+        Action::ToggleTableMode => {
+            app.convert_tab.toggle_table_mode();
+            app.results = vec![trf(Text::TableMode, &[&on_off(app.convert_tab.table_mode)])];
+        }
+        Action::ToggleQuoteFields => {
+            app.convert_tab.toggle_quote_fields();
+            app.results = vec![trf(
+                Text::QuoteFields,
+                &[&on_off(app.convert_tab.quote_fields)],
+            )];
+        }
+        Action::Analyze => handle_analyze(app),
+        Action::CycleAnalysisSource => {
+            app.analysis_tab.cycle_source();
+            app.results = vec![trf(
+                Text::AnalysisSourceChanged,
+                &[&tr(analysis_source_name(app.analysis_tab.source))],
+            )];
+        }
+        Action::Join => handle_join(app),
+        Action::CycleJoinType => {
+            app.merge_tab.cycle_join_type();
+            refresh_merge_settings(app);
+        }
+        Action::ShiftList1Key(delta) => {
+            app.merge_tab.shift_key(true, delta);
+            refresh_merge_settings(app);
+        }
+        Action::ShiftList2Key(delta) => {
+            app.merge_tab.shift_key(false, delta);
+            refresh_merge_settings(app);
+        }
+        Action::CycleJoinSeparator => {
+            app.merge_tab.cycle_separator();
+            refresh_merge_settings(app);
+        }
+        Action::Paste => match crate::clipboard::get_from_clipboard(app.clipboard.as_mut()) {
+            Ok(text) => handle_paste(app, text),
+            Err(e) => {
+                app.results = vec![trf(Text::ErrorPasting, &[&e])];
+            }
+        },
+        Action::Copy => {
+            // Copy active panel to clipboard (Ctrl+C on Linux, Cmd+C on macOS)
+            let Some(panel) = PanelContent::active(app) else {
+                return Ok(());
+            };
+            let text = panel.content(app);
+            match copy_text(app, tr(panel.name()), &text) {
+                Ok(_) => {
+                    if app.is_list_tab() && panel != PanelContent::Summary {
+                        app.results = vec![trf(Text::Copied, &[&tr(panel.name())])];
+                    }
+                }
+                Err(e) => {
+                    app.results = vec![trf(Text::ErrorCopying, &[&e])];
+                }
+            }
+        }
+        Action::CopySummary => handle_copy_summary(app)?,
+        Action::ExportReport => handle_export_report(app),
+        Action::Move(motion) if app.active_tab == Tab::Results => {
+            let index = move_selection(app.results_tab.selected(), motion);
+            app.results_tab.select(index);
+        }
+        Action::Move(motion) if app.active_tab == Tab::Analysis => {
+            let index = move_selection(app.analysis_tab.selected(), motion);
+            app.analysis_tab.select(index);
+        }
+        Action::Move(motion) if app.active_tab == Tab::Merge => {
+            let index = move_selection(app.merge_tab.selected(), motion);
+            app.merge_tab.select(index);
+        }
+        Action::Move(motion) => {
+            let cursor_move = match motion {
+                Motion::Left => CursorMove::Back,
+                Motion::Down => CursorMove::Down,
+                Motion::Up => CursorMove::Up,
+                Motion::Right => CursorMove::Forward,
+                Motion::WordForward => CursorMove::WordForward,
+                Motion::WordBack => CursorMove::WordBack,
+                Motion::LineStart => CursorMove::Head,
+                Motion::LineEnd => CursorMove::End,
+                Motion::Top => CursorMove::Top,
+                Motion::Bottom => CursorMove::Bottom,
+            };
+            if let Some(textarea) = app.active_textarea() {
+                textarea.move_cursor(cursor_move);
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Row selected after a vertical motion from `current` (clamped by the caller)
+fn move_selection(current: usize, motion: Motion) -> usize {
+    match motion {
+        Motion::Down => current + 1,
+        Motion::Up => current.saturating_sub(1),
+        Motion::Top => 0,
+        Motion::Bottom => usize::MAX,
+        _ => current,
+    }
+}
+
+/// Handle trim and dedup operation - replaces panel content
+fn handle_trim_dedup(app: &mut App) -> Result<(), io::Error> {
+    if !app.is_list_tab() {
+        return Ok(());
+    }
+
+    let delimiter = app.delimiter;
+    let empty_items = app.empty_items;
+    let trim_set = app.compare_options.trim_set;
+    let Some(panel) = app.active_panel_id() else {
+        app.results = vec![tr(Text::SelectList).to_string()];
+        return Ok(());
+    };
+
+    let active_text = join_lines_with_delimiter(app.textarea(panel).lines(), delimiter);
+    let items = parse_list_with(&active_text, delimiter, empty_items);
+
+    if items.is_empty() {
+        app.results = vec![tr(Text::NoItemsToProcess).to_string()];
+        return Ok(());
+    }
+
+    // Count BEFORE processing to show original stats
+    let original_total = items.len();
+    let original_unique = items.iter().collect::<std::collections::HashSet<_>>().len();
+
+    // Apply trim (including the configured trim characters) and dedup (no sorting)
+    let trimmed: Vec<String> = items
+        .iter()
+        .map(|item| trim_set.trim(item).to_string())
+        .collect();
+    let result = process_single_list(&trimmed, true, true, false, false);
+
+    // Replace panel content with processed items
+    let new_content: Vec<String> = result.items.clone();
+    app.save_undo(panel);
+    let textarea = app.textarea(panel);
+    textarea.select_all();
+    textarea.cut();
+    textarea.insert_str(new_content.join("\n"));
+
+    // Show stats in results
+    app.results = vec![trf(
+        Text::TrimDedupDone,
+        &[
+            &app.number_format.count(original_total),
+            &app.number_format.count(original_unique),
+        ],
+    )];
+
+    app.publish_active_panel_edited();
+
+    Ok(())
+}
+
+/// Remove from or keep in List 1 the items found in List 2, saving an undo snapshot first
+fn handle_filter_list1(app: &mut App, filter: ListFilter) {
+    let list1_text = join_lines_with_delimiter(app.input_tab.list1.lines(), app.delimiter);
+    let list2_text = join_lines_with_delimiter(app.input_tab.list2.lines(), app.delimiter);
+    let list1 = parse_items_with(&list1_text, app.delimiter, app.empty_items);
+    let list2 = parse_items_with(&list2_text, app.delimiter, app.empty_items);
+
+    if list1.is_empty() || list2.is_empty() {
+        app.results = vec![tr(Text::NothingToFilter).to_string()];
+        return;
+    }
+
+    let filtered = filter_by_list(&list1, &list2, filter, app.compare_options);
+    let removed = list1.len() - filtered.len();
+    let numbers = app.number_format;
+    app.results = vec![match filter {
+        ListFilter::Remove => trf(
+            Text::FilterRemoved,
+            &[&numbers.count(removed), &numbers.count(filtered.len())],
+        ),
+        ListFilter::Keep => trf(
+            Text::FilterKept,
+            &[&numbers.count(filtered.len()), &numbers.count(removed)],
+        ),
+    }];
+
+    app.save_undo(PanelId::List1);
+    let textarea = &mut app.input_tab.list1;
+    textarea.select_all();
+    textarea.cut();
+    textarea.insert_str(filtered.join("\n"));
+    app.publish(AppEvent::PanelEdited(PanelId::List1));
+}
+
+/// Replace the active list with its `item<TAB>count` histogram, saving an undo snapshot first
+fn handle_count_frequencies(app: &mut App) {
+    let Some(panel) = app.active_panel_id() else {
+        app.results = vec![tr(Text::SelectList).to_string()];
+        return;
+    };
+    let delimiter = app.panel_delimiter(panel);
+    let text = join_lines_with_delimiter(app.textarea(panel).lines(), delimiter);
+    let items = parse_list_with(&text, delimiter, app.empty_items);
+
+    if items.is_empty() {
+        app.results = vec![tr(Text::NoItemsToProcess).to_string()];
+        return;
+    }
+
+    let frequencies = count_frequencies(&items);
+    app.results = vec![trf(
+        Text::FrequenciesCounted,
+        &[
+            &app.number_format.count(items.len()),
+            &app.number_format.count(frequencies.len()),
+        ],
+    )];
+
+    app.save_undo(panel);
+    let textarea = app.textarea(panel);
+    textarea.select_all();
+    textarea.cut();
+    textarea.insert_str(frequency_lines(&frequencies).join("\n"));
+    app.publish(AppEvent::PanelEdited(panel));
+}
+
+/// Keep only the items of the active list that occur more than once, saving an undo snapshot first
+///
+/// Each duplicated item is kept once, as `item<TAB>count` when `counts` is set.
+fn handle_keep_duplicates(app: &mut App, counts: bool) {
+    let Some(panel) = app.active_panel_id() else {
+        app.results = vec![tr(Text::SelectList).to_string()];
+        return;
+    };
+    let delimiter = app.panel_delimiter(panel);
+    let text = join_lines_with_delimiter(app.textarea(panel).lines(), delimiter);
+    let items = parse_list_with(&text, delimiter, app.empty_items);
+
+    if items.is_empty() {
+        app.results = vec![tr(Text::NoItemsToProcess).to_string()];
+        return;
+    }
+
+    let duplicates = keep_duplicates(&items);
+    if duplicates.is_empty() {
+        app.results = vec![trf(
+            Text::NoDuplicates,
+            &[&app.number_format.count(items.len())],
+        )];
+        return;
+    }
+    let occurrences: usize = duplicates.iter().map(|f| f.count).sum();
+    app.results = vec![trf(
+        Text::DuplicatesKept,
+        &[
+            &app.number_format.count(duplicates.len()),
+            &app.number_format.count(occurrences),
+            &app.number_format.count(items.len() - occurrences),
+        ],
+    )];
+
+    let lines = if counts {
+        frequency_lines(&duplicates)
+    } else {
+        duplicates.into_iter().map(|f| f.item).collect()
+    };
+    app.save_undo(panel);
+    let textarea = app.textarea(panel);
+    textarea.select_all();
+    textarea.cut();
+    textarea.insert_str(lines.join("\n"));
+    app.publish(AppEvent::PanelEdited(panel));
+}
+
+/// Show how many lines of `panel` replacing `pattern` with `replacement` would change
+fn preview_replace_regex(app: &mut App, panel: PanelId, pattern: &str, replacement: &str) {
+    // The pattern was checked when the find prompt was submitted
+    let Ok(regex) = Regex::new(pattern) else {
+        return;
+    };
+    let lines = app.textarea(panel).lines();
+    let changed = count_replaced_lines(lines, &regex, replacement);
+    let total = lines.len();
+    let numbers = app.number_format;
+    app.results = vec![trf(
+        Text::ReplacePreview,
+        &[
+            &numbers.count(changed),
+            &numbers.count(total),
+            &tr(PanelContent::from(panel).name()),
+        ],
+    )];
+}
+
+/// Replace the matches of `pattern` in the lines of `panel`, saving an undo snapshot first
+fn handle_replace_regex(app: &mut App, panel: PanelId, pattern: &str, replacement: &str) {
+    let regex = match Regex::new(pattern) {
+        Ok(regex) => regex,
+        Err(err) => {
+            app.results = vec![trf(Text::InvalidRegex, &[&err])];
+            return;
+        }
+    };
+    let lines = app.textarea(panel).lines();
+    let total = lines.len();
+    let (replaced, changed) = replace_lines(lines, &regex, replacement);
+    if changed == 0 {
+        app.results = vec![trf(Text::NothingReplaced, &[&pattern])];
+        return;
+    }
+    let numbers = app.number_format;
+    app.results = vec![trf(
+        Text::RegexReplaced,
+        &[&pattern, &numbers.count(changed), &numbers.count(total)],
+    )];
+
+    app.save_undo(panel);
+    let textarea = app.textarea(panel);
+    textarea.select_all();
+    textarea.cut();
+    textarea.insert_str(replaced.join("\n"));
+    app.publish(AppEvent::PanelEdited(panel));
+}
+
+/// Keep or drop the items of `panel` matching `pattern`, saving an undo snapshot first
+fn handle_filter_regex(app: &mut App, panel: PanelId, filter: ListFilter, pattern: &str) {
+    let regex = match Regex::new(pattern) {
+        Ok(regex) => regex,
+        Err(err) => {
+            app.results = vec![trf(Text::InvalidRegex, &[&err])];
+            return;
+        }
+    };
+    let delimiter = app.panel_delimiter(panel);
+    let text = join_lines_with_delimiter(app.textarea(panel).lines(), delimiter);
+    let items = parse_list_with(&text, delimiter, app.empty_items);
+
+    if items.is_empty() {
+        app.results = vec![tr(Text::NoItemsToProcess).to_string()];
+        return;
+    }
+
+    let filtered = filter_by_regex(&items, &regex, filter);
+    let removed = items.len() - filtered.len();
+    let numbers = app.number_format;
+    app.results = vec![match filter {
+        ListFilter::Keep => trf(
+            Text::RegexKept,
+            &[
+                &numbers.count(filtered.len()),
+                &pattern,
+                &numbers.count(removed),
+            ],
+        ),
+        ListFilter::Remove => trf(
+            Text::RegexDropped,
+            &[
+                &numbers.count(removed),
+                &pattern,
+                &numbers.count(filtered.len()),
+            ],
+        ),
+    }];
+
+    app.save_undo(panel);
+    let textarea = app.textarea(panel);
+    textarea.select_all();
+    textarea.cut();
+    textarea.insert_str(filtered.join("\n"));
+    app.publish(AppEvent::PanelEdited(panel));
+}
+
+/// Remove blank items and stopwords from the active list, saving an undo snapshot first
+fn handle_remove_stopwords(app: &mut App) {
+    let Some(panel) = app.active_panel_id() else {
+        app.results = vec![tr(Text::SelectList).to_string()];
+        return;
+    };
+    let delimiter = app.panel_delimiter(panel);
+    let text = join_lines_with_delimiter(app.textarea(panel).lines(), delimiter);
+    let items = parse_list_with(&text, delimiter, app.empty_items);
+
+    if items.is_empty() {
+        app.results = vec![tr(Text::NoItemsToProcess).to_string()];
+        return;
+    }
+
+    let kept = remove_stopwords(&items, &app.stopwords);
+    app.results = vec![trf(
+        Text::StopwordsRemoved,
+        &[
+            &app.number_format.count(items.len() - kept.len()),
+            &app.number_format.count(kept.len()),
+        ],
+    )];
+
+    app.save_undo(panel);
+    let textarea = app.textarea(panel);
+    textarea.select_all();
+    textarea.cut();
+    textarea.insert_str(kept.join("\n"));
+    app.publish(AppEvent::PanelEdited(panel));
+}
+
+/// Strip log prefixes (timestamps, levels, thread ids) from the active editor's items
+fn handle_strip_log_prefixes(app: &mut App) {
+    let Some(panel) = app.active_panel_id() else {
+        app.results = vec![tr(Text::SelectList).to_string()];
+        return;
+    };
+    let presets = match LogPreset::compile_all(&app.log_presets) {
+        Ok(presets) => presets,
+        Err((name, err)) => {
+            app.results = vec![trf(Text::InvalidLogPreset, &[&name, &err])];
+            return;
+        }
+    };
+    let delimiter = app.panel_delimiter(panel);
+    let text = join_lines_with_delimiter(app.textarea(panel).lines(), delimiter);
+    let items = parse_list_with(&text, delimiter, app.empty_items);
+
+    if items.is_empty() {
+        app.results = vec![tr(Text::NoItemsToProcess).to_string()];
+        return;
+    }
+
+    let messages = strip_log_prefixes(&items, &presets);
+    let names: Vec<&str> = presets.iter().map(|preset| preset.name.as_str()).collect();
+    app.results = vec![trf(
+        Text::LogPrefixesStripped,
+        &[&app.number_format.count(messages.len()), &names.join(", ")],
+    )];
+
+    app.save_undo(panel);
+    let textarea = app.textarea(panel);
+    textarea.select_all();
+    textarea.cut();
+    textarea.insert_str(messages.join("\n"));
+    app.publish(AppEvent::PanelEdited(panel));
+}
+
+/// Handle sort ascending operation - replaces panel content
+fn handle_sort_asc(app: &mut App) -> Result<(), io::Error> {
+    if !app.is_list_tab() {
+        return Ok(());
+    }
+
+    let delimiter = app.delimiter;
+    let empty_items = app.empty_items;
+    let Some(panel) = app.active_panel_id() else {
+        app.results = vec![tr(Text::SelectList).to_string()];
+        return Ok(());
+    };
+
+    let active_text = join_lines_with_delimiter(app.textarea(panel).lines(), delimiter);
+    let items = parse_list_with(&active_text, delimiter, empty_items);
+
+    if items.is_empty() {
+        app.results = vec![tr(Text::NoItemsToSort).to_string()];
+        return Ok(());
+    }
+
+    // Apply sort ascending (no trim, no dedup)
+    let result = process_single_list(&items, false, false, true, false);
+
+    // Replace panel content with sorted items
+    let new_content: Vec<String> = result.items.clone();
+    app.save_undo(panel);
+    let textarea = app.textarea(panel);
+    textarea.select_all();
+    textarea.cut();
+    textarea.insert_str(new_content.join("\n"));
+
+    // Show stats in results
+    app.results = vec![trf(
+        Text::SortedAsc,
+        &[&app.number_format.count(items.len())],
+    )];
+
+    app.publish_active_panel_edited();
+
+    Ok(())
+}
+
+/// Handle sort descending operation - replaces panel content
+fn handle_sort_desc(app: &mut App) -> Result<(), io::Error> {
+    if !app.is_list_tab() {
+        return Ok(());
+    }
+
+    let delimiter = app.delimiter;
+    let empty_items = app.empty_items;
+    let Some(panel) = app.active_panel_id() else {
+        app.results = vec![tr(Text::SelectList).to_string()];
+        return Ok(());
+    };
+
+    let active_text = join_lines_with_delimiter(app.textarea(panel).lines(), delimiter);
+    let items = parse_list_with(&active_text, delimiter, empty_items);
+
+    if items.is_empty() {
+        app.results = vec![tr(Text::NoItemsToSort).to_string()];
+        return Ok(());
+    }
+
+    // Apply sort descending (no trim, no dedup)
+    let result = process_single_list(&items, false, false, false, true);
+
+    // Replace panel content with sorted items
+    let new_content: Vec<String> = result.items.clone();
+    app.save_undo(panel);
+    let textarea = app.textarea(panel);
+    textarea.select_all();
+    textarea.cut();
+    textarea.insert_str(new_content.join("\n"));
+
+    // Show stats in results
+    app.results = vec![trf(
+        Text::SortedDesc,
+        &[&app.number_format.count(items.len())],
+    )];
+
+    app.publish_active_panel_edited();
+
+    Ok(())
+}
+
+/// Handle compare operations
+fn handle_compare_operations(app: &mut App) -> Result<(), io::Error> {
+    if task_busy(app) {
+        return Ok(());
+    }
+    if app.needs_sample_preview() {
+        preview_sample(app);
+        return Ok(());
+    }
+    if app.live_counts.list1 + app.live_counts.list2 >= app.background_above {
+        start_comparison(app);
+    } else if run_comparison(app) {
+        // Switch to Results tab
+        app.go_to_tab(Tab::Results);
+    }
+
+    Ok(())
+}
+
+/// Dry run for huge inputs: show overlap estimated on a sample and ask for
+/// a second F12 before running the full comparison
+///
+/// Like the comparison, the sample is taken on a worker thread from
+/// `background_above` items on.
+fn preview_sample(app: &mut App) {
+    let list1_text = join_lines_with_delimiter(app.input_tab.list1.lines(), app.delimiter);
+    let list2_text = join_lines_with_delimiter(app.input_tab.list2.lines(), app.delimiter);
+    let list1 = parse_items_with(&list1_text, app.delimiter, app.empty_items);
+    let list2 = parse_items_with(&list2_text, app.delimiter, app.empty_items);
+    let total = list1.len() + list2.len();
+    let options = app.compare_options;
+    if app.live_counts.list1 + app.live_counts.list2 < app.background_above {
+        let estimate = sample_compare(&list1, &list2, options, SAMPLE_SIZE);
+        show_sample_estimate(app, estimate, total);
+        return;
+    }
+
+    let list1: Vec<String> = list1.into_iter().map(Cow::into_owned).collect();
+    let list2: Vec<String> = list2.into_iter().map(Cow::into_owned).collect();
+    app.task = Some(BackgroundTask::spawn(Text::TaskSampling, move |progress| {
+        let estimate = sample_compare_with_progress(
+            &list1,
+            &list2,
+            options,
+            SAMPLE_SIZE,
+            &mut |done, total| progress.report(done, total),
+        )?;
+        Some(TaskOutput::Sample { estimate, total })
+    }));
+}
+
+/// Show the estimate of a sampled dry run over `total` items in the INFO panel
+fn show_sample_estimate(app: &mut App, estimate: SampleEstimate, total: usize) {
+    let numbers = app.number_format;
+
+    app.results = vec![
+        trf(
+            Text::SampleEstimate,
+            &[
+                &numbers.count(estimate.rate),
+                &numbers.count(estimate.sampled),
+                &numbers.ratio(estimate.stats.first_in_second()),
+                &numbers.ratio(estimate.stats.second_in_first()),
+                &numbers.ratio(estimate.stats.overlap()),
+            ],
+        ),
+        trf(Text::SampleConfirm, &[&numbers.count(total)]),
+    ];
+    app.sample_previewed = true;
+    // The estimate is shown in the INFO panel, which only lists messages when focused
+    app.input_tab.panel = InputPanel::Info;
+}
+
+/// Compare List 1 and List 2 with the current options, returning whether results were produced
+fn run_comparison(app: &mut App) -> bool {
+    let list1_text = join_lines_with_delimiter(app.input_tab.list1.lines(), app.delimiter);
+    let list2_text = join_lines_with_delimiter(app.input_tab.list2.lines(), app.delimiter);
+    let Some((list1_items, list2_items, missing)) =
+        comparison_inputs(app, &list1_text, &list2_text)
+    else {
+        return false;
+    };
+
+    // Use current options (case sensitivity / trim) selected by the user
+    let result = compare_lists(&list1_items, &list2_items, app.compare_options)
+        .with_delimiter(app.delimiter);
+    let invalid = invalid_items(app.compare_options, &list1_items, &list2_items);
+    show_comparison(app, result, missing, invalid);
+    true
+}
+
+/// Compare List 1 and List 2 on a worker thread, showing the results when it finishes
+fn start_comparison(app: &mut App) {
+    let list1_text = join_lines_with_delimiter(app.input_tab.list1.lines(), app.delimiter);
+    let list2_text = join_lines_with_delimiter(app.input_tab.list2.lines(), app.delimiter);
+    let Some((list1_items, list2_items, missing)) =
+        comparison_inputs(app, &list1_text, &list2_text)
+    else {
+        return;
+    };
+
+    let list1: Vec<String> = list1_items.into_iter().map(Cow::into_owned).collect();
+    let list2: Vec<String> = list2_items.into_iter().map(Cow::into_owned).collect();
+    let options = app.compare_options;
+    let delimiter = app.delimiter;
+    app.task = Some(BackgroundTask::spawn(
+        Text::TaskComparing,
+        move |progress| {
+            let result =
+                compare_lists_with_progress(&list1, &list2, options, &mut |done, total| {
+                    progress.report(done, total)
+                })?;
+            Some(TaskOutput::Compare {
+                result: Box::new(result.with_delimiter(delimiter)),
+                missing,
+                invalid: invalid_items(options, &list1, &list2),
+            })
+        },
+    ));
+}
+
+/// Items of List 1 and List 2 to compare with the rows lacking the compare
+/// column, or nothing after showing why they cannot be compared
+fn comparison_inputs<'a>(
+    app: &mut App,
+    first: &'a str,
+    second: &'a str,
+) -> Option<ComparedItems<'a>> {
+    let (list1_items, list2_items, missing) = match compared_items(app, first, second) {
+        Ok(selected) => selected,
+        Err(message) => {
+            app.results = vec![message];
+            return None;
+        }
+    };
+
+    if list1_items.is_empty() && list2_items.is_empty() {
+        app.results = vec![tr(Text::BothListsEmpty).to_string()];
+        return None;
+    }
+    Some((list1_items, list2_items, missing))
+}
+
+/// Number of items the normalization preset of `options` rejects
+fn invalid_items<S: AsRef<str>>(options: CompareOptions, list1: &[S], list2: &[S]) -> usize {
+    let preset = options.normalize;
+    list1
+        .iter()
+        .chain(list2)
+        .map(AsRef::as_ref)
+        .filter(|item| !item.is_empty() && !preset.is_valid(item))
+        .count()
+}
+
+/// Store the results of a comparison and summarize them in the INFO panel
+fn show_comparison(app: &mut App, result: CompareResult, missing: usize, invalid: usize) {
+    // Store detailed results for Tab 2
+    let results_tab = &mut app.results_tab;
+    results_tab.record_separator = detect_record_separator(&result.union);
+    results_tab.set_compare_results(Some(result.clone()));
+    results_tab.stale = false;
+    results_tab.selection = [0; 4];
+    results_tab.ensure_visible_panel();
+
+    // Format summary results for Tab 1 (2 lines max)
+    let summary = trf(
+        Text::CompareSummary,
+        &[
+            &app.number_format.count(result.only_in_first.len()),
+            &app.number_format.count(result.only_in_second.len()),
+            &app.number_format.count(result.intersection.len()),
+            &app.number_format.count(result.union.len()),
+        ],
+    );
+    let ratios = trf(
+        Text::CompareRatios,
+        &[
+            &app.number_format.ratio(result.stats.first_in_second()),
+            &app.number_format.ratio(result.stats.second_in_first()),
+            &app.number_format.ratio(result.stats.overlap()),
+        ],
+    );
+    app.results = vec![summary, ratios];
+    push_missing_column(app, missing);
+    push_near_matches(app, &result);
+    if invalid > 0 {
+        app.results.push(trf(
+            Text::PresetInvalid,
+            &[
+                &app.number_format.count(invalid),
+                &app.compare_options.normalize.name(),
+            ],
+        ));
+    }
+    app.results.push(tr(Text::CompareComplete).to_string());
+}
+
+/// Whether a background task is still running, telling how to stop it if so
+fn task_busy(app: &mut App) -> bool {
+    let Some(task) = &app.task else {
+        return false;
+    };
+    app.results = vec![trf(Text::TaskBusy, &[&tr(task.name)])];
+    true
+}
+
+/// Stop the background task, leaving everything as it was before it started
+fn cancel_task(app: &mut App) {
+    if let Some(task) = app.task.take() {
+        task.cancel();
+        app.results = vec![trf(Text::TaskCancelled, &[&tr(task.name)])];
+    }
+}
+
+/// Take the progress of the background task, applying its output once it finished
+fn poll_task(app: &mut App) {
+    let Some(task) = app.task.as_mut() else {
+        return;
+    };
+    match task.poll() {
+        TaskState::Running => {}
+        TaskState::Done(output) => {
+            // The run ended while no event was being handled
+            let elapsed = task.elapsed();
+            app.task = None;
+            app.notify_finished(elapsed);
+            match output {
+                TaskOutput::Compare {
+                    result,
+                    missing,
+                    invalid,
+                } => {
+                    show_comparison(app, *result, missing, invalid);
+                    app.go_to_tab(Tab::Results);
+                }
+                TaskOutput::Sample { estimate, total } => {
+                    show_sample_estimate(app, estimate, total);
+                    app.go_to_tab(Tab::Input);
+                }
+                TaskOutput::Load { panel, path, bytes } => load_bytes_into(app, panel, path, bytes),
+            }
+        }
+        TaskState::Stopped => {
+            let name = task.name;
+            app.task = None;
+            app.results = vec![trf(Text::TaskFailed, &[&tr(name)])];
+        }
+    }
+}
+
+/// Items of List 1, items of List 2 and the rows lacking the compare column
+type ComparedItems<'a> = (Vec<Cow<'a, str>>, Vec<Cow<'a, str>>, usize);
+
+/// Items of List 1 and List 2 as compared
+///
+/// Without a compare column these are the items parsed with the list
+/// delimiter. With one, the editors are read as rows, one per line whatever
+/// the delimiter, and the column's values are the items. Also returns how
+/// many rows lacked the column, or the message to show.
+fn compared_items<'a>(
+    app: &App,
+    first: &'a str,
+    second: &'a str,
+) -> Result<ComparedItems<'a>, String> {
+    let Some(selector) = &app.compare_column else {
+        return Ok((
+            parse_items_with(first, app.delimiter, app.empty_items),
+            parse_items_with(second, app.delimiter, app.empty_items),
+            0,
+        ));
+    };
+    let column = |rows: &[String]| match extract_column(rows, selector) {
+        Ok(column) => Ok(column),
+        Err(ColumnError::NoSeparator) => Err(trf(Text::ColumnNoSeparator, &[selector])),
+        Err(ColumnError::UnknownHeader(name)) => Err(trf(Text::ColumnUnknownHeader, &[&name])),
+    };
+    let first = column(app.input_tab.list1.lines())?;
+    let second = column(app.input_tab.list2.lines())?;
+    let items = |values: Vec<String>| {
+        values
+            .into_iter()
+            .filter(|value| app.empty_items == EmptyItems::Keep || !value.is_empty())
+            .map(Cow::Owned)
+            .collect()
+    };
+    Ok((
+        items(first.values),
+        items(second.values),
+        first.missing + second.missing,
+    ))
+}
+
+/// Apply the tolerance prompt: empty for exact matching, or `0.01` / `1%`
+fn set_tolerance(app: &mut App, answer: &str) {
+    if answer.is_empty() {
+        app.set_tolerance(None);
+        app.results = vec![tr(Text::ToleranceOff).to_string()];
+        return;
+    }
+    match Tolerance::parse(answer) {
+        Some(tolerance) => {
+            app.set_tolerance(Some(tolerance));
+            app.results = vec![trf(Text::ToleranceSet, &[&tolerance])];
+        }
+        None => app.results = vec![trf(Text::InvalidTolerance, &[&answer])],
+    }
+}
+
+/// Near matches listed in the INFO panel; the rest are only counted
+const NEAR_MATCH_LINES: usize = 5;
+
+/// Add lines listing the numbers matched within the tolerance and their differences
+fn push_near_matches(app: &mut App, result: &CompareResult) {
+    let largest = result
+        .near_matches
+        .iter()
+        .max_by(|a, b| a.delta.abs().total_cmp(&b.delta.abs()));
+    let (Some(tolerance), Some(largest)) = (result.options.tolerance, largest) else {
+        return;
+    };
+    let count = app.number_format.count(result.near_matches.len());
+    app.results.push(trf(
+        Text::NearMatches,
+        &[&count, &tolerance, &largest.delta_text()],
+    ));
+    for near in result.near_matches.iter().take(NEAR_MATCH_LINES) {
+        app.results.push(format!(
+            "  {} ≈ {} (Δ {})",
+            near.first,
+            near.second,
+            near.delta_text()
+        ));
+    }
+    let more = result.near_matches.len().saturating_sub(NEAR_MATCH_LINES);
+    if more > 0 {
+        let more = app.number_format.count(more);
+        app.results.push(trf(Text::NearMatchesMore, &[&more]));
+    }
+}
+
+/// Add a line counting the rows skipped for lacking the compare column
+fn push_missing_column(app: &mut App, missing: usize) {
+    if let (Some(column), true) = (&app.compare_column, missing > 0) {
+        let line = trf(
+            Text::ColumnMissing,
+            &[&app.number_format.count(missing), column],
+        );
+        app.results.push(line);
+    }
+}
+
+/// Check every List 1 item against List 2 as reference, returning whether a report was produced
+fn run_validation(app: &mut App, mode: VerdictMode) -> bool {
+    let list1_text = join_lines_with_delimiter(app.input_tab.list1.lines(), app.delimiter);
+    let list2_text = join_lines_with_delimiter(app.input_tab.list2.lines(), app.delimiter);
+    let (items, reference, missing) = match compared_items(app, &list1_text, &list2_text) {
+        Ok(selected) => selected,
+        Err(message) => {
+            app.results = vec![message];
+            return false;
+        }
+    };
+
+    if items.is_empty() || reference.is_empty() {
+        app.results = vec![tr(Text::NothingToValidate).to_string()];
+        return false;
+    }
+
+    let report = match check_against_reference(&items, &reference, mode, app.compare_options) {
+        Ok(report) => report,
+        Err(err) => {
+            app.results = vec![trf(Text::InvalidPattern, &[&err])];
+            return false;
+        }
+    };
+
+    let numbers = app.number_format;
+    app.results = vec![trf(
+        Text::Validated,
+        &[
+            &numbers.count(items.len()),
+            &tr(verdict_mode_name(mode)),
+            &numbers.count(report.passed),
+            &numbers.count(report.failed),
+        ],
+    )];
+    push_missing_column(app, missing);
+    app.results_tab.show_verdict(report);
+    true
+}
+
+/// Compare List 1 and List 2 by key, returning whether a report was produced
+///
+/// The editors are read line by line whatever the delimiter, as keyed files are.
+/// For lockfiles List 1 is the old file, so keys missing in List 2 were removed.
+fn run_keyed_comparison(app: &mut App, preset: KeyedPreset) -> bool {
+    let report = match compare_keyed(
+        &app.input_tab.list1.lines().join("\n"),
+        &app.input_tab.list2.lines().join("\n"),
+        preset,
+    ) {
+        Ok(report) => report,
+        Err(err) => {
+            app.results = vec![trf(Text::KeyedParseError, &[&preset.display_name(), &err])];
+            return false;
+        }
+    };
+    if report.missing_in_second.is_empty()
+        && report.missing_in_first.is_empty()
+        && report.changed.is_empty()
+        && report.unchanged == 0
+    {
+        app.results = vec![trf(Text::NothingToCompareKeyed, &[&preset.display_name()])];
+        return false;
+    }
+
+    let numbers = app.number_format;
+    let summary = if preset.is_lockfile() {
+        Text::PackagesCompared
+    } else {
+        Text::KeyedCompared
+    };
+    app.results = vec![trf(
+        summary,
+        &[
+            &preset.display_name(),
+            &numbers.count(report.missing_in_second.len()),
+            &numbers.count(report.missing_in_first.len()),
+            &numbers.count(report.changed.len()),
+            &numbers.count(report.unchanged),
+        ],
+    )];
+    if let Some(totals) = &report.totals {
+        app.results.push(amount_totals_line(totals));
+    }
+    app.results_tab.show_keyed(report);
+    true
+}
+
+/// Subscriber: recompute the live item counts of the panel that changed
+fn refresh_live_counts(app: &mut App, event: AppEvent) -> Result<(), io::Error> {
+    let empty_items = app.empty_items;
+    let count = |textarea: &tui_textarea::TextArea, delimiter: Delimiter| {
+        count_items(&parse_items_with(
+            &join_lines_with_delimiter(textarea.lines(), delimiter),
+            delimiter,
+            empty_items,
+        ))
+    };
+
+    let panels: &[PanelId] = match event {
+        AppEvent::PanelEdited(panel) => &[panel],
+        AppEvent::DelimiterChanged => &[PanelId::List1, PanelId::List2, PanelId::SingleList],
+        AppEvent::CompareOptionsChanged => &[],
+    };
+    for panel in panels {
+        match panel {
+            PanelId::List1 => {
+                app.live_counts.list1 = count(&app.input_tab.list1, app.delimiter).0;
+            }
+            PanelId::List2 => {
+                app.live_counts.list2 = count(&app.input_tab.list2, app.delimiter).0;
+            }
+            PanelId::SingleList => {
+                let (total, unique) = count(&app.single_list, app.delimiter);
+                app.live_counts.single_list = total;
+                app.live_counts.single_list_unique = unique;
+            }
+            PanelId::ConvertInput => {}
+        }
+    }
+
+    Ok(())
+}
+
+/// Display name of the list the Analysis tab examines
+fn analysis_source_name(source: AnalysisSource) -> Text {
+    match source {
+        AnalysisSource::List1 => Text::List1,
+        AnalysisSource::List2 => Text::List2,
+        AnalysisSource::SingleList => Text::SingleList,
+    }
+}
+
+/// Analyze the Analysis tab's source list and render each panel's lines
+fn handle_analyze(app: &mut App) {
+    let source = app.analysis_tab.source;
+    let editor = match source {
+        AnalysisSource::List1 => &app.input_tab.list1,
+        AnalysisSource::List2 => &app.input_tab.list2,
+        AnalysisSource::SingleList => &app.single_list,
+    };
+    let text = join_lines_with_delimiter(editor.lines(), app.delimiter);
+    let items = parse_items_with(&text, app.delimiter, app.empty_items);
+    if items.is_empty() {
+        app.results = vec![trf(
+            Text::NothingToAnalyze,
+            &[&tr(analysis_source_name(source))],
+        )];
+        return;
+    }
+
+    let analysis = analyze(&items);
+    let numbers = app.number_format;
+    app.results = vec![trf(
+        Text::Analyzed,
+        &[
+            &numbers.count(analysis.stats.total),
+            &tr(analysis_source_name(source)),
+            &numbers.count(analysis.stats.duplicated),
+            &numbers.count(analysis.findings.len()),
+        ],
+    )];
+
+    let tab = &mut app.analysis_tab;
+    tab.lines = analysis_lines(&analysis, &numbers);
+    tab.item_count = Some(analysis.stats.total);
+    tab.stale = false;
+    tab.selection = [0; 4];
+}
+
+/// Lines shown in each Analysis panel, indexed by [`AnalysisPanel::index`]
+fn analysis_lines(analysis: &Analysis, numbers: &NumberFormat) -> [Vec<String>; 4] {
+    // Whole numbers without decimals, everything else with two
+    let value = |v: f64| numbers.decimal(v, if v.fract() == 0.0 { 0 } else { 2 });
+    let stats = &analysis.stats;
+
+    let mut stat_lines = vec![
+        trf(
+            Text::StatItems,
+            &[&numbers.count(stats.total), &numbers.count(stats.unique)],
+        ),
+        trf(Text::StatDuplicated, &[&numbers.count(stats.duplicated)]),
+        trf(Text::StatEmpty, &[&numbers.count(stats.empty)]),
+        trf(
+            Text::StatLength,
+            &[
+                &numbers.count(stats.min_length),
+                &numbers.count(stats.max_length),
+                &numbers.decimal(stats.mean_length, 1),
+            ],
+        ),
+    ];
+    if let Some(numeric) = stats.numeric {
+        stat_lines.push(trf(
+            Text::StatNumericRange,
+            &[
+                &value(numeric.min),
+                &value(numeric.max),
+                &value(numeric.sum),
+            ],
+        ));
+        stat_lines.push(trf(
+            Text::StatNumericCenter,
+            &[&value(numeric.mean), &value(numeric.median)],
+        ));
+    }
+
+    let frequency_line = |f: &Frequency| format!("{} × {}", numbers.count(f.count), f.item);
+    let findings = analysis
+        .findings
+        .iter()
+        .map(|finding| {
+            let issue = match finding.issue {
+                Issue::Empty => Text::IssueEmpty,
+                Issue::SurroundingWhitespace => Text::IssueWhitespace,
+                Issue::ControlCharacters => Text::IssueControl,
+                Issue::CaseVariant => Text::IssueCaseVariant,
+            };
+            trf(
+                Text::FindingLine,
+                &[
+                    &numbers.count(finding.position),
+                    &tr(issue),
+                    &finding.item.escape_debug(),
+                ],
+            )
+        })
+        .collect();
+
+    [
+        stat_lines,
+        analysis.frequencies.iter().map(frequency_line).collect(),
+        analysis.duplicates().map(frequency_line).collect(),
+        findings,
+    ]
+}
+
+/// Number of items of each list used for the Merge tab preview
+const JOIN_PREVIEW_ITEMS: usize = 200;
+
+/// Display name of a join type
+fn join_type_name(join_type: JoinType) -> Text {
+    match join_type {
+        JoinType::Inner => Text::JoinInner,
+        JoinType::Left => Text::JoinLeft,
+        JoinType::Right => Text::JoinRight,
+        JoinType::Full => Text::JoinFull,
+        JoinType::Zip => Text::JoinZip,
+    }
+}
+
+/// Items of List 1 and List 2, optionally only those on the first `limit` editor lines
+fn join_inputs(app: &App, limit: Option<usize>) -> (Vec<String>, Vec<String>) {
+    let items = |lines: &[String]| {
+        let lines = &lines[..limit.map_or(lines.len(), |n| n.min(lines.len()))];
+        parse_list_with(
+            &join_lines_with_delimiter(lines, app.delimiter),
+            app.delimiter,
+            app.empty_items,
+        )
+    };
+    (
+        items(app.input_tab.list1.lines()),
+        items(app.input_tab.list2.lines()),
+    )
+}
+
+/// Join options from the Merge tab settings, detecting the separator from List 1 when set to auto
+fn join_options(app: &App, left: &[String]) -> JoinOptions {
+    let tab = &app.merge_tab;
+    JoinOptions {
+        join_type: tab.join_type,
+        left_key: tab.left_key,
+        right_key: tab.right_key,
+        separator: tab
+            .separator
+            .or_else(|| detect_record_separator(left))
+            .unwrap_or(','),
+        compare: app.compare_options,
+    }
+}
+
+/// Separator shown in the Merge tab: the chosen one, or `auto (<detected>)`
+fn join_separator_label(app: &App) -> String {
+    let tab = &app.merge_tab;
+    match tab.separator {
+        Some(sep) => sep.escape_debug().to_string(),
+        None => {
+            let (left, _) = join_inputs(app, Some(JOIN_PREVIEW_ITEMS));
+            let detected = join_options(app, &left).separator;
+            trf(Text::JoinSeparatorAuto, &[&detected.escape_debug()])
+        }
+    }
+}
+
+/// Rebuild the Merge tab Settings panel: settings, key field names and a preview join
+fn refresh_merge_settings(app: &mut App) {
+    let (left, right) = join_inputs(app, Some(JOIN_PREVIEW_ITEMS));
+    let options = join_options(app, &left);
+    let numbers = app.number_format;
+    let key_line = |list: Text, items: &[String], column: usize| {
+        let field = items
+            .first()
+            .and_then(|first| first.split(options.separator).nth(column));
+        match field {
+            Some(field) => trf(
+                Text::JoinKeyLine,
+                &[&tr(list), &numbers.count(column + 1), &field.trim()],
+            ),
+            None => trf(
+                Text::JoinKeyMissing,
+                &[&tr(list), &numbers.count(column + 1)],
+            ),
+        }
+    };
+
+    let mut lines = vec![trf(
+        Text::JoinTypeLine,
+        &[&tr(join_type_name(options.join_type))],
+    )];
+    if options.join_type.uses_keys() {
+        lines.push(key_line(Text::List1, &left, options.left_key));
+        lines.push(key_line(Text::List2, &right, options.right_key));
+    } else {
+        lines.push(tr(Text::JoinKeysUnused).to_string());
+    }
+    lines.push(trf(Text::JoinSeparatorLine, &[&join_separator_label(app)]));
+    if !left.is_empty() || !right.is_empty() {
+        lines.push(String::new());
+        lines.push(trf(
+            Text::JoinPreview,
+            &[&numbers.count(JOIN_PREVIEW_ITEMS)],
+        ));
+        lines.extend(join_lists(&left, &right, &options).rows);
+    }
+
+    let tab = &mut app.merge_tab;
+    tab.settings = lines;
+    tab.selection[MergePanel::Settings.index()] = 0;
+}
+
+/// Join List 1 with List 2 using the Merge tab settings and show the rows in the Result panel
+fn handle_join(app: &mut App) {
+    let (left, right) = join_inputs(app, None);
+    if left.is_empty() && right.is_empty() {
+        app.results = vec![tr(Text::NothingToJoin).to_string()];
+        return;
+    }
+
+    let options = join_options(app, &left);
+    let result = join_lists(&left, &right, &options);
+    let numbers = app.number_format;
+    app.results = vec![trf(
+        Text::Joined,
+        &[
+            &tr(join_type_name(options.join_type)),
+            &numbers.count(result.rows.len()),
+            &numbers.count(result.matched),
+            &numbers.count(result.left_only),
+            &numbers.count(result.right_only),
+        ],
+    )];
+
+    let tab = &mut app.merge_tab;
+    tab.row_count = Some(result.rows.len());
+    tab.rows = result.rows;
+    tab.stale = false;
+    tab.selection[MergePanel::Result.index()] = 0;
+    tab.panel = MergePanel::Result;
+}
+
+/// Whether an event changes what a comparison of List 1 and List 2 would produce
+fn affects_comparison(event: AppEvent) -> bool {
+    match event {
+        AppEvent::PanelEdited(panel) => matches!(panel, PanelId::List1 | PanelId::List2),
+        AppEvent::DelimiterChanged | AppEvent::CompareOptionsChanged => true,
+    }
+}
+
+/// Subscriber: flag existing results as outdated when their inputs change
+fn mark_results_stale(app: &mut App, event: AppEvent) -> Result<(), io::Error> {
+    if affects_comparison(event) {
+        let results_tab = &mut app.results_tab;
+        results_tab.stale |= results_tab.compare_results.is_some();
+        results_tab.verdict_stale |= results_tab.verdict.is_some();
+        results_tab.keyed_stale |= results_tab.keyed.is_some();
+    }
+
+    Ok(())
+}
+
+/// Subscriber: ask for a fresh sampled estimate once the compared inputs change
+fn reset_sample_preview(app: &mut App, event: AppEvent) -> Result<(), io::Error> {
+    if affects_comparison(event) {
+        app.sample_previewed = false;
+    }
+
+    Ok(())
+}
+
+/// Subscriber: flag the analysis as outdated when its source list changes
+fn mark_analysis_stale(app: &mut App, event: AppEvent) -> Result<(), io::Error> {
+    let source = app.analysis_tab.source.panel_id();
+    let affected = match event {
+        AppEvent::PanelEdited(panel) => panel == source,
+        AppEvent::DelimiterChanged => true,
+        AppEvent::CompareOptionsChanged => false,
+    };
+    if affected && app.analysis_tab.item_count.is_some() {
+        app.analysis_tab.stale = true;
+    }
+
+    Ok(())
+}
+
+/// Subscriber: refresh the join preview and flag the last join as outdated when its inputs change
+fn refresh_merge_preview(app: &mut App, event: AppEvent) -> Result<(), io::Error> {
+    if affects_comparison(event) {
+        app.merge_tab.mark_stale();
+        if app.active_tab == Tab::Merge {
+            refresh_merge_settings(app);
+        }
+    }
+
+    Ok(())
+}
+
+/// Subscriber: rerun the comparison in place when auto-compare is on and its inputs change
+fn auto_compare_on_change(app: &mut App, event: AppEvent) -> Result<(), io::Error> {
+    if app.auto_compare && affects_comparison(event) {
+        run_comparison(app);
+    }
+
+    Ok(())
+}
+
+/// Files written by the app itself, never picked as comparison inputs
+const OUTPUT_PANELS: &[PanelContent] = &[
+    PanelContent::Summary,
+    PanelContent::OnlyInList1,
+    PanelContent::OnlyInList2,
+    PanelContent::Intersection,
+    PanelContent::Union,
+    PanelContent::Verdict,
+    PanelContent::MissingInList2,
+    PanelContent::MissingInList1,
+    PanelContent::ChangedValues,
+    PanelContent::ConvertInput,
+    PanelContent::ConvertOutput,
+    PanelContent::SingleList,
+    PanelContent::AnalysisStats,
+    PanelContent::Frequencies,
+    PanelContent::Duplicates,
+    PanelContent::Validation,
+    PanelContent::MergeResult,
+];
+
+/// Find the two files to compare in `dir`
+///
+/// Prefers `list1.txt` and `list2.txt`; otherwise picks the two most recently
+/// modified list-like files (`.txt`, `.csv`, `.tsv`, `.json`), older one first.
+fn detect_comparison_files(dir: &Path) -> Option<(PathBuf, PathBuf)> {
+    let (first, second) = (
+        dir.join(PanelContent::List1.filename()),
+        dir.join(PanelContent::List2.filename()),
+    );
+    if first.is_file() && second.is_file() {
+        return Some((first, second));
+    }
+
+    let mut candidates: Vec<(SystemTime, PathBuf)> = fs::read_dir(dir)
+        .ok()?
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.path())
+        .filter(|path| path.is_file())
+        .filter(|path| {
+            let ext = path.extension().and_then(|e| e.to_str()).unwrap_or("");
+            let name = path.file_name().and_then(|n| n.to_str()).unwrap_or("");
+            matches!(ext, "txt" | "csv" | "tsv" | "json")
+                && !OUTPUT_PANELS.iter().any(|panel| panel.filename() == name)
+        })
+        .filter_map(|path| Some((fs::metadata(&path).ok()?.modified().ok()?, path)))
+        .collect();
+    candidates.sort();
+
+    let newest = candidates.len().checked_sub(2)?;
+    let mut pair = candidates.drain(newest..).map(|(_, path)| path);
+    Some((pair.next()?, pair.next()?))
+}
+
+/// Load List 1 and List 2 from files in the base directory, then compare them
+fn handle_load_and_compare(app: &mut App) -> Result<(), io::Error> {
+    let dir = app.paths.work.clone();
+    let Some((first, second)) = detect_comparison_files(&dir) else {
+        app.results = vec![trf(Text::NoComparisonFiles, &[&dir.display()])];
+        return Ok(());
+    };
+    load_and_compare_files(app, &first, &second)
+}
+
+/// Load `first` into List 1 and `second` into List 2, then compare them
+fn load_and_compare_files(app: &mut App, first: &Path, second: &Path) -> Result<(), io::Error> {
+    if needs_spill(app, first, second) {
+        let dir = app.paths.work.clone();
+        return handle_external_compare(app, &dir, first, second);
+    }
+
+    if let Some(panel) = [PanelId::List1, PanelId::List2]
+        .into_iter()
+        .find(|panel| app.is_read_only(*panel))
+    {
+        let name = if panel == PanelId::List1 {
+            Text::List1
+        } else {
+            Text::List2
+        };
+        app.results = vec![trf(Text::ReadOnlyBlocked, &[&tr(name)])];
+        return Ok(());
+    }
+
+    let mut loaded = Vec::with_capacity(2);
+    for path in [first, second] {
+        match fs::read(path) {
+            Ok(bytes) => {
+                if let Some(reason) = detect_binary(&bytes) {
+                    let reason = binary_reason(app, reason);
+                    app.results = vec![trf(Text::BinaryRefused, &[&path.display(), &reason])];
+                    return Ok(());
+                }
+                let content = String::from_utf8_lossy(&bytes);
+                loaded.push((
+                    parse_list_with(&content, app.delimiter, app.empty_items),
+                    LineEnding::detect(&content),
+                ));
+            }
+            Err(err) => {
+                app.results = vec![trf(Text::FailedToLoad, &[&path.display(), &err])];
+                return Ok(());
+            }
+        }
+    }
+
+    let warning = long_items_warning(app, loaded.iter().flat_map(|(items, _)| items));
+    for (panel, (items, ending)) in [PanelId::List1, PanelId::List2].into_iter().zip(loaded) {
+        app.remember_line_ending(panel, ending);
+        app.save_undo(panel);
+        let textarea = app.textarea(panel);
+        textarea.select_all();
+        textarea.cut();
+        textarea.insert_str(items.join("\n"));
+    }
+    app.publish(AppEvent::PanelEdited(PanelId::List1));
+    app.publish(AppEvent::PanelEdited(PanelId::List2));
+    // Deliver the edits first so the fresh results are not marked stale
+    app.dispatch_events()?;
+
+    handle_compare_operations(app)?;
+    app.results.insert(
+        0,
+        trf(Text::LoadedPair, &[&first.display(), &second.display()]),
+    );
+    if let Some(warning) = warning {
+        app.results.insert(1, warning);
+    }
+
+    Ok(())
+}
+
+/// Start or stop watch mode on the files load & compare picks
+///
+/// Starting loads and compares the files right away; afterwards they are
+/// compared again whenever they change on disk.
+fn toggle_watch(app: &mut App) -> Result<(), io::Error> {
+    if app.watch.take().is_some() {
+        app.results = vec![tr(Text::WatchOff).to_string()];
+        return Ok(());
+    }
+    let dir = app.paths.work.clone();
+    let Some((first, second)) = detect_comparison_files(&dir) else {
+        app.results = vec![trf(Text::NoComparisonFiles, &[&dir.display()])];
+        return Ok(());
+    };
+    let mut watch = Watch::new(first.clone(), second.clone(), app.watch_interval);
+    load_and_compare_files(app, &first, &second)?;
+    if let Some(result) = &app.results_tab.compare_results {
+        watch.record(result);
+    }
+    app.results.insert(
+        0,
+        trf(
+            Text::WatchOn,
+            &[
+                &first.display(),
+                &second.display(),
+                &app.watch_interval.as_secs(),
+            ],
+        ),
+    );
+    app.watch = Some(watch);
+    Ok(())
+}
+
+/// Compare the watched files again if they changed since the last run
+///
+/// Waits while the user is typing or a modal is open, so edits in progress
+/// are not replaced.
+fn watch_tick(app: &mut App) -> Result<(), io::Error> {
+    if app.mode == Mode::Insert || app.has_modal() {
+        return Ok(());
+    }
+    let Some(watch) = app.watch.as_mut() else {
+        return Ok(());
+    };
+    if !watch.due(Instant::now()) {
+        return Ok(());
+    }
+    let (first, second) = (watch.first.clone(), watch.second.clone());
+    load_and_compare_files(app, &first, &second)?;
+    let changed = match (app.watch.as_mut(), &app.results_tab.compare_results) {
+        (Some(watch), Some(result)) => watch.record(result).then_some(watch.changes),
+        _ => None,
+    };
+    if let Some(changes) = changed {
+        let at = format_timestamp(SystemTime::now());
+        app.results.insert(
+            0,
+            trf(
+                Text::WatchChanged,
+                &[&at, &app.number_format.count(changes)],
+            ),
+        );
+    }
+    Ok(())
+}
+
+/// Warning for items longer than the configured `max_item_length`, if any
+fn long_items_warning<'a>(
+    app: &App,
+    items: impl IntoIterator<Item = &'a String>,
+) -> Option<String> {
+    let max_len = app.max_item_length;
+    if max_len == 0 {
+        return None;
+    }
+    let long = items
+        .into_iter()
+        .filter(|item| is_long_item(item, max_len))
+        .count();
+    (long > 0).then(|| {
+        trf(
+            Text::LongItems,
+            &[
+                &app.number_format.count(long),
+                &app.number_format.count(max_len),
+            ],
+        )
+    })
+}
+
+/// Whether either input file is too large to load into the editors
+fn needs_spill(app: &App, first: &Path, second: &Path) -> bool {
+    app.spill_above > 0
+        && !app.delimiter.is_quoted()
+        && app
+            .delimiter
+            .field_separator()
+            .is_some_and(|sep| sep.is_ascii())
+        && [first, second]
+            .iter()
+            .any(|path| fs::metadata(path).is_ok_and(|meta| meta.len() >= app.spill_above))
+}
+
+/// Compare two huge files on disk, writing each result category to its default file
+/// instead of loading the lists into the editors
+fn handle_external_compare(
+    app: &mut App,
+    dir: &Path,
+    first: &Path,
+    second: &Path,
+) -> Result<(), io::Error> {
+    let outputs = SpillOutputs {
+        only_in_first: dir.join(PanelContent::OnlyInList1.filename()),
+        only_in_second: dir.join(PanelContent::OnlyInList2.filename()),
+        intersection: dir.join(PanelContent::Intersection.filename()),
+        union: dir.join(PanelContent::Union.filename()),
+    };
+    let summary = match compare_files_external(
+        first,
+        second,
+        app.delimiter,
+        app.compare_options,
+        &outputs,
+        &SpillConfig {
+            temp_dir: app.paths.cache.clone(),
+            ..SpillConfig::default()
+        },
+    ) {
+        Ok(summary) => summary,
+        Err(err) => {
+            app.results = vec![trf(Text::SpillFailed, &[&err])];
+            return Ok(());
+        }
+    };
+
+    // Results are on disk only; drop in-memory results that no longer match
+    app.results_tab.set_compare_results(None);
+    app.results_tab.stale = false;
+    let numbers = app.number_format;
+    app.results = vec![
+        trf(Text::SpillDone, &[&first.display(), &second.display()]),
+        trf(
+            Text::CompareSummary,
+            &[
+                &numbers.count(summary.only_in_first),
+                &numbers.count(summary.only_in_second),
+                &numbers.count(summary.intersection),
+                &numbers.count(summary.union),
+            ],
+        ),
+        trf(
+            Text::CompareRatios,
+            &[
+                &numbers.ratio(summary.stats.first_in_second()),
+                &numbers.ratio(summary.stats.second_in_first()),
+                &numbers.ratio(summary.stats.overlap()),
+            ],
+        ),
+        trf(Text::SpillWritten, &[&dir.display()]),
+    ];
+    // Show the summary in the Input tab's INFO panel
+    app.go_to_tab(Tab::Input);
+    app.input_tab.panel = InputPanel::Info;
+
+    Ok(())
+}
+
+/// Copy a Markdown summary of the last comparison to the clipboard
+fn handle_copy_summary(app: &mut App) -> Result<(), io::Error> {
+    let Some(result) = &app.results_tab.compare_results else {
+        app.results = vec![tr(Text::NoComparison).to_string()];
+        return Ok(());
+    };
+
+    let summary = crate::report::summary_markdown(result, &app.number_format);
+
+    match copy_text(app, tr(Text::HistorySummary), &summary) {
+        Ok(_) => app.results = vec![tr(Text::CopiedSummary).to_string()],
+        Err(e) => app.results = vec![trf(Text::ErrorCopying, &[&e])],
+    }
+
+    Ok(())
+}
+
+/// Copy `text` to the clipboard, appending it to the clipboard history file too when that is on
+///
+/// `what` names the copied content in the history. The text is kept in the
+/// history even when the clipboard itself is unavailable.
+fn copy_text(app: &mut App, what: &str, text: &str) -> Result<(), io::Error> {
+    let history = if app.clipboard_history {
+        let path = app
+            .paths
+            .state
+            .join(crate::clipboard::CLIPBOARD_HISTORY_FILE);
+        crate::clipboard::append_history(&path, what, text, SystemTime::now())
+            .map_err(|err| io::Error::other(trf(Text::HistoryFailed, &[&path.display(), &err])))
+    } else {
+        Ok(())
+    };
+    crate::clipboard::copy_to_clipboard(app.clipboard.as_mut(), text)?;
+    history
+}
+
+/// Ask where to export a full Markdown report of the last comparison
+fn handle_export_report(app: &mut App) {
+    if app.results_tab.compare_results.is_none() {
+        app.results = vec![tr(Text::NoComparison).to_string()];
+        return;
+    }
+    app.prompt = Some(Prompt::new(PromptKind::ExportReport, DEFAULT_REPORT_FILE));
+}
+
+/// File name offered by the report prompt
+const DEFAULT_REPORT_FILE: &str = "comparison_report.md";
+
+/// Path typed in the report prompt, relative to the working directory, with
+/// the `.md` extension added when it has none
+fn report_path(app: &App, answer: &str) -> PathBuf {
+    let mut path = app.paths.work.join(answer);
+    if path.extension().is_none() {
+        path.set_extension("md");
+    }
+    path
+}
+
+/// Write the Markdown report of the last comparison to `path`
+fn export_report(app: &mut App, path: &Path) {
+    let Some(result) = &app.results_tab.compare_results else {
+        app.results = vec![tr(Text::NoComparison).to_string()];
+        return;
+    };
+
+    let report = crate::report::report_markdown(result, &app.number_format);
+    match fs::write(path, report) {
+        Ok(_) => app.results = vec![trf(Text::ExportedReport, &[&path.display()])],
+        Err(err) => app.results = vec![trf(Text::FailedToSave, &[&path.display(), &err])],
+    }
+}
+
+/// Convert input in the Convert tab using selected source/target delimiters.
+/// The source delimiter is applied to parse the input; the target delimiter is used to render and save the output.
+fn handle_convert_operation(app: &mut App) -> Result<(), io::Error> {
+    if app.active_tab != Tab::Convert {
+        return Ok(());
+    }
+
+    app.convert_tab.pending_repair = None;
+    app.convert_tab.json_summary.clear();
+    if app.convert_tab.markdown {
+        handle_convert_markdown(app);
+        return Ok(());
+    }
+    let convert = &app.convert_tab;
+    let key_value = convert.key_value;
+    if key_value.is_none()
+        && convert.fixed_widths.is_none()
+        && convert.literal.is_none()
+        && !convert.source_delimiter.is_structured()
+        && matches!(
+            convert.target_delimiter,
+            Delimiter::Json | Delimiter::JsonLines
+        )
+    {
+        handle_convert_rows_to_json(app);
+        return Ok(());
+    }
+    if key_value == Some(KeyValueOutput::Columns)
+        || (key_value.is_none() && convert.fixed_widths.is_some())
+        || (key_value.is_none() && convert.table_mode && !convert.source_delimiter.is_structured())
+    {
+        handle_convert_table(app);
+        return Ok(());
+    }
+    let json_source = key_value.is_none() && convert.source_delimiter.is_structured();
+    let target = convert.target_delimiter;
+    if json_source && matches!(target, Delimiter::Custom(_)) && target.field_separator().is_none() {
+        // JSON objects become CSV rows, which need a single-character separator
+        app.results = vec![trf(Text::FieldSeparatorTooLong, &[&target.display_name()])];
+        app.convert_tab.clear_output();
+        return Ok(());
+    }
+
+    let source_text = if json_source || key_value.is_some() {
+        // For JSON, join all lines with newline to preserve structure
+        app.convert_tab.input.lines().join("\n")
+    } else {
+        join_lines_with_delimiter(
+            app.convert_tab.input.lines(),
+            app.convert_tab.source_delimiter,
+        )
+    };
+
+    let (items, _repaired_json) = if let Some(output) = key_value {
+        // Keys or values, one item each
+        (
+            extract_key_values(&source_text, output, ',', false),
+            source_text,
+        )
+    } else if json_source {
+        let source = app.convert_tab.source_delimiter;
+        match crate::parser::parse_structured_to_list(
+            &source_text,
+            source,
+            app.convert_tab.target_delimiter.as_char(),
+            app.convert_tab.quotes_output(),
+            app.convert_tab.json_strategy,
+            app.convert_tab.nested_json,
+        ) {
+            Ok((list, repaired)) => {
+                // Preview the repaired JSON; the input only changes when accepted
+                // (JSON Lines and YAML are never repaired, they come back as JSON)
+                app.convert_tab.pending_repair = (source == Delimiter::Json
+                    && repaired.trim() != source_text.trim())
+                .then(|| repaired.clone());
+                app.convert_tab.json_summary = summarize_json_text(&repaired)
+                    .map_or_else(Vec::new, |summary| {
+                        json_summary_lines(&summary, &app.number_format)
+                    });
+                match &app.convert_tab.json_path {
+                    Some(path) => match extract_json_path(&repaired, source, path) {
+                        Ok(items) if items.is_empty() => {
+                            app.results = vec![trf(Text::JsonPathNoMatch, &[path])];
+                            app.convert_tab.clear_output();
+                            return Ok(());
+                        }
+                        Ok(items) => (items, repaired),
+                        Err(e) => {
+                            app.results = vec![trf(Text::JsonError, &[&e])];
+                            app.convert_tab.clear_output();
+                            return Ok(());
+                        }
+                    },
+                    None => (list, repaired),
+                }
+            }
+            Err(e) => {
+                let error = match source {
+                    Delimiter::Yaml => Text::YamlError,
+                    _ => Text::JsonError,
+                };
+                app.results = vec![trf(error, &[&e])];
+                app.convert_tab.clear_output();
+                return Ok(());
+            }
+        }
+    } else {
+        (
+            parse_list(&source_text, app.convert_tab.source_delimiter),
+            source_text,
+        )
+    };
+
+    if items.is_empty() {
+        app.results = vec![tr(Text::NothingToConvert).to_string()];
+        app.convert_tab.clear_output();
+        return Ok(());
+    }
+
+    // Special handling for JSON source: it already formatted CSV rows if needed,
+    // unless a path extracted plain values
+    if let Some(literal) = app.convert_tab.literal {
+        let literal = to_list_literal(&items, literal);
+        app.convert_tab.output_items = vec![literal.clone()];
+        app.convert_tab.output_serialized = literal;
+    } else if json_source && app.convert_tab.json_path.is_none() {
+        app.convert_tab.output_serialized = items.join("\n");
+        app.convert_tab.output_items = items.clone();
+    } else {
+        let target = app.convert_tab.target_delimiter;
+        let quote = app.convert_tab.quotes_output();
+        let wrap = &app.convert_tab.wrap;
+        // Quote first, so a wrap goes around the quoted field
+        let written: Vec<String> = items
+            .iter()
+            .map(|item| {
+                let item = if quote {
+                    quote_csv_field(item, target.as_char())
+                } else {
+                    Cow::Borrowed(item.as_str())
+                };
+                wrap.apply(&item).into_owned()
+            })
+            .collect();
+        app.convert_tab.output_serialized = written.join(target.as_str());
+        app.convert_tab.output_items = if target == Delimiter::Newline {
+            written
+        } else {
+            vec![app.convert_tab.output_serialized.clone()]
+        };
+    }
+
+    app.convert_tab.item_count = items.len();
+    app.convert_tab.panel = ConvertPanel::Output;
+    app.results = vec![trf(
+        Text::Converted,
+        &[
+            &app.number_format.count(items.len()),
+            &app.convert_tab.target_name(),
+        ],
+    )];
+
+    Ok(())
+}
+
+/// Rows of a delimited Convert input, split on the source delimiter
+///
+/// Rows are lines whatever the delimiter, as in table mode; a newline source
+/// splits on the first of tab, `|`, `;` or `,` found in the first row.
+/// Returns `None` without a field separator.
+fn delimited_source_rows(convert: &ConvertTab) -> Option<Vec<Vec<String>>> {
+    let lines = convert.input.lines();
+    let separator = match convert.source_delimiter {
+        Delimiter::Newline => detect_field_separator(lines),
+        delimiter => delimiter.field_separator(),
+    }?;
+    Some(delimited_rows(&lines.join("\n"), separator))
+}
+
+/// Convert delimited rows with a header into JSON objects, one per data row
+///
+/// A JSON target writes an array with one object per line, a JSON Lines
+/// target the bare objects.
+fn handle_convert_rows_to_json(app: &mut App) {
+    let Some(rows) = delimited_source_rows(&app.convert_tab).filter(|rows| !rows.is_empty()) else {
+        app.results = vec![tr(Text::JsonNeedsHeader).to_string()];
+        app.convert_tab.clear_output();
+        return;
+    };
+
+    let objects = rows_to_json_objects(&rows);
+    let lines = match app.convert_tab.target_delimiter {
+        Delimiter::Json => json_array_lines(&objects),
+        _ => objects,
+    };
+    app.convert_tab.output_serialized = lines.join("\n");
+    app.convert_tab.output_items = lines;
+    app.convert_tab.item_count = rows.len() - 1;
+    app.convert_tab.panel = ConvertPanel::Output;
+    app.results = vec![trf(
+        Text::Converted,
+        &[
+            &app.number_format.count(app.convert_tab.item_count),
+            &app.convert_tab.target_name(),
+        ],
+    )];
+}
+
+/// Convert JSON objects or delimited rows with a header into a Markdown table
+fn handle_convert_markdown(app: &mut App) {
+    let convert = &app.convert_tab;
+    let source = convert.source_delimiter;
+    let rows = if source.is_structured() {
+        let input = convert.input.lines().join("\n");
+        match structured_rows(&input, source, convert.json_strategy, convert.nested_json) {
+            Ok(rows) => rows,
+            Err(e) => {
+                let error = match source {
+                    Delimiter::Yaml => Text::YamlError,
+                    _ => Text::JsonError,
+                };
+                app.results = vec![trf(error, &[&e])];
+                app.convert_tab.clear_output();
+                return;
+            }
+        }
+    } else {
+        delimited_source_rows(convert)
+    };
+    let Some(rows) = rows.filter(|rows| !rows.is_empty()) else {
+        app.results = vec![tr(Text::MarkdownNeedsHeader).to_string()];
+        app.convert_tab.clear_output();
+        return;
+    };
+
+    let table = to_markdown_table(&rows);
+    app.convert_tab.output_serialized = table.join("\n");
+    app.convert_tab.output_items = table;
+    app.convert_tab.item_count = rows.len() - 1;
+    app.convert_tab.panel = ConvertPanel::Output;
+    app.results = vec![trf(
+        Text::Converted,
+        &[
+            &app.number_format.count(app.convert_tab.item_count),
+            &app.convert_tab.target_name(),
+        ],
+    )];
+}
+
+/// INFO lines describing the records and fields of JSON input
+fn json_summary_lines(summary: &JsonSummary, numbers: &NumberFormat) -> Vec<String> {
+    let header = trf(
+        Text::JsonSummary,
+        &[
+            &numbers.count(summary.records),
+            &numbers.count(summary.fields.len()),
+            &summary.depth,
+        ],
+    );
+    std::iter::once(header)
+        .chain(
+            summary
+                .fields
+                .iter()
+                .map(|field| format!("  {}", field.describe())),
+        )
+        .collect()
+}
+
+/// Replace the Convert input with the previewed JSON repair
+fn handle_accept_json_repair(app: &mut App) {
+    if app.is_read_only(PanelId::ConvertInput) {
+        app.results = vec![trf(
+            Text::ReadOnlyBlocked,
+            &[&tr(PanelContent::ConvertInput.name())],
+        )];
+        return;
+    }
+    let Some(repaired) = app.convert_tab.pending_repair.take() else {
+        return;
+    };
+    app.save_undo(PanelId::ConvertInput);
+    app.convert_tab.input = tui_textarea::TextArea::from(repaired.lines().map(String::from));
+    app.publish(AppEvent::PanelEdited(PanelId::ConvertInput));
+    app.results = vec![tr(Text::JsonRepairApplied).to_string()];
+}
+
+/// Restore an older (`delta < 0`) or newer conversion from the converter history
+///
+/// An undo snapshot of the input is saved first, so edits made since the last
+/// conversion can be recovered with `u`.
+fn handle_convert_history(app: &mut App, delta: isize) {
+    if app.is_read_only(PanelId::ConvertInput) {
+        app.results = vec![trf(
+            Text::ReadOnlyBlocked,
+            &[&tr(PanelContent::ConvertInput.name())],
+        )];
+        return;
+    }
+    let Some(snapshot) = app.convert_tab.step_history(delta).cloned() else {
+        app.results = vec![tr(if delta < 0 {
+            Text::NoOlderConversion
+        } else {
+            Text::NoNewerConversion
+        })
+        .to_string()];
+        return;
+    };
+
+    app.save_undo(PanelId::ConvertInput);
+    let convert = &mut app.convert_tab;
+    convert.input = tui_textarea::TextArea::from(snapshot.input);
+    convert.output_items = snapshot.output_items;
+    convert.output_serialized = snapshot.output_serialized;
+    convert.item_count = snapshot.item_count;
+    convert.pending_repair = None;
+    convert.refresh_aligned_preview();
+    app.publish(AppEvent::PanelEdited(PanelId::ConvertInput));
+    app.results = vec![trf(
+        Text::ConversionRestored,
+        &[
+            &(app.convert_tab.history_pos + 1),
+            &app.convert_tab.history.len(),
+        ],
+    )];
+}
+
+/// Replace List 1 or List 2 with the converter output and switch to the Input tab
+///
+/// An undo snapshot of the list is saved first.
+fn handle_send_to_list(app: &mut App, panel: PanelId) {
+    let name = PanelContent::from(panel).name();
+    if app.convert_tab.output_items.is_empty() {
+        app.results = vec![tr(Text::NothingToSend).to_string()];
+        return;
+    }
+    if app.is_read_only(panel) {
+        app.results = vec![trf(Text::ReadOnlyBlocked, &[&tr(name)])];
+        return;
+    }
+
+    app.save_undo(panel);
+    let text = app.convert_tab.output_serialized.clone();
+    let textarea = app.textarea(panel);
+    textarea.select_all();
+    textarea.cut();
+    textarea.insert_str(text);
+    app.publish(AppEvent::PanelEdited(panel));
+
+    app.go_to_tab(Tab::Input);
+    app.input_tab.panel = if panel == PanelId::List1 {
+        InputPanel::List1
+    } else {
+        InputPanel::List2
+    };
+    app.results = vec![trf(
+        Text::SentToList,
+        &[
+            &app.number_format.count(app.convert_tab.item_count),
+            &tr(name),
+        ],
+    )];
+}
+
+/// Convert the Convert input row by row (key-value columns, fixed-width source or table mode)
+///
+/// Key-value records become one row per record and fixed-width rows are
+/// sliced by the column widths. Otherwise a newline source has no field
+/// separator of its own, so one shared by every row is detected instead.
+fn handle_convert_table(app: &mut App) {
+    let convert = &app.convert_tab;
+    if matches!(
+        convert.target_delimiter,
+        Delimiter::Newline | Delimiter::Json | Delimiter::JsonLines | Delimiter::Yaml
+    ) {
+        app.results = vec![tr(Text::TableNeedsFieldTarget).to_string()];
+        app.convert_tab.clear_output();
+        return;
+    }
+    let Some(target) = convert.target_delimiter.field_separator() else {
+        app.results = vec![trf(
+            Text::FieldSeparatorTooLong,
+            &[&convert.target_delimiter.display_name()],
+        )];
+        app.convert_tab.clear_output();
+        return;
+    };
+
+    let input = convert.input.lines().join("\n");
+    let rows = if convert.key_value.is_some() {
+        extract_key_values(
+            &input,
+            KeyValueOutput::Columns,
+            target,
+            convert.quotes_output(),
+        )
+    } else if let Some(widths) = &convert.fixed_widths {
+        convert_fixed_width(&input, widths, target, convert.quotes_output())
+    } else {
+        let source = match convert.source_delimiter {
+            Delimiter::Newline => match detect_record_separator(convert.input.lines()) {
+                Some(sep) => sep,
+                None => {
+                    app.results = vec![tr(Text::NoFieldSeparator).to_string()];
+                    app.convert_tab.clear_output();
+                    return;
+                }
+            },
+            delimiter => match delimiter.field_separator() {
+                Some(sep) => sep,
+                None => {
+                    app.results = vec![trf(
+                        Text::FieldSeparatorTooLong,
+                        &[&delimiter.display_name()],
+                    )];
+                    app.convert_tab.clear_output();
+                    return;
+                }
+            },
+        };
+        convert_table(&input, source, target, convert.quotes_output())
+    };
+    if rows.is_empty() {
+        app.results = vec![tr(Text::NothingToConvert).to_string()];
+        app.convert_tab.clear_output();
+        return;
+    }
+
+    app.convert_tab.output_serialized = rows.join("\n");
+    app.convert_tab.item_count = rows.len();
+    app.convert_tab.output_items = rows;
+    app.convert_tab.panel = ConvertPanel::Output;
+    app.results = vec![trf(
+        Text::ConvertedTable,
+        &[
+            &app.number_format.count(app.convert_tab.item_count),
+            &app.convert_tab.target_delimiter.display_name(),
+        ],
+    )];
+}
+
+/// Environment details shown in the diagnostics modal
+fn collect_diagnostics(app: &App) -> Vec<(Text, String)> {
+    let env_or_unset = |var: &str| {
+        env::var(var)
+            .ok()
+            .filter(|v| !v.is_empty())
+            .unwrap_or_else(|| tr(Text::DiagUnset).to_string())
+    };
+
+    let size = crossterm::terminal::size()
+        .map(|(w, h)| format!("{}x{}", w, h))
+        .unwrap_or_else(|_| tr(Text::DiagUnknown).to_string());
+    let keyboard = match crossterm::terminal::supports_keyboard_enhancement() {
+        Ok(true) => tr(Text::DiagYes),
+        Ok(false) => tr(Text::DiagNo),
+        Err(_) => tr(Text::DiagUnknown),
+    };
+
+    let mut config_vars: Vec<String> = env::vars()
+        .filter(|(name, _)| name.starts_with("LIST_UTILS_"))
+        .map(|(name, value)| format!("{}={}", name, value))
+        .collect();
+    config_vars.sort();
+    let config = if config_vars.is_empty() {
+        tr(Text::DiagUnset).to_string()
+    } else {
+        config_vars.join(", ")
+    };
+
+    vec![
+        (Text::DiagTerminalSize, size),
+        (Text::DiagTerm, env_or_unset("TERM")),
+        (Text::DiagColorTerm, env_or_unset("COLORTERM")),
+        (Text::DiagTermProgram, env_or_unset("TERM_PROGRAM")),
+        (Text::DiagKeyboardEnhancement, keyboard.to_string()),
+        (
+            Text::DiagClipboard,
+            crate::clipboard::backend_description(app.clipboard.as_ref()),
+        ),
+        (Text::DiagLanguage, format!("{:?}", crate::i18n::lang())),
+        (
+            Text::DiagParallel,
+            if cfg!(feature = "parallel") {
+                trf(
+                    Text::DiagParallelOn,
+                    &[&app
+                        .number_format
+                        .count(crate::operations::parallel::PARALLEL_THRESHOLD)],
+                )
+            } else {
+                tr(Text::DiagNo).to_string()
+            },
+        ),
+        (Text::DiagWorkDir, app.paths.work.display().to_string()),
+        (Text::DiagDataDir, app.paths.data.display().to_string()),
+        (Text::DiagCacheDir, app.paths.cache.display().to_string()),
+        (Text::DiagStateDir, app.paths.state.display().to_string()),
+        (
+            Text::DiagClipboardHistory,
+            if app.clipboard_history {
+                app.paths
+                    .state
+                    .join(crate::clipboard::CLIPBOARD_HISTORY_FILE)
+                    .display()
+                    .to_string()
+            } else {
+                tr(Text::DiagNo).to_string()
+            },
+        ),
+        (Text::DiagConfig, trf(Text::DiagConfigEnv, &[&config])),
+        (
+            Text::DiagConfigFile,
+            Config::path()
+                .map(|path| path.display().to_string())
+                .unwrap_or_else(|| tr(Text::DiagUnset).to_string()),
+        ),
+    ]
+}
+
+/// Persist the grid layout and report the new split ratios
+fn save_grid_layout(app: &mut App) {
+    // Start from the file so hand-edited settings such as the stopwords survive
+    let config = Config {
+        grid: app.results_tab.grid_layout,
+        ..Config::load()
+    };
+    let grid = app.results_tab.grid_layout;
+    app.results = vec![trf(
+        Text::GridLayoutStatus,
+        &[&grid.rows, &grid.top, &grid.bottom],
+    )];
+    if let Err(e) = config.save() {
+        app.results
+            .push(trf(Text::FailedToSave, &[&tr(Text::DiagConfigFile), &e]));
+    }
+}
+
+/// Item count of both lists plus approximate memory usage, for the status bar
+fn usage_label(app: &App) -> String {
+    let items = app.live_counts.list1 + app.live_counts.list2;
+    trf(
+        Text::StatusUsage,
+        &[
+            &app.number_format.count(items),
+            &app.number_format.bytes(app.approximate_memory()),
+        ],
+    )
+}
+
+fn active_panel_label(app: &App) -> Option<String> {
+    let label = match app.active_tab {
+        Tab::Input => match app.input_tab.panel {
+            InputPanel::List1 => tr(Text::List1).to_string(),
+            InputPanel::List2 => tr(Text::List2).to_string(),
+            InputPanel::Info => tr(Text::Results).to_string(),
+        },
+        Tab::List => tr(Text::SingleList).to_string(),
+        Tab::Analysis => {
+            let content = PanelContent::from(app.analysis_tab.panel);
+            trf(
+                Text::ItemsCount,
+                &[
+                    &tr(content.name()),
+                    &app.number_format.count(content.item_count(app)),
+                ],
+            )
+        }
+        Tab::Merge => {
+            let name = match app.merge_tab.panel {
+                MergePanel::Settings => Text::MergeSettings,
+                MergePanel::Result => Text::MergeResult,
+            };
+            trf(
+                Text::ItemsCount,
+                &[
+                    &tr(name),
+                    &app.number_format
+                        .count(app.merge_tab.lines(app.merge_tab.panel).len()),
+                ],
+            )
+        }
+        Tab::Convert => match app.convert_tab.panel {
+            ConvertPanel::Input => trf(
+                Text::ConvertInputLabel,
+                &[&app.convert_tab.source_delimiter.display_name()],
+            ),
+            ConvertPanel::Output => trf(
+                Text::ConvertOutputLabel,
+                &[
+                    &app.convert_tab.target_delimiter.display_name(),
+                    &app.number_format.count(app.convert_tab.output_items.len()),
+                ],
+            ),
+        },
+        Tab::Results => {
+            let panel = app.results_tab.panel;
+            let content = PanelContent::active(app).unwrap_or(panel.into());
+            let title = trf(
+                Text::ItemsCount,
+                &[
+                    &tr(content.name()),
+                    &app.number_format.count(content.item_count(app)),
+                ],
+            );
+            match result_position_label(app, panel) {
+                Some(position) => format!("{} · {}", title, position),
+                None => title,
+            }
+        }
+    };
+    Some(label)
+}
 
-    Ok(())
+/// Join lines using the given delimiter so parsing respects the selected separator.
+fn join_lines_with_delimiter(lines: &[String], delimiter: Delimiter) -> String {
+    lines.join(delimiter.as_str())
 }
 
-/// Handle sort ascending operation - replaces panel content
-fn handle_sort_asc(app: &mut App) -> Result<(), io::Error> {
-    if app.active_tab != 0 {
+/// Load content from a file into the active editable panel (List 1/2, Convert Input or List)
+fn handle_load_from_file(app: &mut App) -> Result<(), io::Error> {
+    if !app.is_editable_panel() {
+        app.results = vec![tr(Text::SelectLoadable).to_string()];
         return Ok(());
     }
 
-    let delimiter = app.delimiter;
-    let Some(textarea) = app.active_textarea() else {
-        app.results = vec!["Please select List 1 or List 2".to_string()];
+    let Some(path) = PanelContent::active(app).map(|panel| app.paths.work.join(panel.filename()))
+    else {
+        app.results = vec![tr(Text::NoTargetFile).to_string()];
         return Ok(());
     };
 
-    let active_text = join_lines_with_delimiter(textarea.lines(), delimiter);
-    let items = parse_list(&active_text, delimiter);
-
-    if items.is_empty() {
-        app.results = vec!["No items to sort".to_string()];
+    let Some(panel) = app.active_panel_id() else {
+        app.results = vec![tr(Text::NoActivePanel).to_string()];
+        return Ok(());
+    };
+    if task_busy(app) {
         return Ok(());
     }
 
-    // Apply sort ascending (no trim, no dedup)
-    let result = process_single_list(&items, false, false, true, false);
+    // Large files are read on a worker thread, showing their progress
+    let size = fs::metadata(&path).map_or(0, |meta| meta.len());
+    if size >= BACKGROUND_LOAD_BYTES {
+        app.task = Some(BackgroundTask::spawn(Text::TaskLoading, move |progress| {
+            let bytes = read_with_progress(&path, size, progress)?;
+            Some(TaskOutput::Load { panel, path, bytes })
+        }));
+    } else {
+        let bytes = fs::read(&path);
+        load_bytes_into(app, panel, path, bytes);
+    }
 
-    // Replace panel content with sorted items
-    let new_content: Vec<String> = result.items.clone();
+    Ok(())
+}
+
+/// Load a file's bytes into an editor, holding binary data back for the binary picker
+fn load_bytes_into(app: &mut App, panel: PanelId, path: PathBuf, bytes: io::Result<Vec<u8>>) {
+    match bytes {
+        Ok(bytes) => match detect_binary(&bytes) {
+            // Binary data waits for the user to pick a view or abort
+            Some(reason) => {
+                app.binary_picker = Some(BinaryPicker {
+                    origin: BinaryOrigin::File(path),
+                    bytes,
+                    reason,
+                    selected: 0,
+                    target: panel,
+                });
+            }
+            None => load_text_into(app, panel, &path, &String::from_utf8_lossy(&bytes)),
+        },
+        Err(err) => {
+            app.results = vec![trf(Text::FailedToLoad, &[&path.display(), &err])];
+        }
+    }
+}
+
+/// Replace an editor's content with the items of a file's text
+fn load_text_into(app: &mut App, panel: PanelId, path: &Path, content: &str) {
+    let detected = app.detect_panel_delimiter(panel, content);
+    let items = parse_list_with(content, app.panel_delimiter(panel), app.empty_items);
+    app.remember_line_ending(panel, LineEnding::detect(content));
+    app.save_undo(panel);
+    let textarea = app.textarea(panel);
     textarea.select_all();
     textarea.cut();
-    textarea.insert_str(&new_content.join("\n"));
-
-    // Show stats in results
-    app.results = vec![format!("Sorted ↑ {} items", items.len())];
+    textarea.insert_str(items.join("\n"));
 
-    Ok(())
+    app.publish(AppEvent::PanelEdited(panel));
+    app.results = vec![trf(
+        Text::Loaded,
+        &[&app.number_format.count(items.len()), &path.display()],
+    )];
+    // After the mismatch check, so an offered delimiter is the one detected
+    let mismatch = delimiter_mismatch(app, panel, content);
+    let detected = detected.map(|detected| delimiter_detected(app, panel, detected));
+    app.results.extend(detected);
+    app.results.extend(mismatch);
+    app.results.extend(long_items_warning(app, &items));
+    if panel == PanelId::ConvertInput {
+        app.convert_tab.clear_output();
+    }
 }
 
-/// Handle sort descending operation - replaces panel content
-fn handle_sort_desc(app: &mut App) -> Result<(), io::Error> {
-    if app.active_tab != 0 {
-        return Ok(());
+/// Insert pasted text at the cursor, holding binary data back for the binary picker
+///
+/// With trim on paste, items are trimmed and trailing delimiters dropped first.
+fn handle_paste(app: &mut App, text: String) {
+    let Some(panel) = app.active_panel_id() else {
+        return;
+    };
+    if let Some(reason) = detect_binary(text.as_bytes()) {
+        app.binary_picker = Some(BinaryPicker {
+            origin: BinaryOrigin::Paste,
+            bytes: text.into_bytes(),
+            reason,
+            selected: 0,
+            target: panel,
+        });
+        return;
     }
-
-    let delimiter = app.delimiter;
-    let Some(textarea) = app.active_textarea() else {
-        app.results = vec!["Please select List 1 or List 2".to_string()];
-        return Ok(());
+    let detected = app.detect_panel_delimiter(panel, &text);
+    let delimiter = app.panel_delimiter(panel);
+    let text = if app.trim_on_paste {
+        trim_pasted(&text, delimiter)
+    } else {
+        text
     };
+    // A paste may replace a selection or a whole list, so it can be undone as one change
+    app.save_undo(panel);
+    app.textarea(panel).insert_str(&text);
+    app.publish(AppEvent::PanelEdited(panel));
+    let items = parse_list_with(&text, delimiter, app.empty_items);
+    let mismatch = delimiter_mismatch(app, panel, &text);
+    let messages: Vec<String> = detected
+        .map(|detected| delimiter_detected(app, panel, detected))
+        .into_iter()
+        .chain(mismatch)
+        .chain(long_items_warning(app, &items))
+        .collect();
+    if !messages.is_empty() {
+        app.results = messages;
+    }
+}
 
-    let active_text = join_lines_with_delimiter(textarea.lines(), delimiter);
-    let items = parse_list(&active_text, delimiter);
+/// Warning offering another delimiter when loaded or pasted text is a single item
+fn delimiter_mismatch(app: &mut App, panel: PanelId, text: &str) -> Option<String> {
+    let (delimiter, count) = app.suggest_panel_delimiter(panel, text)?;
+    Some(trf(
+        Text::DelimiterMismatch,
+        &[&delimiter.display_name(), &app.number_format.count(count)],
+    ))
+}
 
-    if items.is_empty() {
-        app.results = vec!["No items to sort".to_string()];
-        return Ok(());
+/// INFO line naming a detected delimiter and the key that cycles it, or that
+/// switches to it when it is only offered
+fn delimiter_detected(app: &mut App, panel: PanelId, detection: DelimiterDetection) -> String {
+    match detection {
+        DelimiterDetection::Applied(delimiter) => {
+            let key = if panel == PanelId::ConvertInput {
+                "F10"
+            } else {
+                "F5"
+            };
+            trf(Text::DelimiterDetected, &[&delimiter.display_name(), &key])
+        }
+        DelimiterDetection::Suggested(delimiter) => {
+            app.suggested_delimiter = Some(delimiter);
+            trf(
+                Text::DelimiterSuggested,
+                &[&delimiter.display_name(), &app.delimiter.display_name()],
+            )
+        }
     }
+}
 
-    // Apply sort descending (no trim, no dedup)
-    let result = process_single_list(&items, false, false, false, true);
+/// Description of why input was taken for binary data
+fn binary_reason(app: &App, reason: BinaryReason) -> String {
+    match reason {
+        BinaryReason::NulByte(offset) => {
+            trf(Text::BinaryNulByte, &[&app.number_format.count(offset)])
+        }
+        BinaryReason::InvalidUtf8(offset) => {
+            trf(Text::BinaryInvalidUtf8, &[&app.number_format.count(offset)])
+        }
+        BinaryReason::ControlCharacters(count) => {
+            trf(Text::BinaryControlChars, &[&app.number_format.count(count)])
+        }
+    }
+}
 
-    // Replace panel content with sorted items
-    let new_content: Vec<String> = result.items.clone();
-    textarea.select_all();
-    textarea.cut();
-    textarea.insert_str(&new_content.join("\n"));
+/// Keys while the binary picker is open: move, load the highlighted view or abort
+fn handle_binary_picker_key(app: &mut App, key_event: KeyEvent) {
+    let Some(picker) = app.binary_picker.as_mut() else {
+        return;
+    };
+    match key_event.code {
+        KeyCode::Esc | KeyCode::Char('q') => {
+            if let Some(picker) = app.binary_picker.take() {
+                abort_binary(app, &picker.origin);
+            }
+        }
+        KeyCode::Down | KeyCode::Char('j') => picker.step(1),
+        KeyCode::Up | KeyCode::Char('k') => picker.step(-1),
+        KeyCode::Enter => {
+            if let Some(picker) = app.binary_picker.take() {
+                load_binary(app, picker);
+            }
+        }
+        _ => {}
+    }
+}
 
-    // Show stats in results
-    app.results = vec![format!("Sorted ↓ {} items", items.len())];
+/// Name of where binary input came from, as shown to the user
+fn binary_origin_label(origin: &BinaryOrigin) -> String {
+    match origin {
+        BinaryOrigin::File(path) => path.display().to_string(),
+        BinaryOrigin::Paste => tr(Text::Clipboard).to_string(),
+    }
+}
 
-    Ok(())
+/// Leave the target editor untouched
+fn abort_binary(app: &mut App, origin: &BinaryOrigin) {
+    app.results = vec![trf(Text::BinaryAborted, &[&binary_origin_label(origin)])];
 }
 
-/// Handle compare operations
-fn handle_compare_operations(app: &mut App) -> Result<(), io::Error> {
-    let list1_text = join_lines_with_delimiter(app.list1.lines(), app.delimiter);
-    let list2_text = join_lines_with_delimiter(app.list2.lines(), app.delimiter);
+/// Load binary input with the view chosen in the picker
+fn load_binary(app: &mut App, picker: BinaryPicker) {
+    let Some(view) = picker.choice() else {
+        abort_binary(app, &picker.origin);
+        return;
+    };
+    let panel = picker.target;
+    let label = binary_origin_label(&picker.origin);
+    let text = match view {
+        BinaryView::Hex => hex_dump(&picker.bytes).join("\n"),
+        BinaryView::Lossy => lossy_text(&picker.bytes),
+    };
+    app.results.clear();
+    match (&picker.origin, view) {
+        (BinaryOrigin::File(path), BinaryView::Lossy) => {
+            load_text_into(app, panel, path, &text);
+        }
+        (BinaryOrigin::File(_), BinaryView::Hex) => {
+            let textarea = app.textarea(panel);
+            textarea.select_all();
+            textarea.cut();
+            textarea.insert_str(&text);
+            app.publish(AppEvent::PanelEdited(panel));
+            if panel == PanelId::ConvertInput {
+                app.convert_tab.clear_output();
+            }
+        }
+        (BinaryOrigin::Paste, _) => {
+            app.textarea(panel).insert_str(&text);
+            app.publish(AppEvent::PanelEdited(panel));
+        }
+    }
+    let shown = picker.bytes.len().min(HEX_VIEW_LIMIT);
+    let message = match view {
+        BinaryView::Hex => trf(
+            Text::BinaryLoadedHex,
+            &[&label, &app.number_format.count(shown)],
+        ),
+        BinaryView::Lossy => trf(Text::BinaryLoadedLossy, &[&label]),
+    };
+    app.results.insert(0, message);
+}
 
-    let list1_items = parse_list(&list1_text, app.delimiter);
-    let list2_items = parse_list(&list2_text, app.delimiter);
+/// Save the active panel content to a file
+fn handle_save_to_file(app: &mut App) -> Result<(), io::Error> {
+    let Some(panel) = PanelContent::active(app) else {
+        app.results = vec![tr(Text::NoTargetFile).to_string()];
+        return Ok(());
+    };
+    let path = app.paths.work.join(panel.filename());
+    let panel_name = tr(panel.name());
 
-    if list1_items.is_empty() && list2_items.is_empty() {
-        app.results = vec!["Both lists are empty".to_string()];
+    let text = panel.content(app);
+    if text.is_empty() {
+        app.results = vec![trf(Text::NothingToSave, &[&panel_name])];
         return Ok(());
     }
 
-    // Use current options (case sensitivity / trim) selected by the user
-    let result = compare_lists(&list1_items, &list2_items, app.compare_options);
-
-    // Store detailed results for Tab 2
-    app.compare_results = Some(result.clone());
-
-    // Format summary results for Tab 1 (2 lines max)
-    let summary = format!(
-        "Only L1: {} | Only L2: {} | Inter: {} | Union: {}",
-        result.only_in_first.len(),
-        result.only_in_second.len(),
-        result.intersection.len(),
-        result.union.len()
-    );
-    app.results = vec![
-        summary,
-        "Compare complete. Details available in Results tab.".to_string(),
-    ];
-
-    // Switch to Results tab
-    app.go_to_tab(1);
+    let text = app.line_ending_for(panel.panel_id()).apply(&text);
+    match fs::write(&path, app.save_encoding.encode(&text)) {
+        Ok(_) => {
+            app.results = vec![trf(Text::Saved, &[&panel_name, &path.display()])];
+        }
+        Err(err) => {
+            app.results = vec![trf(Text::FailedToSave, &[&path.display(), &err])];
+        }
+    }
 
     Ok(())
 }
 
-/// Convert input in the Convert tab using selected source/target delimiters.
-/// The source delimiter is applied to parse the input; the target delimiter is used to render and save the output.
-fn handle_convert_operation(app: &mut App) -> Result<(), io::Error> {
-    if app.active_tab != 2 {
-        return Ok(());
+/// Open the reference list picker for the active editor
+fn handle_open_references(app: &mut App) {
+    let Some(target) = app.active_panel_id() else {
+        return;
+    };
+    let dir = references::library_dir(&app.paths);
+    match references::reference_names(&dir) {
+        Ok(names) if names.is_empty() => {
+            app.results = vec![trf(Text::NoReferences, &[&dir.display()])];
+        }
+        Ok(names) => {
+            app.reference_picker = Some(ReferencePicker {
+                names,
+                selected: 0,
+                target,
+            });
+        }
+        Err(err) => {
+            app.results = vec![trf(Text::FailedToLoad, &[&dir.display(), &err])];
+        }
     }
+}
 
-    let source_text = if app.convert_source_delimiter == Delimiter::Json {
-        // For JSON, join all lines with newline to preserve structure
-        app.convert_input.lines().join("\n")
-    } else {
-        join_lines_with_delimiter(app.convert_input.lines(), app.convert_source_delimiter)
+/// Keys while the reference picker is open: move, load the highlighted list or close
+fn handle_picker_key(app: &mut App, key_event: KeyEvent) {
+    let Some(picker) = app.reference_picker.as_mut() else {
+        return;
     };
-
-    let (items, _repaired_json) = if app.convert_source_delimiter == Delimiter::Json {
-        match crate::parser::parse_json_to_list(
-            &source_text,
-            app.convert_target_delimiter.as_char(),
-        ) {
-            Ok((list, repaired)) => {
-                // Update the input area with the (possibly repaired) JSON
-                // so the user can see the quotes if they were added
-                app.convert_input =
-                    tui_textarea::TextArea::from(repaired.lines().map(String::from));
-                (list, repaired)
-            }
-            Err(e) => {
-                app.results = vec![format!("JSON Error: {}", e)];
-                app.convert_output_items.clear();
-                app.convert_output_serialized.clear();
-                return Ok(());
+    match key_event.code {
+        KeyCode::Esc | KeyCode::Char('q') => app.reference_picker = None,
+        KeyCode::Down | KeyCode::Char('j') => picker.step(1),
+        KeyCode::Up | KeyCode::Char('k') => picker.step(-1),
+        KeyCode::Char('g') => picker.selected = 0,
+        KeyCode::Char('G') => picker.step(isize::MAX),
+        KeyCode::Enter => {
+            if let Some(picker) = app.reference_picker.take() {
+                if let Some(name) = picker.selected_name() {
+                    load_reference_into(app, name, picker.target);
+                }
             }
         }
-    } else {
-        (
-            parse_list(&source_text, app.convert_source_delimiter),
-            source_text,
-        )
-    };
-
-    if items.is_empty() {
-        app.results = vec!["Nothing to convert".to_string()];
-        app.convert_output_items.clear();
-        app.convert_output_serialized.clear();
-        return Ok(());
+        _ => {}
     }
+}
 
-    // Special handling for JSON source: it already formatted CSV rows if needed
-    if app.convert_source_delimiter == Delimiter::Json {
-        app.convert_output_serialized = items.join("\n");
-        app.convert_output_items = items.clone();
-    } else {
-        let target_sep = app.convert_target_delimiter.as_char().to_string();
-        app.convert_output_serialized = items.join(&target_sep);
-        app.convert_output_items = if app.convert_target_delimiter == Delimiter::Newline {
-            items.clone()
-        } else {
-            vec![app.convert_output_serialized.clone()]
-        };
-    }
+/// Replace an editor's content with the reference list `name`, one item per line
+fn load_reference_into(app: &mut App, name: &str, panel: PanelId) {
+    let dir = references::library_dir(&app.paths);
+    let path = references::reference_path(&dir, name);
+    let content = match references::load_reference(&dir, name) {
+        Ok(content) => content,
+        Err(err) => {
+            app.results = vec![trf(Text::FailedToLoad, &[&path.display(), &err])];
+            return;
+        }
+    };
 
-    app.active_panel = 1; // focus output
-    app.results = vec![format!(
-        "Converted {} item(s) to {}",
-        items.len(),
-        app.convert_target_delimiter.display_name()
+    let items = parse_list(&content, Delimiter::Newline);
+    let textarea = app.textarea(panel);
+    textarea.select_all();
+    textarea.cut();
+    textarea.insert_str(items.join("\n"));
+    app.publish(AppEvent::PanelEdited(panel));
+    if panel == PanelId::ConvertInput {
+        app.convert_tab.clear_output();
+    }
+    app.results = vec![trf(
+        Text::ReferenceLoaded,
+        &[
+            &name,
+            &tr(PanelContent::from(panel).name()),
+            &app.number_format.count(items.len()),
+        ],
     )];
+}
 
+/// Keys while a text prompt is open: Enter submits, Esc cancels, Tab switches a
+/// regex filter between keeping and dropping, everything else edits (and
+/// refreshes the preview of a replacement)
+fn handle_prompt_key(app: &mut App, key_event: KeyEvent) -> Result<(), io::Error> {
+    match key_event.code {
+        KeyCode::Esc => app.prompt = None,
+        KeyCode::Tab => {
+            if let Some(prompt) = app.prompt.as_mut() {
+                prompt.toggle_filter();
+            }
+        }
+        KeyCode::Enter => {
+            if let Some(prompt) = app.prompt.take() {
+                let answer = prompt.value();
+                submit_prompt(app, prompt.kind, &answer)?;
+            }
+        }
+        _ => {
+            if let Some(prompt) = app.prompt.as_mut() {
+                prompt.input.input(Input::from(key_event));
+                if let PromptKind::ReplaceWith(panel, pattern) = &prompt.kind {
+                    let (panel, pattern, replacement) = (*panel, pattern.clone(), prompt.value());
+                    preview_replace_regex(app, panel, &pattern, &replacement);
+                }
+            }
+        }
+    }
     Ok(())
 }
 
-fn active_panel_label(app: &App) -> Option<String> {
-    if app.active_tab == 0 {
-        let label = match app.active_panel {
-            0 => "List 1",
-            1 => "List 2",
-            _ => "Results",
-        };
-        return Some(label.to_string());
+/// Act on a submitted prompt answer
+fn submit_prompt(app: &mut App, kind: PromptKind, answer: &str) -> Result<(), io::Error> {
+    match kind {
+        PromptKind::SaveReference(panel) => save_reference_from(app, panel, answer.trim()),
+        PromptKind::FixedWidths => set_fixed_widths(app, answer.trim()),
+        PromptKind::JsonPath => set_json_path(app, answer.trim()),
+        // Spaces are kept, so `, ` can end every item
+        PromptKind::ItemWrap => {
+            app.convert_tab.wrap = ItemWrap::parse(answer);
+            let wrap = &app.convert_tab.wrap;
+            app.results = vec![if wrap.is_empty() {
+                tr(Text::ItemWrapOff).to_string()
+            } else {
+                trf(Text::ItemWrapOn, &[&wrap.apply("…")])
+            }];
+        }
+        // Spaces are kept, so ` - ` splits on the dash with its spaces
+        PromptKind::CustomDelimiter(slot) => set_custom_delimiter(app, slot, answer),
+        PromptKind::ExportBundle => export_bundle(app, &bundle_path(app, answer.trim())),
+        PromptKind::ExportReport => export_report(app, &report_path(app, answer.trim())),
+        PromptKind::ImportBundle => import_bundle(app, &bundle_path(app, answer.trim()))?,
+        PromptKind::GitRevisions => load_git_revisions(app, answer)?,
+        PromptKind::Tolerance => set_tolerance(app, answer.trim()),
+        // Spaces are kept, as they may be part of the pattern
+        PromptKind::RegexFilter(panel, filter) => handle_filter_regex(app, panel, filter, answer),
+        PromptKind::ReplaceFind(panel) => match Regex::new(answer) {
+            Ok(_) => {
+                let kind = PromptKind::ReplaceWith(panel, answer.to_string());
+                app.prompt = Some(Prompt::new(kind, ""));
+                preview_replace_regex(app, panel, answer, "");
+            }
+            Err(err) => app.results = vec![trf(Text::InvalidRegex, &[&err])],
+        },
+        // An empty replacement deletes the matches
+        PromptKind::ReplaceWith(panel, pattern) => {
+            handle_replace_regex(app, panel, &pattern, answer)
+        }
+        PromptKind::CompareColumn => {
+            app.set_compare_column(ColumnSelector::parse(answer));
+            app.results = vec![match &app.compare_column {
+                Some(column) => trf(Text::CompareColumn, &[column]),
+                None => tr(Text::CompareWholeRows).to_string(),
+            }];
+        }
     }
+    Ok(())
+}
 
-    if app.active_tab == 2 {
-        let label = match app.active_panel {
-            0 => format!(
-                "Convert Input (Src {})",
-                app.convert_source_delimiter.display_name()
-            ),
-            1 => format!(
-                "Convert Output (Dst {}) [{} items]",
-                app.convert_target_delimiter.display_name(),
-                app.convert_output_items.len()
-            ),
-            _ => "Convert".to_string(),
-        };
-        return Some(label);
+/// Load a file at two git revisions into List 1 and List 2, then compare them
+///
+/// The answer is `PATH [REV1 [REV2]]`, the path relative to the working directory.
+fn load_git_revisions(app: &mut App, answer: &str) -> Result<(), io::Error> {
+    let Some((path, revisions)) = parse_git_request(answer) else {
+        app.results = vec![tr(Text::InvalidGitRequest).to_string()];
+        return Ok(());
+    };
+    let path = app.paths.work.join(path);
+    let mut contents = Vec::with_capacity(2);
+    for revision in revisions {
+        let label = PathBuf::from(format!("{}@{}", path.display(), revision));
+        match git_show(&path, revision) {
+            Ok(bytes) => {
+                if let Some(reason) = detect_binary(&bytes) {
+                    let reason = binary_reason(app, reason);
+                    app.results = vec![trf(Text::GitBinary, &[&label.display(), &reason])];
+                    return Ok(());
+                }
+                contents.push((label, String::from_utf8_lossy(&bytes).into_owned()));
+            }
+            Err(err) => {
+                app.results = vec![trf(Text::GitFailed, &[&label.display(), &err])];
+                return Ok(());
+            }
+        }
     }
 
-    if let Some(ref compare_results) = app.compare_results {
-        let (label, count) = match app.active_panel {
-            0 => ("Only in List 1", compare_results.only_in_first.len()),
-            1 => ("Only in List 2", compare_results.only_in_second.len()),
-            2 => ("Intersection", compare_results.intersection.len()),
-            _ => ("Union", compare_results.union.len()),
-        };
-        Some(format!("{} ({} items)", label, count))
-    } else {
-        let label = match app.active_panel {
-            0 => "Only in List 1",
-            1 => "Only in List 2",
-            2 => "Intersection",
-            _ => "Union",
-        };
-        Some(format!("{} (0 items)", label))
+    let mut messages = Vec::new();
+    for (panel, (label, content)) in [PanelId::List1, PanelId::List2].into_iter().zip(contents) {
+        load_text_into(app, panel, &label, &content);
+        messages.append(&mut app.results);
     }
+    // Deliver the edits first so the fresh results are not marked stale
+    app.dispatch_events()?;
+    handle_compare_operations(app)?;
+    messages.append(&mut app.results);
+    app.results = messages;
+    Ok(())
 }
 
-/// Join lines using the given delimiter so parsing respects the selected separator.
-fn join_lines_with_delimiter(lines: &[String], delimiter: Delimiter) -> String {
-    let sep = delimiter.as_char().to_string();
-    lines.join(&sep)
-}
+/// File name offered by the bundle prompts
+const DEFAULT_BUNDLE_FILE: &str = "comparison.lcmp";
 
-/// Extract the current panel content and a friendly name
-fn active_panel_content(app: &App) -> (String, String) {
-    if app.active_tab == 0 {
-        match app.active_panel {
-            0 => (
-                join_lines_with_delimiter(app.list1.lines(), app.delimiter),
-                "List 1".to_string(),
-            ),
-            1 => (
-                join_lines_with_delimiter(app.list2.lines(), app.delimiter),
-                "List 2".to_string(),
-            ),
-            _ => (app.results.join("\n"), "Results".to_string()),
-        }
-    } else if app.active_tab == 2 {
-        match app.active_panel {
-            0 => (
-                join_lines_with_delimiter(app.convert_input.lines(), app.convert_source_delimiter),
-                "Convert Input".to_string(),
-            ),
-            1 => (
-                app.convert_output_serialized.clone(),
-                "Convert Output".to_string(),
-            ),
-            _ => ("".to_string(), "Results".to_string()),
-        }
-    } else if let Some(ref compare_results) = app.compare_results {
-        let (items, name) = match app.active_panel {
-            0 => (&compare_results.only_in_first, "Only in List 1"),
-            1 => (&compare_results.only_in_second, "Only in List 2"),
-            2 => (&compare_results.intersection, "Intersection"),
-            _ => (&compare_results.union, "Union"),
-        };
-        (items.join("\n"), name.to_string())
-    } else {
-        ("".to_string(), "Results".to_string())
+/// Path typed in a bundle prompt, relative to the working directory, with the
+/// `.lcmp` extension added when it has none
+fn bundle_path(app: &App, answer: &str) -> PathBuf {
+    let mut path = app.paths.work.join(answer);
+    if path.extension().is_none() {
+        path.set_extension(BUNDLE_EXTENSION);
     }
+    path
+}
+
+/// Save List 1, List 2, the parsing and comparison options and the last results
+fn export_bundle(app: &mut App, path: &Path) {
+    let bundle = ComparisonBundle::new(
+        app.input_tab.list1.lines(),
+        app.input_tab.list2.lines(),
+        app.delimiter,
+        app.empty_items,
+        app.compare_options,
+        app.results_tab.compare_results.as_ref(),
+    );
+    app.results = match fs::write(path, bundle.to_json()) {
+        Ok(_) => vec![trf(Text::BundleExported, &[&path.display()])],
+        Err(err) => vec![trf(Text::FailedToSave, &[&path.display(), &err])],
+    };
 }
 
-/// Resolve a default file path for the active panel, allowing a base directory override
-fn file_path_for_panel(app: &App) -> Option<PathBuf> {
-    let base_dir = env::var("LIST_UTILS_DIR").unwrap_or_else(|_| ".".to_string());
+/// Restore the lists and options of a bundle and compare them again
+///
+/// The recorded comparison time is kept, and a warning is shown when the
+/// replayed results differ from the recorded ones.
+fn import_bundle(app: &mut App, path: &Path) -> Result<(), io::Error> {
+    let bundle = match fs::read_to_string(path) {
+        Ok(text) => ComparisonBundle::from_json(&text),
+        Err(err) => {
+            app.results = vec![trf(Text::FailedToLoad, &[&path.display(), &err])];
+            return Ok(());
+        }
+    };
+    let bundle = match bundle {
+        Ok(bundle) => bundle,
+        Err(err) => {
+            app.results = vec![trf(Text::InvalidBundle, &[&path.display(), &err])];
+            return Ok(());
+        }
+    };
 
-    let filename = match app.active_tab {
-        0 => match app.active_panel {
-            0 => Some("list1.txt"),
-            1 => Some("list2.txt"),
-            2 => Some("results.txt"),
-            _ => None,
-        },
-        1 => match app.active_panel {
-            0 => Some("only_in_list1.txt"),
-            1 => Some("only_in_list2.txt"),
-            2 => Some("intersection.txt"),
-            3 => Some("union.txt"),
-            _ => None,
-        },
-        2 => match app.active_panel {
-            0 => Some("convert_input.txt"),
-            1 => Some("convert_output.txt"),
-            _ => None,
-        },
-        _ => None,
-    }?;
+    for (panel, lines) in [
+        (PanelId::List1, &bundle.list1),
+        (PanelId::List2, &bundle.list2),
+    ] {
+        app.save_undo(panel);
+        *app.textarea(panel) = tui_textarea::TextArea::from(lines.iter().cloned());
+        app.publish(AppEvent::PanelEdited(panel));
+    }
+    app.delimiter = bundle.delimiter();
+    app.detected_delimiter = None;
+    app.suggested_delimiter = None;
+    app.empty_items = bundle.empty_items();
+    app.compare_options = bundle.compare_options();
+    app.publish(AppEvent::DelimiterChanged);
+    app.publish(AppEvent::CompareOptionsChanged);
+    // Deliver the edits first so the replayed results are not marked stale
+    app.dispatch_events()?;
 
-    Some(PathBuf::from(base_dir).join(filename))
+    let mut messages = vec![trf(Text::BundleImported, &[&path.display()])];
+    if bundle.results.is_some() && run_comparison(app) {
+        if let (Some(result), Some(compared_at)) = (
+            app.results_tab.compare_results.as_mut(),
+            bundle.compared_at(),
+        ) {
+            result.compared_at = compared_at;
+            if !bundle.matches(result) {
+                messages.push(tr(Text::BundleMismatch).to_string());
+            }
+        }
+        messages.append(&mut app.results);
+    }
+    app.results = messages;
+    Ok(())
 }
 
-/// Pick content to persist based on active panel and delimiter rules
-fn content_for_save(app: &App) -> (String, String) {
-    if app.active_tab == 0 {
-        match app.active_panel {
-            0 => (
-                join_lines_with_delimiter(app.list1.lines(), app.delimiter),
-                "List 1".to_string(),
-            ),
-            1 => (
-                join_lines_with_delimiter(app.list2.lines(), app.delimiter),
-                "List 2".to_string(),
-            ),
-            _ => (app.results.join("\n"), "Results".to_string()),
+/// Use the typed text as the delimiter of `slot`
+fn set_custom_delimiter(app: &mut App, slot: DelimiterSlot, answer: &str) {
+    let Some(custom) = CustomDelimiter::new(answer) else {
+        app.results = vec![tr(Text::InvalidCustomDelimiter).to_string()];
+        return;
+    };
+    let delimiter = Delimiter::Custom(custom);
+    let message = match slot {
+        DelimiterSlot::Lists => {
+            app.delimiter = delimiter;
+            app.detected_delimiter = None;
+            app.suggested_delimiter = None;
+            app.publish(AppEvent::DelimiterChanged);
+            Text::DelimiterSwitched
         }
-    } else if app.active_tab == 2 {
-        match app.active_panel {
-            0 => (
-                join_lines_with_delimiter(app.convert_input.lines(), app.convert_source_delimiter),
-                "Convert Input".to_string(),
-            ),
-            1 => (
-                app.convert_output_serialized.clone(),
-                "Convert Output".to_string(),
-            ),
-            _ => ("".to_string(), "Results".to_string()),
-        }
-    } else if let Some(ref compare_results) = app.compare_results {
-        let (items, name) = match app.active_panel {
-            0 => (&compare_results.only_in_first, "Only in List 1"),
-            1 => (&compare_results.only_in_second, "Only in List 2"),
-            2 => (&compare_results.intersection, "Intersection"),
-            _ => (&compare_results.union, "Union"),
-        };
-        (items.join("\n"), name.to_string())
+        DelimiterSlot::ConvertSource => {
+            app.convert_tab.source_delimiter = delimiter;
+            app.convert_tab.detected_source = None;
+            app.convert_tab.suggested_source = None;
+            Text::SourceDelimiter
+        }
+        DelimiterSlot::ConvertTarget => {
+            app.convert_tab.target_delimiter = delimiter;
+            Text::TargetDelimiter
+        }
+    };
+    app.results = vec![trf(message, &[&delimiter.display_name()])];
+}
+
+/// Use the typed column widths for the Convert input (blank turns fixed-width off)
+fn set_fixed_widths(app: &mut App, answer: &str) {
+    let Some(widths) = parse_column_widths(answer) else {
+        app.results = vec![trf(Text::InvalidColumnWidths, &[&answer])];
+        return;
+    };
+    app.results = if widths.is_empty() {
+        vec![tr(Text::FixedWidthOff).to_string()]
     } else {
-        ("".to_string(), "Results".to_string())
-    }
+        vec![trf(Text::FixedWidthOn, &[&join_widths(&widths)])]
+    };
+    app.convert_tab.fixed_widths = (!widths.is_empty()).then_some(widths);
 }
 
-/// Load content from a file into the active editable panel (List 1 or List 2)
-fn handle_load_from_file(app: &mut App) -> Result<(), io::Error> {
-    if !((app.active_tab == 0 && (app.active_panel == 0 || app.active_panel == 1))
-        || (app.active_tab == 2 && app.active_panel == 0))
-    {
-        app.results = vec!["Select a loadable panel (List 1/2 or Convert Input)".to_string()];
-        return Ok(());
+/// Extract the values at the typed path from JSON sources (blank converts whole objects)
+fn set_json_path(app: &mut App, answer: &str) {
+    if answer.is_empty() {
+        app.convert_tab.json_path = None;
+        app.results = vec![tr(Text::JsonPathOff).to_string()];
+        return;
     }
+    match JsonPath::parse(answer) {
+        Ok(path) => {
+            app.results = vec![trf(Text::JsonPathOn, &[&path])];
+            app.convert_tab.json_path = Some(path);
+        }
+        Err(e) => app.results = vec![trf(Text::InvalidJsonPath, &[&e])],
+    }
+}
 
-    let Some(path) = file_path_for_panel(app) else {
-        app.results = vec!["No target file for this panel".to_string()];
-        return Ok(());
-    };
+/// Column widths as typed in the fixed-width prompt
+fn join_widths(widths: &[usize]) -> String {
+    widths
+        .iter()
+        .map(usize::to_string)
+        .collect::<Vec<_>>()
+        .join(",")
+}
 
-    match fs::read_to_string(&path) {
-        Ok(content) => {
-            let delimiter = if app.active_tab == 2 {
-                app.convert_source_delimiter
-            } else {
-                app.delimiter
-            };
-            let items = parse_list(&content, delimiter);
-            let Some(textarea) = app.active_textarea() else {
-                app.results = vec!["No active panel".to_string()];
-                return Ok(());
-            };
-            textarea.select_all();
-            textarea.cut();
-            textarea.insert_str(&items.join("\n"));
+/// Store an editor's items, one per line, as the reference list `name`
+fn save_reference_from(app: &mut App, panel: PanelId, name: &str) {
+    if !references::is_valid_name(name) {
+        app.results = vec![trf(Text::InvalidReferenceName, &[&name])];
+        return;
+    }
+    let dir = references::library_dir(&app.paths);
 
-            let count = items.len();
-            app.results = vec![format!("Loaded {} item(s) from {}", count, path.display())];
-            if app.active_tab == 2 {
-                app.convert_output_items.clear();
-                app.convert_output_serialized.clear();
-            }
+    let content = PanelContent::from(panel);
+    let items = parse_list_with(
+        &content.content(app),
+        app.panel_delimiter(panel),
+        app.empty_items,
+    );
+    let panel_name = tr(content.name());
+    match references::save_reference(&dir, name, &items.join("\n")) {
+        Ok(path) => {
+            app.results = vec![trf(
+                Text::ReferenceSaved,
+                &[&panel_name, &name, &path.display()],
+            )];
         }
         Err(err) => {
-            app.results = vec![format!("Failed to load {}: {}", path.display(), err)];
+            let path = references::reference_path(&dir, name);
+            app.results = vec![trf(Text::FailedToSave, &[&path.display(), &err])];
         }
     }
-
-    Ok(())
 }
 
-/// Save the active panel content to a file
-fn handle_save_to_file(app: &mut App) -> Result<(), io::Error> {
-    let Some(path) = file_path_for_panel(app) else {
-        app.results = vec!["No target file for this panel".to_string()];
-        return Ok(());
-    };
+/// Directory of the data directory workspace snapshots are saved in
+const WORKSPACES_DIR: &str = "workspaces";
 
-    let (text, panel_name) = content_for_save(app);
-    if text.is_empty() {
-        app.results = vec![format!("Nothing to save from {}", panel_name)];
+/// Save every non-empty panel into a new `workspaces/workspace_<timestamp>` directory
+/// of the data directory
+fn handle_save_workspace(app: &mut App) -> Result<(), io::Error> {
+    let files: Vec<_> = PanelContent::WORKSPACE
+        .iter()
+        .map(|panel| (panel.filename(), panel.content(app), panel.panel_id()))
+        .filter(|(_, text, _)| !text.trim().is_empty())
+        .map(|(name, text, panel)| {
+            let text = app.line_ending_for(panel).apply(&text);
+            (name, app.save_encoding.encode(&text))
+        })
+        .collect();
+    if files.is_empty() {
+        app.results = vec![tr(Text::NothingToSaveWorkspace).to_string()];
         return Ok(());
     }
 
-    match fs::write(&path, text) {
-        Ok(_) => {
-            app.results = vec![format!("Saved {} to {}", panel_name, path.display())];
-        }
-        Err(err) => {
-            app.results = vec![format!("Failed to save {}: {}", path.display(), err)];
+    let dir = app.paths.data.join(WORKSPACES_DIR).join(format!(
+        "workspace_{}",
+        crate::report::file_stamp(SystemTime::now())
+    ));
+    let written = fs::create_dir_all(&dir).and_then(|_| {
+        for (name, text) in &files {
+            fs::write(dir.join(name), text)?;
         }
-    }
+        Ok(())
+    });
+
+    app.results = match written {
+        Ok(_) => vec![trf(
+            Text::SavedWorkspace,
+            &[&app.number_format.count(files.len()), &dir.display()],
+        )],
+        Err(err) => vec![trf(Text::FailedToSave, &[&dir.display(), &err])],
+    };
 
     Ok(())
 }
@@ -0,0 +1,207 @@
+//! Number formatting shared by the INFO panel and exports
+use std::env;
+
+/// How counts and numeric stats are rendered
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct NumberFormat {
+    /// Thousands separator (`None` prints digits ungrouped)
+    pub thousands_separator: Option<char>,
+    /// Decimal separator for fractional values
+    pub decimal_separator: char,
+    /// Switch to scientific notation for values at or above this magnitude
+    pub scientific_above: Option<f64>,
+}
+
+impl Default for NumberFormat {
+    fn default() -> Self {
+        Self {
+            thousands_separator: Some(','),
+            decimal_separator: '.',
+            scientific_above: None,
+        }
+    }
+}
+
+impl NumberFormat {
+    /// Separators conventionally used by a locale (`en_US`, `es-AR`, `fr`, `plain`, ...)
+    pub fn for_locale(locale: &str) -> Self {
+        let code = locale
+            .split(['_', '-', '.'])
+            .next()
+            .unwrap_or("")
+            .to_ascii_lowercase();
+        let (thousands, decimal) = match code.as_str() {
+            "es" | "de" | "it" | "pt" | "nl" | "da" | "id" | "tr" => (Some('.'), ','),
+            "fr" | "ru" | "pl" | "sv" | "fi" | "nb" | "cs" | "uk" => (Some(' '), ','),
+            "plain" | "none" => (None, '.'),
+            _ => (Some(','), '.'),
+        };
+        Self {
+            thousands_separator: thousands,
+            decimal_separator: decimal,
+            scientific_above: None,
+        }
+    }
+
+    /// Build the format from the environment
+    ///
+    /// The locale comes from `LIST_UTILS_NUMBER_LOCALE`, then `LIST_UTILS_LANG`,
+    /// `LC_ALL`, `LC_NUMERIC` and `LANG`. `LIST_UTILS_SCIENTIFIC_ABOVE` (e.g. `1e9`)
+    /// enables scientific notation for huge values.
+    pub fn from_env() -> Self {
+        let locale = [
+            "LIST_UTILS_NUMBER_LOCALE",
+            "LIST_UTILS_LANG",
+            "LC_ALL",
+            "LC_NUMERIC",
+            "LANG",
+        ]
+        .iter()
+        .filter_map(|var| env::var(var).ok())
+        .find(|value| !value.is_empty())
+        .unwrap_or_default();
+
+        let mut format = Self::for_locale(&locale);
+        format.scientific_above = env::var("LIST_UTILS_SCIENTIFIC_ABOVE")
+            .ok()
+            .and_then(|v| v.trim().parse::<f64>().ok())
+            .filter(|v| *v > 0.0);
+        format
+    }
+
+    /// Format an item count
+    pub fn count(&self, n: usize) -> String {
+        if self.is_huge(n as f64) {
+            return self.scientific(n as f64);
+        }
+        self.group(&n.to_string())
+    }
+
+    /// Format a fractional value with a fixed number of decimals
+    pub fn decimal(&self, value: f64, precision: usize) -> String {
+        if self.is_huge(value.abs()) {
+            return self.scientific(value);
+        }
+        let formatted = format!("{:.*}", precision, value.abs());
+        let (int_part, frac_part) = match formatted.split_once('.') {
+            Some((i, f)) => (i, Some(f)),
+            None => (formatted.as_str(), None),
+        };
+        let mut out = String::new();
+        if value < 0.0 && formatted.chars().any(|c| c.is_ascii_digit() && c != '0') {
+            out.push('-');
+        }
+        out.push_str(&self.group(int_part));
+        if let Some(frac) = frac_part {
+            out.push(self.decimal_separator);
+            out.push_str(frac);
+        }
+        out
+    }
+
+    /// Format `count` as a percentage of `total` with one decimal
+    pub fn percent(&self, count: usize, total: usize) -> String {
+        let fraction = if total == 0 {
+            0.0
+        } else {
+            count as f64 / total as f64
+        };
+        self.ratio(fraction)
+    }
+
+    /// Format a fraction (`0.87`) as a percentage with one decimal (`87.0%`)
+    pub fn ratio(&self, fraction: f64) -> String {
+        format!("{}%", self.decimal(fraction * 100.0, 1))
+    }
+
+    /// Format a byte size with a binary unit (`B`, `KiB`, `MiB`, `GiB`)
+    pub fn bytes(&self, n: usize) -> String {
+        const UNITS: [&str; 4] = ["B", "KiB", "MiB", "GiB"];
+        let mut value = n as f64;
+        let mut unit = 0;
+        while value >= 1024.0 && unit < UNITS.len() - 1 {
+            value /= 1024.0;
+            unit += 1;
+        }
+        if unit == 0 {
+            format!("{} {}", self.count(n), UNITS[0])
+        } else {
+            format!("{} {}", self.decimal(value, 1), UNITS[unit])
+        }
+    }
+
+    fn is_huge(&self, value: f64) -> bool {
+        self.scientific_above.is_some_and(|limit| value >= limit)
+    }
+
+    fn scientific(&self, value: f64) -> String {
+        format!("{:.2e}", value).replace('.', &self.decimal_separator.to_string())
+    }
+
+    /// Insert thousands separators into a string of ASCII digits
+    fn group(&self, digits: &str) -> String {
+        let Some(sep) = self.thousands_separator else {
+            return digits.to_string();
+        };
+        let mut out = String::with_capacity(digits.len() + digits.len() / 3);
+        for (i, c) in digits.chars().enumerate() {
+            if i > 0 && (digits.len() - i).is_multiple_of(3) {
+                out.push(sep);
+            }
+            out.push(c);
+        }
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_count_grouping() {
+        let en = NumberFormat::default();
+        assert_eq!(en.count(0), "0");
+        assert_eq!(en.count(999), "999");
+        assert_eq!(en.count(1_000), "1,000");
+        assert_eq!(en.count(1_234_567), "1,234,567");
+
+        let es = NumberFormat::for_locale("es_AR.UTF-8");
+        assert_eq!(es.count(1_234_567), "1.234.567");
+
+        let plain = NumberFormat::for_locale("plain");
+        assert_eq!(plain.count(1_234_567), "1234567");
+    }
+
+    #[test]
+    fn test_decimal_and_percent() {
+        let en = NumberFormat::default();
+        assert_eq!(en.decimal(12345.678, 2), "12,345.68");
+        assert_eq!(en.decimal(-0.04, 1), "0.0");
+        assert_eq!(en.percent(1, 3), "33.3%");
+        assert_eq!(en.percent(1, 0), "0.0%");
+        assert_eq!(en.ratio(0.875), "87.5%");
+
+        let es = NumberFormat::for_locale("es");
+        assert_eq!(es.percent(1, 2), "50,0%");
+    }
+
+    #[test]
+    fn test_bytes() {
+        let en = NumberFormat::default();
+        assert_eq!(en.bytes(512), "512 B");
+        assert_eq!(en.bytes(1536), "1.5 KiB");
+        assert_eq!(en.bytes(3 * 1024 * 1024), "3.0 MiB");
+        assert_eq!(NumberFormat::for_locale("es").bytes(1536), "1,5 KiB");
+    }
+
+    #[test]
+    fn test_scientific_threshold() {
+        let format = NumberFormat {
+            scientific_above: Some(1e6),
+            ..NumberFormat::default()
+        };
+        assert_eq!(format.count(999_999), "999,999");
+        assert_eq!(format.count(12_300_000), "1.23e7");
+    }
+}
@@ -0,0 +1,158 @@
+//! Pick one column out of delimited rows so it can be compared as a list
+//!
+//! Pasting whole CSV/TSV rows makes every row unique; selecting the column
+//! that holds the identifiers (by number or by header name) turns the rows
+//! back into a plain list.
+use crate::parser::{split_quoted, RECORD_SEPARATORS};
+use std::fmt;
+
+/// Which column of the rows is used
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ColumnSelector {
+    /// Column by position (0-based)
+    Index(usize),
+    /// Column whose header (first row) matches, ignoring case
+    Name(String),
+}
+
+impl ColumnSelector {
+    /// Parse a prompt answer: a 1-based column number or a header name
+    ///
+    /// Returns `None` for an empty answer or column `0`.
+    pub fn parse(answer: &str) -> Option<Self> {
+        let answer = answer.trim();
+        if answer.is_empty() {
+            return None;
+        }
+        match answer.parse::<usize>() {
+            Ok(number) => number.checked_sub(1).map(ColumnSelector::Index),
+            Err(_) => Some(ColumnSelector::Name(answer.to_string())),
+        }
+    }
+}
+
+impl fmt::Display for ColumnSelector {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ColumnSelector::Index(index) => write!(f, "{}", index + 1),
+            ColumnSelector::Name(name) => f.write_str(name),
+        }
+    }
+}
+
+/// Why a column could not be extracted
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ColumnError {
+    /// The first row has no tab, `|`, `;` or `,` to split on
+    NoSeparator,
+    /// No header in the first row matches the name
+    UnknownHeader(String),
+}
+
+/// Values of the selected column and how many rows lacked it
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ColumnValues {
+    /// One value per row that has the column, in row order
+    pub values: Vec<String>,
+    /// Rows with fewer fields than the column needs
+    pub missing: usize,
+}
+
+/// Field separator of the rows: the first of [`RECORD_SEPARATORS`] splitting
+/// the first non-blank row into at least two fields
+pub fn detect_field_separator<S: AsRef<str>>(rows: &[S]) -> Option<char> {
+    let first = rows
+        .iter()
+        .map(AsRef::as_ref)
+        .find(|row| !row.trim().is_empty())?;
+    RECORD_SEPARATORS
+        .into_iter()
+        .find(|&sep| split_quoted(first, sep).len() >= 2)
+}
+
+/// Extract one column from delimited rows
+///
+/// Fields are split honouring CSV quotes and trimmed. Selecting by name
+/// consumes the first row as the header; blank rows are skipped.
+///
+/// # Errors
+/// Fails when no field separator is found or the header name is unknown.
+pub fn extract_column<S: AsRef<str>>(
+    rows: &[S],
+    selector: &ColumnSelector,
+) -> Result<ColumnValues, ColumnError> {
+    let mut rows = rows
+        .iter()
+        .map(AsRef::as_ref)
+        .filter(|row| !row.trim().is_empty());
+    let Some(first) = rows.clone().next() else {
+        return Ok(ColumnValues::default());
+    };
+    let separator = detect_field_separator(&[first]).ok_or(ColumnError::NoSeparator)?;
+    let index = match selector {
+        ColumnSelector::Index(index) => *index,
+        ColumnSelector::Name(name) => {
+            rows.next();
+            split_quoted(first, separator)
+                .iter()
+                .position(|header| header.trim().eq_ignore_ascii_case(name))
+                .ok_or_else(|| ColumnError::UnknownHeader(name.clone()))?
+        }
+    };
+    let mut column = ColumnValues::default();
+    for row in rows {
+        match split_quoted(row, separator).get(index) {
+            Some(field) => column.values.push(field.trim().to_string()),
+            None => column.missing += 1,
+        }
+    }
+    Ok(column)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_selector() {
+        assert_eq!(ColumnSelector::parse(" 3 "), Some(ColumnSelector::Index(2)));
+        assert_eq!(
+            ColumnSelector::parse("Email"),
+            Some(ColumnSelector::Name("Email".to_string()))
+        );
+        assert_eq!(ColumnSelector::parse("0"), None);
+        assert_eq!(ColumnSelector::parse(""), None);
+        assert_eq!(ColumnSelector::Index(2).to_string(), "3");
+    }
+
+    #[test]
+    fn test_extract_column() {
+        let rows = [
+            "id,name,email",
+            "1,\"Doe, John\",j@x.com",
+            "",
+            "2,Ann",
+            "3, Bo ,b@x.com",
+        ];
+        let by_name = extract_column(&rows, &ColumnSelector::Name("NAME".into())).unwrap();
+        assert_eq!(by_name.values, vec!["Doe, John", "Ann", "Bo"]);
+        assert_eq!(by_name.missing, 0);
+
+        let by_index = extract_column(&rows[1..], &ColumnSelector::Index(2)).unwrap();
+        assert_eq!(by_index.values, vec!["j@x.com", "b@x.com"]);
+        assert_eq!(by_index.missing, 1);
+
+        let tsv = ["a\tb", "c\td"];
+        let second = extract_column(&tsv, &ColumnSelector::Index(1)).unwrap();
+        assert_eq!(second.values, vec!["b", "d"]);
+
+        assert_eq!(
+            extract_column(&rows, &ColumnSelector::Name("phone".into())),
+            Err(ColumnError::UnknownHeader("phone".to_string()))
+        );
+        assert_eq!(
+            extract_column(&["plain"], &ColumnSelector::Index(0)),
+            Err(ColumnError::NoSeparator)
+        );
+    }
+}
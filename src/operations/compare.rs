@@ -1,4 +1,16 @@
-/// Operations for comparing two lists
+//! Operations for comparing two lists
+use super::normalize::NormalizePreset;
+use super::parallel;
+use super::smart_sort::SmartSort;
+use super::tolerance::{match_within_tolerance, NearMatch, Tolerance};
+use super::trim::TrimSet;
+use super::typography::fold_typography;
+use crate::parser::Delimiter;
+use std::borrow::Cow;
+use std::collections::hash_map::DefaultHasher;
+use std::collections::{HashMap, HashSet};
+use std::hash::{Hash, Hasher};
+use std::time::SystemTime;
 
 /// Options for list comparison
 #[derive(Debug, Clone, Copy)]
@@ -7,6 +19,18 @@ pub struct CompareOptions {
     pub case_sensitive: bool,
     /// Whether to trim spaces before comparison
     pub trim_spaces: bool,
+    /// Extra characters trimmed along with the spaces
+    pub trim_set: TrimSet,
+    /// Whether curly quotes, dashes and special spaces are folded to ASCII first
+    pub fold_typography: bool,
+    /// Canonical form items of a known kind (emails, ...) are rewritten to
+    pub normalize: NormalizePreset,
+    /// How far apart numbers left unmatched may be and still match
+    pub tolerance: Option<Tolerance>,
+    /// Whether results keep the order items appear in the lists instead of being sorted
+    pub preserve_order: bool,
+    /// How results are sorted when the input order is not kept
+    pub smart_sort: SmartSort,
 }
 
 impl Default for CompareOptions {
@@ -14,10 +38,74 @@ impl Default for CompareOptions {
         Self {
             case_sensitive: false,
             trim_spaces: true,
+            trim_set: TrimSet::default(),
+            fold_typography: false,
+            normalize: NormalizePreset::None,
+            tolerance: None,
+            preserve_order: false,
+            smart_sort: SmartSort::default(),
         }
     }
 }
 
+/// Distinct item counts behind the comparison ratios (after normalization)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CompareStats {
+    /// Distinct items in the first list
+    pub first_unique: usize,
+    /// Distinct items in the second list
+    pub second_unique: usize,
+    /// Distinct items present in both lists
+    pub shared: usize,
+    /// Distinct items present in either list
+    pub combined: usize,
+}
+
+impl CompareStats {
+    /// Fraction (0.0-1.0) of the first list's distinct items found in the second list
+    pub fn first_in_second(&self) -> f64 {
+        ratio(self.shared, self.first_unique)
+    }
+
+    /// Fraction (0.0-1.0) of the second list's distinct items found in the first list
+    pub fn second_in_first(&self) -> f64 {
+        ratio(self.shared, self.second_unique)
+    }
+
+    /// Overlap of both lists (Jaccard index: shared / combined distinct items)
+    pub fn overlap(&self) -> f64 {
+        ratio(self.shared, self.combined)
+    }
+}
+
+fn ratio(part: usize, total: usize) -> f64 {
+    if total == 0 {
+        0.0
+    } else {
+        part as f64 / total as f64
+    }
+}
+
+/// Estimated comparison ratios computed on a sample of both lists
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SampleEstimate {
+    /// Distinct counts scaled back up to the full lists
+    pub stats: CompareStats,
+    /// One in `rate` distinct items was sampled (1 means the estimate is exact)
+    pub rate: usize,
+    /// Number of items (from both lists) that fell into the sample
+    pub sampled: usize,
+}
+
+/// Where an item first appears in the compared lists and how often
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Occurrence {
+    /// Position of the first appearance, counting List 2 after List 1
+    pub first_index: usize,
+    /// Appearances in both lists
+    pub count: usize,
+}
+
 /// Result of comparing two lists
 #[derive(Debug, Clone)]
 pub struct CompareResult {
@@ -29,35 +117,53 @@ pub struct CompareResult {
     pub intersection: Vec<String>,
     /// All unique items from both lists (union)
     pub union: Vec<String>,
+    /// Distinct counts used for coverage and overlap ratios
+    pub stats: CompareStats,
+    /// Numbers matched within the tolerance (their first-list item is in the intersection)
+    pub near_matches: Vec<NearMatch>,
+    /// Appearances of every item as written, for the display orders of the Results tab
+    pub occurrences: HashMap<String, Occurrence>,
+    /// Options the comparison was run with
+    pub options: CompareOptions,
+    /// Delimiter the lists were parsed with
+    pub delimiter: Delimiter,
+    /// When the comparison was run
+    pub compared_at: SystemTime,
 }
 
-/// Check if all items can be parsed as numbers (integers or floats)
-fn all_numeric(items: &[String]) -> bool {
-    !items.is_empty() && items.iter().all(|s| s.trim().parse::<f64>().is_ok())
-}
+impl CompareResult {
+    /// Record the delimiter the compared lists were parsed with
+    pub fn with_delimiter(mut self, delimiter: Delimiter) -> Self {
+        self.delimiter = delimiter;
+        self
+    }
 
-/// Sort items intelligently (numeric if all numbers, otherwise alphabetic)
-fn sort_items_smart(items: &mut [String]) {
-    if all_numeric(items) {
-        // Numeric sort
-        items.sort_by(|a, b| {
-            let a_num: f64 = a.trim().parse().unwrap_or(0.0);
-            let b_num: f64 = b.trim().parse().unwrap_or(0.0);
-            a_num
-                .partial_cmp(&b_num)
-                .unwrap_or(std::cmp::Ordering::Equal)
-        });
-    } else {
-        // Alphabetic sort
-        items.sort();
+    /// First item of List 1 when List 2 has it too, as a header row shared by both would be
+    pub fn first_shared_item(&self) -> Option<&str> {
+        let (first, _) = self
+            .occurrences
+            .iter()
+            .find(|(_, occurrence)| occurrence.first_index == 0)?;
+        self.intersection.contains(first).then_some(first.as_str())
     }
 }
 
 /// Normalize an item according to comparison options
-fn normalize_item(item: &str, options: CompareOptions) -> String {
-    let mut normalized = item.to_string();
+///
+/// Typographic characters are folded first (if enabled), so a non-breaking
+/// space can then be trimmed. Trimming strips the [`TrimSet`] characters along
+/// with the spaces, and the [`NormalizePreset`] rewrites what is left.
+pub(crate) fn normalize_item(item: &str, options: CompareOptions) -> String {
+    let mut normalized = if options.fold_typography {
+        fold_typography(item).into_owned()
+    } else {
+        item.to_string()
+    };
     if options.trim_spaces {
-        normalized = normalized.trim().to_string();
+        normalized = options.trim_set.trim(&normalized).to_string();
+    }
+    if let Cow::Owned(rewritten) = options.normalize.apply(&normalized) {
+        normalized = rewritten;
     }
     if !options.case_sensitive {
         normalized = normalized.to_lowercase();
@@ -67,73 +173,253 @@ fn normalize_item(item: &str, options: CompareOptions) -> String {
 
 /// Compare two lists and return the differences and common elements
 ///
+/// Inputs may be owned or borrowed items (`String`, `&str`, `Cow<str>`);
+/// only the items that end up in the results are copied.
+///
 /// # Arguments
 /// * `list1` - First list of items
 /// * `list2` - Second list of items
 /// * `options` - Comparison options
 ///
 /// # Returns
-/// CompareResult with all comparison results
-pub fn compare_lists(list1: &[String], list2: &[String], options: CompareOptions) -> CompareResult {
-    // Normalize items according to options
-    let normalized1: Vec<(String, String)> = list1
-        .iter()
-        .map(|item| (normalize_item(item, options), item.clone()))
-        .collect();
-    let normalized2: Vec<(String, String)> = list2
-        .iter()
-        .map(|item| (normalize_item(item, options), item.clone()))
-        .collect();
+/// CompareResult with all comparison results, stamped with `options` and the
+/// current time. The delimiter defaults to newline; use
+/// [`CompareResult::with_delimiter`] to record another one.
+pub fn compare_lists<S: AsRef<str> + Sync>(
+    list1: &[S],
+    list2: &[S],
+    options: CompareOptions,
+) -> CompareResult {
+    compare_lists_with_progress(list1, list2, options, &mut |_, _| true)
+        .expect("comparison without cancellation")
+}
+
+/// Steps reported by [`compare_lists_with_progress`]
+pub const COMPARE_STEPS: usize = 6;
+
+/// [`compare_lists`], calling `progress` with the steps done out of the total
+/// after each one
+///
+/// Returns `None` as soon as `progress` returns false, which cancels the rest.
+pub fn compare_lists_with_progress<S: AsRef<str> + Sync>(
+    list1: &[S],
+    list2: &[S],
+    options: CompareOptions,
+    progress: &mut dyn FnMut(usize, usize) -> bool,
+) -> Option<CompareResult> {
+    let mut step = |done: usize| progress(done, COMPARE_STEPS).then_some(());
+
+    // Normalize items according to options (in parallel for large lists)
+    let normalized1: Vec<(String, &str)> = parallel::map(list1, |item| {
+        (normalize_item(item.as_ref(), options), item.as_ref())
+    });
+    step(1)?;
+    let normalized2: Vec<(String, &str)> = parallel::map(list2, |item| {
+        (normalize_item(item.as_ref(), options), item.as_ref())
+    });
+    step(2)?;
 
     // Create sets for efficient lookup
-    let set1: std::collections::HashSet<String> =
-        normalized1.iter().map(|(n, _)| n.clone()).collect();
-    let set2: std::collections::HashSet<String> =
-        normalized2.iter().map(|(n, _)| n.clone()).collect();
+    let set1: HashSet<&str> = parallel::to_set(&normalized1, |(n, _)| n.as_str());
+    let set2: HashSet<&str> = parallel::to_set(&normalized2, |(n, _)| n.as_str());
+    step(3)?;
 
     // Find items only in first list
-    let mut only_in_first: Vec<String> = normalized1
-        .iter()
-        .filter(|(normalized, _)| !set2.contains(normalized))
-        .map(|(_, original)| original.clone())
-        .collect();
+    let mut only_in_first: Vec<String> = parallel::filter_map(&normalized1, |(n, original)| {
+        (!set2.contains(n.as_str())).then(|| original.to_string())
+    });
 
     // Find items only in second list
-    let mut only_in_second: Vec<String> = normalized2
-        .iter()
-        .filter(|(normalized, _)| !set1.contains(normalized))
-        .map(|(_, original)| original.clone())
-        .collect();
+    let mut only_in_second: Vec<String> = parallel::filter_map(&normalized2, |(n, original)| {
+        (!set1.contains(n.as_str())).then(|| original.to_string())
+    });
 
     // Find intersection
-    let mut intersection: Vec<String> = normalized1
+    let mut intersection: Vec<String> = parallel::filter_map(&normalized1, |(n, original)| {
+        set2.contains(n.as_str()).then(|| original.to_string())
+    });
+
+    // Pair up the numbers left over that are close enough
+    let near_matches = match options.tolerance {
+        Some(tolerance) => {
+            match_within_tolerance(&mut only_in_first, &mut only_in_second, tolerance)
+        }
+        None => Vec::new(),
+    };
+    intersection.extend(near_matches.iter().map(|near| near.first.clone()));
+
+    // Find union (all unique items, in order of first appearance), a near match counting once
+    let near_seconds: HashSet<&str> = near_matches
         .iter()
-        .filter(|(normalized, _)| set2.contains(normalized))
-        .map(|(_, original)| original.clone())
+        .map(|near| near.second.as_str())
         .collect();
+    let mut seen: HashSet<&str> = HashSet::new();
+    let mut union: Vec<String> = normalized1
+        .iter()
+        .map(|(_, original)| *original)
+        .chain(
+            normalized2
+                .iter()
+                .map(|(_, original)| *original)
+                .filter(|original| !near_seconds.contains(original)),
+        )
+        .filter(|original| seen.insert(original))
+        .map(str::to_string)
+        .collect();
+
+    step(4)?;
+
+    let shared = set1.intersection(&set2).count() + near_matches.len();
+    let stats = CompareStats {
+        first_unique: set1.len(),
+        second_unique: set2.len(),
+        shared,
+        combined: set1.len() + set2.len() - shared,
+    };
 
-    // Find union (all unique items)
-    let mut union_set = std::collections::HashSet::new();
-    for (_, original) in &normalized1 {
-        union_set.insert(original.clone());
+    let mut occurrences: HashMap<String, Occurrence> = HashMap::new();
+    for (index, item) in list1.iter().chain(list2).enumerate() {
+        occurrences
+            .entry(item.as_ref().to_string())
+            .or_insert(Occurrence {
+                first_index: index,
+                count: 0,
+            })
+            .count += 1;
     }
-    for (_, original) in &normalized2 {
-        union_set.insert(original.clone());
+
+    step(5)?;
+
+    // Sort all result vectors intelligently (numbers numerically, text alphabetically)
+    if !options.preserve_order {
+        let sort = options.smart_sort;
+        sort.sort(&mut only_in_first);
+        sort.sort(&mut only_in_second);
+        sort.sort(&mut intersection);
+        sort.sort(&mut union);
     }
-    let mut union: Vec<String> = union_set.into_iter().collect();
 
-    // Sort all result vectors intelligently (numeric if all numbers, otherwise alphabetic)
-    sort_items_smart(&mut only_in_first);
-    sort_items_smart(&mut only_in_second);
-    sort_items_smart(&mut intersection);
-    sort_items_smart(&mut union);
+    step(COMPARE_STEPS)?;
 
-    CompareResult {
+    Some(CompareResult {
         only_in_first,
         only_in_second,
         intersection,
         union,
-    }
+        stats,
+        near_matches,
+        occurrences,
+        options,
+        delimiter: Delimiter::Newline,
+        compared_at: SystemTime::now(),
+    })
+}
+
+/// How [`filter_by_list`] treats the items found in the other list
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ListFilter {
+    /// Drop them (the other list is a blacklist)
+    Remove,
+    /// Keep only them (the other list is a whitelist)
+    Keep,
+}
+
+/// Filter `list` by membership in `other`, keeping the original order and duplicates
+///
+/// Items are matched with the same normalization as [`compare_lists`].
+pub fn filter_by_list<S: AsRef<str>>(
+    list: &[S],
+    other: &[S],
+    filter: ListFilter,
+    options: CompareOptions,
+) -> Vec<String> {
+    let other: HashSet<String> = other
+        .iter()
+        .map(|item| normalize_item(item.as_ref(), options))
+        .collect();
+    let keep_found = filter == ListFilter::Keep;
+    list.iter()
+        .map(AsRef::as_ref)
+        .filter(|item| other.contains(&normalize_item(item, options)) == keep_found)
+        .map(str::to_string)
+        .collect()
+}
+
+/// Estimate the comparison ratios of two lists from a sample of about `target` items per list
+///
+/// Items are sampled by the hash of their normalized value, so an item kept
+/// from one list is also kept from the other and shared items stay shared;
+/// a random sample of each list would badly underestimate the overlap.
+///
+/// # Arguments
+/// * `list1` - First list of items
+/// * `list2` - Second list of items
+/// * `options` - Comparison options (same normalization as [`compare_lists`])
+/// * `target` - Approximate number of items to sample from the larger list
+pub fn sample_compare<S: AsRef<str>>(
+    list1: &[S],
+    list2: &[S],
+    options: CompareOptions,
+    target: usize,
+) -> SampleEstimate {
+    sample_compare_with_progress(list1, list2, options, target, &mut |_, _| true)
+        .expect("sample without cancellation")
+}
+
+/// [`sample_compare`], reporting the items read so far out of both lists to `progress`
+///
+/// Stops and returns `None` as soon as `progress` returns `false`.
+pub fn sample_compare_with_progress<S: AsRef<str>>(
+    list1: &[S],
+    list2: &[S],
+    options: CompareOptions,
+    target: usize,
+    progress: &mut dyn FnMut(usize, usize) -> bool,
+) -> Option<SampleEstimate> {
+    let rate = list1.len().max(list2.len()).div_ceil(target.max(1)).max(1);
+    let total = list1.len() + list2.len();
+    let mut read = 0;
+    let mut sampled = 0;
+    let mut sample = |list: &[S]| -> Option<HashSet<String>> {
+        let mut set = HashSet::new();
+        for item in list {
+            let normalized = normalize_item(item.as_ref(), options);
+            if rate == 1 || sample_hash(&normalized).is_multiple_of(rate as u64) {
+                sampled += 1;
+                set.insert(normalized);
+            }
+            read += 1;
+            if read % SAMPLE_PROGRESS_ITEMS == 0 && !progress(read, total) {
+                return None;
+            }
+        }
+        Some(set)
+    };
+    let set1 = sample(list1)?;
+    let set2 = sample(list2)?;
+
+    let shared = set1.intersection(&set2).count();
+    let stats = CompareStats {
+        first_unique: set1.len() * rate,
+        second_unique: set2.len() * rate,
+        shared: shared * rate,
+        combined: (set1.len() + set2.len() - shared) * rate,
+    };
+    Some(SampleEstimate {
+        stats,
+        rate,
+        sampled,
+    })
+}
+
+/// Items read between progress reports of [`sample_compare_with_progress`]
+const SAMPLE_PROGRESS_ITEMS: usize = 10_000;
+
+/// Stable hash used to pick sampled items (identical across both lists and runs)
+fn sample_hash(item: &str) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    item.hash(&mut hasher);
+    hasher.finish()
 }
 
 #[cfg(test)]
@@ -152,6 +438,12 @@ mod tests {
         assert_eq!(result.intersection.len(), 2);
         assert!(result.intersection.contains(&"b".to_string()));
         assert!(result.intersection.contains(&"c".to_string()));
+        assert!(!result.options.case_sensitive);
+        assert_eq!(result.delimiter, Delimiter::Newline);
+        assert_eq!(
+            result.with_delimiter(Delimiter::Comma).delimiter,
+            Delimiter::Comma
+        );
     }
 
     #[test]
@@ -161,6 +453,7 @@ mod tests {
         let options = CompareOptions {
             case_sensitive: false,
             trim_spaces: false,
+            ..CompareOptions::default()
         };
         let result = compare_lists(&list1, &list2, options);
 
@@ -176,6 +469,7 @@ mod tests {
         let options = CompareOptions {
             case_sensitive: true,
             trim_spaces: false,
+            ..CompareOptions::default()
         };
         let result = compare_lists(&list1, &list2, options);
 
@@ -191,6 +485,7 @@ mod tests {
         let options = CompareOptions {
             case_sensitive: false,
             trim_spaces: true,
+            ..CompareOptions::default()
         };
         let result = compare_lists(&list1, &list2, options);
 
@@ -199,6 +494,38 @@ mod tests {
         assert_eq!(result.intersection.len(), 2);
     }
 
+    #[test]
+    fn test_compare_trim_set() {
+        let options = CompareOptions {
+            trim_set: TrimSet::new(true, false, true),
+            ..CompareOptions::default()
+        };
+        let result = compare_lists(&["\"apple\",", "pear."], &["apple", "'pear'"], options);
+        assert_eq!(result.intersection, vec!["\"apple\",", "pear."]);
+
+        let untrimmed = CompareOptions {
+            trim_spaces: false,
+            ..options
+        };
+        let result = compare_lists(&["\"apple\","], &["apple"], untrimmed);
+        assert!(result.intersection.is_empty());
+    }
+
+    #[test]
+    fn test_compare_fold_typography() {
+        let list1 = vec!["don’t", "“yes”"];
+        let list2 = vec!["don't", "\"yes\"\u{00A0}"];
+        let plain = compare_lists(&list1, &list2, CompareOptions::default());
+        assert!(plain.intersection.is_empty());
+
+        let options = CompareOptions {
+            fold_typography: true,
+            ..CompareOptions::default()
+        };
+        let folded = compare_lists(&list1, &list2, options);
+        assert_eq!(folded.intersection.len(), 2);
+    }
+
     #[test]
     fn test_compare_numeric_sorting() {
         // Test that numeric results are sorted numerically, not alphabetically
@@ -223,5 +550,127 @@ mod tests {
 
         // Union should be sorted numerically: 4, 5, 9, 10, 11, 12
         assert_eq!(result.union, vec!["4", "5", "9", "10", "11", "12"]);
+
+        // Unless the input order is kept
+        let options = CompareOptions {
+            preserve_order: true,
+            ..CompareOptions::default()
+        };
+        let result = compare_lists(&list1, &list2, options);
+        assert_eq!(result.only_in_first, vec!["10", "11", "4"]);
+        assert_eq!(result.union, vec!["10", "9", "11", "4", "5", "12"]);
+    }
+
+    #[test]
+    fn test_compare_reports_progress_and_cancels() {
+        let list1 = vec!["a", "b"];
+        let list2 = vec!["b", "c"];
+        let options = CompareOptions::default();
+        let mut steps = Vec::new();
+        let result = compare_lists_with_progress(&list1, &list2, options, &mut |done, total| {
+            steps.push((done, total));
+            true
+        });
+        assert_eq!(result.unwrap().intersection, vec!["b"]);
+        assert_eq!(steps.len(), COMPARE_STEPS);
+        assert_eq!(steps.last(), Some(&(COMPARE_STEPS, COMPARE_STEPS)));
+
+        let result = compare_lists_with_progress(&list1, &list2, options, &mut |done, _| done < 2);
+        assert!(result.is_none());
+    }
+
+    #[test]
+    fn test_compare_stats_ratios() {
+        let list1 = vec![
+            "a".to_string(),
+            "b".to_string(),
+            "B".to_string(),
+            "c".to_string(),
+        ];
+        let list2 = vec!["b".to_string(), "d".to_string()];
+        let result = compare_lists(&list1, &list2, CompareOptions::default());
+
+        assert_eq!(
+            result.stats,
+            CompareStats {
+                first_unique: 3,
+                second_unique: 2,
+                shared: 1,
+                combined: 4,
+            }
+        );
+        assert!((result.stats.first_in_second() - 1.0 / 3.0).abs() < 1e-9);
+        assert_eq!(result.stats.second_in_first(), 0.5);
+        assert_eq!(result.stats.overlap(), 0.25);
+        assert_eq!(CompareStats::default().overlap(), 0.0);
+    }
+
+    #[test]
+    fn test_sample_compare_estimates_overlap() {
+        let list1: Vec<String> = (0..20_000).map(|i| format!("item{}", i)).collect();
+        let list2: Vec<String> = (10_000..30_000).map(|i| format!("item{}", i)).collect();
+        let estimate = sample_compare(&list1, &list2, CompareOptions::default(), 2_000);
+
+        assert_eq!(estimate.rate, 10);
+        assert!(estimate.sampled < 6_000);
+        assert!((estimate.stats.first_in_second() - 0.5).abs() < 0.1);
+        assert!((estimate.stats.overlap() - 1.0 / 3.0).abs() < 0.1);
+    }
+
+    #[test]
+    fn test_sample_compare_reports_progress_and_cancels() {
+        let list1: Vec<String> = (0..20_000).map(|i| format!("item{}", i)).collect();
+        let list2 = list1.clone();
+        let options = CompareOptions::default();
+        let mut reports = Vec::new();
+        let estimate =
+            sample_compare_with_progress(&list1, &list2, options, 2_000, &mut |done, total| {
+                reports.push((done, total));
+                true
+            });
+        assert_eq!(
+            estimate,
+            Some(sample_compare(&list1, &list2, options, 2_000))
+        );
+        assert_eq!(
+            reports,
+            vec![
+                (10_000, 40_000),
+                (20_000, 40_000),
+                (30_000, 40_000),
+                (40_000, 40_000)
+            ]
+        );
+
+        let cancelled =
+            sample_compare_with_progress(&list1, &list2, options, 2_000, &mut |_, _| false);
+        assert_eq!(cancelled, None);
+    }
+
+    #[test]
+    fn test_sample_compare_small_lists_are_exact() {
+        let list1 = vec!["a".to_string(), "b".to_string()];
+        let list2 = vec!["B".to_string(), "c".to_string()];
+        let options = CompareOptions::default();
+        let estimate = sample_compare(&list1, &list2, options, 10_000);
+
+        assert_eq!(estimate.rate, 1);
+        assert_eq!(estimate.stats, compare_lists(&list1, &list2, options).stats);
+    }
+
+    #[test]
+    fn test_filter_by_list() {
+        let list = vec!["b", "A", "c", "a", " d "];
+        let other = vec!["a", "d"];
+        let options = CompareOptions::default();
+
+        assert_eq!(
+            filter_by_list(&list, &other, ListFilter::Remove, options),
+            vec!["b", "c"]
+        );
+        assert_eq!(
+            filter_by_list(&list, &other, ListFilter::Keep, options),
+            vec!["A", "a", " d "]
+        );
     }
 }
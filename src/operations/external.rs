@@ -0,0 +1,420 @@
+//! Disk-backed comparison for lists larger than memory
+//!
+//! Each input file is streamed item by item, cut into runs of
+//! [`SpillConfig::chunk_items`] items that are sorted by normalized value and
+//! spilled to temporary files. The runs of each list are then merged and the
+//! two sorted streams are merge-joined, writing every result category straight
+//! to its output file. Memory use is bounded by the chunk size (plus the items
+//! sharing one normalized value), not by the size of the inputs.
+use super::compare::{normalize_item, CompareOptions, CompareStats};
+use crate::parser::Delimiter;
+use std::cmp::Reverse;
+use std::collections::{BTreeSet, BinaryHeap};
+use std::env;
+use std::fs::{self, File};
+use std::io::{self, BufRead, BufReader, BufWriter, Read, Write};
+use std::path::{Path, PathBuf};
+use std::process;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// Default number of items sorted in memory before a run is spilled to disk
+pub const DEFAULT_CHUNK_ITEMS: usize = 1_000_000;
+
+/// Where and in which batch size the spill mode keeps its temporary runs
+#[derive(Debug, Clone)]
+pub struct SpillConfig {
+    /// Items sorted in memory per temporary run
+    pub chunk_items: usize,
+    /// Directory holding the temporary runs, created if missing (the runs are
+    /// removed when the comparison ends)
+    pub temp_dir: PathBuf,
+}
+
+impl Default for SpillConfig {
+    fn default() -> Self {
+        Self {
+            chunk_items: DEFAULT_CHUNK_ITEMS,
+            temp_dir: env::temp_dir(),
+        }
+    }
+}
+
+/// Files the result categories are written to (one item per line)
+#[derive(Debug, Clone)]
+pub struct SpillOutputs {
+    /// Items only in the first list
+    pub only_in_first: PathBuf,
+    /// Items only in the second list
+    pub only_in_second: PathBuf,
+    /// Items of the first list also present in the second
+    pub intersection: PathBuf,
+    /// All unique items from both lists
+    pub union: PathBuf,
+}
+
+/// Item counts of a disk-backed comparison (the items themselves are in [`SpillOutputs`])
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SpillSummary {
+    /// Items written to the only-in-first file
+    pub only_in_first: usize,
+    /// Items written to the only-in-second file
+    pub only_in_second: usize,
+    /// Items written to the intersection file
+    pub intersection: usize,
+    /// Items written to the union file
+    pub union: usize,
+    /// Distinct counts for coverage and overlap ratios
+    pub stats: CompareStats,
+}
+
+/// Compare two list files without loading them into memory
+///
+/// Results match [`compare_lists`](super::compare_lists) except for ordering:
+/// every output file is sorted by normalized value instead of numerically.
+///
+/// # Arguments
+/// * `first` - File holding the first list
+/// * `second` - File holding the second list
+/// * `delimiter` - Item delimiter of both files (JSON is not supported)
+/// * `options` - Comparison options
+/// * `outputs` - Files to write the result categories to
+/// * `config` - Chunk size and temporary directory
+pub fn compare_files_external(
+    first: &Path,
+    second: &Path,
+    delimiter: Delimiter,
+    options: CompareOptions,
+    outputs: &SpillOutputs,
+    config: &SpillConfig,
+) -> Result<SpillSummary, io::Error> {
+    if delimiter.is_quoted()
+        || !delimiter
+            .field_separator()
+            .is_some_and(|sep| sep.is_ascii())
+    {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "JSON input, quoted CSV and multi-byte delimiters cannot be compared in disk-backed mode",
+        ));
+    }
+    fs::create_dir_all(&config.temp_dir)?;
+    let runs1 = spill_sorted_runs(first, delimiter, options, config)?;
+    let runs2 = spill_sorted_runs(second, delimiter, options, config)?;
+
+    let mut only_in_first = ItemWriter::create(&outputs.only_in_first)?;
+    let mut only_in_second = ItemWriter::create(&outputs.only_in_second)?;
+    let mut intersection = ItemWriter::create(&outputs.intersection)?;
+    let mut union = ItemWriter::create(&outputs.union)?;
+
+    let mut left = MergedRuns::open(&runs1)?;
+    let mut right = MergedRuns::open(&runs2)?;
+    let mut stats = CompareStats::default();
+
+    loop {
+        let key = match (left.peek_key(), right.peek_key()) {
+            (None, None) => break,
+            (Some(a), None) => a.to_string(),
+            (None, Some(b)) => b.to_string(),
+            (Some(a), Some(b)) => a.min(b).to_string(),
+        };
+        let group1 = left.take_group(&key)?;
+        let group2 = right.take_group(&key)?;
+
+        stats.combined += 1;
+        match (group1.is_empty(), group2.is_empty()) {
+            (false, false) => {
+                stats.first_unique += 1;
+                stats.second_unique += 1;
+                stats.shared += 1;
+                intersection.write_all(&group1)?;
+            }
+            (false, true) => {
+                stats.first_unique += 1;
+                only_in_first.write_all(&group1)?;
+            }
+            _ => {
+                stats.second_unique += 1;
+                only_in_second.write_all(&group2)?;
+            }
+        }
+        let distinct: BTreeSet<&String> = group1.iter().chain(&group2).collect();
+        union.write_all(distinct)?;
+    }
+
+    Ok(SpillSummary {
+        only_in_first: only_in_first.finish()?,
+        only_in_second: only_in_second.finish()?,
+        intersection: intersection.finish()?,
+        union: union.finish()?,
+        stats,
+    })
+}
+
+/// Stream the items of a file, split on a single-byte delimiter
+fn read_items(
+    path: &Path,
+    delimiter: Delimiter,
+    mut each: impl FnMut(String) -> Result<(), io::Error>,
+) -> Result<(), io::Error> {
+    let mut reader = BufReader::new(File::open(path)?);
+    let separator = delimiter.as_char() as u8;
+    let mut buffer = Vec::new();
+    loop {
+        buffer.clear();
+        if reader.read_until(separator, &mut buffer)? == 0 {
+            return Ok(());
+        }
+        if buffer.last() == Some(&separator) {
+            buffer.pop();
+        }
+        if delimiter == Delimiter::Newline && buffer.last() == Some(&b'\r') {
+            buffer.pop();
+        }
+        each(String::from_utf8_lossy(&buffer).into_owned())?;
+    }
+}
+
+/// Split a list file into sorted runs of at most `chunk_items` (key, item) records
+fn spill_sorted_runs(
+    path: &Path,
+    delimiter: Delimiter,
+    options: CompareOptions,
+    config: &SpillConfig,
+) -> Result<Vec<TempRun>, io::Error> {
+    let chunk_items = config.chunk_items.max(1);
+    let mut runs = Vec::new();
+    let mut chunk: Vec<(String, String)> = Vec::with_capacity(chunk_items.min(65_536));
+
+    read_items(path, delimiter, |item| {
+        chunk.push((normalize_item(&item, options), item));
+        if chunk.len() >= chunk_items {
+            runs.push(TempRun::write(&mut chunk, &config.temp_dir)?);
+        }
+        Ok(())
+    })?;
+    if !chunk.is_empty() {
+        runs.push(TempRun::write(&mut chunk, &config.temp_dir)?);
+    }
+    Ok(runs)
+}
+
+static RUN_COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+/// A sorted run on disk, deleted when dropped
+struct TempRun {
+    path: PathBuf,
+}
+
+impl TempRun {
+    /// Sort the records and write them to a new temporary file, emptying `records`
+    fn write(records: &mut Vec<(String, String)>, dir: &Path) -> Result<Self, io::Error> {
+        records.sort_unstable();
+        let run = Self {
+            path: dir.join(format!(
+                "list-utils-{}-{}.run",
+                process::id(),
+                RUN_COUNTER.fetch_add(1, Ordering::Relaxed)
+            )),
+        };
+        let mut writer = BufWriter::new(File::create(&run.path)?);
+        for (key, item) in records.drain(..) {
+            write_field(&mut writer, &key)?;
+            write_field(&mut writer, &item)?;
+        }
+        writer.flush()?;
+        Ok(run)
+    }
+}
+
+impl Drop for TempRun {
+    fn drop(&mut self) {
+        let _ = fs::remove_file(&self.path);
+    }
+}
+
+/// Length-prefixed field, so items may contain any character
+fn write_field(writer: &mut impl Write, field: &str) -> Result<(), io::Error> {
+    writer.write_all(&(field.len() as u64).to_le_bytes())?;
+    writer.write_all(field.as_bytes())
+}
+
+fn read_field(reader: &mut impl Read) -> Result<Option<String>, io::Error> {
+    let mut len = [0u8; 8];
+    match reader.read_exact(&mut len) {
+        Ok(()) => {}
+        Err(e) if e.kind() == io::ErrorKind::UnexpectedEof => return Ok(None),
+        Err(e) => return Err(e),
+    }
+    let mut bytes = vec![0u8; u64::from_le_bytes(len) as usize];
+    reader.read_exact(&mut bytes)?;
+    String::from_utf8(bytes)
+        .map(Some)
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+}
+
+fn read_record(reader: &mut impl Read) -> Result<Option<(String, String)>, io::Error> {
+    let Some(key) = read_field(reader)? else {
+        return Ok(None);
+    };
+    let item = read_field(reader)?
+        .ok_or_else(|| io::Error::new(io::ErrorKind::UnexpectedEof, "truncated spill run"))?;
+    Ok(Some((key, item)))
+}
+
+/// K-way merge of sorted runs into one sorted stream of (key, item) records
+struct MergedRuns {
+    readers: Vec<BufReader<File>>,
+    heap: BinaryHeap<Reverse<(String, String, usize)>>,
+}
+
+impl MergedRuns {
+    fn open(runs: &[TempRun]) -> Result<Self, io::Error> {
+        let mut merged = Self {
+            readers: Vec::with_capacity(runs.len()),
+            heap: BinaryHeap::with_capacity(runs.len()),
+        };
+        for (index, run) in runs.iter().enumerate() {
+            merged.readers.push(BufReader::new(File::open(&run.path)?));
+            merged.refill(index)?;
+        }
+        Ok(merged)
+    }
+
+    fn refill(&mut self, index: usize) -> Result<(), io::Error> {
+        if let Some((key, item)) = read_record(&mut self.readers[index])? {
+            self.heap.push(Reverse((key, item, index)));
+        }
+        Ok(())
+    }
+
+    fn peek_key(&self) -> Option<&str> {
+        self.heap.peek().map(|Reverse((key, _, _))| key.as_str())
+    }
+
+    /// Pop every item whose normalized value equals `key`
+    fn take_group(&mut self, key: &str) -> Result<Vec<String>, io::Error> {
+        let mut group = Vec::new();
+        while self.peek_key() == Some(key) {
+            let Some(Reverse((_, item, index))) = self.heap.pop() else {
+                break;
+            };
+            group.push(item);
+            self.refill(index)?;
+        }
+        Ok(group)
+    }
+}
+
+/// Buffered output file that counts the items written to it
+struct ItemWriter {
+    writer: BufWriter<File>,
+    count: usize,
+}
+
+impl ItemWriter {
+    fn create(path: &Path) -> Result<Self, io::Error> {
+        Ok(Self {
+            writer: BufWriter::new(File::create(path)?),
+            count: 0,
+        })
+    }
+
+    fn write_all<S: AsRef<str>>(
+        &mut self,
+        items: impl IntoIterator<Item = S>,
+    ) -> Result<(), io::Error> {
+        for item in items {
+            self.writer.write_all(item.as_ref().as_bytes())?;
+            self.writer.write_all(b"\n")?;
+            self.count += 1;
+        }
+        Ok(())
+    }
+
+    fn finish(mut self) -> Result<usize, io::Error> {
+        self.writer.flush()?;
+        Ok(self.count)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::operations::compare_lists;
+
+    fn scratch_dir(name: &str) -> PathBuf {
+        let dir = env::temp_dir().join(format!("list-utils-test-{}-{}", name, process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    fn read_sorted(path: &Path) -> Vec<String> {
+        let mut items: Vec<String> = fs::read_to_string(path)
+            .unwrap()
+            .lines()
+            .map(str::to_string)
+            .collect();
+        items.sort();
+        items
+    }
+
+    #[test]
+    fn test_external_matches_in_memory_compare() {
+        let dir = scratch_dir("external");
+        let list1 = ["b", "a", "A", "c", " d ", "a", "x"];
+        let list2 = ["D", "c", "e", "B", "z"];
+        fs::write(dir.join("one.txt"), list1.join("\r\n")).unwrap();
+        fs::write(dir.join("two.txt"), list2.join("\n") + "\n").unwrap();
+
+        let outputs = SpillOutputs {
+            only_in_first: dir.join("only1.txt"),
+            only_in_second: dir.join("only2.txt"),
+            intersection: dir.join("inter.txt"),
+            union: dir.join("union.txt"),
+        };
+        let config = SpillConfig {
+            chunk_items: 2,
+            temp_dir: dir.clone(),
+        };
+        let options = CompareOptions::default();
+        let summary = compare_files_external(
+            &dir.join("one.txt"),
+            &dir.join("two.txt"),
+            Delimiter::Newline,
+            options,
+            &outputs,
+            &config,
+        )
+        .unwrap();
+
+        let to_vec = |items: &[&str]| items.iter().map(|s| s.to_string()).collect::<Vec<_>>();
+        let expected = compare_lists(&to_vec(&list1), &to_vec(&list2), options);
+        let sorted = |mut items: Vec<String>| {
+            items.sort();
+            items
+        };
+        assert_eq!(
+            read_sorted(&outputs.only_in_first),
+            sorted(expected.only_in_first)
+        );
+        assert_eq!(
+            read_sorted(&outputs.only_in_second),
+            sorted(expected.only_in_second)
+        );
+        assert_eq!(
+            read_sorted(&outputs.intersection),
+            sorted(expected.intersection)
+        );
+        assert_eq!(read_sorted(&outputs.union), sorted(expected.union));
+        assert_eq!(summary.stats, expected.stats);
+        assert_eq!(summary.union, 10);
+
+        // Temporary runs are cleaned up
+        let leftovers = fs::read_dir(&dir)
+            .unwrap()
+            .filter(|e| e.as_ref().unwrap().path().extension() == Some("run".as_ref()))
+            .count();
+        assert_eq!(leftovers, 0);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,110 @@
+//! Read files as they were at a git revision
+//!
+//! Runs `git show REV:./FILE` from the file's directory, so any path inside a
+//! work tree works without knowing where the repository root is.
+use std::io;
+use std::path::Path;
+use std::process::Command;
+
+/// Revisions loaded when the prompt names only the file
+pub const DEFAULT_REVISIONS: [&str; 2] = ["HEAD~1", "HEAD"];
+
+/// Content of `path` at `revision` (a commit, branch, tag or `HEAD~n`)
+///
+/// # Errors
+/// Fails when git cannot be run, or with git's own message when the path is
+/// not in a repository or did not exist at that revision.
+pub fn git_show(path: &Path, revision: &str) -> io::Result<Vec<u8>> {
+    let name = path
+        .file_name()
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "not a file path"))?;
+    let dir = match path.parent() {
+        Some(dir) if !dir.as_os_str().is_empty() => dir,
+        _ => Path::new("."),
+    };
+    let output = Command::new("git")
+        .arg("-C")
+        .arg(dir)
+        .arg("show")
+        .arg(format!("{}:./{}", revision, name.to_string_lossy()))
+        .output()?;
+    if output.status.success() {
+        Ok(output.stdout)
+    } else {
+        let message = String::from_utf8_lossy(&output.stderr);
+        Err(io::Error::other(message.trim().to_string()))
+    }
+}
+
+/// Split a prompt answer `PATH [REV1 [REV2]]` into the path and two revisions
+///
+/// Missing revisions default to [`DEFAULT_REVISIONS`]; with a single revision
+/// it is compared against `HEAD`.
+pub fn parse_git_request(answer: &str) -> Option<(&str, [&str; 2])> {
+    let mut words = answer.split_whitespace();
+    let path = words.next()?;
+    let revisions = match (words.next(), words.next()) {
+        (Some(first), Some(second)) => [first, second],
+        (Some(first), None) => [first, DEFAULT_REVISIONS[1]],
+        _ => DEFAULT_REVISIONS,
+    };
+    words.next().is_none().then_some((path, revisions))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+    use std::fs;
+
+    fn git(dir: &Path, args: &[&str]) -> bool {
+        Command::new("git")
+            .arg("-C")
+            .arg(dir)
+            .args(["-c", "user.name=test", "-c", "user.email=test@example.com"])
+            .args(args)
+            .output()
+            .is_ok_and(|output| output.status.success())
+    }
+
+    #[test]
+    fn test_parse_git_request() {
+        assert_eq!(
+            parse_git_request(" ids.txt "),
+            Some(("ids.txt", ["HEAD~1", "HEAD"]))
+        );
+        assert_eq!(
+            parse_git_request("ids.txt v1.0"),
+            Some(("ids.txt", ["v1.0", "HEAD"]))
+        );
+        assert_eq!(
+            parse_git_request("ids.txt main~3 main"),
+            Some(("ids.txt", ["main~3", "main"]))
+        );
+        assert_eq!(parse_git_request(""), None);
+        assert_eq!(parse_git_request("a b c d"), None);
+    }
+
+    #[test]
+    fn test_git_show_reads_revisions() {
+        let dir = env::temp_dir().join(format!("list-utils-git-{}", std::process::id()));
+        fs::create_dir_all(dir.join("data")).unwrap();
+        // Skip where git is not installed
+        if !git(&dir, &["init", "-q"]) {
+            fs::remove_dir_all(dir).unwrap();
+            return;
+        }
+        let file = dir.join("data").join("ids.txt");
+        fs::write(&file, "a\nb\n").unwrap();
+        assert!(git(&dir, &["add", "."]));
+        assert!(git(&dir, &["commit", "-qm", "first"]));
+        fs::write(&file, "b\nc\n").unwrap();
+        assert!(git(&dir, &["commit", "-qam", "second"]));
+
+        assert_eq!(git_show(&file, "HEAD~1").unwrap(), b"a\nb\n");
+        assert_eq!(git_show(&file, "HEAD").unwrap(), b"b\nc\n");
+        assert!(git_show(&file, "HEAD~5").is_err());
+        assert!(git_show(&dir.join("data").join("missing.txt"), "HEAD").is_err());
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
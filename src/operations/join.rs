@@ -0,0 +1,302 @@
+//! Join and zip two lists of delimited records
+use super::compare::{normalize_item, CompareOptions};
+use std::collections::{HashMap, HashSet};
+
+/// How rows of the two lists are combined
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum JoinType {
+    /// Only rows whose key is in both lists
+    #[default]
+    Inner,
+    /// Every List 1 row, with List 2 fields when the key matches
+    Left,
+    /// Every List 2 row, with List 1 fields when the key matches
+    Right,
+    /// Every row of both lists, matched where possible
+    Full,
+    /// Row N of List 1 next to row N of List 2, ignoring keys
+    Zip,
+}
+
+impl JoinType {
+    /// The join type `t` switches to next
+    pub fn next(self) -> Self {
+        match self {
+            JoinType::Inner => JoinType::Left,
+            JoinType::Left => JoinType::Right,
+            JoinType::Right => JoinType::Full,
+            JoinType::Full => JoinType::Zip,
+            JoinType::Zip => JoinType::Inner,
+        }
+    }
+
+    /// Whether rows are matched by key (every type but [`JoinType::Zip`])
+    pub fn uses_keys(self) -> bool {
+        self != JoinType::Zip
+    }
+}
+
+/// Settings of a join
+#[derive(Debug, Clone, Copy)]
+pub struct JoinOptions {
+    /// How rows are combined
+    pub join_type: JoinType,
+    /// Key column of List 1 (0-based)
+    pub left_key: usize,
+    /// Key column of List 2 (0-based)
+    pub right_key: usize,
+    /// Field separator of both lists and of the joined rows
+    pub separator: char,
+    /// Case sensitivity and trimming applied when matching keys
+    pub compare: CompareOptions,
+}
+
+/// Joined rows and how many rows of each list found a partner
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct JoinResult {
+    /// Joined rows, fields separated by the join separator
+    pub rows: Vec<String>,
+    /// Rows built from a List 1 row and a List 2 row
+    pub matched: usize,
+    /// List 1 rows without a partner
+    pub left_only: usize,
+    /// List 2 rows without a partner
+    pub right_only: usize,
+}
+
+/// Join two lists of records on a key column, or zip them row by row
+///
+/// The output holds every List 1 field followed by the List 2 fields except
+/// its key column; a missing side is filled with empty fields so all rows
+/// have the same width. Several matches for one key produce one row per pair.
+/// Rows whose key column is missing never match.
+pub fn join_lists<S: AsRef<str>>(left: &[S], right: &[S], options: &JoinOptions) -> JoinResult {
+    let sep = options.separator;
+    let split = |items: &[S]| -> Vec<Vec<String>> {
+        items
+            .iter()
+            .map(|item| item.as_ref().split(sep).map(str::to_string).collect())
+            .collect()
+    };
+    let left = split(left);
+    let right = split(right);
+    let left_width = left.iter().map(Vec::len).max().unwrap_or(0);
+    let right_width = right.iter().map(Vec::len).max().unwrap_or(0);
+    let row = Row {
+        left_width,
+        right_width,
+        left_key: options.left_key,
+        right_key: options.right_key,
+        separator: sep,
+    };
+
+    let mut result = JoinResult::default();
+    if options.join_type == JoinType::Zip {
+        for i in 0..left.len().max(right.len()) {
+            let (l, r) = (left.get(i), right.get(i));
+            match (l, r) {
+                (Some(_), Some(_)) => result.matched += 1,
+                (Some(_), None) => result.left_only += 1,
+                _ => result.right_only += 1,
+            }
+            result.rows.push(row.zip(l, r));
+        }
+        return result;
+    }
+
+    let key = |fields: &[String], index: usize| {
+        fields
+            .get(index)
+            .map(|field| normalize_item(field, options.compare))
+    };
+    let index_by_key = |rows: &[Vec<String>], column: usize| {
+        let mut index: HashMap<String, Vec<usize>> = HashMap::new();
+        for (i, fields) in rows.iter().enumerate() {
+            if let Some(k) = key(fields, column) {
+                index.entry(k).or_default().push(i);
+            }
+        }
+        index
+    };
+
+    if options.join_type == JoinType::Right {
+        // Walk List 2 so its order is kept
+        let left_by_key = index_by_key(&left, options.left_key);
+        let mut matched_left = HashSet::new();
+        for fields in &right {
+            match key(fields, options.right_key).and_then(|k| left_by_key.get(&k)) {
+                Some(indexes) => {
+                    for &l in indexes {
+                        matched_left.insert(l);
+                        result.rows.push(row.join(Some(&left[l]), Some(fields)));
+                        result.matched += 1;
+                    }
+                }
+                None => {
+                    result.right_only += 1;
+                    result.rows.push(row.join(None, Some(fields)));
+                }
+            }
+        }
+        result.left_only = left.len() - matched_left.len();
+        return result;
+    }
+
+    let right_by_key = index_by_key(&right, options.right_key);
+    let mut matched_right = HashSet::new();
+    for fields in &left {
+        match key(fields, options.left_key).and_then(|k| right_by_key.get(&k)) {
+            Some(indexes) => {
+                for &r in indexes {
+                    matched_right.insert(r);
+                    result.rows.push(row.join(Some(fields), Some(&right[r])));
+                    result.matched += 1;
+                }
+            }
+            None => {
+                result.left_only += 1;
+                if options.join_type != JoinType::Inner {
+                    result.rows.push(row.join(Some(fields), None));
+                }
+            }
+        }
+    }
+    for (i, fields) in right.iter().enumerate() {
+        if !matched_right.contains(&i) {
+            result.right_only += 1;
+            if options.join_type == JoinType::Full {
+                result.rows.push(row.join(None, Some(fields)));
+            }
+        }
+    }
+
+    result
+}
+
+/// Shapes joined rows so every row has the same fields
+struct Row {
+    left_width: usize,
+    right_width: usize,
+    left_key: usize,
+    right_key: usize,
+    separator: char,
+}
+
+impl Row {
+    /// List 1 fields followed by the List 2 fields without its key column
+    ///
+    /// A missing List 1 row takes the List 2 key in its key column so the
+    /// key stays visible in full and right joins.
+    fn join(&self, left: Option<&Vec<String>>, right: Option<&Vec<String>>) -> String {
+        let mut fields = vec![String::new(); self.left_width];
+        match (left, right) {
+            (Some(l), _) => fields[..l.len()].clone_from_slice(l),
+            (None, Some(r)) => {
+                if let (Some(slot), Some(key)) =
+                    (fields.get_mut(self.left_key), r.get(self.right_key))
+                {
+                    *slot = key.clone();
+                }
+            }
+            (None, None) => {}
+        }
+        for column in (0..self.right_width).filter(|&c| c != self.right_key) {
+            fields.push(
+                right
+                    .and_then(|r| r.get(column))
+                    .cloned()
+                    .unwrap_or_default(),
+            );
+        }
+        fields.join(&self.separator.to_string())
+    }
+
+    /// All fields of both rows, padding a missing row with empty fields
+    fn zip(&self, left: Option<&Vec<String>>, right: Option<&Vec<String>>) -> String {
+        let padded = |row: Option<&Vec<String>>, width: usize| {
+            let mut fields = row.cloned().unwrap_or_default();
+            fields.resize(width, String::new());
+            fields
+        };
+        let mut fields = padded(left, self.left_width);
+        fields.extend(padded(right, self.right_width));
+        fields.join(&self.separator.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn options(join_type: JoinType) -> JoinOptions {
+        JoinOptions {
+            join_type,
+            left_key: 0,
+            right_key: 0,
+            separator: ',',
+            compare: CompareOptions::default(),
+        }
+    }
+
+    const USERS: [&str; 3] = ["1,ana", "2,bob", "3,eve"];
+    const ORDERS: [&str; 3] = ["2,book", "4,lamp", "2,pen"];
+
+    #[test]
+    fn test_inner_join() {
+        let result = join_lists(&USERS, &ORDERS, &options(JoinType::Inner));
+        assert_eq!(result.rows, vec!["2,bob,book", "2,bob,pen"]);
+        assert_eq!(
+            (result.matched, result.left_only, result.right_only),
+            (2, 2, 1)
+        );
+    }
+
+    #[test]
+    fn test_left_join() {
+        let result = join_lists(&USERS, &ORDERS, &options(JoinType::Left));
+        assert_eq!(
+            result.rows,
+            vec!["1,ana,", "2,bob,book", "2,bob,pen", "3,eve,"]
+        );
+    }
+
+    #[test]
+    fn test_right_join_keeps_list2_order() {
+        let result = join_lists(&USERS, &ORDERS, &options(JoinType::Right));
+        assert_eq!(result.rows, vec!["2,bob,book", "4,,lamp", "2,bob,pen"]);
+    }
+
+    #[test]
+    fn test_full_join() {
+        let result = join_lists(&USERS, &ORDERS, &options(JoinType::Full));
+        assert_eq!(
+            result.rows,
+            vec!["1,ana,", "2,bob,book", "2,bob,pen", "3,eve,", "4,,lamp"]
+        );
+    }
+
+    #[test]
+    fn test_zip_pads_shorter_list() {
+        let result = join_lists(&["a,1", "b,2"], &["x"], &options(JoinType::Zip));
+        assert_eq!(result.rows, vec!["a,1,x", "b,2,"]);
+        assert_eq!(
+            (result.matched, result.left_only, result.right_only),
+            (1, 1, 0)
+        );
+    }
+
+    #[test]
+    fn test_key_columns_and_compare_options() {
+        let opts = JoinOptions {
+            left_key: 1,
+            compare: CompareOptions {
+                case_sensitive: false,
+                trim_spaces: true,
+                ..CompareOptions::default()
+            },
+            ..options(JoinType::Inner)
+        };
+        let result = join_lists(&["x,Ana"], &["ana ,42", "bob,7"], &opts);
+        assert_eq!(result.rows, vec!["x,Ana,42"]);
+    }
+}
@@ -0,0 +1,664 @@
+//! Comparison of two files keyed by name, such as `.env` files and lockfiles
+//!
+//! Each side is parsed into `(key, value)` entries; keys found on one side only
+//! and keys whose values differ are reported separately, so a renamed variable
+//! and a changed setting do not look alike.
+use super::columns::detect_field_separator;
+use crate::parser::{split_key_value, split_quoted};
+use std::collections::{BTreeMap, HashMap};
+use std::net::IpAddr;
+
+/// Separator between the fields of keyed report rows
+pub const KEYED_SEPARATOR: char = '\t';
+
+/// How the lines of a keyed file are read
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum KeyedPreset {
+    /// `.env` and properties files: `KEY=VALUE` or `key: value` lines
+    #[default]
+    Env,
+    /// Python `requirements.txt`: package name and version specifier
+    Requirements,
+    /// npm `package.json`: every dependency section, name and version range
+    PackageJson,
+    /// Rust `Cargo.lock`: package name and locked version
+    CargoLock,
+    /// Hosts files and DNS zone files: name and target address or record
+    Hosts,
+    /// `id,amount` records: amounts summed per id and reconciled
+    Amounts,
+}
+
+impl KeyedPreset {
+    /// The preset to switch to next
+    pub fn next(self) -> Self {
+        match self {
+            KeyedPreset::Env => KeyedPreset::Requirements,
+            KeyedPreset::Requirements => KeyedPreset::PackageJson,
+            KeyedPreset::PackageJson => KeyedPreset::CargoLock,
+            KeyedPreset::CargoLock => KeyedPreset::Hosts,
+            KeyedPreset::Hosts => KeyedPreset::Amounts,
+            KeyedPreset::Amounts => KeyedPreset::Env,
+        }
+    }
+
+    /// Short name shown to the user
+    pub fn display_name(self) -> &'static str {
+        match self {
+            KeyedPreset::Env => ".env",
+            KeyedPreset::Requirements => "requirements.txt",
+            KeyedPreset::PackageJson => "package.json",
+            KeyedPreset::CargoLock => "Cargo.lock",
+            KeyedPreset::Hosts => "hosts/DNS",
+            KeyedPreset::Amounts => "id,amount",
+        }
+    }
+
+    /// Whether entries are packages and values their versions
+    pub fn is_lockfile(self) -> bool {
+        matches!(
+            self,
+            KeyedPreset::Requirements | KeyedPreset::PackageJson | KeyedPreset::CargoLock
+        )
+    }
+
+    /// Parse `input` into its entries, in file order
+    ///
+    /// # Errors
+    /// Fails when a `package.json` is not valid JSON.
+    pub fn parse(self, input: &str) -> Result<Vec<(String, String)>, String> {
+        match self {
+            KeyedPreset::Env => Ok(parse_env(input)),
+            KeyedPreset::Requirements => Ok(parse_requirements(input)),
+            KeyedPreset::PackageJson => parse_package_json(input),
+            KeyedPreset::CargoLock => Ok(parse_cargo_lock(input)),
+            KeyedPreset::Hosts => Ok(parse_hosts(input)),
+            KeyedPreset::Amounts => Ok(parse_amounts(input)),
+        }
+    }
+}
+
+/// Entries of a `.env` or properties file
+///
+/// Blank lines and `#` or `!` comments are skipped, an `export ` prefix is
+/// dropped and a value wrapped in matching single or double quotes is
+/// unquoted. Lines without `=` or `:` are ignored.
+pub fn parse_env(input: &str) -> Vec<(String, String)> {
+    input
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with(['#', '!']))
+        .filter_map(|line| split_key_value(line.strip_prefix("export ").unwrap_or(line)))
+        .map(|(key, value)| (key.to_string(), unquote(value).to_string()))
+        .collect()
+}
+
+/// Version operators of a requirement, longest first
+const REQUIREMENT_OPERATORS: [&str; 8] = ["===", "==", "~=", "!=", ">=", "<=", ">", "<"];
+
+/// Packages of a `requirements.txt`
+///
+/// Names are normalized as pip does (lowercase, runs of `-`, `_` and `.` as
+/// one `-`) and extras are dropped. The value is the version of a `==` pin,
+/// otherwise the whole specifier (`>=1.0,<2`), or empty when unpinned.
+/// Comments, environment markers and option lines (`-r`, `-e`, `--index-url`)
+/// are skipped.
+pub fn parse_requirements(input: &str) -> Vec<(String, String)> {
+    input
+        .lines()
+        .map(|line| line.split(" #").next().unwrap_or_default())
+        .map(|line| line.split(';').next().unwrap_or_default().trim())
+        .filter(|line| !line.is_empty() && !line.starts_with(['#', '-']))
+        .map(|line| {
+            let at = line
+                .find(|c: char| REQUIREMENT_OPERATORS.iter().any(|op| op.starts_with(c)))
+                .unwrap_or(line.len());
+            let name = line[..at].split('[').next().unwrap_or_default().trim();
+            let spec: String = line[at..].split_whitespace().collect();
+            let version = ["===", "=="]
+                .iter()
+                .find_map(|pin| spec.strip_prefix(pin).filter(|v| !v.contains(',')))
+                .map_or(spec.clone(), str::to_string);
+            (normalize_package_name(name), version)
+        })
+        .filter(|(name, _)| !name.is_empty())
+        .collect()
+}
+
+fn normalize_package_name(name: &str) -> String {
+    let mut normalized = String::with_capacity(name.len());
+    for part in name.split(['-', '_', '.']).filter(|part| !part.is_empty()) {
+        if !normalized.is_empty() {
+            normalized.push('-');
+        }
+        normalized.push_str(&part.to_lowercase());
+    }
+    normalized
+}
+
+/// Dependency sections of a `package.json`, in the order they are read
+pub const PACKAGE_JSON_SECTIONS: [&str; 4] = [
+    "dependencies",
+    "devDependencies",
+    "peerDependencies",
+    "optionalDependencies",
+];
+
+/// Dependencies of a `package.json` with their version ranges
+///
+/// # Errors
+/// Fails when the text is not a JSON object.
+pub fn parse_package_json(input: &str) -> Result<Vec<(String, String)>, String> {
+    let manifest: serde_json::Value = serde_json::from_str(input).map_err(|e| e.to_string())?;
+    if !manifest.is_object() {
+        return Err("package.json must be a JSON object".to_string());
+    }
+    Ok(PACKAGE_JSON_SECTIONS
+        .iter()
+        .filter_map(|section| manifest.get(section)?.as_object())
+        .flatten()
+        .map(|(name, version)| {
+            let version = match version.as_str() {
+                Some(text) => text.to_string(),
+                None => version.to_string(),
+            };
+            (name.clone(), version)
+        })
+        .collect())
+}
+
+/// Packages of a `Cargo.lock` with their locked versions
+///
+/// A crate locked at several versions gets them all, sorted and separated by
+/// `, `, so a new major version next to the old one shows as a change.
+pub fn parse_cargo_lock(input: &str) -> Vec<(String, String)> {
+    let mut entries = Vec::new();
+    let mut current: (Option<String>, Option<String>) = (None, None);
+    for line in input.lines().map(str::trim) {
+        if line.starts_with('[') {
+            if let (Some(name), version) = std::mem::take(&mut current) {
+                entries.push((name, version.unwrap_or_default()));
+            }
+            continue;
+        }
+        let Some((key, value)) = line.split_once('=') else {
+            continue;
+        };
+        let value = value.trim().trim_matches('"').to_string();
+        match key.trim() {
+            "name" => current.0 = Some(value),
+            "version" => current.1 = Some(value),
+            _ => {}
+        }
+    }
+    if let (Some(name), version) = current {
+        entries.push((name, version.unwrap_or_default()));
+    }
+    group_values(entries)
+}
+
+/// Record types read from zone files
+const RECORD_TYPES: [&str; 11] = [
+    "A", "AAAA", "CNAME", "MX", "NS", "PTR", "SRV", "TXT", "CAA", "SOA", "ALIAS",
+];
+
+/// Name-to-target pairs of a hosts file or DNS zone file
+///
+/// Hosts lines (`10.0.0.1 db db.local`) map every name to the address. Zone
+/// records (`www 3600 IN A 10.0.0.1`) are keyed by name and type, such as
+/// `www A`, so an address and a mail exchanger of one name are compared
+/// separately; an indented record continues the previous name. Names are
+/// lowercased without their trailing dot. A name with several targets gets
+/// them all, sorted and separated by `, `. Comments (`#`, `;`) and `$`
+/// directives are skipped.
+pub fn parse_hosts(input: &str) -> Vec<(String, String)> {
+    let mut entries = Vec::new();
+    let mut last_name = String::new();
+    for line in input.lines() {
+        let content = line.split(['#', ';']).next().unwrap_or_default();
+        let mut fields: Vec<&str> = content.split_whitespace().collect();
+        if fields.is_empty() || fields[0].starts_with('$') {
+            continue;
+        }
+        if fields[0].parse::<IpAddr>().is_ok() {
+            let target = fields[0];
+            entries.extend(
+                fields[1..]
+                    .iter()
+                    .map(|name| (dns_name(name), target.to_string())),
+            );
+            continue;
+        }
+        let continues = line.starts_with([' ', '\t']);
+        if !continues {
+            last_name = dns_name(fields.remove(0));
+        }
+        let Some(at) = fields
+            .iter()
+            .position(|field| RECORD_TYPES.contains(&field.to_ascii_uppercase().as_str()))
+        else {
+            continue;
+        };
+        let record_type = fields[at].to_ascii_uppercase();
+        let target = fields[at + 1..].join(" ");
+        let target = target.trim_end_matches('.');
+        entries.push((format!("{} {}", last_name, record_type), target.to_string()));
+    }
+    group_values(entries)
+}
+
+fn dns_name(name: &str) -> String {
+    name.trim_end_matches('.').to_lowercase()
+}
+
+/// Merge the entries of each key, at the position of its first occurrence
+///
+/// The values of a repeated key are sorted, deduplicated and joined with `, `.
+fn group_values(entries: Vec<(String, String)>) -> Vec<(String, String)> {
+    let mut values: BTreeMap<String, Vec<String>> = BTreeMap::new();
+    let mut order: Vec<String> = Vec::new();
+    for (key, value) in entries {
+        values
+            .entry(key.clone())
+            .or_insert_with(|| {
+                order.push(key);
+                Vec::new()
+            })
+            .push(value);
+    }
+    order
+        .into_iter()
+        .map(|key| {
+            let mut group = values.remove(&key).unwrap_or_default();
+            group.sort();
+            group.dedup();
+            (key, group.join(", "))
+        })
+        .collect()
+}
+
+/// Amounts are kept in millionths, so sums are exact
+const AMOUNT_SCALE: i128 = 1_000_000;
+
+/// Ids of `id,amount` records with the sum of their amounts
+///
+/// The field separator (tab, `|`, `;` or `,`) is taken from the first line and
+/// CSV quotes are honoured. The id is the first field and the amount the last;
+/// lines whose amount is not a number, such as a header, are skipped. Sums are
+/// written with [`format_amount`], so `10.5` and `10.50` are the same value.
+pub fn parse_amounts(input: &str) -> Vec<(String, String)> {
+    let lines: Vec<&str> = input
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .collect();
+    let Some(separator) = detect_field_separator(&lines) else {
+        return Vec::new();
+    };
+    let mut sums: HashMap<String, i128> = HashMap::new();
+    let mut order: Vec<String> = Vec::new();
+    for line in lines {
+        let fields = split_quoted(line, separator);
+        let (Some(id), Some(amount), true) = (fields.first(), fields.last(), fields.len() >= 2)
+        else {
+            continue;
+        };
+        let (id, Some(amount)) = (id.trim(), parse_amount(amount)) else {
+            continue;
+        };
+        if id.is_empty() {
+            continue;
+        }
+        let sum = sums.entry(id.to_string()).or_insert_with(|| {
+            order.push(id.to_string());
+            0
+        });
+        *sum += amount;
+    }
+    order
+        .into_iter()
+        .map(|id| {
+            let sum = format_amount(sums[&id]);
+            (id, sum)
+        })
+        .collect()
+}
+
+/// Amount in millionths, or `None` if `text` is not a number
+///
+/// Spaces, currency symbols and thousands separators are ignored and
+/// `(12.50)` is negative. When both `.` and `,` appear the later one is the
+/// decimal mark; a lone `,` is one too unless three digits follow it.
+pub fn parse_amount(text: &str) -> Option<i128> {
+    let mut text: String = text
+        .chars()
+        .filter(|c| !c.is_whitespace() && !"$€£¥_'\"".contains(*c))
+        .collect();
+    let mut negative = false;
+    if let Some(inner) = text.strip_prefix('(').and_then(|t| t.strip_suffix(')')) {
+        text = inner.to_string();
+        negative = true;
+    }
+    if let Some(rest) = text.strip_prefix('-') {
+        text = rest.to_string();
+        negative = !negative;
+    } else if let Some(rest) = text.strip_prefix('+') {
+        text = rest.to_string();
+    }
+    let decimal_mark = match (text.rfind('.'), text.rfind(',')) {
+        (Some(dot), Some(comma)) => Some(if dot > comma { '.' } else { ',' }),
+        (Some(_), None) if text.matches('.').count() == 1 => Some('.'),
+        (None, Some(comma)) if text.matches(',').count() == 1 && text.len() - comma != 4 => {
+            Some(',')
+        }
+        _ => None,
+    };
+    let (whole, fraction) = match decimal_mark.and_then(|mark| text.rsplit_once(mark)) {
+        Some((whole, fraction)) => (whole.replace(['.', ','], ""), fraction.to_string()),
+        None => (text.replace(['.', ','], ""), String::new()),
+    };
+    let digits = |part: &str| part.chars().all(|c| c.is_ascii_digit());
+    if (whole.is_empty() && fraction.is_empty())
+        || !digits(&whole)
+        || !digits(&fraction)
+        || fraction.len() > 6
+    {
+        return None;
+    }
+    let whole: i128 = if whole.is_empty() {
+        0
+    } else {
+        whole.parse().ok()?
+    };
+    let fraction: i128 = format!("{:0<6}", fraction).parse().ok()?;
+    let amount = whole.checked_mul(AMOUNT_SCALE)? + fraction;
+    Some(if negative { -amount } else { amount })
+}
+
+/// Write an amount in millionths with at least two decimals (`-1234.50`)
+pub fn format_amount(amount: i128) -> String {
+    let sign = if amount < 0 { "-" } else { "" };
+    let amount = amount.unsigned_abs();
+    let scale = AMOUNT_SCALE.unsigned_abs();
+    let fraction = format!("{:06}", amount % scale);
+    let fraction = fraction.trim_end_matches('0');
+    format!("{}{}.{:0<2}", sign, amount / scale, fraction)
+}
+
+fn unquote(value: &str) -> &str {
+    ['"', '\'']
+        .iter()
+        .find_map(|&quote| {
+            value
+                .strip_prefix(quote)
+                .and_then(|rest| rest.strip_suffix(quote))
+        })
+        .unwrap_or(value)
+}
+
+/// Outcome of comparing two keyed files
+#[derive(Debug, Clone)]
+pub struct KeyedReport {
+    /// How the files were read
+    pub preset: KeyedPreset,
+    /// `KEY<TAB>value` rows of keys only the first file has, in its order
+    pub missing_in_second: Vec<String>,
+    /// `KEY<TAB>value` rows of keys only the second file has, in its order
+    pub missing_in_first: Vec<String>,
+    /// `KEY<TAB>first<TAB>second` rows of keys whose values differ, in first-file
+    /// order; amount rows end with the difference (second minus first)
+    pub changed: Vec<String>,
+    /// Number of keys with the same value on both sides
+    pub unchanged: usize,
+    /// Sums of the amounts, with the [`KeyedPreset::Amounts`] preset
+    pub totals: Option<AmountTotals>,
+}
+
+/// Amount sums of a reconciliation, in millionths (see [`format_amount`])
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct AmountTotals {
+    /// Sum of the first file's amounts for ids found in both files
+    pub matched_first: i128,
+    /// Sum of the second file's amounts for ids found in both files
+    pub matched_second: i128,
+    /// Sum of the amounts of ids only the first file has
+    pub only_first: i128,
+    /// Sum of the amounts of ids only the second file has
+    pub only_second: i128,
+}
+
+impl AmountTotals {
+    /// Total of every amount in the first file
+    pub fn first(&self) -> i128 {
+        self.matched_first + self.only_first
+    }
+
+    /// Total of every amount in the second file
+    pub fn second(&self) -> i128 {
+        self.matched_second + self.only_second
+    }
+}
+
+/// Compare two keyed files by key
+///
+/// Keys are matched exactly; when a file repeats a key its last value wins,
+/// as it does when the file is loaded (amounts of a repeated id are summed
+/// when parsing instead). Reconciliations also get their [`AmountTotals`].
+///
+/// # Errors
+/// Returns the parse error of either file, as [`KeyedPreset::parse`] does.
+pub fn compare_keyed(
+    first: &str,
+    second: &str,
+    preset: KeyedPreset,
+) -> Result<KeyedReport, String> {
+    let first = last_values(preset.parse(first)?);
+    let second = last_values(preset.parse(second)?);
+    let lookup = |entries: &[(String, String)]| -> HashMap<String, String> {
+        entries.iter().cloned().collect()
+    };
+    let (first_values, second_values) = (lookup(&first), lookup(&second));
+    let row = |fields: &[&str]| fields.join(&KEYED_SEPARATOR.to_string());
+
+    let mut report = KeyedReport {
+        preset,
+        missing_in_second: Vec::new(),
+        missing_in_first: Vec::new(),
+        changed: Vec::new(),
+        unchanged: 0,
+        totals: None,
+    };
+    let mut totals = AmountTotals::default();
+    let amount = |value: &str| parse_amount(value).unwrap_or_default();
+    for (key, value) in &first {
+        match second_values.get(key) {
+            None => {
+                totals.only_first += amount(value);
+                report.missing_in_second.push(row(&[key, value]));
+            }
+            Some(other) => {
+                totals.matched_first += amount(value);
+                totals.matched_second += amount(other);
+                if other == value {
+                    report.unchanged += 1;
+                } else if preset == KeyedPreset::Amounts {
+                    let difference = format_amount(amount(other) - amount(value));
+                    report.changed.push(row(&[key, value, other, &difference]));
+                } else {
+                    report.changed.push(row(&[key, value, other]));
+                }
+            }
+        }
+    }
+    report.missing_in_first = second
+        .iter()
+        .filter(|(key, _)| !first_values.contains_key(key))
+        .map(|(key, value)| {
+            totals.only_second += amount(value);
+            row(&[key, value])
+        })
+        .collect();
+    if preset == KeyedPreset::Amounts {
+        report.totals = Some(totals);
+    }
+    Ok(report)
+}
+
+/// Keep the last entry of every key, at the position of its first occurrence
+fn last_values(entries: Vec<(String, String)>) -> Vec<(String, String)> {
+    let mut positions: HashMap<String, usize> = HashMap::new();
+    let mut unique: Vec<(String, String)> = Vec::new();
+    for (key, value) in entries {
+        match positions.get(&key) {
+            Some(&at) => unique[at].1 = value,
+            None => {
+                positions.insert(key.clone(), unique.len());
+                unique.push((key, value));
+            }
+        }
+    }
+    unique
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_env() {
+        let env = "# comment\n\nexport DB_HOST=db1\nPORT = \"5432\"\nname: 'app'\n! note\nnot a pair\nURL=http://x:80\n";
+        assert_eq!(
+            parse_env(env),
+            vec![
+                ("DB_HOST".to_string(), "db1".to_string()),
+                ("PORT".to_string(), "5432".to_string()),
+                ("name".to_string(), "app".to_string()),
+                ("URL".to_string(), "http://x:80".to_string()),
+            ]
+        );
+        assert_eq!(
+            parse_env("EMPTY=\nQUOTE=\"a'"),
+            vec![
+                ("EMPTY".to_string(), String::new()),
+                ("QUOTE".to_string(), "\"a'".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_compare_keyed() {
+        let first = "A=1\nB=2\nC=3\nB=4\n";
+        let second = "C=3\nB=5\nD=6\n";
+        let report = compare_keyed(first, second, KeyedPreset::Env).unwrap();
+        assert_eq!(report.missing_in_second, vec!["A\t1"]);
+        assert_eq!(report.missing_in_first, vec!["D\t6"]);
+        assert_eq!(report.changed, vec!["B\t4\t5"]);
+        assert_eq!(report.unchanged, 1);
+    }
+
+    #[test]
+    fn test_parse_requirements() {
+        let requirements = "# deps\nDjango==4.2.1\nrequests[socks] >= 2.0, < 3 ; python_version > \"3.8\"\n-r base.txt\nzope.interface\nPyYAML==6.0 # pinned\n";
+        assert_eq!(
+            parse_requirements(requirements),
+            vec![
+                ("django".to_string(), "4.2.1".to_string()),
+                ("requests".to_string(), ">=2.0,<3".to_string()),
+                ("zope-interface".to_string(), String::new()),
+                ("pyyaml".to_string(), "6.0".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_package_json() {
+        let manifest = r#"{"name": "app", "dependencies": {"react": "^18.2.0"},
+            "devDependencies": {"jest": "29.0.0"}}"#;
+        assert_eq!(
+            parse_package_json(manifest).unwrap(),
+            vec![
+                ("react".to_string(), "^18.2.0".to_string()),
+                ("jest".to_string(), "29.0.0".to_string()),
+            ]
+        );
+        assert!(parse_package_json("[]").is_err());
+        assert!(parse_package_json("react=1").is_err());
+    }
+
+    #[test]
+    fn test_compare_cargo_lock() {
+        let old = "version = 3\n\n[[package]]\nname = \"serde\"\nversion = \"1.0.1\"\n\n[[package]]\nname = \"syn\"\nversion = \"1.0.0\"\ndependencies = [\n \"quote\",\n]\n\n[[package]]\nname = \"rand\"\nversion = \"0.8.5\"\n";
+        let new = "[[package]]\nname = \"syn\"\nversion = \"2.0.0\"\n\n[[package]]\nname = \"syn\"\nversion = \"1.0.0\"\n\n[[package]]\nname = \"serde\"\nversion = \"1.0.1\"\n\n[[package]]\nname = \"regex\"\nversion = \"1.10.0\"\n";
+        let report = compare_keyed(old, new, KeyedPreset::CargoLock).unwrap();
+        assert_eq!(report.missing_in_second, vec!["rand\t0.8.5"]);
+        assert_eq!(report.missing_in_first, vec!["regex\t1.10.0"]);
+        assert_eq!(report.changed, vec!["syn\t1.0.0\t1.0.0, 2.0.0"]);
+        assert_eq!(report.unchanged, 1);
+    }
+
+    #[test]
+    fn test_parse_hosts_and_zones() {
+        let hosts =
+            "# local\n127.0.0.1 localhost\n10.0.0.5  db  DB.internal. # primary\n::1 localhost\n";
+        assert_eq!(
+            parse_hosts(hosts),
+            vec![
+                ("localhost".to_string(), "127.0.0.1, ::1".to_string()),
+                ("db".to_string(), "10.0.0.5".to_string()),
+                ("db.internal".to_string(), "10.0.0.5".to_string()),
+            ]
+        );
+        let zone = "$ORIGIN example.com.\n$TTL 3600\n@ IN MX 10 mail.example.com.\nwww 300 IN A 192.0.2.1\n    IN AAAA 2001:db8::1 ; v6\nMail CNAME www\n";
+        assert_eq!(
+            parse_hosts(zone),
+            vec![
+                ("@ MX".to_string(), "10 mail.example.com".to_string()),
+                ("www A".to_string(), "192.0.2.1".to_string()),
+                ("www AAAA".to_string(), "2001:db8::1".to_string()),
+                ("mail CNAME".to_string(), "www".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_amount() {
+        let amounts = [
+            ("12", 12_000_000),
+            ("-3.5", -3_500_000),
+            ("(12.50)", -12_500_000),
+            ("$1,234.56", 1_234_560_000),
+            ("1.234,56 €", 1_234_560_000),
+            ("1,234", 1_234_000_000),
+            ("0,5", 500_000),
+            (".25", 250_000),
+        ];
+        for (text, amount) in amounts {
+            assert_eq!(parse_amount(text), Some(amount), "{}", text);
+        }
+        for invalid in ["amount", "", "1.2.3x", "0.0000001"] {
+            assert_eq!(parse_amount(invalid), None, "{}", invalid);
+        }
+        assert_eq!(format_amount(-12_500_000), "-12.50");
+        assert_eq!(format_amount(1_234_567), "1.234567");
+        assert_eq!(format_amount(0), "0.00");
+    }
+
+    #[test]
+    fn test_reconcile_amounts() {
+        let ledger = "id,amount\nA1,10.5\nA2,\"1,000.00\"\nA1,4.5\nA3,2\n";
+        let bank = "id;amount\nA1;15\nA2;999,99\nA4;7\n";
+        let report = compare_keyed(ledger, bank, KeyedPreset::Amounts).unwrap();
+        assert_eq!(report.missing_in_second, vec!["A3\t2.00"]);
+        assert_eq!(report.missing_in_first, vec!["A4\t7.00"]);
+        assert_eq!(report.changed, vec!["A2\t1000.00\t999.99\t-0.01"]);
+        assert_eq!(report.unchanged, 1);
+        let totals = report.totals.unwrap();
+        assert_eq!(format_amount(totals.matched_first), "1015.00");
+        assert_eq!(format_amount(totals.matched_second), "1014.99");
+        assert_eq!(format_amount(totals.first()), "1017.00");
+        assert_eq!(format_amount(totals.second()), "1021.99");
+        assert!(compare_keyed("A=1", "A=1", KeyedPreset::Env)
+            .unwrap()
+            .totals
+            .is_none());
+    }
+}
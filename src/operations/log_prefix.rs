@@ -0,0 +1,145 @@
+//! Stripping of log line prefixes so two log extracts can be compared by message only
+//!
+//! Each preset is a named regular expression matching one prefix (a timestamp,
+//! a log level, a thread id...). Presets are applied repeatedly from the start
+//! of the line, in any order, until none matches.
+use regex::Regex;
+
+/// Built-in `(name, pattern)` presets, used unless the config file overrides them
+pub const DEFAULT_LOG_PRESETS: [(&str, &str); 3] = [
+    (
+        "timestamp",
+        r"\[?\d{4}-\d{2}-\d{2}[T ]\d{2}:\d{2}:\d{2}(?:[.,]\d+)?(?:Z|[+-]\d{2}:?\d{2})?\]?",
+    ),
+    (
+        "level",
+        r"(?i)\[?(?:trace|debug|info|notice|warn(?:ing)?|error|err|fatal|critical)\b\]?:?",
+    ),
+    (
+        "thread",
+        r"\[(?:[\w.-]*(?:thread|pool|worker|main|exec)[\w.-]*|(?:pid|tid)?[:=]?\d+)\]",
+    ),
+];
+
+/// A compiled log prefix preset
+#[derive(Debug, Clone)]
+pub struct LogPreset {
+    /// Name of the preset in the config file
+    pub name: String,
+    regex: Regex,
+}
+
+impl LogPreset {
+    /// Compile a preset; the pattern only matches at the start of a line
+    ///
+    /// # Errors
+    /// Returns an error if `pattern` is not a valid regular expression.
+    pub fn new(name: &str, pattern: &str) -> Result<Self, regex::Error> {
+        Ok(Self {
+            name: name.to_string(),
+            regex: Regex::new(&format!(r"^(?:{})\s*", pattern))?,
+        })
+    }
+
+    /// Compile `(name, pattern)` presets
+    ///
+    /// # Errors
+    /// Returns the name of the first invalid preset with its error.
+    pub fn compile_all<S: AsRef<str>>(
+        presets: &[(S, S)],
+    ) -> Result<Vec<Self>, (String, regex::Error)> {
+        presets
+            .iter()
+            .map(|(name, pattern)| {
+                Self::new(name.as_ref(), pattern.as_ref())
+                    .map_err(|err| (name.as_ref().to_string(), err))
+            })
+            .collect()
+    }
+}
+
+/// Strip every leading prefix matched by the presets from one line
+pub fn strip_log_prefix<'a>(line: &'a str, presets: &[LogPreset]) -> &'a str {
+    let mut rest = line.trim_start();
+    while let Some(found) = presets
+        .iter()
+        .filter_map(|preset| preset.regex.find(rest))
+        .find(|found| !found.is_empty())
+    {
+        rest = &rest[found.end()..];
+    }
+    rest.trim_end()
+}
+
+/// Strip log prefixes from every item, dropping items left blank
+///
+/// # Arguments
+/// * `items` - Log lines
+/// * `presets` - Compiled prefix presets
+///
+/// # Returns
+/// The messages, in input order
+pub fn strip_log_prefixes(items: &[String], presets: &[LogPreset]) -> Vec<String> {
+    items
+        .iter()
+        .map(|item| strip_log_prefix(item, presets))
+        .filter(|message| !message.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn defaults() -> Vec<LogPreset> {
+        LogPreset::compile_all(&DEFAULT_LOG_PRESETS).unwrap()
+    }
+
+    #[test]
+    fn test_strip_common_prefixes() {
+        let presets = defaults();
+        assert_eq!(
+            strip_log_prefix(
+                "2024-05-01T10:00:00.123Z INFO [main] Server started",
+                &presets
+            ),
+            "Server started"
+        );
+        assert_eq!(
+            strip_log_prefix(
+                "[2024-05-01 10:00:00,5] [ERROR]: [worker-3] Disk full",
+                &presets
+            ),
+            "Disk full"
+        );
+        assert_eq!(
+            strip_log_prefix("warn [pid=42] retrying", &presets),
+            "retrying"
+        );
+        // Words inside the message are kept
+        assert_eq!(
+            strip_log_prefix("User info updated", &presets),
+            "User info updated"
+        );
+        assert_eq!(
+            strip_log_prefix("Information only", &presets),
+            "Information only"
+        );
+    }
+
+    #[test]
+    fn test_strip_log_prefixes_drops_blank_messages() {
+        let items = vec![
+            "2024-05-01 10:00:00 DEBUG".to_string(),
+            "2024-05-01 10:00:01 DEBUG tick".to_string(),
+        ];
+        assert_eq!(strip_log_prefixes(&items, &defaults()), vec!["tick"]);
+    }
+
+    #[test]
+    fn test_invalid_preset_names_the_preset() {
+        let err = LogPreset::compile_all(&[("broken", "(")]).unwrap_err();
+        assert_eq!(err.0, "broken");
+    }
+}
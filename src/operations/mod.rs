@@ -1,6 +1,38 @@
 /// Operations module for list manipulations
+pub mod columns;
 pub mod compare;
+pub mod external;
+pub mod git;
+pub mod join;
+pub mod keyed;
+pub mod log_prefix;
+pub mod normalize;
+pub mod parallel;
+pub mod regex_filter;
+pub mod regex_replace;
+pub mod result_sort;
 pub mod single_list;
+pub mod smart_sort;
+pub mod stats;
+pub mod tolerance;
+pub mod trim;
+pub mod typography;
+pub mod verdict;
 
+pub use columns::*;
 pub use compare::*;
+pub use external::*;
+pub use git::*;
+pub use join::*;
+pub use keyed::*;
+pub use log_prefix::*;
+pub use normalize::*;
+pub use regex_filter::*;
+pub use regex_replace::*;
+pub use result_sort::*;
 pub use single_list::*;
+pub use smart_sort::*;
+pub use stats::*;
+pub use tolerance::*;
+pub use trim::*;
+pub use verdict::*;
@@ -0,0 +1,391 @@
+//! Normalization presets that put items of a known kind in canonical form before comparing
+//!
+//! CRM exports write `John Doe <John@Example.com>` where a mailing tool writes
+//! `john@example.com`; a crawler lists `HTTP://Example.com:80/docs/` where a
+//! sitemap has `http://example.com/docs`; a Windows export lists
+//! `C:\Data\.\Report.txt` where a Linux one has `c:/data/report.txt`. A
+//! preset rewrites both forms to the same key so the comparison matches them.
+//! Items the preset cannot read are compared as typed.
+use std::borrow::Cow;
+
+/// Canonical form items are rewritten to before comparing
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum NormalizePreset {
+    /// Items are compared as typed
+    #[default]
+    None,
+    /// Email addresses: display names dropped, lowercased
+    Email,
+    /// Email addresses with `+tags` removed from the local part as well
+    EmailNoTags,
+    /// URLs: scheme and host lowercased, default port and trailing slash dropped
+    Url,
+    /// URLs with tracking parameters (`utm_*`, `fbclid`, ...) removed as well
+    UrlNoTracking,
+    /// URLs with the whole query string removed as well
+    UrlNoQuery,
+    /// File paths: `/` separators, `.` and `..` resolved, Windows paths lowercased
+    Path,
+}
+
+/// Every preset, in cycling order
+const PRESETS: [NormalizePreset; 7] = [
+    NormalizePreset::None,
+    NormalizePreset::Email,
+    NormalizePreset::EmailNoTags,
+    NormalizePreset::Url,
+    NormalizePreset::UrlNoTracking,
+    NormalizePreset::UrlNoQuery,
+    NormalizePreset::Path,
+];
+
+/// Query parameters that only track where a visitor came from
+const TRACKING_PARAMS: [&str; 9] = [
+    "fbclid", "gclid", "dclid", "msclkid", "yclid", "igshid", "mc_cid", "mc_eid", "_ga",
+];
+
+/// What happens to the query string of a URL
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UrlQuery {
+    /// Kept as is
+    Keep,
+    /// Tracking parameters removed
+    NoTracking,
+    /// Removed entirely
+    Drop,
+}
+
+impl NormalizePreset {
+    /// The preset to switch to next
+    pub fn next(self) -> Self {
+        let index = PRESETS.iter().position(|&preset| preset == self);
+        PRESETS[index.map_or(0, |index| (index + 1) % PRESETS.len())]
+    }
+
+    /// Short name shown to the user and stored in bundles
+    pub fn name(self) -> &'static str {
+        match self {
+            NormalizePreset::None => "none",
+            NormalizePreset::Email => "email",
+            NormalizePreset::EmailNoTags => "email-no-tags",
+            NormalizePreset::Url => "url",
+            NormalizePreset::UrlNoTracking => "url-no-tracking",
+            NormalizePreset::UrlNoQuery => "url-no-query",
+            NormalizePreset::Path => "path",
+        }
+    }
+
+    /// Parse a preset from its [`NormalizePreset::name`]
+    pub fn from_name(name: &str) -> Option<Self> {
+        PRESETS.into_iter().find(|preset| preset.name() == name)
+    }
+
+    /// Canonical form of `item`, or the item itself when the preset cannot read it
+    pub fn apply(self, item: &str) -> Cow<'_, str> {
+        let normalized = match self {
+            NormalizePreset::None => None,
+            NormalizePreset::Email => normalize_email(item, false),
+            NormalizePreset::EmailNoTags => normalize_email(item, true),
+            NormalizePreset::Url => normalize_url(item, UrlQuery::Keep),
+            NormalizePreset::UrlNoTracking => normalize_url(item, UrlQuery::NoTracking),
+            NormalizePreset::UrlNoQuery => normalize_url(item, UrlQuery::Drop),
+            NormalizePreset::Path => normalize_path(item),
+        };
+        normalized.map_or(Cow::Borrowed(item), Cow::Owned)
+    }
+
+    /// Whether the preset can read `item` (always true without a preset)
+    pub fn is_valid(self, item: &str) -> bool {
+        match self {
+            NormalizePreset::None => true,
+            NormalizePreset::Email | NormalizePreset::EmailNoTags => {
+                normalize_email(item, false).is_some()
+            }
+            NormalizePreset::Url | NormalizePreset::UrlNoTracking | NormalizePreset::UrlNoQuery => {
+                normalize_url(item, UrlQuery::Keep).is_some()
+            }
+            NormalizePreset::Path => normalize_path(item).is_some(),
+        }
+    }
+}
+
+/// Lowercased address of an email item, or `None` if it is not a valid address
+///
+/// A display name (`John <j@x.com>`), surrounding quotes and a `mailto:`
+/// prefix are dropped. With `strip_tags`, a `+tag` suffix of the local part
+/// goes too, so `ann+news@x.com` matches `ann@x.com`.
+pub fn normalize_email(item: &str, strip_tags: bool) -> Option<String> {
+    let mut address = item.trim();
+    if let (Some(open), true) = (address.rfind('<'), address.ends_with('>')) {
+        address = &address[open + 1..address.len() - 1];
+    }
+    let address = address.trim().trim_matches(['"', '\'']);
+    let address = match address.get(..7) {
+        Some(scheme) if scheme.eq_ignore_ascii_case("mailto:") => &address[7..],
+        _ => address,
+    };
+    let (local, domain) = address.split_once('@')?;
+    let valid_local = !local.is_empty()
+        && !local.starts_with('.')
+        && !local.ends_with('.')
+        && !local.contains("..")
+        && local
+            .chars()
+            .all(|c| c.is_alphanumeric() || "!#$%&'*+-/=?^_`{|}~.".contains(c));
+    let valid_domain = domain.contains('.')
+        && domain.split('.').all(|label| {
+            !label.is_empty()
+                && !label.starts_with('-')
+                && !label.ends_with('-')
+                && label.chars().all(|c| c.is_alphanumeric() || c == '-')
+        });
+    if !valid_local || !valid_domain {
+        return None;
+    }
+    let local = match local.split_once('+') {
+        Some((untagged, _)) if strip_tags && !untagged.is_empty() => untagged,
+        _ => local,
+    };
+    Some(format!("{}@{}", local, domain).to_lowercase())
+}
+
+/// Port a scheme uses when the URL names none
+fn default_port(scheme: &str) -> Option<&'static str> {
+    match scheme {
+        "http" | "ws" => Some("80"),
+        "https" | "wss" => Some("443"),
+        "ftp" => Some("21"),
+        _ => None,
+    }
+}
+
+/// Canonical form of a URL item, or `None` if it is not an absolute URL
+///
+/// The scheme and host are lowercased, the scheme's default port and a
+/// trailing slash of the path are dropped, and the query string is handled
+/// as `query` says. The user info, path case and fragment are kept.
+pub fn normalize_url(item: &str, query: UrlQuery) -> Option<String> {
+    let (scheme, rest) = item.trim().split_once("://")?;
+    let valid_scheme = scheme.starts_with(|c: char| c.is_ascii_alphabetic())
+        && scheme
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || "+-.".contains(c));
+    if !valid_scheme || rest.contains(char::is_whitespace) {
+        return None;
+    }
+    let scheme = scheme.to_ascii_lowercase();
+
+    let (rest, fragment) = match rest.split_once('#') {
+        Some((rest, fragment)) => (rest, Some(fragment)),
+        None => (rest, None),
+    };
+    let (rest, params) = match rest.split_once('?') {
+        Some((rest, params)) => (rest, Some(params)),
+        None => (rest, None),
+    };
+    let (authority, path) = rest.split_at(rest.find('/').unwrap_or(rest.len()));
+    let (user, host) = match authority.rsplit_once('@') {
+        Some((user, host)) => (Some(user), host),
+        None => (None, authority),
+    };
+    // The port follows the last colon unless it closes an IPv6 literal
+    let (host, port) = match host.rsplit_once(':') {
+        Some((name, port)) if !port.contains(']') => (name, Some(port)),
+        _ => (host, None),
+    };
+    if host.is_empty() || port.is_some_and(|port| !port.chars().all(|c| c.is_ascii_digit())) {
+        return None;
+    }
+
+    let mut url = format!("{}://", scheme);
+    if let Some(user) = user {
+        url.push_str(user);
+        url.push('@');
+    }
+    url.push_str(&host.to_lowercase());
+    if let Some(port) = port.filter(|&port| !port.is_empty() && default_port(&scheme) != Some(port))
+    {
+        url.push(':');
+        url.push_str(port);
+    }
+    url.push_str(path.trim_end_matches('/'));
+    let params = match (params, query) {
+        (None, _) | (_, UrlQuery::Drop) => None,
+        (Some(params), UrlQuery::Keep) => Some(params.to_string()),
+        (Some(params), UrlQuery::NoTracking) => Some(
+            params
+                .split('&')
+                .filter(|param| {
+                    let key = param
+                        .split('=')
+                        .next()
+                        .unwrap_or_default()
+                        .to_ascii_lowercase();
+                    !key.starts_with("utm_") && !TRACKING_PARAMS.contains(&key.as_str())
+                })
+                .collect::<Vec<_>>()
+                .join("&"),
+        ),
+    };
+    if let Some(params) = params.filter(|params| !params.is_empty()) {
+        url.push('?');
+        url.push_str(&params);
+    }
+    if let Some(fragment) = fragment {
+        url.push('#');
+        url.push_str(fragment);
+    }
+    Some(url)
+}
+
+/// Canonical form of a file path item, or `None` if it is empty or holds control characters
+///
+/// Backslashes become `/`, repeated and trailing separators go, and `.` and
+/// `..` segments are resolved (a `..` above the root is dropped, one leading a
+/// relative path is kept). Windows paths (a drive letter, a UNC `\\server`
+/// prefix or any backslash) are lowercased, since Windows ignores case.
+pub fn normalize_path(item: &str) -> Option<String> {
+    let path = item.trim().trim_matches('"');
+    if path.is_empty() || path.contains(char::is_control) {
+        return None;
+    }
+    let has_drive = path.as_bytes().get(1) == Some(&b':')
+        && path.starts_with(|c: char| c.is_ascii_alphabetic());
+    let windows = has_drive || path.contains('\\');
+    let path = path.replace('\\', "/");
+    let (prefix, rest) = if has_drive {
+        let (drive, rest) = path.split_at(2);
+        let root = if rest.starts_with('/') { "/" } else { "" };
+        (format!("{}{}", drive, root), rest)
+    } else if let Some(rest) = path.strip_prefix("//") {
+        ("//".to_string(), rest)
+    } else if let Some(rest) = path.strip_prefix('/') {
+        ("/".to_string(), rest)
+    } else {
+        (String::new(), path.as_str())
+    };
+    let rooted = prefix.ends_with('/');
+
+    let mut segments: Vec<&str> = Vec::new();
+    for segment in rest.split('/') {
+        match segment {
+            "" | "." => {}
+            ".." if segments.last().is_some_and(|&last| last != "..") => {
+                segments.pop();
+            }
+            ".." if rooted => {}
+            segment => segments.push(segment),
+        }
+    }
+    let mut normalized = prefix + &segments.join("/");
+    if normalized.is_empty() {
+        normalized.push('.');
+    }
+    if windows {
+        normalized = normalized.to_lowercase();
+    }
+    Some(normalized)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize_email() {
+        let email = |item| normalize_email(item, false);
+        assert_eq!(email(" Ann@Example.COM ").unwrap(), "ann@example.com");
+        assert_eq!(
+            email("John Doe <John.Doe@x.org>").unwrap(),
+            "john.doe@x.org"
+        );
+        assert_eq!(email("\"j@x.io\"").unwrap(), "j@x.io");
+        assert_eq!(email("MAILTO:j@x.io").unwrap(), "j@x.io");
+        assert_eq!(email("ann+news@x.com").unwrap(), "ann+news@x.com");
+        assert_eq!(
+            normalize_email("Ann+News@x.com", true).unwrap(),
+            "ann@x.com"
+        );
+        for invalid in [
+            "ann",
+            "ann@",
+            "@x.com",
+            "a@b",
+            "a b@x.com",
+            "a..b@x.com",
+            "a@x..com",
+            "a@-x.com",
+        ] {
+            assert_eq!(email(invalid), None, "{}", invalid);
+        }
+    }
+
+    #[test]
+    fn test_normalize_url() {
+        let url = |item| normalize_url(item, UrlQuery::Keep).unwrap();
+        assert_eq!(
+            url("HTTP://Example.COM:80/Docs/"),
+            "http://example.com/Docs"
+        );
+        assert_eq!(url("https://example.com/"), "https://example.com");
+        assert_eq!(
+            url("https://example.com:8443/a?b=1#top"),
+            "https://example.com:8443/a?b=1#top"
+        );
+        assert_eq!(
+            url("https://User@Example.com:443?"),
+            "https://User@example.com"
+        );
+        assert_eq!(url("http://[::1]:80/x"), "http://[::1]/x");
+        assert_eq!(
+            normalize_url(
+                "https://x.com/a/?utm_source=mail&id=7&fbclid=abc",
+                UrlQuery::NoTracking
+            )
+            .unwrap(),
+            "https://x.com/a?id=7"
+        );
+        assert_eq!(
+            normalize_url("https://x.com/a?id=7#frag", UrlQuery::Drop).unwrap(),
+            "https://x.com/a#frag"
+        );
+        for invalid in [
+            "example.com/a",
+            "://x.com",
+            "http://",
+            "http://x.com:8o",
+            "http://a b.com",
+        ] {
+            assert_eq!(normalize_url(invalid, UrlQuery::Keep), None, "{}", invalid);
+        }
+    }
+
+    #[test]
+    fn test_normalize_path() {
+        let path = |item| normalize_path(item).unwrap();
+        assert_eq!(path("C:\\Data\\.\\Report.txt"), "c:/data/report.txt");
+        assert_eq!(path("c:/data/report.txt"), "c:/data/report.txt");
+        assert_eq!(path("/srv//app/./logs/../conf/"), "/srv/app/conf");
+        assert_eq!(path("/../etc"), "/etc");
+        assert_eq!(path("../src/./lib.rs"), "../src/lib.rs");
+        assert_eq!(path("a/b/../../.."), "..");
+        assert_eq!(path("./"), ".");
+        assert_eq!(path("\\\\Server\\Share\\File"), "//server/share/file");
+        assert_eq!(path("/Home/Ann"), "/Home/Ann");
+        assert_eq!(path("\"D:Notes\\a.txt\""), "d:notes/a.txt");
+        assert_eq!(normalize_path("  "), None);
+        assert_eq!(normalize_path("a\tb"), None);
+    }
+
+    #[test]
+    fn test_preset_keeps_unreadable_items() {
+        assert_eq!(NormalizePreset::Email.apply("Not An Email"), "Not An Email");
+        assert_eq!(NormalizePreset::None.apply("A <a@x.com>"), "A <a@x.com>");
+        assert!(!NormalizePreset::Email.is_valid("nope"));
+        assert!(NormalizePreset::None.is_valid("nope"));
+        assert_eq!(
+            NormalizePreset::from_name("email-no-tags"),
+            Some(NormalizePreset::EmailNoTags)
+        );
+    }
+}
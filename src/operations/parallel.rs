@@ -0,0 +1,114 @@
+//! Optional data parallelism for large lists
+//!
+//! With the `parallel` feature (on by default) inputs of at least
+//! [`PARALLEL_THRESHOLD`] items are processed with rayon. Smaller inputs, and
+//! builds without the feature, take the sequential path, since spreading a
+//! few thousand items over threads costs more than it saves.
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+use std::cmp::Ordering;
+use std::collections::HashSet;
+use std::hash::Hash;
+
+/// Smallest input processed in parallel
+pub const PARALLEL_THRESHOLD: usize = 50_000;
+
+/// Map every item, in parallel for large inputs (order is preserved)
+pub fn map<'a, T, U, F>(items: &'a [T], f: F) -> Vec<U>
+where
+    T: Sync,
+    U: Send,
+    F: Fn(&'a T) -> U + Sync + Send,
+{
+    #[cfg(feature = "parallel")]
+    {
+        if items.len() >= PARALLEL_THRESHOLD {
+            return items.par_iter().map(f).collect();
+        }
+    }
+    items.iter().map(f).collect()
+}
+
+/// Keep the mapped items that are `Some`, in parallel for large inputs (order is preserved)
+pub fn filter_map<'a, T, U, F>(items: &'a [T], f: F) -> Vec<U>
+where
+    T: Sync,
+    U: Send,
+    F: Fn(&'a T) -> Option<U> + Sync + Send,
+{
+    #[cfg(feature = "parallel")]
+    {
+        if items.len() >= PARALLEL_THRESHOLD {
+            return items.par_iter().filter_map(f).collect();
+        }
+    }
+    items.iter().filter_map(f).collect()
+}
+
+/// Collect the keys of every item into a set, hashing in parallel for large inputs
+pub fn to_set<'a, T, K, F>(items: &'a [T], key: F) -> HashSet<K>
+where
+    T: Sync,
+    K: Eq + Hash + Send,
+    F: Fn(&'a T) -> K + Sync + Send,
+{
+    #[cfg(feature = "parallel")]
+    {
+        if items.len() >= PARALLEL_THRESHOLD {
+            return items.par_iter().map(key).collect();
+        }
+    }
+    items.iter().map(key).collect()
+}
+
+/// Whether every item matches, checked in parallel for large inputs
+pub fn all<T, F>(items: &[T], f: F) -> bool
+where
+    T: Sync,
+    F: Fn(&T) -> bool + Sync + Send,
+{
+    #[cfg(feature = "parallel")]
+    {
+        if items.len() >= PARALLEL_THRESHOLD {
+            return items.par_iter().all(f);
+        }
+    }
+    items.iter().all(f)
+}
+
+/// Stable sort with a comparator, in parallel for large inputs
+pub fn sort_by<T, F>(items: &mut [T], compare: F)
+where
+    T: Send,
+    F: Fn(&T, &T) -> Ordering + Sync,
+{
+    #[cfg(feature = "parallel")]
+    {
+        if items.len() >= PARALLEL_THRESHOLD {
+            items.par_sort_by(compare);
+            return;
+        }
+    }
+    items.sort_by(compare);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_large_inputs_match_sequential_results() {
+        let items: Vec<usize> = (0..PARALLEL_THRESHOLD * 2).rev().collect();
+
+        let doubled = map(&items, |n| n * 2);
+        assert_eq!(doubled[0], (PARALLEL_THRESHOLD * 2 - 1) * 2);
+        let even = filter_map(&items, |n| (n % 2 == 0).then_some(*n));
+        assert_eq!(even.len(), PARALLEL_THRESHOLD);
+        assert_eq!(to_set(&items, |n| n % 10).len(), 10);
+        assert!(all(&items, |n| *n < PARALLEL_THRESHOLD * 2));
+
+        let mut sorted = items.clone();
+        sort_by(&mut sorted, |a, b| a.cmp(b));
+        assert!(sorted.windows(2).all(|w| w[0] <= w[1]));
+    }
+}
@@ -0,0 +1,42 @@
+//! Filtering of a list by a regular expression, like `grep` and `grep -v`
+use super::ListFilter;
+use regex::Regex;
+
+/// Keep the items of `items` matching `regex`, or drop them, keeping the order
+///
+/// An item matches when the regex matches anywhere in it; anchor the pattern
+/// with `^...$` to match whole items.
+pub fn filter_by_regex<S: AsRef<str>>(
+    items: &[S],
+    regex: &Regex,
+    filter: ListFilter,
+) -> Vec<String> {
+    let keep_matching = filter == ListFilter::Keep;
+    items
+        .iter()
+        .map(AsRef::as_ref)
+        .filter(|item| regex.is_match(item) == keep_matching)
+        .map(str::to_string)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_filter_by_regex() {
+        let items = ["ERR-1", "ok", "err-2", "ERR-1"];
+        let regex = Regex::new(r"^ERR-\d$").unwrap();
+        assert_eq!(
+            filter_by_regex(&items, &regex, ListFilter::Keep),
+            vec!["ERR-1", "ERR-1"]
+        );
+        assert_eq!(
+            filter_by_regex(&items, &regex, ListFilter::Remove),
+            vec!["ok", "err-2"]
+        );
+        let regex = Regex::new("(?i)err").unwrap();
+        assert_eq!(filter_by_regex(&items, &regex, ListFilter::Keep).len(), 3);
+    }
+}
@@ -0,0 +1,71 @@
+//! Find and replace by a regular expression, line by line, like `sed 's/…/…/g'`
+//!
+//! The replacement may refer to capture groups as `$1` or `$name`; write
+//! `${1}` when letters follow (`$1a` reads as a group named `1a`) and `$$`
+//! for a literal `$`. Patterns never match across a line break.
+use regex::Regex;
+use std::borrow::Cow;
+
+/// `line` with every match of `regex` replaced, or `None` when it stays the same
+fn replace_line(line: &str, regex: &Regex, replacement: &str) -> Option<String> {
+    match regex.replace_all(line, replacement) {
+        Cow::Owned(replaced) if replaced != line => Some(replaced),
+        _ => None,
+    }
+}
+
+/// Number of `lines` that replacing the matches of `regex` would change
+pub fn count_replaced_lines<S: AsRef<str>>(lines: &[S], regex: &Regex, replacement: &str) -> usize {
+    lines
+        .iter()
+        .filter(|line| replace_line(line.as_ref(), regex, replacement).is_some())
+        .count()
+}
+
+/// `lines` with every match of `regex` replaced, and the number of lines changed
+pub fn replace_lines<S: AsRef<str>>(
+    lines: &[S],
+    regex: &Regex,
+    replacement: &str,
+) -> (Vec<String>, usize) {
+    let mut changed = 0;
+    let lines = lines
+        .iter()
+        .map(AsRef::as_ref)
+        .map(|line| match replace_line(line, regex, replacement) {
+            Some(replaced) => {
+                changed += 1;
+                replaced
+            }
+            None => line.to_string(),
+        })
+        .collect();
+    (lines, changed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_replace_lines() {
+        let lines = ["2024-01-05 start", "no date", "2023-12-31 end"];
+        let regex = Regex::new(r"(\d{4})-(\d{2})-(\d{2})").unwrap();
+        assert_eq!(count_replaced_lines(&lines, &regex, "$3/$2/$1"), 2);
+        assert_eq!(
+            replace_lines(&lines, &regex, "$3/$2/$1"),
+            (
+                vec![
+                    "05/01/2024 start".to_string(),
+                    "no date".to_string(),
+                    "31/12/2023 end".to_string()
+                ],
+                2
+            )
+        );
+        // A replacement giving back the same text changes nothing
+        assert_eq!(count_replaced_lines(&lines, &regex, "$0"), 0);
+        let regex = Regex::new("o").unwrap();
+        assert_eq!(replace_lines(&["foo"], &regex, "${0}0").0, vec!["fo0o0"]);
+    }
+}
@@ -0,0 +1,134 @@
+//! Display orders of the Results tab panels
+//!
+//! Results are stored in the order the comparison produced them; a panel can
+//! list its items in another order without touching the stored results, so
+//! reports and bundles stay the same whatever is on screen.
+use super::compare::Occurrence;
+use super::parallel;
+use super::smart_sort::{SmartSort, TieBreak};
+use std::collections::HashMap;
+
+/// Order a result panel lists its items in
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ResultSort {
+    /// As compared: numbers numerically and text alphabetically in separate
+    /// groups, unless the comparison kept the input order
+    #[default]
+    Smart,
+    /// First appearance in List 1, then in List 2
+    Original,
+    /// Alphabetic, ignoring case
+    Alphabetic,
+    /// Numbers in numeric order, then the other items alphabetically
+    Numeric,
+    /// Shortest first
+    Length,
+    /// Most repeated in the compared lists first
+    Frequency,
+}
+
+impl ResultSort {
+    /// The order to switch to next
+    pub fn next(self) -> Self {
+        match self {
+            ResultSort::Smart => ResultSort::Original,
+            ResultSort::Original => ResultSort::Alphabetic,
+            ResultSort::Alphabetic => ResultSort::Numeric,
+            ResultSort::Numeric => ResultSort::Length,
+            ResultSort::Length => ResultSort::Frequency,
+            ResultSort::Frequency => ResultSort::Smart,
+        }
+    }
+
+    /// Short name shown to the user
+    pub fn display_name(self) -> &'static str {
+        match self {
+            ResultSort::Smart => "smart",
+            ResultSort::Original => "original order",
+            ResultSort::Alphabetic => "alphabetic",
+            ResultSort::Numeric => "numeric",
+            ResultSort::Length => "length",
+            ResultSort::Frequency => "frequency",
+        }
+    }
+}
+
+/// `items` in the `sort` order, or `None` for the smart order they are stored in
+///
+/// The sort is stable, so ties keep the smart order. `occurrences` gives the
+/// positions and counts of the original and frequency orders; items missing
+/// from it come first.
+pub fn sort_results(
+    items: &[String],
+    sort: ResultSort,
+    occurrences: &HashMap<String, Occurrence>,
+) -> Option<Vec<String>> {
+    let occurrence = |item: &String| occurrences.get(item).copied().unwrap_or_default();
+    let mut sorted = items.to_vec();
+    match sort {
+        ResultSort::Smart => return None,
+        ResultSort::Original => parallel::sort_by(&mut sorted, |a, b| {
+            occurrence(a).first_index.cmp(&occurrence(b).first_index)
+        }),
+        ResultSort::Alphabetic => parallel::sort_by(&mut sorted, |a, b| {
+            let lower = |item: &String| {
+                item.chars()
+                    .flat_map(char::to_lowercase)
+                    .collect::<Vec<_>>()
+            };
+            lower(a).cmp(&lower(b))
+        }),
+        ResultSort::Numeric => {
+            let numeric = SmartSort {
+                ties: TieBreak::Input,
+                ..SmartSort::default()
+            };
+            parallel::sort_by(&mut sorted, |a, b| numeric.compare(a, b))
+        }
+        ResultSort::Length => parallel::sort_by(&mut sorted, |a, b| {
+            a.chars().count().cmp(&b.chars().count())
+        }),
+        ResultSort::Frequency => parallel::sort_by(&mut sorted, |a, b| {
+            occurrence(b).count.cmp(&occurrence(a).count)
+        }),
+    }
+    Some(sorted)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::operations::{compare_lists, CompareOptions};
+
+    #[test]
+    fn test_sort_results() {
+        let first = ["pear", "10", "Apple", "9", "pear"];
+        let second = ["kiwi", "pear", "pear"];
+        let result = compare_lists(&first, &second, CompareOptions::default());
+        let sort = |sort| sort_results(&result.union, sort, &result.occurrences);
+
+        assert_eq!(sort(ResultSort::Smart), None);
+        assert_eq!(result.union, vec!["9", "10", "Apple", "kiwi", "pear"]);
+        let sorted = |items: &[&str]| Some(items.iter().map(|s| s.to_string()).collect());
+        assert_eq!(
+            sort(ResultSort::Original),
+            sorted(&["pear", "10", "Apple", "9", "kiwi"])
+        );
+        assert_eq!(
+            sort(ResultSort::Alphabetic),
+            sorted(&["10", "9", "Apple", "kiwi", "pear"])
+        );
+        assert_eq!(
+            sort(ResultSort::Numeric),
+            sorted(&["9", "10", "Apple", "kiwi", "pear"])
+        );
+        assert_eq!(
+            sort(ResultSort::Length),
+            sorted(&["9", "10", "kiwi", "pear", "Apple"])
+        );
+        assert_eq!(
+            sort(ResultSort::Frequency),
+            sorted(&["pear", "9", "10", "Apple", "kiwi"])
+        );
+    }
+}
@@ -1,4 +1,6 @@
-/// Operations for single list manipulation
+//! Operations for single list manipulation
+use super::{parallel, Frequency};
+use std::collections::HashMap;
 
 /// Result of single list operations
 #[derive(Debug, Clone)]
@@ -40,9 +42,35 @@ pub fn remove_duplicates(items: &[String]) -> Vec<String> {
         .collect()
 }
 
+/// Remove blank items and items matching a stopword, preserving order
+///
+/// Items are trimmed and compared case-insensitively, so `" N/A "` matches
+/// the stopword `n/a`.
+///
+/// # Arguments
+/// * `items` - Vector of items to filter
+/// * `stopwords` - Noise values to drop
+///
+/// # Returns
+/// New vector without blank items and stopwords
+pub fn remove_stopwords<S: AsRef<str>>(items: &[String], stopwords: &[S]) -> Vec<String> {
+    let stopwords: std::collections::HashSet<String> = stopwords
+        .iter()
+        .map(|word| word.as_ref().trim().to_lowercase())
+        .collect();
+    items
+        .iter()
+        .filter(|item| {
+            let item = item.trim();
+            !item.is_empty() && !stopwords.contains(&item.to_lowercase())
+        })
+        .cloned()
+        .collect()
+}
+
 /// Check if all items can be parsed as numbers (integers or floats)
 fn all_numeric(items: &[String]) -> bool {
-    !items.is_empty() && items.iter().all(|s| s.trim().parse::<f64>().is_ok())
+    !items.is_empty() && parallel::all(items, |s| s.trim().parse::<f64>().is_ok())
 }
 
 /// Sort items in ascending order
@@ -58,7 +86,7 @@ pub fn sort_ascending(items: &[String]) -> Vec<String> {
 
     if all_numeric(&sorted) {
         // Numeric sort
-        sorted.sort_by(|a, b| {
+        parallel::sort_by(&mut sorted, |a, b| {
             let a_num: f64 = a.trim().parse().unwrap_or(0.0);
             let b_num: f64 = b.trim().parse().unwrap_or(0.0);
             a_num
@@ -67,7 +95,7 @@ pub fn sort_ascending(items: &[String]) -> Vec<String> {
         });
     } else {
         // Alphabetic sort
-        sorted.sort();
+        parallel::sort_by(&mut sorted, |a, b| a.cmp(b));
     }
 
     sorted
@@ -86,7 +114,7 @@ pub fn sort_descending(items: &[String]) -> Vec<String> {
 
     if all_numeric(&sorted) {
         // Numeric sort descending
-        sorted.sort_by(|a, b| {
+        parallel::sort_by(&mut sorted, |a, b| {
             let a_num: f64 = a.trim().parse().unwrap_or(0.0);
             let b_num: f64 = b.trim().parse().unwrap_or(0.0);
             b_num
@@ -95,7 +123,7 @@ pub fn sort_descending(items: &[String]) -> Vec<String> {
         });
     } else {
         // Alphabetic sort descending
-        sorted.sort_by(|a, b| b.cmp(a));
+        parallel::sort_by(&mut sorted, |a, b| b.cmp(a));
     }
 
     sorted
@@ -108,12 +136,58 @@ pub fn sort_descending(items: &[String]) -> Vec<String> {
 ///
 /// # Returns
 /// Tuple of (total_count, unique_count)
-pub fn count_items(items: &[String]) -> (usize, usize) {
+pub fn count_items<S: AsRef<str>>(items: &[S]) -> (usize, usize) {
     let total = items.len();
-    let unique = items.iter().collect::<std::collections::HashSet<_>>().len();
+    let unique = items
+        .iter()
+        .map(AsRef::as_ref)
+        .collect::<std::collections::HashSet<&str>>()
+        .len();
     (total, unique)
 }
 
+/// Count how often each distinct item occurs
+///
+/// Items are compared exactly as given. The most frequent come first; items
+/// with the same count keep the order they were first seen in.
+pub fn count_frequencies<S: AsRef<str>>(items: &[S]) -> Vec<Frequency> {
+    let mut index: HashMap<&str, usize> = HashMap::new();
+    let mut frequencies: Vec<Frequency> = Vec::new();
+    for item in items {
+        let item = item.as_ref();
+        match index.get(item) {
+            Some(&at) => frequencies[at].count += 1,
+            None => {
+                index.insert(item, frequencies.len());
+                frequencies.push(Frequency {
+                    item: item.to_string(),
+                    count: 1,
+                });
+            }
+        }
+    }
+    // Stable sort keeps first-seen order among equal counts
+    frequencies.sort_by_key(|f| std::cmp::Reverse(f.count));
+    frequencies
+}
+
+/// Items that occur more than once, each with its count, most frequent first
+///
+/// The inverse of [`remove_duplicates`]: every item seen only once is dropped.
+pub fn keep_duplicates<S: AsRef<str>>(items: &[S]) -> Vec<Frequency> {
+    let mut frequencies = count_frequencies(items);
+    frequencies.retain(|f| f.count > 1);
+    frequencies
+}
+
+/// Lines of `item<TAB>count` for `frequencies`, in their order
+pub fn frequency_lines(frequencies: &[Frequency]) -> Vec<String> {
+    frequencies
+        .iter()
+        .map(|f| format!("{}\t{}", f.item, f.count))
+        .collect()
+}
+
 /// Apply all operations to a list and return results
 ///
 /// # Arguments
@@ -231,6 +305,17 @@ mod tests {
         assert_eq!(result, vec!["10", "2", "abc"]);
     }
 
+    #[test]
+    fn test_remove_stopwords() {
+        let items: Vec<String> = ["apple", " N/A ", "", "null", "pear", "-", "NULLABLE"]
+            .map(str::to_string)
+            .to_vec();
+        assert_eq!(
+            remove_stopwords(&items, &["n/a", "null", "-"]),
+            vec!["apple", "pear", "NULLABLE"]
+        );
+    }
+
     #[test]
     fn test_count_items() {
         let items = vec!["a".to_string(), "b".to_string(), "a".to_string()];
@@ -238,4 +323,19 @@ mod tests {
         assert_eq!(total, 3);
         assert_eq!(unique, 2);
     }
+
+    #[test]
+    fn test_count_frequencies() {
+        let frequencies = count_frequencies(&["b", "a", "c", "a", "c", "a"]);
+        assert_eq!(frequency_lines(&frequencies), vec!["a\t3", "c\t2", "b\t1"]);
+        assert!(count_frequencies::<&str>(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_keep_duplicates() {
+        let duplicates = keep_duplicates(&["x", "b", "a", "b", "a", "a", "c"]);
+        let items: Vec<&str> = duplicates.iter().map(|f| f.item.as_str()).collect();
+        assert_eq!(items, vec!["a", "b"]);
+        assert!(keep_duplicates(&["a", "b"]).is_empty());
+    }
 }
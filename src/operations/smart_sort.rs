@@ -0,0 +1,142 @@
+//! Smart ordering of comparison results: numbers numerically, text alphabetically
+//!
+//! Lists mixing numbers and text are sorted in two groups instead of falling
+//! back to byte order for everything, so `9` still comes before `10` when a
+//! stray `n/a` is in the list.
+use super::parallel;
+use std::cmp::Ordering;
+
+/// Where numbers go in a list that also holds text
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum NumberPlacement {
+    /// Numbers before text
+    #[default]
+    First,
+    /// Text before numbers
+    Last,
+}
+
+impl NumberPlacement {
+    /// Name used in the config file and bundles
+    pub fn name(self) -> &'static str {
+        match self {
+            NumberPlacement::First => "first",
+            NumberPlacement::Last => "last",
+        }
+    }
+
+    /// Placement for a name written by [`NumberPlacement::name`]
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name.trim() {
+            "first" => Some(NumberPlacement::First),
+            "last" => Some(NumberPlacement::Last),
+            _ => None,
+        }
+    }
+}
+
+/// Order of items that sort equal, such as `1`, `1.0` and `01`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TieBreak {
+    /// By their text, so the result does not depend on the input order
+    #[default]
+    Text,
+    /// In the order they were given
+    Input,
+}
+
+impl TieBreak {
+    /// Name used in the config file and bundles
+    pub fn name(self) -> &'static str {
+        match self {
+            TieBreak::Text => "text",
+            TieBreak::Input => "input",
+        }
+    }
+
+    /// Tie-break for a name written by [`TieBreak::name`]
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name.trim() {
+            "text" => Some(TieBreak::Text),
+            "input" => Some(TieBreak::Input),
+            _ => None,
+        }
+    }
+}
+
+/// How comparison results are sorted
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct SmartSort {
+    /// Where numbers go among text
+    pub numbers: NumberPlacement,
+    /// Order of items that sort equal
+    pub ties: TieBreak,
+}
+
+/// The item as a number, if it is one (surrounding spaces allowed, NaN is text)
+fn number(item: &str) -> Option<f64> {
+    item.trim().parse::<f64>().ok().filter(|n| !n.is_nan())
+}
+
+impl SmartSort {
+    /// Order of two items: numbers numerically, text by its bytes, the
+    /// groups as set by `numbers` and ties as set by `ties`
+    pub fn compare(self, a: &str, b: &str) -> Ordering {
+        let numbers_first = self.numbers == NumberPlacement::First;
+        let order = match (number(a), number(b)) {
+            (Some(x), Some(y)) => x.total_cmp(&y),
+            (Some(_), None) if numbers_first => Ordering::Less,
+            (Some(_), None) => Ordering::Greater,
+            (None, Some(_)) if numbers_first => Ordering::Greater,
+            (None, Some(_)) => Ordering::Less,
+            (None, None) => a.cmp(b),
+        };
+        match self.ties {
+            TieBreak::Text => order.then_with(|| a.cmp(b)),
+            TieBreak::Input => order,
+        }
+    }
+
+    /// Sort `items` in place (stable, in parallel for large lists)
+    pub fn sort(self, items: &mut [String]) {
+        parallel::sort_by(items, |a, b| self.compare(a, b));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sorted(sort: SmartSort, items: &[&str]) -> Vec<String> {
+        let mut items: Vec<String> = items.iter().map(|s| s.to_string()).collect();
+        sort.sort(&mut items);
+        items
+    }
+
+    #[test]
+    fn test_mixed_lists_keep_numeric_order() {
+        let items = ["10", "b", "9", "n/a", "-1.5"];
+        assert_eq!(
+            sorted(SmartSort::default(), &items),
+            vec!["-1.5", "9", "10", "b", "n/a"]
+        );
+        let last = SmartSort {
+            numbers: NumberPlacement::Last,
+            ..SmartSort::default()
+        };
+        assert_eq!(sorted(last, &items), vec!["b", "n/a", "-1.5", "9", "10"]);
+    }
+
+    #[test]
+    fn test_tie_break() {
+        let items = ["1.0", "01", "1"];
+        assert_eq!(sorted(SmartSort::default(), &items), vec!["01", "1", "1.0"]);
+        let input = SmartSort {
+            ties: TieBreak::Input,
+            ..SmartSort::default()
+        };
+        assert_eq!(sorted(input, &items), vec!["1.0", "01", "1"]);
+        assert_eq!(TieBreak::from_name(" input "), Some(TieBreak::Input));
+        assert_eq!(NumberPlacement::from_name("middle"), None);
+    }
+}
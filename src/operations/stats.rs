@@ -0,0 +1,251 @@
+//! Single-list analytics: frequencies, summary statistics, duplicates and validation
+use super::count_frequencies;
+use std::collections::HashMap;
+
+/// How often an item occurs in a list
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Frequency {
+    /// The item as it appears in the list
+    pub item: String,
+    /// Number of occurrences
+    pub count: usize,
+}
+
+/// Statistics of a list whose items are all numbers
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct NumericStats {
+    /// Smallest value
+    pub min: f64,
+    /// Largest value
+    pub max: f64,
+    /// Sum of all values
+    pub sum: f64,
+    /// Arithmetic mean
+    pub mean: f64,
+    /// Middle value (mean of the two middle values for even counts)
+    pub median: f64,
+}
+
+/// Summary statistics of a list
+#[derive(Debug, Clone, PartialEq)]
+pub struct ListStats {
+    /// Number of items
+    pub total: usize,
+    /// Number of distinct items
+    pub unique: usize,
+    /// Number of distinct items that occur more than once
+    pub duplicated: usize,
+    /// Number of empty (or whitespace-only) items
+    pub empty: usize,
+    /// Shortest item length, in characters
+    pub min_length: usize,
+    /// Longest item length, in characters
+    pub max_length: usize,
+    /// Average item length, in characters
+    pub mean_length: f64,
+    /// Numeric statistics, when every non-empty item is a number
+    pub numeric: Option<NumericStats>,
+}
+
+/// Kind of problem found in an item
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Issue {
+    /// The item is empty or only whitespace
+    Empty,
+    /// The item starts or ends with whitespace
+    SurroundingWhitespace,
+    /// The item contains control characters (other than tabs)
+    ControlCharacters,
+    /// The item only differs in case from an earlier item
+    CaseVariant,
+}
+
+/// A problem found in one item
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Finding {
+    /// 1-based position of the item in the list
+    pub position: usize,
+    /// The offending item
+    pub item: String,
+    /// What is wrong with it
+    pub issue: Issue,
+}
+
+/// Everything the Analysis tab shows about a list
+#[derive(Debug, Clone, PartialEq)]
+pub struct Analysis {
+    /// Summary statistics
+    pub stats: ListStats,
+    /// Every distinct item, most frequent first (ties keep first-seen order)
+    pub frequencies: Vec<Frequency>,
+    /// Validation problems, in list order
+    pub findings: Vec<Finding>,
+}
+
+impl Analysis {
+    /// Items that occur more than once, most frequent first
+    pub fn duplicates(&self) -> impl Iterator<Item = &Frequency> {
+        self.frequencies.iter().filter(|f| f.count > 1)
+    }
+}
+
+/// Analyze a list: count frequencies, compute statistics and validate each item
+///
+/// Items are compared exactly as given; no trimming or case folding is applied.
+pub fn analyze<S: AsRef<str>>(items: &[S]) -> Analysis {
+    let mut first_by_case: HashMap<String, &str> = HashMap::new();
+    let mut findings = Vec::new();
+
+    for (i, item) in items.iter().enumerate() {
+        let item = item.as_ref();
+        let issue = if item.trim().is_empty() {
+            Some(Issue::Empty)
+        } else if item.trim() != item {
+            Some(Issue::SurroundingWhitespace)
+        } else if item.chars().any(|c| c.is_control() && c != '\t') {
+            Some(Issue::ControlCharacters)
+        } else {
+            let first = *first_by_case.entry(item.to_lowercase()).or_insert(item);
+            (first != item).then_some(Issue::CaseVariant)
+        };
+        if let Some(issue) = issue {
+            findings.push(Finding {
+                position: i + 1,
+                item: item.to_string(),
+                issue,
+            });
+        }
+    }
+
+    let frequencies = count_frequencies(items);
+    let stats = list_stats(items, &frequencies);
+
+    Analysis {
+        stats,
+        frequencies,
+        findings,
+    }
+}
+
+/// Summary statistics for `items`, given their frequencies
+fn list_stats<S: AsRef<str>>(items: &[S], frequencies: &[Frequency]) -> ListStats {
+    let lengths: Vec<usize> = items.iter().map(|s| s.as_ref().chars().count()).collect();
+    let mean_length = if items.is_empty() {
+        0.0
+    } else {
+        lengths.iter().sum::<usize>() as f64 / items.len() as f64
+    };
+
+    ListStats {
+        total: items.len(),
+        unique: frequencies.len(),
+        duplicated: frequencies.iter().filter(|f| f.count > 1).count(),
+        empty: items
+            .iter()
+            .filter(|s| s.as_ref().trim().is_empty())
+            .count(),
+        min_length: lengths.iter().copied().min().unwrap_or(0),
+        max_length: lengths.iter().copied().max().unwrap_or(0),
+        mean_length,
+        numeric: numeric_stats(items),
+    }
+}
+
+/// Numeric statistics when every non-empty item parses as a number
+fn numeric_stats<S: AsRef<str>>(items: &[S]) -> Option<NumericStats> {
+    let mut values = items
+        .iter()
+        .map(|s| s.as_ref().trim())
+        .filter(|s| !s.is_empty())
+        .map(|s| s.parse::<f64>().ok().filter(|v| v.is_finite()))
+        .collect::<Option<Vec<f64>>>()?;
+    if values.is_empty() {
+        return None;
+    }
+
+    values.sort_by(|a, b| a.total_cmp(b));
+    let sum: f64 = values.iter().sum();
+    let middle = values.len() / 2;
+    let median = if values.len().is_multiple_of(2) {
+        (values[middle - 1] + values[middle]) / 2.0
+    } else {
+        values[middle]
+    };
+
+    Some(NumericStats {
+        min: values[0],
+        max: values[values.len() - 1],
+        sum,
+        mean: sum / values.len() as f64,
+        median,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_frequencies_most_frequent_first() {
+        let analysis = analyze(&["b", "a", "b", "c", "a", "b"]);
+        let counts: Vec<(&str, usize)> = analysis
+            .frequencies
+            .iter()
+            .map(|f| (f.item.as_str(), f.count))
+            .collect();
+        assert_eq!(counts, vec![("b", 3), ("a", 2), ("c", 1)]);
+        assert_eq!(analysis.duplicates().count(), 2);
+    }
+
+    #[test]
+    fn test_stats() {
+        let analysis = analyze(&["apple", "kiwi", "apple", ""]);
+        let stats = analysis.stats;
+        assert_eq!(stats.total, 4);
+        assert_eq!(stats.unique, 3);
+        assert_eq!(stats.duplicated, 1);
+        assert_eq!(stats.empty, 1);
+        assert_eq!(stats.min_length, 0);
+        assert_eq!(stats.max_length, 5);
+        assert_eq!(stats.mean_length, 3.5);
+        assert_eq!(stats.numeric, None);
+    }
+
+    #[test]
+    fn test_numeric_stats() {
+        let numeric = analyze(&["3", " 1", "10", "2", ""]).stats.numeric.unwrap();
+        assert_eq!(numeric.min, 1.0);
+        assert_eq!(numeric.max, 10.0);
+        assert_eq!(numeric.sum, 16.0);
+        assert_eq!(numeric.mean, 4.0);
+        assert_eq!(numeric.median, 2.5);
+    }
+
+    #[test]
+    fn test_validation_findings() {
+        let analysis = analyze(&["Apple", " pear", "", "apple", "ok\u{7}", "Apple"]);
+        let issues: Vec<(usize, Issue)> = analysis
+            .findings
+            .iter()
+            .map(|f| (f.position, f.issue))
+            .collect();
+        assert_eq!(
+            issues,
+            vec![
+                (2, Issue::SurroundingWhitespace),
+                (3, Issue::Empty),
+                (4, Issue::CaseVariant),
+                (5, Issue::ControlCharacters),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_empty_list() {
+        let analysis = analyze::<&str>(&[]);
+        assert_eq!(analysis.stats.total, 0);
+        assert_eq!(analysis.stats.mean_length, 0.0);
+        assert!(analysis.frequencies.is_empty());
+        assert!(analysis.findings.is_empty());
+    }
+}
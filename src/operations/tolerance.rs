@@ -0,0 +1,180 @@
+//! Matching of numbers that differ by less than a tolerance
+//!
+//! Amounts exported with different rounding (`10.00` and `10.001`) never match
+//! exactly; with a tolerance the numbers left over by the exact comparison are
+//! paired up when they are close enough, and the difference is reported.
+use std::fmt;
+
+/// Relative error forgiven when comparing a difference with the tolerance
+const ROUNDING_SLACK: f64 = 1e-9;
+
+/// How far apart two numbers may be and still match
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Tolerance {
+    /// By at most this much
+    Absolute(f64),
+    /// By at most this percentage of the larger magnitude
+    Relative(f64),
+}
+
+impl Tolerance {
+    /// Parse a tolerance as typed: `0.01`, or `1%` for a relative one
+    ///
+    /// Returns `None` for anything but a non-negative finite number.
+    pub fn parse(text: &str) -> Option<Self> {
+        let text = text.trim();
+        let (number, relative) = match text.strip_suffix('%') {
+            Some(number) => (number.trim(), true),
+            None => (text, false),
+        };
+        let value: f64 = number.parse().ok()?;
+        if !value.is_finite() || value < 0.0 {
+            return None;
+        }
+        Some(if relative {
+            Tolerance::Relative(value)
+        } else {
+            Tolerance::Absolute(value)
+        })
+    }
+
+    /// Whether `a` and `b` are within the tolerance of each other
+    ///
+    /// A rounding error of the subtraction is forgiven, so `10.01` is within
+    /// `0.01` of `10.00`.
+    pub fn matches(self, a: f64, b: f64) -> bool {
+        let magnitude = a.abs().max(b.abs());
+        let limit = match self {
+            Tolerance::Absolute(limit) => limit,
+            Tolerance::Relative(percent) => magnitude * percent / 100.0,
+        };
+        (a - b).abs() <= limit + magnitude.max(1.0) * ROUNDING_SLACK
+    }
+}
+
+impl fmt::Display for Tolerance {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Tolerance::Absolute(limit) => write!(f, "{}", limit),
+            Tolerance::Relative(percent) => write!(f, "{}%", percent),
+        }
+    }
+}
+
+/// Two numbers matched within the tolerance
+#[derive(Debug, Clone, PartialEq)]
+pub struct NearMatch {
+    /// Item of the first list
+    pub first: String,
+    /// Item of the second list
+    pub second: String,
+    /// Second minus first
+    pub delta: f64,
+    /// Decimals of the more precise item, used to write the delta
+    pub precision: usize,
+}
+
+impl NearMatch {
+    /// The delta with a sign, as many decimals as the more precise item
+    pub fn delta_text(&self) -> String {
+        format!("{:+.*}", self.precision, self.delta)
+    }
+}
+
+fn decimals(item: &str) -> usize {
+    item.split_once('.').map_or(0, |(_, fraction)| {
+        fraction.chars().take_while(char::is_ascii_digit).count()
+    })
+}
+
+/// Pair up the numeric items of `first` and `second` that are within `tolerance`
+///
+/// Both lists are walked in numeric order and each item is matched at most
+/// once, to the closest candidate left. Matched items are removed from the
+/// lists; items that are not numbers stay.
+pub fn match_within_tolerance(
+    first: &mut Vec<String>,
+    second: &mut Vec<String>,
+    tolerance: Tolerance,
+) -> Vec<NearMatch> {
+    let numbers = |items: &[String]| {
+        let mut numbers: Vec<(f64, usize)> = items
+            .iter()
+            .enumerate()
+            .filter_map(|(i, item)| Some((item.trim().parse::<f64>().ok()?, i)))
+            .filter(|(value, _)| value.is_finite())
+            .collect();
+        numbers.sort_by(|a, b| a.0.total_cmp(&b.0));
+        numbers
+    };
+    let (a, b) = (numbers(first), numbers(second));
+    let mut matches = Vec::new();
+    let mut matched = (vec![false; first.len()], vec![false; second.len()]);
+    let (mut i, mut j) = (0, 0);
+    while i < a.len() && j < b.len() {
+        let ((x, xi), (y, yi)) = (a[i], b[j]);
+        if tolerance.matches(x, y) {
+            // Prefer the next second item when it is closer to this first item
+            if j + 1 < b.len() && (b[j + 1].0 - x).abs() < (y - x).abs() {
+                j += 1;
+                continue;
+            }
+            let (item1, item2) = (&first[xi], &second[yi]);
+            matches.push(NearMatch {
+                first: item1.clone(),
+                second: item2.clone(),
+                delta: y - x,
+                precision: decimals(item1).max(decimals(item2)),
+            });
+            matched.0[xi] = true;
+            matched.1[yi] = true;
+            i += 1;
+            j += 1;
+        } else if x < y {
+            i += 1;
+        } else {
+            j += 1;
+        }
+    }
+    let keep = |items: &mut Vec<String>, matched: &[bool]| {
+        let mut index = 0;
+        items.retain(|_| {
+            index += 1;
+            !matched[index - 1]
+        });
+    };
+    keep(first, &matched.0);
+    keep(second, &matched.1);
+    matches
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_tolerance() {
+        assert_eq!(Tolerance::parse("0.01"), Some(Tolerance::Absolute(0.01)));
+        assert_eq!(Tolerance::parse(" 1.5 % "), Some(Tolerance::Relative(1.5)));
+        assert_eq!(Tolerance::parse("-1"), None);
+        assert_eq!(Tolerance::parse("abc"), None);
+        assert_eq!(Tolerance::Relative(1.5).to_string(), "1.5%");
+        assert!(Tolerance::Relative(1.0).matches(100.0, 101.0));
+        assert!(!Tolerance::Relative(1.0).matches(100.0, 102.0));
+        assert!(Tolerance::Absolute(0.01).matches(10.0, 10.01));
+        assert!(!Tolerance::Absolute(0.01).matches(10.0, 10.011));
+    }
+
+    #[test]
+    fn test_match_within_tolerance() {
+        let mut first = vec!["10.00".to_string(), "apple".to_string(), "20".to_string()];
+        let mut second = vec!["10.004".to_string(), "10.001".to_string(), "25".to_string()];
+        let matches = match_within_tolerance(&mut first, &mut second, Tolerance::Absolute(0.01));
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].first, "10.00");
+        assert_eq!(matches[0].second, "10.001");
+        assert_eq!(matches[0].delta_text(), "+0.001");
+        assert_eq!(first, vec!["apple", "20"]);
+        assert_eq!(second, vec!["10.004", "25"]);
+    }
+}
@@ -0,0 +1,169 @@
+//! Character sets stripped from the ends of items when trimming is on
+//!
+//! Whitespace is always trimmed; a [`TrimSet`] adds quotes, brackets,
+//! trailing punctuation and a few user-chosen characters on top of it.
+
+/// Quote characters stripped with [`TrimSet::quotes`] (straight, curly and angle quotes)
+pub const TRIM_QUOTES: &str = "\"'`“”‘’«»";
+/// Bracket characters stripped with [`TrimSet::brackets`]
+pub const TRIM_BRACKETS: &str = "()[]{}<>";
+/// Punctuation stripped from the end of items with [`TrimSet::punctuation`]
+pub const TRIM_PUNCTUATION: &str = ".,;:!?…";
+/// Most characters a custom trim set can hold
+pub const MAX_CUSTOM_TRIM_CHARS: usize = 8;
+
+/// Extra characters stripped from both ends of items, besides whitespace
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct TrimSet {
+    /// Strip quotes ([`TRIM_QUOTES`])
+    pub quotes: bool,
+    /// Strip brackets ([`TRIM_BRACKETS`])
+    pub brackets: bool,
+    /// Strip trailing punctuation ([`TRIM_PUNCTUATION`])
+    pub punctuation: bool,
+    /// User-specified characters (the first `custom_len` entries are used)
+    custom: [char; MAX_CUSTOM_TRIM_CHARS],
+    custom_len: usize,
+}
+
+impl TrimSet {
+    /// Strip the given character classes (no custom characters)
+    pub const fn new(quotes: bool, brackets: bool, punctuation: bool) -> Self {
+        Self {
+            quotes,
+            brackets,
+            punctuation,
+            custom: ['\0'; MAX_CUSTOM_TRIM_CHARS],
+            custom_len: 0,
+        }
+    }
+
+    /// Also strip the characters of `chars` (whitespace and repeats are skipped,
+    /// at most [`MAX_CUSTOM_TRIM_CHARS`] are kept)
+    pub fn with_custom(mut self, chars: &str) -> Self {
+        self.custom_len = 0;
+        for c in chars.chars().filter(|c| !c.is_whitespace()) {
+            if self.custom_len == MAX_CUSTOM_TRIM_CHARS {
+                break;
+            }
+            if !self.custom().contains(&c) {
+                self.custom[self.custom_len] = c;
+                self.custom_len += 1;
+            }
+        }
+        self
+    }
+
+    /// User-specified characters
+    pub fn custom(&self) -> &[char] {
+        &self.custom[..self.custom_len]
+    }
+
+    /// The next step of the quotes -> + brackets -> + punctuation -> none cycle,
+    /// keeping the custom characters
+    pub fn next_preset(self) -> Self {
+        let (quotes, brackets, punctuation) = match (self.quotes, self.brackets, self.punctuation) {
+            (false, false, false) => (true, false, false),
+            (true, false, false) => (true, true, false),
+            (true, true, false) => (true, true, true),
+            _ => (false, false, false),
+        };
+        Self {
+            custom: self.custom,
+            custom_len: self.custom_len,
+            ..Self::new(quotes, brackets, punctuation)
+        }
+    }
+
+    /// Whether only whitespace is trimmed
+    pub fn is_empty(&self) -> bool {
+        !self.quotes && !self.brackets && !self.punctuation && self.custom_len == 0
+    }
+
+    /// Every character stripped besides whitespace, for display
+    pub fn chars(&self) -> String {
+        let mut chars = String::new();
+        for (on, set) in [
+            (self.quotes, TRIM_QUOTES),
+            (self.brackets, TRIM_BRACKETS),
+            (self.punctuation, TRIM_PUNCTUATION),
+        ] {
+            if on {
+                chars.push_str(set);
+            }
+        }
+        chars.extend(self.custom());
+        chars
+    }
+
+    fn strips_start(&self, c: char) -> bool {
+        (self.quotes && TRIM_QUOTES.contains(c))
+            || (self.brackets && TRIM_BRACKETS.contains(c))
+            || self.custom().contains(&c)
+    }
+
+    fn strips_end(&self, c: char) -> bool {
+        self.strips_start(c) || (self.punctuation && TRIM_PUNCTUATION.contains(c))
+    }
+
+    /// Strip whitespace and the characters of the set from both ends of `item`
+    ///
+    /// Stripping repeats until nothing changes, so `"(a)",` becomes `a`.
+    pub fn trim<'a>(&self, item: &'a str) -> &'a str {
+        let mut trimmed = item.trim();
+        loop {
+            let next = trimmed
+                .trim_start_matches(|c| self.strips_start(c))
+                .trim_end_matches(|c| self.strips_end(c))
+                .trim();
+            if next.len() == trimmed.len() {
+                return next;
+            }
+            trimmed = next;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_trims_whitespace_only() {
+        let set = TrimSet::default();
+        assert!(set.is_empty());
+        assert_eq!(set.trim("  \"a\".  "), "\"a\".");
+    }
+
+    #[test]
+    fn test_trim_quotes_brackets_and_trailing_punctuation() {
+        let set = TrimSet::new(true, true, true);
+        assert_eq!(set.trim(" \"(apple)\", "), "apple");
+        assert_eq!(set.trim("“pear”"), "pear");
+        // Punctuation is only stripped from the end
+        assert_eq!(set.trim("...and so on."), "...and so on");
+    }
+
+    #[test]
+    fn test_next_preset_keeps_custom_characters() {
+        let set = TrimSet::default().with_custom("#");
+        let presets: Vec<TrimSet> = std::iter::successors(Some(set), |s| Some(s.next_preset()))
+            .skip(1)
+            .take(4)
+            .collect();
+        assert_eq!(
+            presets[0],
+            TrimSet::new(true, false, false).with_custom("#")
+        );
+        assert_eq!(presets[2], TrimSet::new(true, true, true).with_custom("#"));
+        assert_eq!(presets[3], set);
+    }
+
+    #[test]
+    fn test_custom_characters() {
+        let set = TrimSet::default().with_custom("#* #*0123456789");
+        assert_eq!(set.custom(), &['#', '*', '0', '1', '2', '3', '4', '5']);
+        assert_eq!(set.trim("## *title* ##"), "title");
+        assert_eq!(set.chars(), "#*012345");
+    }
+}
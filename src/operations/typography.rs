@@ -0,0 +1,57 @@
+//! Folding of typographic characters to their plain ASCII equivalents
+//!
+//! Text pasted from word processors silently carries curly quotes, dashes and
+//! special spaces, so `don’t` and `don't` would otherwise never match.
+use std::borrow::Cow;
+
+/// ASCII replacement of a typographic character (`Some("")` drops it)
+fn ascii_equivalent(c: char) -> Option<&'static str> {
+    Some(match c {
+        '‘' | '’' | '‚' | '‛' | '′' | '‹' | '›' => "'",
+        '“' | '”' | '„' | '‟' | '″' | '«' | '»' => "\"",
+        '‐' | '‑' | '‒' | '–' | '—' | '―' | '−' => "-",
+        '…' => "...",
+        '\u{00A0}' | '\u{2002}'..='\u{200A}' | '\u{202F}' | '\u{205F}' => " ",
+        '\u{200B}'..='\u{200D}' | '\u{2060}' | '\u{FEFF}' => "",
+        _ => return None,
+    })
+}
+
+/// Replace curly quotes, dashes, ellipses and special spaces with ASCII and drop zero-width characters
+///
+/// Returns the input unchanged (borrowed) when it holds none of them.
+pub fn fold_typography(item: &str) -> Cow<'_, str> {
+    if !item.chars().any(|c| ascii_equivalent(c).is_some()) {
+        return Cow::Borrowed(item);
+    }
+    let mut folded = String::with_capacity(item.len());
+    for c in item.chars() {
+        match ascii_equivalent(c) {
+            Some(replacement) => folded.push_str(replacement),
+            None => folded.push(c),
+        }
+    }
+    Cow::Owned(folded)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fold_quotes_dashes_and_spaces() {
+        assert_eq!(fold_typography("don’t"), "don't");
+        assert_eq!(fold_typography("“quoted”"), "\"quoted\"");
+        assert_eq!(fold_typography("2020–2024 — done…"), "2020-2024 - done...");
+        assert_eq!(fold_typography("a\u{00A0}b\u{200B}c\u{FEFF}"), "a bc");
+    }
+
+    #[test]
+    fn test_plain_text_is_borrowed() {
+        assert!(matches!(
+            fold_typography("plain 'ascii' - text"),
+            Cow::Borrowed(_)
+        ));
+        assert_eq!(fold_typography("ñandú"), "ñandú");
+    }
+}
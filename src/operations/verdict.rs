@@ -0,0 +1,189 @@
+//! Pass/fail check of every item of a list against a reference list
+use super::compare::{normalize_item, CompareOptions};
+use super::typography::fold_typography;
+use regex::{RegexSet, RegexSetBuilder};
+use std::borrow::Cow;
+use std::collections::HashSet;
+
+/// Separator between an item and its verdict in the report rows
+pub const VERDICT_SEPARATOR: char = '\t';
+
+/// How items are matched against the reference list
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum VerdictMode {
+    /// An item passes if the reference list contains it
+    #[default]
+    Exact,
+    /// An item passes if it fully matches one of the reference regular expressions
+    Pattern,
+}
+
+/// Outcome of checking a list against a reference list
+#[derive(Debug, Clone)]
+pub struct VerdictReport {
+    /// One `item<TAB>PASS|FAIL` row per checked item, in input order
+    pub rows: Vec<String>,
+    /// Number of items that passed
+    pub passed: usize,
+    /// Number of items that failed
+    pub failed: usize,
+    /// How items were matched
+    pub mode: VerdictMode,
+    /// Options the items were normalized with
+    pub options: CompareOptions,
+}
+
+/// Check every item against the reference list
+///
+/// In [`VerdictMode::Exact`] items and references are normalized with
+/// `options` like a comparison. In [`VerdictMode::Pattern`] each reference is
+/// a regular expression that must match the whole (folded and trimmed, if
+/// enabled) item,
+/// ignoring case unless the comparison is case-sensitive.
+///
+/// # Errors
+/// Returns an error if a reference is not a valid regular expression.
+pub fn check_against_reference<S: AsRef<str>, R: AsRef<str>>(
+    items: &[S],
+    reference: &[R],
+    mode: VerdictMode,
+    options: CompareOptions,
+) -> Result<VerdictReport, regex::Error> {
+    let verdicts: Vec<bool> = match mode {
+        VerdictMode::Exact => {
+            let allowed: HashSet<String> = reference
+                .iter()
+                .map(|item| normalize_item(item.as_ref(), options))
+                .collect();
+            items
+                .iter()
+                .map(|item| allowed.contains(&normalize_item(item.as_ref(), options)))
+                .collect()
+        }
+        VerdictMode::Pattern => {
+            let patterns = build_patterns(reference, options)?;
+            items
+                .iter()
+                .map(|item| {
+                    let item = if options.fold_typography {
+                        fold_typography(item.as_ref())
+                    } else {
+                        Cow::Borrowed(item.as_ref())
+                    };
+                    let item = if options.trim_spaces {
+                        options.trim_set.trim(&item)
+                    } else {
+                        &item
+                    };
+                    patterns.is_match(item)
+                })
+                .collect()
+        }
+    };
+
+    let passed = verdicts.iter().filter(|&&pass| pass).count();
+    let rows = items
+        .iter()
+        .zip(&verdicts)
+        .map(|(item, &pass)| {
+            let verdict = if pass { "PASS" } else { "FAIL" };
+            format!("{}{}{}", item.as_ref(), VERDICT_SEPARATOR, verdict)
+        })
+        .collect();
+
+    Ok(VerdictReport {
+        rows,
+        passed,
+        failed: verdicts.len() - passed,
+        mode,
+        options,
+    })
+}
+
+fn build_patterns<R: AsRef<str>>(
+    reference: &[R],
+    options: CompareOptions,
+) -> Result<RegexSet, regex::Error> {
+    let anchored = reference.iter().map(|pattern| {
+        let pattern = pattern.as_ref();
+        let pattern = if options.trim_spaces {
+            pattern.trim()
+        } else {
+            pattern
+        };
+        format!("^(?:{})$", pattern)
+    });
+    RegexSetBuilder::new(anchored)
+        .case_insensitive(!options.case_sensitive)
+        .build()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_exact_verdicts_keep_input_order() {
+        let items = vec!["US", " ar", "XX", "es"];
+        let reference = vec!["AR", "ES", "US"];
+        let report = check_against_reference(
+            &items,
+            &reference,
+            VerdictMode::Exact,
+            CompareOptions::default(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            report.rows,
+            vec!["US\tPASS", " ar\tPASS", "XX\tFAIL", "es\tPASS"]
+        );
+        assert_eq!((report.passed, report.failed), (3, 1));
+    }
+
+    #[test]
+    fn test_exact_verdicts_respect_case() {
+        let options = CompareOptions {
+            case_sensitive: true,
+            trim_spaces: true,
+            ..CompareOptions::default()
+        };
+        let report =
+            check_against_reference(&["AR", "ar"], &["AR"], VerdictMode::Exact, options).unwrap();
+        assert_eq!(report.rows, vec!["AR\tPASS", "ar\tFAIL"]);
+    }
+
+    #[test]
+    fn test_pattern_verdicts_match_whole_item() {
+        let items = vec!["AB-123", "ab-12", "AB-1234", "user@example.com"];
+        let reference = vec![r"[A-Z]{2}-\d{3}", r"[^@\s]+@[^@\s]+"];
+        let report = check_against_reference(
+            &items,
+            &reference,
+            VerdictMode::Pattern,
+            CompareOptions::default(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            report.rows,
+            vec![
+                "AB-123\tPASS",
+                "ab-12\tFAIL",
+                "AB-1234\tFAIL",
+                "user@example.com\tPASS"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_invalid_pattern_is_an_error() {
+        let result = check_against_reference(
+            &["a"],
+            &["(unclosed"],
+            VerdictMode::Pattern,
+            CompareOptions::default(),
+        );
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,294 @@
+//! Single source of truth for what each panel holds
+//!
+//! Copy, save, workspace export and panel stats all go through
+//! [`PanelContent`], so a panel's name, default file and serialized text are
+//! defined once instead of in one `match` per feature.
+use crate::app::{
+    AnalysisPanel, App, ConvertPanel, InputPanel, MergePanel, PanelId, ResultsPanel, ResultsView,
+    Tab,
+};
+use crate::i18n::Text;
+use crate::parser::{parse_items, Delimiter};
+
+/// Every panel whose content can be copied, saved or exported
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PanelContent {
+    /// List 1 editor (Input tab)
+    List1,
+    /// List 2 editor (Input tab)
+    List2,
+    /// INFO/summary messages (Input tab)
+    Summary,
+    /// Items only in List 1 (Results tab)
+    OnlyInList1,
+    /// Items only in List 2 (Results tab)
+    OnlyInList2,
+    /// Items in both lists (Results tab)
+    Intersection,
+    /// All unique items (Results tab)
+    Union,
+    /// Pass/fail report of List 1 against List 2 (Results tab)
+    Verdict,
+    /// Keys only List 1 has, by keyed comparison (Results tab)
+    MissingInList2,
+    /// Keys only List 2 has, by keyed comparison (Results tab)
+    MissingInList1,
+    /// Keys whose values differ, by keyed comparison (Results tab)
+    ChangedValues,
+    /// Converter input editor (Convert tab)
+    ConvertInput,
+    /// Converter output (Convert tab)
+    ConvertOutput,
+    /// Single list editor (List tab)
+    SingleList,
+    /// Summary statistics (Analysis tab)
+    AnalysisStats,
+    /// Item frequencies (Analysis tab)
+    Frequencies,
+    /// Duplicated items (Analysis tab)
+    Duplicates,
+    /// Validation findings (Analysis tab)
+    Validation,
+    /// Joined rows (Merge tab)
+    MergeResult,
+}
+
+impl PanelContent {
+    /// Panels saved by the workspace export, in write order
+    pub const WORKSPACE: [PanelContent; 18] = [
+        PanelContent::List1,
+        PanelContent::List2,
+        PanelContent::SingleList,
+        PanelContent::ConvertInput,
+        PanelContent::ConvertOutput,
+        PanelContent::OnlyInList1,
+        PanelContent::OnlyInList2,
+        PanelContent::Intersection,
+        PanelContent::Union,
+        PanelContent::Verdict,
+        PanelContent::MissingInList2,
+        PanelContent::MissingInList1,
+        PanelContent::ChangedValues,
+        PanelContent::AnalysisStats,
+        PanelContent::Frequencies,
+        PanelContent::Duplicates,
+        PanelContent::Validation,
+        PanelContent::MergeResult,
+    ];
+
+    /// The panel focused in the current tab (`None` for the Merge settings)
+    pub fn active(app: &App) -> Option<Self> {
+        Some(match app.active_tab {
+            Tab::Input => match app.input_tab.panel {
+                InputPanel::List1 => PanelContent::List1,
+                InputPanel::List2 => PanelContent::List2,
+                InputPanel::Info => PanelContent::Summary,
+            },
+            Tab::Results if app.results_tab.view == ResultsView::Verdict => PanelContent::Verdict,
+            Tab::Results if app.results_tab.view == ResultsView::Keyed => {
+                PanelContent::keyed(app.results_tab.panel)
+            }
+            Tab::Results => app.results_tab.panel.into(),
+            Tab::Convert => match app.convert_tab.panel {
+                ConvertPanel::Input => PanelContent::ConvertInput,
+                ConvertPanel::Output => PanelContent::ConvertOutput,
+            },
+            Tab::List => PanelContent::SingleList,
+            Tab::Analysis => app.analysis_tab.panel.into(),
+            Tab::Merge => match app.merge_tab.panel {
+                MergePanel::Settings => return None,
+                MergePanel::Result => PanelContent::MergeResult,
+            },
+        })
+    }
+
+    /// Keyed comparison panel shown in a result panel slot of the Keyed view
+    pub fn keyed(panel: ResultsPanel) -> Self {
+        match panel {
+            ResultsPanel::OnlyInList1 => PanelContent::MissingInList2,
+            ResultsPanel::OnlyInList2 => PanelContent::MissingInList1,
+            _ => PanelContent::ChangedValues,
+        }
+    }
+
+    /// Editor behind the panel, if it is one
+    pub fn panel_id(self) -> Option<PanelId> {
+        match self {
+            PanelContent::List1 => Some(PanelId::List1),
+            PanelContent::List2 => Some(PanelId::List2),
+            PanelContent::ConvertInput => Some(PanelId::ConvertInput),
+            PanelContent::SingleList => Some(PanelId::SingleList),
+            _ => None,
+        }
+    }
+
+    /// Display name of the panel
+    pub fn name(self) -> Text {
+        match self {
+            PanelContent::List1 => Text::List1,
+            PanelContent::List2 => Text::List2,
+            PanelContent::Summary => Text::Results,
+            PanelContent::OnlyInList1 => Text::OnlyInList1,
+            PanelContent::OnlyInList2 => Text::OnlyInList2,
+            PanelContent::Intersection => Text::Intersection,
+            PanelContent::Union => Text::Union,
+            PanelContent::Verdict => Text::Verdict,
+            PanelContent::MissingInList2 => Text::MissingInList2,
+            PanelContent::MissingInList1 => Text::MissingInList1,
+            PanelContent::ChangedValues => Text::ChangedValues,
+            PanelContent::ConvertInput => Text::ConvertInput,
+            PanelContent::ConvertOutput => Text::ConvertOutput,
+            PanelContent::SingleList => Text::SingleList,
+            PanelContent::AnalysisStats => Text::AnalysisStats,
+            PanelContent::Frequencies => Text::AnalysisFrequencies,
+            PanelContent::Duplicates => Text::AnalysisDuplicates,
+            PanelContent::Validation => Text::AnalysisValidation,
+            PanelContent::MergeResult => Text::MergeResult,
+        }
+    }
+
+    /// Default file name (relative to the base directory)
+    pub fn filename(self) -> &'static str {
+        match self {
+            PanelContent::List1 => "list1.txt",
+            PanelContent::List2 => "list2.txt",
+            PanelContent::Summary => "results.txt",
+            PanelContent::OnlyInList1 => "only_in_list1.txt",
+            PanelContent::OnlyInList2 => "only_in_list2.txt",
+            PanelContent::Intersection => "intersection.txt",
+            PanelContent::Union => "union.txt",
+            PanelContent::Verdict => "verdict.txt",
+            PanelContent::MissingInList2 => "missing_in_list2.txt",
+            PanelContent::MissingInList1 => "missing_in_list1.txt",
+            PanelContent::ChangedValues => "changed_values.txt",
+            PanelContent::ConvertInput => "convert_input.txt",
+            PanelContent::ConvertOutput => "convert_output.txt",
+            PanelContent::SingleList => "single_list.txt",
+            PanelContent::AnalysisStats => "analysis_stats.txt",
+            PanelContent::Frequencies => "frequencies.txt",
+            PanelContent::Duplicates => "duplicates.txt",
+            PanelContent::Validation => "validation.txt",
+            PanelContent::MergeResult => "merge_result.txt",
+        }
+    }
+
+    /// Items of a result panel (empty before the first comparison or for other panels)
+    pub fn result_items(self, app: &App) -> &[String] {
+        let panel = match self {
+            PanelContent::OnlyInList1 => ResultsPanel::OnlyInList1,
+            PanelContent::OnlyInList2 => ResultsPanel::OnlyInList2,
+            PanelContent::Intersection => ResultsPanel::Intersection,
+            PanelContent::Union => ResultsPanel::Union,
+            _ => return &[],
+        };
+        app.results_tab.category_items(panel)
+    }
+
+    /// Rows of a keyed comparison panel (empty before the first keyed comparison or for other panels)
+    pub fn keyed_rows(self, app: &App) -> &[String] {
+        let panel = match self {
+            PanelContent::MissingInList2 => ResultsPanel::OnlyInList1,
+            PanelContent::MissingInList1 => ResultsPanel::OnlyInList2,
+            PanelContent::ChangedValues => ResultsPanel::Intersection,
+            _ => return &[],
+        };
+        app.results_tab.keyed_rows(panel)
+    }
+
+    /// Lines of an Analysis panel (empty for other panels)
+    pub fn analysis_lines(self, app: &App) -> &[String] {
+        let panel = match self {
+            PanelContent::AnalysisStats => AnalysisPanel::Stats,
+            PanelContent::Frequencies => AnalysisPanel::Frequencies,
+            PanelContent::Duplicates => AnalysisPanel::Duplicates,
+            PanelContent::Validation => AnalysisPanel::Validation,
+            _ => return &[],
+        };
+        app.analysis_tab.lines(panel)
+    }
+
+    /// Serialized content, as copied to the clipboard or written to the panel's file
+    ///
+    /// Editors are joined with their delimiter so the text parses back the same way.
+    pub fn content(self, app: &App) -> String {
+        let joined = |lines: &[String], delimiter: Delimiter| lines.join(delimiter.as_str());
+        match self {
+            PanelContent::List1 => joined(app.input_tab.list1.lines(), app.delimiter),
+            PanelContent::List2 => joined(app.input_tab.list2.lines(), app.delimiter),
+            PanelContent::Summary => app.results.join("\n"),
+            PanelContent::ConvertInput => joined(
+                app.convert_tab.input.lines(),
+                app.convert_tab.source_delimiter,
+            ),
+            PanelContent::ConvertOutput => app.convert_tab.output_serialized.clone(),
+            PanelContent::SingleList => joined(app.single_list.lines(), app.delimiter),
+            PanelContent::AnalysisStats
+            | PanelContent::Frequencies
+            | PanelContent::Duplicates
+            | PanelContent::Validation => self.analysis_lines(app).join("\n"),
+            PanelContent::Verdict => app.results_tab.verdict_rows().join("\n"),
+            PanelContent::MissingInList2
+            | PanelContent::MissingInList1
+            | PanelContent::ChangedValues => self.keyed_rows(app).join("\n"),
+            PanelContent::MergeResult => app.merge_tab.rows.join("\n"),
+            _ => self.result_items(app).join("\n"),
+        }
+    }
+
+    /// Number of items the panel holds
+    pub fn item_count(self, app: &App) -> usize {
+        match self {
+            PanelContent::List1 => app.live_counts.list1,
+            PanelContent::List2 => app.live_counts.list2,
+            PanelContent::Summary => app.results.len(),
+            PanelContent::ConvertInput => {
+                parse_items(&self.content(app), app.convert_tab.source_delimiter).len()
+            }
+            PanelContent::ConvertOutput => app.convert_tab.item_count,
+            PanelContent::SingleList => app.live_counts.single_list,
+            PanelContent::AnalysisStats
+            | PanelContent::Frequencies
+            | PanelContent::Duplicates
+            | PanelContent::Validation => self.analysis_lines(app).len(),
+            PanelContent::Verdict => app.results_tab.verdict_rows().len(),
+            PanelContent::MissingInList2
+            | PanelContent::MissingInList1
+            | PanelContent::ChangedValues => self.keyed_rows(app).len(),
+            PanelContent::MergeResult => app.merge_tab.rows.len(),
+            _ => self.result_items(app).len(),
+        }
+    }
+}
+
+impl From<ResultsPanel> for PanelContent {
+    fn from(panel: ResultsPanel) -> Self {
+        match panel {
+            ResultsPanel::OnlyInList1 => PanelContent::OnlyInList1,
+            ResultsPanel::OnlyInList2 => PanelContent::OnlyInList2,
+            ResultsPanel::Intersection => PanelContent::Intersection,
+            ResultsPanel::Union => PanelContent::Union,
+        }
+    }
+}
+
+impl From<AnalysisPanel> for PanelContent {
+    fn from(panel: AnalysisPanel) -> Self {
+        match panel {
+            AnalysisPanel::Stats => PanelContent::AnalysisStats,
+            AnalysisPanel::Frequencies => PanelContent::Frequencies,
+            AnalysisPanel::Duplicates => PanelContent::Duplicates,
+            AnalysisPanel::Validation => PanelContent::Validation,
+        }
+    }
+}
+
+impl From<PanelId> for PanelContent {
+    fn from(panel: PanelId) -> Self {
+        match panel {
+            PanelId::List1 => PanelContent::List1,
+            PanelId::List2 => PanelContent::List2,
+            PanelId::ConvertInput => PanelContent::ConvertInput,
+            PanelId::SingleList => PanelContent::SingleList,
+        }
+    }
+}
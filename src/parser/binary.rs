@@ -0,0 +1,147 @@
+//! Detection of binary data in loaded files and pastes, with safe views of it
+//!
+//! Binary content pasted into an editor garbles the terminal, so it is caught
+//! before loading and shown as a hex dump or as text with the offending bytes
+//! replaced.
+
+/// Bytes inspected for control characters
+const CONTROL_SAMPLE: usize = 8192;
+
+/// Bytes shown by [`hex_dump`]; longer input is cut
+pub const HEX_VIEW_LIMIT: usize = 64 * 1024;
+
+/// Bytes per [`hex_dump`] line
+const HEX_LINE_WIDTH: usize = 16;
+
+/// Why some input was taken for binary data
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BinaryReason {
+    /// A NUL byte at the given offset
+    NulByte(usize),
+    /// Bytes that are not valid UTF-8, starting at the given offset
+    InvalidUtf8(usize),
+    /// Many control characters (the count found in the inspected sample)
+    ControlCharacters(usize),
+}
+
+/// How binary input is shown in an editor
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BinaryView {
+    /// Offset, hex bytes and printable characters, 16 bytes per line
+    Hex,
+    /// The text with invalid bytes and control characters replaced by `�`
+    Lossy,
+}
+
+/// Control characters other than tab, line breaks and form feed
+fn is_binary_control(byte: u8) -> bool {
+    (byte < 0x20 && !matches!(byte, b'\t' | b'\n' | b'\r' | 0x0c)) || byte == 0x7f
+}
+
+/// Detect binary data
+///
+/// Input is binary when it holds a NUL byte, is not valid UTF-8, or when more
+/// than one in ten of its first bytes are control characters.
+pub fn detect_binary(bytes: &[u8]) -> Option<BinaryReason> {
+    if let Some(offset) = bytes.iter().position(|&byte| byte == 0) {
+        return Some(BinaryReason::NulByte(offset));
+    }
+    if let Err(err) = std::str::from_utf8(bytes) {
+        return Some(BinaryReason::InvalidUtf8(err.valid_up_to()));
+    }
+    let sample = &bytes[..bytes.len().min(CONTROL_SAMPLE)];
+    let controls = sample
+        .iter()
+        .filter(|&&byte| is_binary_control(byte))
+        .count();
+    (controls * 10 > sample.len()).then_some(BinaryReason::ControlCharacters(controls))
+}
+
+/// Text of `bytes` with invalid UTF-8 and control characters replaced by `�`
+pub fn lossy_text(bytes: &[u8]) -> String {
+    String::from_utf8_lossy(bytes)
+        .chars()
+        .map(|c| {
+            if c.is_ascii() && is_binary_control(c as u8) {
+                char::REPLACEMENT_CHARACTER
+            } else {
+                c
+            }
+        })
+        .collect()
+}
+
+/// Hex dump of the first [`HEX_VIEW_LIMIT`] bytes, one line per 16 bytes
+///
+/// Lines look like `00000010  48 65 6c 6c 6f 00  |Hello.|`.
+pub fn hex_dump(bytes: &[u8]) -> Vec<String> {
+    bytes[..bytes.len().min(HEX_VIEW_LIMIT)]
+        .chunks(HEX_LINE_WIDTH)
+        .enumerate()
+        .map(|(line, chunk)| {
+            let hex: Vec<String> = chunk.iter().map(|byte| format!("{:02x}", byte)).collect();
+            let printable: String = chunk
+                .iter()
+                .map(|&byte| {
+                    if byte.is_ascii_graphic() || byte == b' ' {
+                        byte as char
+                    } else {
+                        '.'
+                    }
+                })
+                .collect();
+            format!(
+                "{:08x}  {:<width$}  |{}|",
+                line * HEX_LINE_WIDTH,
+                hex.join(" "),
+                printable,
+                width = HEX_LINE_WIDTH * 3 - 1
+            )
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_detect_binary() {
+        assert_eq!(detect_binary("plain\ttext\r\nñ".as_bytes()), None);
+        assert_eq!(detect_binary(b"ab\0cd"), Some(BinaryReason::NulByte(2)));
+        assert_eq!(
+            detect_binary(b"abc\xff\xfe"),
+            Some(BinaryReason::InvalidUtf8(3))
+        );
+        assert_eq!(
+            detect_binary(b"a\x01\x02b"),
+            Some(BinaryReason::ControlCharacters(2))
+        );
+        // A few escape sequences (colored logs) are still text
+        assert_eq!(detect_binary(b"\x1b[31merror: disk full\x1b[0m"), None);
+    }
+
+    #[test]
+    fn test_lossy_text_replaces_bad_bytes() {
+        assert_eq!(lossy_text(b"a\xffb\x07c\td"), "a\u{fffd}b\u{fffd}c\td");
+    }
+
+    #[test]
+    fn test_hex_dump() {
+        let bytes: Vec<u8> = (0..18).map(|i| b'A' + i).chain([0]).collect();
+        let lines = hex_dump(&bytes);
+        assert_eq!(lines.len(), 2);
+        assert_eq!(
+            lines[0],
+            "00000000  41 42 43 44 45 46 47 48 49 4a 4b 4c 4d 4e 4f 50  |ABCDEFGHIJKLMNOP|"
+        );
+        assert_eq!(
+            lines[1],
+            "00000010  51 52 00                                         |QR.|"
+        );
+        assert_eq!(
+            hex_dump(&[0; HEX_VIEW_LIMIT + 1]).len(),
+            HEX_VIEW_LIMIT / 16
+        );
+    }
+}
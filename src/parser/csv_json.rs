@@ -0,0 +1,105 @@
+//! JSON objects from delimited rows with a header, the reverse of the JSON to
+//! CSV conversion
+//!
+//! Keys keep the header order, so objects are written field by field instead
+//! of through `serde_json::Map`, which sorts its keys.
+use serde_json::Value;
+
+/// JSON value of a field: `null` when empty, a boolean or number when it
+/// reads as one, a string otherwise
+///
+/// Numbers must follow the JSON grammar, so `007` and `+1` stay strings, and
+/// integers too large for 64 bits stay strings rather than lose digits.
+pub fn infer_json_value(field: &str) -> Value {
+    let field = field.trim();
+    if field.is_empty() {
+        return Value::Null;
+    }
+    if field.eq_ignore_ascii_case("true") || field.eq_ignore_ascii_case("false") {
+        return Value::Bool(field.eq_ignore_ascii_case("true"));
+    }
+    match serde_json::from_str::<serde_json::Number>(field) {
+        Ok(number) if number.is_f64() && !field.contains(['.', 'e', 'E']) => {
+            Value::String(field.to_string())
+        }
+        Ok(number) => Value::Number(number),
+        Err(_) => Value::String(field.to_string()),
+    }
+}
+
+/// One compact JSON object per data row of `rows`, the first of which is the header
+///
+/// Blank header names become `column N` (1-based), as do fields past the
+/// end of the header; fields missing from a short row are `null`.
+pub fn rows_to_json_objects<S: AsRef<str>>(rows: &[Vec<S>]) -> Vec<String> {
+    let Some((header, records)) = rows.split_first() else {
+        return Vec::new();
+    };
+    let key = |col: usize| match header.get(col).map(|name| name.as_ref().trim()) {
+        Some(name) if !name.is_empty() => name.to_string(),
+        _ => format!("column {}", col + 1),
+    };
+    records
+        .iter()
+        .map(|record| {
+            let fields: Vec<String> = (0..header.len().max(record.len()))
+                .map(|col| {
+                    let value = record
+                        .get(col)
+                        .map_or(Value::Null, |field| infer_json_value(field.as_ref()));
+                    format!("{}: {}", Value::String(key(col)), value)
+                })
+                .collect();
+            format!("{{{}}}", fields.join(", "))
+        })
+        .collect()
+}
+
+/// Lines of a JSON array holding `objects`, one object per line
+pub fn json_array_lines(objects: &[String]) -> Vec<String> {
+    let last = objects.len().saturating_sub(1);
+    let elements = objects.iter().enumerate().map(|(i, object)| {
+        let comma = if i < last { "," } else { "" };
+        format!("  {}{}", object, comma)
+    });
+    std::iter::once("[".to_string())
+        .chain(elements)
+        .chain(std::iter::once("]".to_string()))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::delimited_rows;
+
+    #[test]
+    fn test_infer_json_value() {
+        assert_eq!(infer_json_value(" 42 "), Value::from(42));
+        assert_eq!(infer_json_value("-1.5e3"), Value::from(-1500.0));
+        assert_eq!(infer_json_value("TRUE"), Value::Bool(true));
+        assert_eq!(infer_json_value(""), Value::Null);
+        assert_eq!(infer_json_value("007"), Value::from("007"));
+        assert_eq!(infer_json_value("+1"), Value::from("+1"));
+        assert_eq!(
+            infer_json_value("123456789012345678901234"),
+            Value::from("123456789012345678901234")
+        );
+    }
+
+    #[test]
+    fn test_rows_to_json_objects() {
+        let rows = delimited_rows("name,qty,,active\n\"Doe, J\",3,x,false\nAnn\n", ',');
+        let objects = rows_to_json_objects(&rows);
+        assert_eq!(
+            objects,
+            vec![
+                r#"{"name": "Doe, J", "qty": 3, "column 3": "x", "active": false}"#,
+                r#"{"name": "Ann", "qty": null, "column 3": null, "active": null}"#,
+            ]
+        );
+        let array: Value = serde_json::from_str(&json_array_lines(&objects).join("\n")).unwrap();
+        assert_eq!(array[0]["qty"], Value::from(3));
+        assert_eq!(json_array_lines(&[]), vec!["[", "]"]);
+    }
+}
@@ -0,0 +1,205 @@
+//! Extraction of the values at a path such as `$.items[*].id` from JSON sources
+//!
+//! Only the common subset of JSONPath is supported: keys (`.name` or
+//! `['name']`), array indexes (negative ones count from the end) and the `*`
+//! wildcard. The leading `$.` may be left out, so a bare `id` picks a
+//! top-level key.
+use super::Delimiter;
+use serde_json::Value;
+use std::fmt;
+
+/// One step of a [`JsonPath`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Step {
+    /// Member of an object
+    Key(String),
+    /// Element of an array, counted from the end when negative
+    Index(i64),
+    /// Every element of an array or member of an object
+    Wildcard,
+}
+
+/// A parsed JSON path, kept with the text it was typed as
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JsonPath {
+    text: String,
+    steps: Vec<Step>,
+}
+
+/// Length of the key at the start of `rest`, which ends at the next `.` or `[`
+fn key_len(rest: &str) -> usize {
+    rest.find(['.', '[']).unwrap_or(rest.len())
+}
+
+impl JsonPath {
+    /// Parse a path as typed: `$.items[*].id`, `$['a key'][0]` or just `id`
+    ///
+    /// # Errors
+    /// Returns what is wrong with the path: an empty key, an unclosed `[` or
+    /// something other than a quoted key, an index or `*` between brackets.
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let text = text.trim();
+        let mut rest = text.strip_prefix('$').unwrap_or(text);
+        let mut steps = Vec::new();
+        // Without `$` the path starts with a key
+        if !text.starts_with('$') && !rest.starts_with(['.', '[']) {
+            let len = key_len(rest);
+            steps.push(Step::Key(rest[..len].to_string()));
+            rest = &rest[len..];
+        }
+        while !rest.is_empty() {
+            if let Some(after) = rest.strip_prefix('.') {
+                let len = key_len(after);
+                let key = &after[..len];
+                steps.push(match key {
+                    "" => return Err(format!("missing key after '.' in {}", text)),
+                    "*" => Step::Wildcard,
+                    key => Step::Key(key.to_string()),
+                });
+                rest = &after[len..];
+            } else if let Some(after) = rest.strip_prefix('[') {
+                let (inner, after) = after
+                    .split_once(']')
+                    .ok_or_else(|| format!("unclosed '[' in {}", text))?;
+                let inner = inner.trim();
+                let quoted = ['\'', '"']
+                    .iter()
+                    .find_map(|&q| inner.strip_prefix(q)?.strip_suffix(q));
+                steps.push(match (inner, quoted) {
+                    (_, Some(key)) => Step::Key(key.to_string()),
+                    ("*", None) => Step::Wildcard,
+                    (index, None) => Step::Index(
+                        index
+                            .parse()
+                            .map_err(|_| format!("invalid index '{}' in {}", index, text))?,
+                    ),
+                });
+                rest = after;
+            } else {
+                return Err(format!("expected '.' or '[' at '{}' in {}", rest, text));
+            }
+        }
+        Ok(Self {
+            text: text.to_string(),
+            steps,
+        })
+    }
+
+    /// The values at this path in `root`, in document order
+    ///
+    /// Steps that do not apply (a key of an array, an index past the end)
+    /// select nothing instead of failing.
+    pub fn select<'a>(&self, root: &'a Value) -> Vec<&'a Value> {
+        let mut values = vec![root];
+        for step in &self.steps {
+            values = values
+                .into_iter()
+                .flat_map(|value| -> Vec<&Value> {
+                    match (step, value) {
+                        (Step::Key(key), Value::Object(map)) => map.get(key).into_iter().collect(),
+                        (Step::Index(index), Value::Array(items)) => {
+                            let index = if *index < 0 {
+                                items.len().checked_sub(index.unsigned_abs() as usize)
+                            } else {
+                                Some(*index as usize)
+                            };
+                            index.and_then(|i| items.get(i)).into_iter().collect()
+                        }
+                        (Step::Wildcard, Value::Array(items)) => items.iter().collect(),
+                        (Step::Wildcard, Value::Object(map)) => map.values().collect(),
+                        _ => Vec::new(),
+                    }
+                })
+                .collect();
+        }
+        values
+    }
+}
+
+impl fmt::Display for JsonPath {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.text)
+    }
+}
+
+/// Items at `path` in `json`, the document text returned by
+/// [`parse_structured_to_list`](super::parse_structured_to_list) for `source`
+///
+/// Strings become items as they are, other values as compact JSON. For JSON
+/// Lines the path is applied to every line's document.
+///
+/// # Errors
+/// Returns the parse error of `json`.
+pub fn extract_json_path(
+    json: &str,
+    source: Delimiter,
+    path: &JsonPath,
+) -> Result<Vec<String>, String> {
+    if json.trim().is_empty() {
+        return Ok(Vec::new());
+    }
+    let document: Value = serde_json::from_str(json).map_err(|e| e.to_string())?;
+    let roots: Vec<&Value> = match (&document, source) {
+        (Value::Array(documents), Delimiter::JsonLines) => documents.iter().collect(),
+        _ => vec![&document],
+    };
+    Ok(roots
+        .into_iter()
+        .flat_map(|root| path.select(root))
+        .map(|value| match value.as_str() {
+            Some(text) => text.to_string(),
+            None => value.to_string(),
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_json_path() {
+        let path = JsonPath::parse(" $.items[*]['the id'] ").unwrap();
+        assert_eq!(
+            path.steps,
+            vec![
+                Step::Key("items".to_string()),
+                Step::Wildcard,
+                Step::Key("the id".to_string())
+            ]
+        );
+        assert_eq!(path.to_string(), "$.items[*]['the id']");
+        assert_eq!(
+            JsonPath::parse("rows[-1]").unwrap().steps,
+            vec![Step::Key("rows".to_string()), Step::Index(-1)]
+        );
+        assert!(JsonPath::parse("$").unwrap().steps.is_empty());
+        assert!(JsonPath::parse("$.items[").is_err());
+        assert!(JsonPath::parse("$.a..b").is_err());
+        assert!(JsonPath::parse("$[x]").is_err());
+    }
+
+    #[test]
+    fn test_extract_json_path() {
+        let json = r#"{"items": [{"id": 1, "tag": "a"}, {"tag": "b"}, {"id": "x7"}]}"#;
+        let extract = |path: &str, source| {
+            extract_json_path(json, source, &JsonPath::parse(path).unwrap()).unwrap()
+        };
+        assert_eq!(extract("$.items[*].id", Delimiter::Json), vec!["1", "x7"]);
+        assert_eq!(
+            extract("items[-1]", Delimiter::Json),
+            vec![r#"{"id":"x7"}"#]
+        );
+        assert!(extract("$.items.id", Delimiter::Json).is_empty());
+
+        let lines = r#"[{"id": 1}, {"id": 2}]"#;
+        let path = JsonPath::parse("id").unwrap();
+        assert_eq!(
+            extract_json_path(lines, Delimiter::JsonLines, &path).unwrap(),
+            vec!["1", "2"]
+        );
+        assert!(extract_json_path(lines, Delimiter::Json, &path)
+            .unwrap()
+            .is_empty());
+    }
+}
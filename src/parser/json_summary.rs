@@ -0,0 +1,187 @@
+//! Field summary of JSON input (key names, types, nulls, nesting) shown before converting
+use serde_json::Value;
+use std::collections::{BTreeMap, BTreeSet};
+
+/// What is known about one key of a JSON array of objects
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct FieldSummary {
+    /// Key name (`[]` for the items of an array of primitives)
+    pub key: String,
+    /// JSON types seen for the key, in a fixed order
+    pub types: Vec<&'static str>,
+    /// Records where the key is `null`
+    pub nulls: usize,
+    /// Records without the key
+    pub missing: usize,
+    /// Deepest nesting of the key's values (0 for scalars)
+    pub depth: usize,
+}
+
+impl FieldSummary {
+    /// One-line description such as `id: number` or `tags: array|null (2 null, depth 1)`
+    pub fn describe(&self) -> String {
+        let mut details = Vec::new();
+        if self.nulls > 0 {
+            details.push(format!("{} null", self.nulls));
+        }
+        if self.missing > 0 {
+            details.push(format!("{} missing", self.missing));
+        }
+        if self.depth > 0 {
+            details.push(format!("depth {}", self.depth));
+        }
+        let types = self.types.join("|");
+        if details.is_empty() {
+            format!("{}: {}", self.key, types)
+        } else {
+            format!("{}: {} ({})", self.key, types, details.join(", "))
+        }
+    }
+}
+
+/// Summary of a JSON document
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct JsonSummary {
+    /// Number of records (array items, or 1 for a single object)
+    pub records: usize,
+    /// Deepest nesting of a record (1 for flat objects)
+    pub depth: usize,
+    /// One entry per key, sorted by key
+    pub fields: Vec<FieldSummary>,
+}
+
+/// Type names in the order they are listed
+const TYPE_ORDER: [&str; 6] = ["string", "number", "boolean", "object", "array", "null"];
+
+fn type_name(value: &Value) -> &'static str {
+    match value {
+        Value::String(_) => "string",
+        Value::Number(_) => "number",
+        Value::Bool(_) => "boolean",
+        Value::Object(_) => "object",
+        Value::Array(_) => "array",
+        Value::Null => "null",
+    }
+}
+
+/// Nesting depth of a value: 0 for scalars, 1 for a flat object or array, and so on
+pub fn json_depth(value: &Value) -> usize {
+    match value {
+        Value::Object(map) => 1 + map.values().map(json_depth).max().unwrap_or(0),
+        Value::Array(items) => 1 + items.iter().map(json_depth).max().unwrap_or(0),
+        _ => 0,
+    }
+}
+
+/// Summarize the records of a JSON document
+///
+/// A single object counts as one record. Objects contribute one field per key;
+/// other records are summarized under the `[]` key.
+pub fn summarize_json(value: &Value) -> JsonSummary {
+    let records: Vec<&Value> = match value {
+        Value::Array(items) => items.iter().collect(),
+        other => vec![other],
+    };
+
+    let mut fields: BTreeMap<String, FieldSummary> = BTreeMap::new();
+    let mut observe = |key: &str, value: Option<&Value>| {
+        let field = fields
+            .entry(key.to_string())
+            .or_insert_with(|| FieldSummary {
+                key: key.to_string(),
+                ..FieldSummary::default()
+            });
+        match value {
+            Some(value) => {
+                let name = type_name(value);
+                if !field.types.contains(&name) {
+                    field.types.push(name);
+                }
+                field.nulls += usize::from(value.is_null());
+                field.depth = field.depth.max(json_depth(value));
+            }
+            None => field.missing += 1,
+        }
+    };
+
+    let keys: BTreeSet<&String> = records
+        .iter()
+        .filter_map(|record| record.as_object())
+        .flat_map(|object| object.keys())
+        .collect();
+    for record in &records {
+        match record.as_object() {
+            Some(object) => {
+                for key in &keys {
+                    observe(key, object.get(key.as_str()));
+                }
+            }
+            None => observe("[]", Some(record)),
+        }
+    }
+
+    let fields = fields
+        .into_values()
+        .map(|mut field| {
+            field
+                .types
+                .sort_by_key(|name| TYPE_ORDER.iter().position(|known| known == name));
+            field
+        })
+        .collect();
+    JsonSummary {
+        records: records.len(),
+        depth: records
+            .iter()
+            .map(|record| json_depth(record))
+            .max()
+            .unwrap_or(0),
+        fields,
+    }
+}
+
+/// Summarize JSON text, or `None` if it is not valid JSON
+pub fn summarize_json_text(text: &str) -> Option<JsonSummary> {
+    serde_json::from_str(text)
+        .ok()
+        .map(|value| summarize_json(&value))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_summarize_objects() {
+        let value: Value = serde_json::from_str(
+            r#"[{"id": 1, "name": "a", "tags": ["x"]},
+                {"id": 2, "name": null, "meta": {"k": {"v": 1}}},
+                {"id": "3"}]"#,
+        )
+        .unwrap();
+        let summary = summarize_json(&value);
+        assert_eq!(summary.records, 3);
+        assert_eq!(summary.depth, 3);
+        let lines: Vec<String> = summary.fields.iter().map(FieldSummary::describe).collect();
+        assert_eq!(
+            lines,
+            vec![
+                "id: string|number",
+                "meta: object (2 missing, depth 2)",
+                "name: string|null (1 null, 1 missing)",
+                "tags: array (2 missing, depth 1)",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_summarize_primitives() {
+        let value: Value = serde_json::from_str(r#"["a", 1, null]"#).unwrap();
+        let summary = summarize_json(&value);
+        assert_eq!(summary.depth, 0);
+        assert_eq!(
+            summary.fields[0].describe(),
+            "[]: string|number|null (1 null)"
+        );
+    }
+}
@@ -0,0 +1,158 @@
+//! Extraction of `key=value` / `key: value` pairs from config dumps and log attributes
+use super::join_record;
+
+/// What the converter outputs from key-value lines
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KeyValueOutput {
+    /// One key per line
+    Keys,
+    /// One value per line
+    Values,
+    /// A header of every key and one record per group of pairs
+    Columns,
+}
+
+impl KeyValueOutput {
+    /// The output to switch to next (`None` turns key-value mode off)
+    pub fn next(current: Option<Self>) -> Option<Self> {
+        match current {
+            None => Some(KeyValueOutput::Keys),
+            Some(KeyValueOutput::Keys) => Some(KeyValueOutput::Values),
+            Some(KeyValueOutput::Values) => Some(KeyValueOutput::Columns),
+            Some(KeyValueOutput::Columns) => None,
+        }
+    }
+
+    /// Short name shown to the user
+    pub fn display_name(self) -> &'static str {
+        match self {
+            KeyValueOutput::Keys => "keys",
+            KeyValueOutput::Values => "values",
+            KeyValueOutput::Columns => "columns",
+        }
+    }
+}
+
+/// Split a line at its first `=` or `:` into a trimmed key and value
+///
+/// Returns `None` for lines without a separator or with an empty key.
+pub fn split_key_value(line: &str) -> Option<(&str, &str)> {
+    let at = line.find(['=', ':'])?;
+    let key = line[..at].trim();
+    (!key.is_empty()).then(|| (key, line[at + 1..].trim()))
+}
+
+/// Group the key-value lines of `input` into records
+///
+/// A blank line, or a key already present in the current record, starts a new
+/// record. Lines that are not key-value pairs are skipped.
+pub fn key_value_records(input: &str) -> Vec<Vec<(&str, &str)>> {
+    let mut records = Vec::new();
+    let mut current: Vec<(&str, &str)> = Vec::new();
+    for line in input.lines() {
+        let pair = split_key_value(line);
+        let repeated = pair.is_some_and(|(key, _)| current.iter().any(|&(k, _)| k == key));
+        if (line.trim().is_empty() || repeated) && !current.is_empty() {
+            records.push(std::mem::take(&mut current));
+        }
+        current.extend(pair);
+    }
+    if !current.is_empty() {
+        records.push(current);
+    }
+    records
+}
+
+/// Extract keys, values or a table of records from key-value lines
+///
+/// # Arguments
+/// * `input` - Lines such as `key=value` or `key: value`
+/// * `output` - What to extract
+/// * `separator` - Field separator of [`KeyValueOutput::Columns`] rows
+/// * `quote` - Quote the table fields with [`super::quote_csv_field`]
+///
+/// # Returns
+/// The output rows; columns follow the order in which keys first appear and
+/// records missing a key leave its field empty.
+pub fn extract_key_values(
+    input: &str,
+    output: KeyValueOutput,
+    separator: char,
+    quote: bool,
+) -> Vec<String> {
+    let records = key_value_records(input);
+    let pairs = records.iter().flatten();
+    match output {
+        KeyValueOutput::Keys => pairs.map(|&(key, _)| key.to_string()).collect(),
+        KeyValueOutput::Values => pairs.map(|&(_, value)| value.to_string()).collect(),
+        KeyValueOutput::Columns => {
+            let mut keys: Vec<&str> = Vec::new();
+            for &(key, _) in pairs {
+                if !keys.contains(&key) {
+                    keys.push(key);
+                }
+            }
+            if keys.is_empty() {
+                return Vec::new();
+            }
+            let rows = records.iter().map(|record| {
+                let fields = keys.iter().map(|key| {
+                    record
+                        .iter()
+                        .find(|(k, _)| k == key)
+                        .map_or("", |&(_, value)| value)
+                });
+                join_record(fields, separator, quote)
+            });
+            std::iter::once(join_record(keys.iter().copied(), separator, quote))
+                .chain(rows)
+                .collect()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DUMP: &str = "host = db1\nport: 5432\nnot a pair\nurl=http://x:80/?a=b\n\nhost=db2\nhost=db3\nport=6543\n";
+
+    #[test]
+    fn test_split_key_value() {
+        assert_eq!(split_key_value(" port : 5432 "), Some(("port", "5432")));
+        assert_eq!(split_key_value("time: 12:00"), Some(("time", "12:00")));
+        assert_eq!(split_key_value("a=b:c"), Some(("a", "b:c")));
+        assert_eq!(split_key_value("=value"), None);
+        assert_eq!(split_key_value("plain"), None);
+    }
+
+    #[test]
+    fn test_records_split_on_blank_lines_and_repeated_keys() {
+        let records = key_value_records(DUMP);
+        assert_eq!(records.len(), 3);
+        assert_eq!(records[1], vec![("host", "db2")]);
+        assert_eq!(records[2], vec![("host", "db3"), ("port", "6543")]);
+    }
+
+    #[test]
+    fn test_extract_keys_values_and_columns() {
+        assert_eq!(
+            extract_key_values(DUMP, KeyValueOutput::Keys, ',', false),
+            vec!["host", "port", "url", "host", "host", "port"]
+        );
+        assert_eq!(
+            extract_key_values(DUMP, KeyValueOutput::Values, ',', false)[..3],
+            ["db1", "5432", "http://x:80/?a=b"]
+        );
+        assert_eq!(
+            extract_key_values(DUMP, KeyValueOutput::Columns, ',', false),
+            vec![
+                "host,port,url",
+                "db1,5432,http://x:80/?a=b",
+                "db2,,",
+                "db3,6543,"
+            ]
+        );
+        assert!(extract_key_values("no pairs", KeyValueOutput::Columns, ',', false).is_empty());
+    }
+}
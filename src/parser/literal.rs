@@ -0,0 +1,95 @@
+//! Python list and JavaScript array literals of converted items
+//!
+//! Items become single-quoted string literals with the escapes both languages
+//! understand, so the output can be pasted into a script as is.
+use std::fmt::Write;
+
+/// Language a list literal is written for
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LiteralTarget {
+    /// `['a', 'b']`
+    Python,
+    /// `['a', 'b']`, with the line separators JavaScript strings cannot hold escaped
+    JavaScript,
+}
+
+impl LiteralTarget {
+    /// The target to switch to next (`None` writes delimited output again)
+    pub fn next(current: Option<Self>) -> Option<Self> {
+        match current {
+            None => Some(LiteralTarget::Python),
+            Some(LiteralTarget::Python) => Some(LiteralTarget::JavaScript),
+            Some(LiteralTarget::JavaScript) => None,
+        }
+    }
+
+    /// Short name shown to the user
+    pub fn display_name(self) -> &'static str {
+        match self {
+            LiteralTarget::Python => "Python list",
+            LiteralTarget::JavaScript => "JS array",
+        }
+    }
+}
+
+/// Single-quoted string literal of `item`
+///
+/// Backslashes, single quotes, line breaks and tabs are escaped; other control
+/// characters become `\xNN`. For JavaScript, U+2028 and U+2029 are escaped too.
+pub fn quote_literal(item: &str, target: LiteralTarget) -> String {
+    let mut literal = String::with_capacity(item.len() + 2);
+    literal.push('\'');
+    for c in item.chars() {
+        match c {
+            '\\' => literal.push_str("\\\\"),
+            '\'' => literal.push_str("\\'"),
+            '\n' => literal.push_str("\\n"),
+            '\r' => literal.push_str("\\r"),
+            '\t' => literal.push_str("\\t"),
+            '\u{2028}' | '\u{2029}' if target == LiteralTarget::JavaScript => {
+                let _ = write!(literal, "\\u{:04x}", c as u32);
+            }
+            c if (c as u32) < 0x20 || c == '\u{7f}' => {
+                let _ = write!(literal, "\\x{:02x}", c as u32);
+            }
+            c => literal.push(c),
+        }
+    }
+    literal.push('\'');
+    literal
+}
+
+/// List literal of `items` for `target`: `['a', 'b']`
+pub fn to_list_literal<S: AsRef<str>>(items: &[S], target: LiteralTarget) -> String {
+    let quoted: Vec<String> = items
+        .iter()
+        .map(|item| quote_literal(item.as_ref(), target))
+        .collect();
+    format!("[{}]", quoted.join(", "))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_list_literal() {
+        assert_eq!(
+            to_list_literal(&["a", "b"], LiteralTarget::Python),
+            "['a', 'b']"
+        );
+        assert_eq!(to_list_literal::<&str>(&[], LiteralTarget::Python), "[]");
+        assert_eq!(
+            quote_literal("it's C:\\tmp\n\u{1}", LiteralTarget::Python),
+            "'it\\'s C:\\\\tmp\\n\\x01'"
+        );
+        assert_eq!(
+            quote_literal("a\u{2028}b", LiteralTarget::JavaScript),
+            "'a\\u2028b'"
+        );
+        assert_eq!(
+            quote_literal("a\u{2028}b", LiteralTarget::Python),
+            "'a\u{2028}b'"
+        );
+    }
+}
@@ -0,0 +1,172 @@
+//! GitHub-flavored Markdown tables of JSON objects or CSV rows with a header
+//!
+//! Cells are padded so the pipes line up, which keeps the table readable as
+//! plain text before it is rendered.
+use super::{parse_structured_to_list, split_quoted, Delimiter, JsonArrayStrategy, NestedJson};
+use std::borrow::Cow;
+
+/// Text of a field as a table cell: trimmed, pipes escaped, line breaks as `<br>`
+fn cell(field: &str) -> String {
+    field
+        .trim()
+        .replace('|', "\\|")
+        .replace("\r\n", "<br>")
+        .replace('\n', "<br>")
+}
+
+fn is_number(cell: &str) -> bool {
+    cell.parse::<f64>().is_ok_and(f64::is_finite)
+}
+
+/// Markdown table lines of `rows`, the first of which is the header
+///
+/// Short rows are padded with empty cells, and columns holding only numbers
+/// are right-aligned. Returns no lines when there are no columns.
+pub fn to_markdown_table<S: AsRef<str>>(rows: &[Vec<S>]) -> Vec<String> {
+    let cells: Vec<Vec<String>> = rows
+        .iter()
+        .map(|row| row.iter().map(|field| cell(field.as_ref())).collect())
+        .collect();
+    let columns = cells.iter().map(Vec::len).max().unwrap_or(0);
+    if columns == 0 {
+        return Vec::new();
+    }
+    let column = |col: usize| cells.iter().skip(1).filter_map(move |row| row.get(col));
+    // The delimiter row needs at least three dashes
+    let widths: Vec<usize> = (0..columns)
+        .map(|col| {
+            let header = cells[0].get(col).map_or(0, |cell| cell.chars().count());
+            column(col)
+                .map(|cell| cell.chars().count())
+                .fold(header.max(3), usize::max)
+        })
+        .collect();
+    let numeric: Vec<bool> = (0..columns)
+        .map(|col| {
+            let mut values = column(col).filter(|cell| !cell.is_empty()).peekable();
+            values.peek().is_some() && values.all(|cell| is_number(cell))
+        })
+        .collect();
+
+    let line = |row: &[String]| {
+        let fields: Vec<String> = (0..columns)
+            .map(|col| {
+                let cell = row.get(col).map_or("", String::as_str);
+                let width = widths[col];
+                if numeric[col] {
+                    format!("{:>width$}", cell)
+                } else {
+                    format!("{:<width$}", cell)
+                }
+            })
+            .collect();
+        format!("| {} |", fields.join(" | "))
+    };
+    let rule: Vec<String> = (0..columns)
+        .map(|col| match numeric[col] {
+            true => format!("{}:", "-".repeat(widths[col] - 1)),
+            false => "-".repeat(widths[col]),
+        })
+        .collect();
+
+    let mut lines = vec![line(&cells[0]), format!("| {} |", rule.join(" | "))];
+    lines.extend(cells[1..].iter().map(|row| line(row)));
+    lines
+}
+
+fn split_row(row: &str, separator: char) -> Vec<String> {
+    split_quoted(row, separator)
+        .into_iter()
+        .map(Cow::into_owned)
+        .collect()
+}
+
+/// Rows of delimited text split on `separator`, honouring CSV quotes
+///
+/// Blank lines are skipped; the first row is the header.
+pub fn delimited_rows(input: &str, separator: char) -> Vec<Vec<String>> {
+    input
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| split_row(line, separator))
+        .collect()
+}
+
+/// Header and rows of the objects in a JSON, JSON Lines or YAML `source`
+///
+/// The objects are converted to CSV rows like any structured source, with
+/// nested values written as set by `nested`, then split again. Returns `None`
+/// when the input holds no objects to take a header from, or `strategy`
+/// writes them as JSON text.
+///
+/// # Errors
+/// Returns the parse error of the input.
+pub fn structured_rows(
+    input: &str,
+    source: Delimiter,
+    strategy: JsonArrayStrategy,
+    nested: NestedJson,
+) -> Result<Option<Vec<Vec<String>>>, String> {
+    let (lines, json) = parse_structured_to_list(input, source, ',', true, strategy, nested)?;
+    let has_objects = serde_json::from_str::<serde_json::Value>(&json).is_ok_and(|value| {
+        value.is_object()
+            || value
+                .as_array()
+                .is_some_and(|items| items.iter().any(serde_json::Value::is_object))
+    });
+    if !has_objects || strategy == JsonArrayStrategy::Stringify {
+        return Ok(None);
+    }
+    // A quoted field may hold a line break, so each record is split on its own
+    Ok(Some(
+        lines.iter().map(|line| split_row(line, ',')).collect(),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_markdown_table() {
+        let rows = delimited_rows("name,qty\n\"Doe, J|r\",10\n\nAnn,2.5\nBo", ',');
+        assert_eq!(
+            to_markdown_table(&rows),
+            vec![
+                "| name      | qty |",
+                "| --------- | --: |",
+                "| Doe, J\\|r |  10 |",
+                "| Ann       | 2.5 |",
+                "| Bo        |     |",
+            ]
+        );
+        assert!(to_markdown_table::<&str>(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_structured_rows() {
+        let json = r#"[{"id": 1, "note": "a\nb"}, {"id": 2}]"#;
+        let rows = structured_rows(
+            json,
+            Delimiter::Json,
+            JsonArrayStrategy::Union,
+            NestedJson::Raw,
+        )
+        .unwrap()
+        .unwrap();
+        assert_eq!(
+            rows,
+            vec![vec!["id", "note"], vec!["1", "a\nb"], vec!["2", ""]]
+        );
+        assert_eq!(to_markdown_table(&rows)[2], "|   1 | a<br>b |");
+        assert_eq!(
+            structured_rows(
+                "[1, 2]",
+                Delimiter::Json,
+                JsonArrayStrategy::Union,
+                NestedJson::Raw
+            ),
+            Ok(None)
+        );
+    }
+}
@@ -1,6 +1,59 @@
 use regex::Regex;
-use serde_json;
+use std::borrow::Cow;
 use std::collections::BTreeSet;
+use unicode_width::UnicodeWidthStr;
+
+pub mod binary;
+pub mod csv_json;
+pub mod json_path;
+pub mod json_summary;
+pub mod key_value;
+pub mod literal;
+pub mod markdown;
+
+pub use binary::*;
+pub use csv_json::*;
+pub use json_path::*;
+pub use json_summary::*;
+pub use key_value::*;
+pub use literal::*;
+pub use markdown::*;
+
+/// Most bytes a custom delimiter can hold
+pub const MAX_CUSTOM_DELIMITER_LEN: usize = 16;
+
+/// User-typed delimiter string such as `||` or `::`
+///
+/// Stored inline (like [`crate::operations::TrimSet`]'s custom characters) so
+/// [`Delimiter`] stays `Copy`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CustomDelimiter {
+    bytes: [u8; MAX_CUSTOM_DELIMITER_LEN],
+    len: usize,
+}
+
+impl CustomDelimiter {
+    /// A custom delimiter, unless `text` is empty, holds a line break or is
+    /// longer than [`MAX_CUSTOM_DELIMITER_LEN`] bytes
+    pub fn new(text: &str) -> Option<Self> {
+        if text.is_empty() || text.len() > MAX_CUSTOM_DELIMITER_LEN || text.contains(['\r', '\n']) {
+            return None;
+        }
+        let mut bytes = [0; MAX_CUSTOM_DELIMITER_LEN];
+        bytes[..text.len()].copy_from_slice(text.as_bytes());
+        Some(Self {
+            bytes,
+            len: text.len(),
+        })
+    }
+
+    /// The delimiter text
+    pub fn as_str(&self) -> &str {
+        // Always a whole `&str` copied in `new`
+        std::str::from_utf8(&self.bytes[..self.len]).unwrap_or_default()
+    }
+}
+
 /// Supported delimiters for parsing lists
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Delimiter {
@@ -12,45 +65,532 @@ pub enum Delimiter {
     Comma,
     /// Semicolon (;)
     Semicolon,
+    /// Comma-separated values with RFC 4180 quotes (`"Smith, John"` is one item)
+    CsvComma,
+    /// Semicolon-separated values with RFC 4180 quotes
+    CsvSemicolon,
     /// JSON format (auto-detected list of objects)
     Json,
+    /// JSON Lines: one JSON document per line (a list of lines outside the Convert tab)
+    JsonLines,
+    /// YAML sequences or mappings (a list of lines outside the Convert tab)
+    Yaml,
+    /// Any string typed by the user
+    Custom(CustomDelimiter),
 }
 
 impl Delimiter {
     /// Get the character representation of the delimiter
+    ///
+    /// A custom delimiter gives its first character; split and join with
+    /// [`Delimiter::as_str`], and check [`Delimiter::field_separator`] where a
+    /// single character is required.
     pub fn as_char(&self) -> char {
         match self {
             Delimiter::Newline => '\n',
             Delimiter::Tab => '\t',
             Delimiter::Comma => ',',
             Delimiter::Semicolon => ';',
+            Delimiter::CsvComma => ',',
+            Delimiter::CsvSemicolon => ';',
             Delimiter::Json => '{', // Logic will handle this specially
+            Delimiter::JsonLines | Delimiter::Yaml => '\n',
+            Delimiter::Custom(custom) => custom.as_str().chars().next().unwrap_or(','),
+        }
+    }
+
+    /// Get the text items are split on and joined with
+    pub fn as_str(&self) -> &str {
+        match self {
+            Delimiter::Newline => "\n",
+            Delimiter::Tab => "\t",
+            Delimiter::Comma => ",",
+            Delimiter::Semicolon => ";",
+            Delimiter::CsvComma => ",",
+            Delimiter::CsvSemicolon => ";",
+            Delimiter::Json => "{",
+            Delimiter::JsonLines | Delimiter::Yaml => "\n",
+            Delimiter::Custom(custom) => custom.as_str(),
+        }
+    }
+
+    /// Whether the Convert tab parses the input as JSON or YAML documents
+    pub fn is_structured(&self) -> bool {
+        matches!(
+            self,
+            Delimiter::Json | Delimiter::JsonLines | Delimiter::Yaml
+        )
+    }
+
+    /// Whether items may be wrapped in double quotes that hide the delimiter
+    pub fn is_quoted(&self) -> bool {
+        matches!(self, Delimiter::CsvComma | Delimiter::CsvSemicolon)
+    }
+
+    /// The single character separating the fields of a record, if the delimiter is one
+    pub fn field_separator(&self) -> Option<char> {
+        let mut chars = self.as_str().chars();
+        match (chars.next(), chars.next()) {
+            (Some(c), None) if !self.is_structured() => Some(c),
+            _ => None,
         }
     }
 
     /// Get a display string for the delimiter
-    pub fn display_name(&self) -> &'static str {
+    pub fn display_name(&self) -> &str {
         match self {
             Delimiter::Newline => "\\n",
             Delimiter::Tab => "\\t",
             Delimiter::Comma => ",",
             Delimiter::Semicolon => ";",
+            Delimiter::CsvComma => "CSV ,",
+            Delimiter::CsvSemicolon => "CSV ;",
             Delimiter::Json => "JSON",
+            Delimiter::JsonLines => "JSONL",
+            Delimiter::Yaml => "YAML",
+            Delimiter::Custom(custom) => custom.as_str(),
+        }
+    }
+
+    /// Parse a delimiter from its name or character (`comma` or `,`, `tab` or `\t`, ...)
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name.to_ascii_lowercase().as_str() {
+            "newline" | "nl" | "\\n" | "\n" => Some(Delimiter::Newline),
+            "tab" | "\\t" | "\t" => Some(Delimiter::Tab),
+            "comma" | "," => Some(Delimiter::Comma),
+            "semicolon" | ";" => Some(Delimiter::Semicolon),
+            "csv" | "csv," => Some(Delimiter::CsvComma),
+            "csv-semicolon" | "csv;" => Some(Delimiter::CsvSemicolon),
+            "json" => Some(Delimiter::Json),
+            "jsonl" | "ndjson" => Some(Delimiter::JsonLines),
+            "yaml" | "yml" => Some(Delimiter::Yaml),
+            _ => None,
         }
     }
 
-    /// Cycle to the next delimiter
+    /// Cycle to the next delimiter (a custom one is left for newline)
     pub fn next(&self) -> Self {
         match self {
             Delimiter::Newline => Delimiter::Tab,
             Delimiter::Tab => Delimiter::Comma,
-            Delimiter::Comma => Delimiter::Semicolon,
-            Delimiter::Semicolon => Delimiter::Json,
-            Delimiter::Json => Delimiter::Newline,
+            Delimiter::Comma => Delimiter::CsvComma,
+            Delimiter::CsvComma => Delimiter::Semicolon,
+            Delimiter::Semicolon => Delimiter::CsvSemicolon,
+            Delimiter::CsvSemicolon => Delimiter::Json,
+            Delimiter::Json => Delimiter::JsonLines,
+            Delimiter::JsonLines => Delimiter::Yaml,
+            Delimiter::Yaml | Delimiter::Custom(_) => Delimiter::Newline,
         }
     }
 }
 
+/// Bytes of pasted or loaded text inspected by [`detect_delimiter`]
+const DETECT_SAMPLE: usize = 64 * 1024;
+
+/// Guess the delimiter of pasted or loaded text
+///
+/// Text wrapped in `[...]` or `{...}` that parses as JSON is JSON, and several
+/// lines that each parse as a JSON object are JSON Lines. Otherwise
+/// the most frequent of tab, semicolon and comma wins over line breaks when it
+/// occurs more than twice per line break, so `Smith, John` lines stay one item
+/// per line while `a,b,c` rows are split on the commas. Comma or semicolon
+/// text holding double quotes is taken as CSV. Returns `None` when the text
+/// holds no delimiter at all.
+pub fn detect_delimiter(text: &str) -> Option<Delimiter> {
+    let trimmed = text.trim();
+    let bracketed = (trimmed.starts_with('[') && trimmed.ends_with(']'))
+        || (trimmed.starts_with('{') && trimmed.ends_with('}'));
+    if bracketed && serde_json::from_str::<serde_json::Value>(trimmed).is_ok() {
+        return Some(Delimiter::Json);
+    }
+    if trimmed.starts_with('{') && is_json_lines(trimmed) {
+        return Some(Delimiter::JsonLines);
+    }
+
+    let mut end = trimmed.len().min(DETECT_SAMPLE);
+    while !trimmed.is_char_boundary(end) {
+        end -= 1;
+    }
+    let sample = &trimmed[..end];
+    let breaks = split_lines(sample).count().saturating_sub(1);
+    let separator = [Delimiter::Tab, Delimiter::Semicolon, Delimiter::Comma]
+        .into_iter()
+        .map(|delimiter| (sample.matches(delimiter.as_char()).count(), delimiter))
+        // Earlier candidates win ties
+        .rev()
+        .max_by_key(|(count, _)| *count)
+        .filter(|(count, _)| *count > 0);
+    match separator {
+        Some((count, delimiter)) if count > breaks * 2 => Some(match delimiter {
+            Delimiter::Comma if sample.contains('"') => Delimiter::CsvComma,
+            Delimiter::Semicolon if sample.contains('"') => Delimiter::CsvSemicolon,
+            delimiter => delimiter,
+        }),
+        _ => (breaks > 0).then_some(Delimiter::Newline),
+    }
+}
+
+/// Occurrences of another delimiter in single-item text that suggest a mismatch
+pub const MISMATCH_MIN_SEPARATORS: usize = 100;
+
+/// Suggest a delimiter for text that parses into a single item with `current`
+///
+/// Pasting a CSV line while the delimiter is newline (or the reverse) yields one
+/// giant item; when another delimiter occurs at least
+/// [`MISMATCH_MIN_SEPARATORS`] times, it is returned with its count.
+pub fn suggest_delimiter(text: &str, current: Delimiter) -> Option<(Delimiter, usize)> {
+    if current.is_structured() || parse_items(text, current).len() != 1 {
+        return None;
+    }
+    [
+        Delimiter::Newline,
+        Delimiter::Tab,
+        Delimiter::Semicolon,
+        Delimiter::Comma,
+    ]
+    .into_iter()
+    .filter(|&delimiter| delimiter != current)
+    .map(|delimiter| {
+        let count = match delimiter {
+            Delimiter::Newline => split_lines(text).count().saturating_sub(1),
+            _ => text.matches(delimiter.as_char()).count(),
+        };
+        (delimiter, count)
+    })
+    .rev()
+    .max_by_key(|(_, count)| *count)
+    .filter(|(_, count)| *count >= MISMATCH_MIN_SEPARATORS)
+}
+
+/// Field separators recognized inside record-style items, in detection priority
+pub const RECORD_SEPARATORS: [char; 4] = ['\t', '|', ';', ','];
+
+/// Detect a field separator shared by every item so the items can be shown as a table
+///
+/// # Arguments
+/// * `items` - The items to inspect
+///
+/// # Returns
+/// The separator if every non-empty item splits into the same number (at least two)
+/// of fields, `None` otherwise.
+pub fn detect_record_separator(items: &[String]) -> Option<char> {
+    let mut records = items
+        .iter()
+        .filter(|item| !item.trim().is_empty())
+        .peekable();
+    records.peek()?;
+    let records: Vec<&String> = records.collect();
+
+    RECORD_SEPARATORS.into_iter().find(|&sep| {
+        let fields = records[0].split(sep).count();
+        fields >= 2 && records.iter().all(|item| item.split(sep).count() == fields)
+    })
+}
+
+/// Split a record-style item into trimmed fields
+///
+/// # Arguments
+/// * `item` - The item to split
+/// * `separator` - Field separator, usually from [`detect_record_separator`]
+pub fn split_record(item: &str, separator: char) -> Vec<&str> {
+    item.split(separator).map(str::trim).collect()
+}
+
+/// Whether a record reads as a header row: at least two fields, none empty or a number
+pub fn looks_like_header(item: &str, separator: char) -> bool {
+    let fields = split_record(item, separator);
+    fields.len() >= 2
+        && fields
+            .iter()
+            .all(|field| !field.is_empty() && field.parse::<f64>().is_err())
+}
+
+/// Widest field of each column over all records, in terminal cells
+///
+/// A record with fewer fields leaves the remaining columns alone.
+pub fn column_widths<S: AsRef<str>>(records: &[S], separator: char) -> Vec<usize> {
+    let mut widths: Vec<usize> = Vec::new();
+    for record in records {
+        for (col, field) in split_record(record.as_ref(), separator)
+            .into_iter()
+            .enumerate()
+        {
+            let width = field.width();
+            match widths.get_mut(col) {
+                Some(widest) => *widest = (*widest).max(width),
+                None => widths.push(width),
+            }
+        }
+    }
+    widths
+}
+
+/// Whether `item` is longer than `max_len` characters
+pub fn is_long_item(item: &str, max_len: usize) -> bool {
+    item.char_indices().nth(max_len).is_some()
+}
+
+/// Shorten `item` to at most `max_len` characters for display, ending it with `…`
+///
+/// Only the rendered text is clipped; operations keep working on the full item.
+pub fn clip_item(item: &str, max_len: usize) -> Cow<'_, str> {
+    if !is_long_item(item, max_len) {
+        return Cow::Borrowed(item);
+    }
+    let end = item
+        .char_indices()
+        .nth(max_len.saturating_sub(1))
+        .map_or(0, |(at, _)| at);
+    Cow::Owned(format!("{}…", &item[..end]))
+}
+
+/// Quote a field for CSV output following RFC 4180
+///
+/// Fields holding the separator, a double quote or a line break are wrapped in
+/// double quotes with embedded quotes doubled; other fields are returned as is.
+pub fn quote_csv_field(field: &str, separator: char) -> Cow<'_, str> {
+    if !field.contains([separator, '"', '\n', '\r']) {
+        return Cow::Borrowed(field);
+    }
+    Cow::Owned(format!("\"{}\"", field.replace('"', "\"\"")))
+}
+
+/// Text written before and after every converted item
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ItemWrap {
+    /// Text before the item
+    pub prefix: String,
+    /// Text after the item
+    pub suffix: String,
+}
+
+impl ItemWrap {
+    /// Parse a wrap as typed: `PREFIX{}SUFFIX`, or text put on both sides (`"`)
+    pub fn parse(spec: &str) -> Self {
+        match spec.split_once("{}") {
+            Some((prefix, suffix)) => Self {
+                prefix: prefix.to_string(),
+                suffix: suffix.to_string(),
+            },
+            None => Self {
+                prefix: spec.to_string(),
+                suffix: spec.to_string(),
+            },
+        }
+    }
+
+    /// The wrap as typed, the inverse of [`ItemWrap::parse`]
+    pub fn spec(&self) -> String {
+        if self.prefix == self.suffix {
+            self.prefix.clone()
+        } else {
+            format!("{}{{}}{}", self.prefix, self.suffix)
+        }
+    }
+
+    /// Whether items are left as they are
+    pub fn is_empty(&self) -> bool {
+        self.prefix.is_empty() && self.suffix.is_empty()
+    }
+
+    /// `item` with the prefix and suffix around it
+    pub fn apply<'a>(&self, item: &'a str) -> Cow<'a, str> {
+        if self.is_empty() {
+            return Cow::Borrowed(item);
+        }
+        Cow::Owned(format!("{}{}{}", self.prefix, item, self.suffix))
+    }
+}
+
+/// Convert delimited rows to another field separator, keeping one row per line
+///
+/// Rows and fields are separate dimensions here: line breaks always end a row
+/// and only the field separator changes. Blank lines are skipped.
+///
+/// # Arguments
+/// * `input` - Rows separated by line breaks
+/// * `source` - Field separator of the input rows
+/// * `target` - Field separator of the output rows
+/// * `quote` - Quote output fields with [`quote_csv_field`]
+pub fn convert_table(input: &str, source: char, target: char, quote: bool) -> Vec<String> {
+    normalize_line_endings(input)
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| join_record(line.split(source), target, quote))
+        .collect()
+}
+
+/// Join fields into a record with `separator`, quoting them with [`quote_csv_field`] if `quote`
+pub fn join_record<'a>(
+    fields: impl IntoIterator<Item = &'a str>,
+    separator: char,
+    quote: bool,
+) -> String {
+    let fields: Vec<Cow<'a, str>> = fields
+        .into_iter()
+        .map(|field| {
+            if quote {
+                quote_csv_field(field, separator)
+            } else {
+                Cow::Borrowed(field)
+            }
+        })
+        .collect();
+    fields.join(&separator.to_string())
+}
+
+/// Parse comma- or space-separated column widths such as `10,8,12`
+///
+/// # Returns
+/// The widths (empty for blank input), or `None` if one is not a positive number.
+pub fn parse_column_widths(text: &str) -> Option<Vec<usize>> {
+    text.split([',', ' '])
+        .filter(|width| !width.is_empty())
+        .map(|width| width.parse().ok().filter(|&width| width > 0))
+        .collect()
+}
+
+/// Guess the column widths of fixed-width rows
+///
+/// A column starts after a gap of at least two character positions that are
+/// blank in every row (single blanks are taken as spaces inside values). Each
+/// width runs up to the next column start; the last one up to the longest row.
+pub fn detect_fixed_widths(lines: &[String]) -> Vec<usize> {
+    let rows: Vec<Vec<char>> = lines
+        .iter()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| line.chars().collect())
+        .collect();
+    let len = rows.iter().map(Vec::len).max().unwrap_or(0);
+    let blank: Vec<bool> = (0..len)
+        .map(|i| {
+            rows.iter()
+                .all(|row| row.get(i).is_none_or(|c| c.is_whitespace()))
+        })
+        .collect();
+
+    let mut widths = Vec::new();
+    let mut start = 0;
+    let mut seen_text = false;
+    for i in 0..len {
+        let after_gap = i >= 2 && blank[i - 1] && blank[i - 2];
+        if after_gap && !blank[i] && seen_text {
+            widths.push(i - start);
+            start = i;
+        }
+        seen_text |= !blank[i];
+    }
+    if len > start {
+        widths.push(len - start);
+    }
+    widths
+}
+
+/// Slice a fixed-width row into trimmed fields
+///
+/// The last column also takes anything past its width, so no text is lost and
+/// every row yields `widths.len()` fields.
+pub fn split_fixed_width<'a>(line: &'a str, widths: &[usize]) -> Vec<&'a str> {
+    let mut rest = line;
+    let mut fields = Vec::with_capacity(widths.len());
+    for (i, &width) in widths.iter().enumerate() {
+        let end = if i + 1 == widths.len() {
+            rest.len()
+        } else {
+            rest.char_indices()
+                .nth(width)
+                .map_or(rest.len(), |(at, _)| at)
+        };
+        fields.push(rest[..end].trim());
+        rest = &rest[end..];
+    }
+    fields
+}
+
+/// Convert fixed-width rows into delimited records, one per non-blank line
+///
+/// # Arguments
+/// * `input` - Rows separated by line breaks
+/// * `widths` - Column widths in characters (see [`split_fixed_width`])
+/// * `target` - Field separator of the output rows
+/// * `quote` - Quote output fields with [`quote_csv_field`]
+pub fn convert_fixed_width(
+    input: &str,
+    widths: &[usize],
+    target: char,
+    quote: bool,
+) -> Vec<String> {
+    normalize_line_endings(input)
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| join_record(split_fixed_width(line, widths), target, quote))
+        .collect()
+}
+
+/// Pad the fields of delimited rows so columns line up when displayed
+///
+/// Intended for previews only: the padding changes the data, so serialized output
+/// must keep using the original rows.
+///
+/// # Arguments
+/// * `rows` - Rows whose fields are separated by `separator`
+/// * `separator` - Field separator (tab rows are aligned with spaces instead)
+pub fn align_columns(rows: &[String], separator: char) -> Vec<String> {
+    let split: Vec<Vec<&str>> = rows
+        .iter()
+        .map(|row| row.split(separator).collect())
+        .collect();
+
+    // Measured in terminal cells, so wide (CJK) and combining characters line up
+    let mut widths: Vec<usize> = Vec::new();
+    for fields in &split {
+        for (col, field) in fields.iter().enumerate() {
+            let len = field.width();
+            match widths.get_mut(col) {
+                Some(width) => *width = (*width).max(len),
+                None => widths.push(len),
+            }
+        }
+    }
+
+    let joiner = if separator == '\t' {
+        "  ".to_string()
+    } else {
+        format!("{} ", separator)
+    };
+
+    split
+        .iter()
+        .map(|fields| {
+            let last = fields.len().saturating_sub(1);
+            fields
+                .iter()
+                .enumerate()
+                .map(|(col, field)| {
+                    if col == last {
+                        field.to_string()
+                    } else {
+                        // `{:<width$}` would pad by chars rather than cells
+                        let padding = widths[col] - field.width();
+                        format!("{}{}", field, " ".repeat(padding))
+                    }
+                })
+                .collect::<Vec<_>>()
+                .join(&joiner)
+        })
+        .collect()
+}
+
+/// What happens to empty items, such as the middle one of `a,,b`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum EmptyItems {
+    /// Keep them as empty items
+    #[default]
+    Keep,
+    /// Drop them, so consecutive delimiters count as one
+    Collapse,
+}
+
 /// Parse a string into a vector of items using the specified delimiter
 ///
 /// # Arguments
@@ -59,33 +599,286 @@ impl Delimiter {
 ///
 /// # Returns
 /// A vector of strings, each representing an item from the list.
-/// Ignores trailing empty element if input ends with delimiter.
+/// Ignores trailing empty element if input ends with delimiter; other empty
+/// items are kept (see [`parse_list_with`]).
 pub fn parse_list(input: &str, delimiter: Delimiter) -> Vec<String> {
+    parse_list_with(input, delimiter, EmptyItems::Keep)
+}
+
+/// [`parse_list`] with a choice of what happens to empty items
+pub fn parse_list_with(input: &str, delimiter: Delimiter, empty: EmptyItems) -> Vec<String> {
+    parse_items_with(input, delimiter, empty)
+        .into_iter()
+        .map(Cow::into_owned)
+        .collect()
+}
+
+/// Parse a string into items borrowed from the input, same rules as [`parse_list`]
+///
+/// Items are only allocated when they contain a carriage return that has to
+/// be normalized (possible with non-newline delimiters), so transient work on
+/// large pastes (counting, comparing) avoids a `String` per item.
+///
+/// # Arguments
+/// * `input` - The input string to parse
+/// * `delimiter` - The delimiter to use for splitting
+pub fn parse_items(input: &str, delimiter: Delimiter) -> Vec<Cow<'_, str>> {
+    parse_items_with(input, delimiter, EmptyItems::Keep)
+}
+
+/// [`parse_items`] with a choice of what happens to empty items
+pub fn parse_items_with(input: &str, delimiter: Delimiter, empty: EmptyItems) -> Vec<Cow<'_, str>> {
     if input.is_empty() {
         return Vec::new();
     }
 
-    // Normalize Windows line endings to avoid empty items when pasting CRLF text
-    let normalized = normalize_line_endings(input);
+    // CRLF/CR count as line breaks so pasted Windows text yields no empty items
+    let mut items: Vec<Cow<'_, str>> = if delimiter == Delimiter::Newline {
+        split_lines(input).map(Cow::Borrowed).collect()
+    } else if delimiter.is_quoted() {
+        split_quoted(input, delimiter.as_char())
+            .into_iter()
+            .map(|item| {
+                if item.contains('\r') {
+                    Cow::Owned(normalize_line_endings(&item))
+                } else {
+                    item
+                }
+            })
+            .collect()
+    } else {
+        input
+            .split(delimiter.as_str())
+            .map(|item| {
+                if item.contains('\r') {
+                    Cow::Owned(normalize_line_endings(item))
+                } else {
+                    Cow::Borrowed(item)
+                }
+            })
+            .collect()
+    };
 
-    let mut items: Vec<String> = normalized
-        .split(delimiter.as_char())
-        .map(|s| s.to_string())
-        .collect();
+    if empty == EmptyItems::Collapse {
+        items.retain(|item| !item.is_empty());
+    } else if items.last().is_some_and(|last| last.is_empty()) {
+        // Remove last element if it's empty (input ended with delimiter)
+        items.pop();
+    }
+
+    items
+}
+
+/// Split on `separator`, honouring RFC 4180 quotes
+///
+/// A field whose first non-blank character is `"` runs to the closing quote,
+/// so it can hold the separator, and `""` inside it stands for one quote. Text
+/// between the closing quote and the next separator is kept; an unclosed quote
+/// runs to the end of the input. Fields without quotes are borrowed as is.
+pub fn split_quoted(input: &str, separator: char) -> Vec<Cow<'_, str>> {
+    let mut fields = Vec::new();
+    let mut rest = input;
+    loop {
+        let Some(quoted) = rest.trim_start_matches(' ').strip_prefix('"') else {
+            match rest.split_once(separator) {
+                Some((field, tail)) => {
+                    fields.push(Cow::Borrowed(field));
+                    rest = tail;
+                    continue;
+                }
+                None => {
+                    fields.push(Cow::Borrowed(rest));
+                    return fields;
+                }
+            }
+        };
+        let (mut field, after) = take_quoted(quoted);
+        let (extra, tail) = match after.split_once(separator) {
+            Some((extra, tail)) => (extra, Some(tail)),
+            None => (after, None),
+        };
+        if !extra.trim().is_empty() {
+            field.to_mut().push_str(extra);
+        }
+        fields.push(field);
+        match tail {
+            Some(tail) => rest = tail,
+            None => return fields,
+        }
+    }
+}
 
-    // Remove last element if it's empty (input ended with delimiter)
-    if let Some(last) = items.last() {
-        if last.is_empty() {
-            items.pop();
+/// Value of a quoted field (after its opening quote) and the text after its closing quote
+fn take_quoted(quoted: &str) -> (Cow<'_, str>, &str) {
+    let mut unescaped: Option<String> = None;
+    let mut start = 0;
+    loop {
+        match quoted[start..].find('"').map(|offset| start + offset) {
+            Some(quote) if quoted[quote + 1..].starts_with('"') => {
+                unescaped
+                    .get_or_insert_with(String::new)
+                    .push_str(&quoted[start..=quote]);
+                start = quote + 2;
+            }
+            closing => {
+                let end = closing.unwrap_or(quoted.len());
+                let after = quoted.get(end + 1..).unwrap_or_default();
+                let value = match unescaped {
+                    Some(mut value) => {
+                        value.push_str(&quoted[start..end]);
+                        Cow::Owned(value)
+                    }
+                    None => Cow::Borrowed(&quoted[start..end]),
+                };
+                return (value, after);
+            }
         }
     }
+}
 
-    items
+/// Trim the whitespace around every item of pasted text and drop trailing empty items
+///
+/// Trailing delimiters (`a, b, c,`) and blank lines go away; empty items in
+/// the middle are left to [`EmptyItems`]. Items are joined back with the
+/// delimiter.
+pub fn trim_pasted(text: &str, delimiter: Delimiter) -> String {
+    let parsed = parse_items(text, delimiter);
+    let mut items: Vec<&str> = parsed.iter().map(|item| item.trim()).collect();
+    while items.last().is_some_and(|item| item.is_empty()) {
+        items.pop();
+    }
+    items.join(delimiter.as_str())
+}
+
+/// Split on LF, CRLF or CR without allocating
+fn split_lines(input: &str) -> impl Iterator<Item = &str> {
+    let mut rest = Some(input);
+    std::iter::from_fn(move || {
+        let text = rest?;
+        match text.find(['\r', '\n']) {
+            Some(i) => {
+                let separator_len = if text[i..].starts_with("\r\n") { 2 } else { 1 };
+                rest = Some(&text[i + separator_len..]);
+                Some(&text[..i])
+            }
+            None => {
+                rest = None;
+                Some(text)
+            }
+        }
+    })
+}
+
+/// Header of the column holding non-object elements with [`JsonArrayStrategy::Union`]
+pub const JSON_VALUE_COLUMN: &str = "(value)";
+
+/// How a JSON array mixing objects and other values is converted
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum JsonArrayStrategy {
+    /// CSV over the union of all keys with blanks for missing ones; other
+    /// elements go to a [`JSON_VALUE_COLUMN`] column
+    #[default]
+    Union,
+    /// CSV over the objects only, other elements are dropped
+    ObjectsOnly,
+    /// One item per element, objects written as compact JSON
+    Stringify,
+}
+
+impl JsonArrayStrategy {
+    /// The strategy to switch to next
+    pub fn next(self) -> Self {
+        match self {
+            JsonArrayStrategy::Union => JsonArrayStrategy::ObjectsOnly,
+            JsonArrayStrategy::ObjectsOnly => JsonArrayStrategy::Stringify,
+            JsonArrayStrategy::Stringify => JsonArrayStrategy::Union,
+        }
+    }
+
+    /// Short name shown to the user
+    pub fn display_name(self) -> &'static str {
+        match self {
+            JsonArrayStrategy::Union => "union of keys",
+            JsonArrayStrategy::ObjectsOnly => "objects only",
+            JsonArrayStrategy::Stringify => "stringify",
+        }
+    }
+}
+
+/// How objects and arrays nested in JSON objects are written in CSV rows
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum NestedJson {
+    /// As compact JSON text in the cell of their key
+    #[default]
+    Raw,
+    /// Expanded into `parent.child` columns, array elements into `parent.0`, `parent.1`…
+    Flatten,
+}
+
+impl NestedJson {
+    /// The other mode
+    pub fn toggle(self) -> Self {
+        match self {
+            NestedJson::Raw => NestedJson::Flatten,
+            NestedJson::Flatten => NestedJson::Raw,
+        }
+    }
+
+    /// Short name shown to the user
+    pub fn display_name(self) -> &'static str {
+        match self {
+            NestedJson::Raw => "as JSON",
+            NestedJson::Flatten => "flattened",
+        }
+    }
+}
+
+/// `object` with its nested objects and arrays expanded into `parent.child` members
+///
+/// Empty objects and arrays stay as they are. A dotted key already in the
+/// object loses to the nested member of the same name.
+fn flatten_object(object: &serde_json::Map<String, serde_json::Value>) -> serde_json::Value {
+    fn walk(
+        key: String,
+        value: &serde_json::Value,
+        flat: &mut serde_json::Map<String, serde_json::Value>,
+    ) {
+        match value {
+            serde_json::Value::Object(members) if !members.is_empty() => {
+                for (child, value) in members {
+                    walk(format!("{}.{}", key, child), value, flat);
+                }
+            }
+            serde_json::Value::Array(items) if !items.is_empty() => {
+                for (index, value) in items.iter().enumerate() {
+                    walk(format!("{}.{}", key, index), value, flat);
+                }
+            }
+            value => {
+                flat.insert(key, value.clone());
+            }
+        }
+    }
+    let mut flat = serde_json::Map::new();
+    for (key, value) in object {
+        walk(key.clone(), value, &mut flat);
+    }
+    serde_json::Value::Object(flat)
 }
 
 /// Parse a string as JSON and convert to a list of items.
 /// Returns (list_of_items, repaired_json_string)
-pub fn parse_json_to_list(input: &str, target_sep: char) -> Result<(Vec<String>, String), String> {
+///
+/// Arrays without objects become one item per element; arrays with objects are
+/// converted according to `strategy`, with nested values written as set by
+/// `nested`. When `quote` is set, CSV fields are quoted with [`quote_csv_field`].
+pub fn parse_json_to_list(
+    input: &str,
+    target_sep: char,
+    quote: bool,
+    strategy: JsonArrayStrategy,
+    nested: NestedJson,
+) -> Result<(Vec<String>, String), String> {
     if input.trim().is_empty() {
         return Ok((Vec::new(), String::new()));
     }
@@ -112,64 +905,190 @@ pub fn parse_json_to_list(input: &str, target_sep: char) -> Result<(Vec<String>,
         return Ok((Vec::new(), repaired));
     }
 
-    // Check if first element is an object
-    if let Some(_) = arr[0].as_object() {
-        // It's a list of objects -> convert to CSV lines
-        let mut csv_lines = Vec::new();
+    let text = |value: &serde_json::Value| match value.as_str() {
+        Some(text) => text.to_string(),
+        None => value.to_string(),
+    };
+    let has_objects = arr.iter().any(serde_json::Value::is_object);
+    if !has_objects || strategy == JsonArrayStrategy::Stringify {
+        // One item per element, objects as compact JSON
+        return Ok((arr.iter().map(text).collect(), repaired));
+    }
+    let arr: Vec<serde_json::Value> = match nested {
+        NestedJson::Raw => arr,
+        NestedJson::Flatten => arr
+            .into_iter()
+            .map(|item| match item.as_object() {
+                Some(object) => flatten_object(object),
+                None => item,
+            })
+            .collect(),
+    };
 
-        // Get all unique keys from all objects
-        let mut keys = BTreeSet::new();
-        for item in &arr {
-            if let Some(obj) = item.as_object() {
-                for key in obj.keys() {
-                    keys.insert(key.clone());
-                }
-            }
-        }
-        let keys_vec: Vec<String> = keys.into_iter().collect();
-
-        // Header row
-        let sep_str = target_sep.to_string();
-        csv_lines.push(keys_vec.join(&sep_str));
-
-        // Data rows
-        for item in &arr {
-            if let Some(obj) = item.as_object() {
-                let row: Vec<String> = keys_vec
-                    .iter()
-                    .map(|k| match obj.get(k) {
-                        Some(val) => {
-                            if val.is_string() {
-                                val.as_str().unwrap().to_string()
-                            } else {
-                                val.to_string()
-                            }
-                        }
-                        None => "".to_string(),
-                    })
-                    .collect();
-                csv_lines.push(row.join(&sep_str));
+    // Objects -> CSV lines over all unique keys
+    let mut keys: Vec<String> = arr
+        .iter()
+        .filter_map(serde_json::Value::as_object)
+        .flat_map(|obj| obj.keys().cloned())
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .collect();
+    let keep_others =
+        strategy == JsonArrayStrategy::Union && arr.iter().any(|item| !item.is_object());
+    if keep_others {
+        keys.push(JSON_VALUE_COLUMN.to_string());
+    }
+
+    let mut csv_lines = vec![join_record(
+        keys.iter().map(String::as_str),
+        target_sep,
+        quote,
+    )];
+    for item in &arr {
+        let row: Vec<String> = match item.as_object() {
+            Some(obj) => keys
+                .iter()
+                .map(|k| obj.get(k).map(text).unwrap_or_default())
+                .collect(),
+            // Other elements fill the value column only
+            None if keep_others => {
+                let mut row = vec![String::new(); keys.len()];
+                row[keys.len() - 1] = text(item);
+                row
             }
-        }
-        Ok((csv_lines, repaired))
+            None => continue,
+        };
+        csv_lines.push(join_record(
+            row.iter().map(String::as_str),
+            target_sep,
+            quote,
+        ));
+    }
+    Ok((csv_lines, repaired))
+}
+
+/// Whether `text` has two or more lines and each non-blank line is a JSON object
+fn is_json_lines(text: &str) -> bool {
+    let mut lines = text.lines().filter(|line| !line.trim().is_empty());
+    lines.clone().nth(1).is_some()
+        && lines.all(|line| {
+            serde_json::from_str::<serde_json::Value>(line).is_ok_and(|value| value.is_object())
+        })
+}
+
+/// Parse JSON Lines (one JSON document per line) and convert to a list of items
+/// Returns (list_of_items, documents_as_a_json_array)
+///
+/// Blank lines are skipped and every document becomes one element, converted
+/// like the elements of an array by [`parse_json_to_list`]: objects give one
+/// CSV row each under a header of all their keys.
+///
+/// # Errors
+/// Returns the line number and parse error of the first invalid line.
+pub fn parse_json_lines_to_list(
+    input: &str,
+    target_sep: char,
+    quote: bool,
+    strategy: JsonArrayStrategy,
+    nested: NestedJson,
+) -> Result<(Vec<String>, String), String> {
+    let documents = input
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(index, line)| {
+            serde_json::from_str(line).map_err(|e| format!("line {}: {}", index + 1, e))
+        })
+        .collect::<Result<Vec<serde_json::Value>, String>>()?;
+    let array = serde_json::Value::Array(documents).to_string();
+    let (items, _) = parse_json_to_list(&array, target_sep, quote, strategy, nested)?;
+    Ok((items, array))
+}
+
+/// Parse YAML and convert to a list of items
+/// Returns (list_of_items, document_as_json)
+///
+/// A sequence or mapping is converted like the same JSON by
+/// [`parse_json_to_list`]: scalars become one item each and mappings one CSV
+/// row each. A stream of several `---` documents is read as a sequence of them.
+///
+/// # Errors
+/// Returns the YAML parse error, or an error for a lone scalar document.
+#[cfg(feature = "yaml")]
+pub fn parse_yaml_to_list(
+    input: &str,
+    target_sep: char,
+    quote: bool,
+    strategy: JsonArrayStrategy,
+    nested: NestedJson,
+) -> Result<(Vec<String>, String), String> {
+    if input.trim().is_empty() {
+        return Ok((Vec::new(), String::new()));
+    }
+    let mut documents = serde_yaml::Deserializer::from_str(input)
+        .map(|document| {
+            serde::Deserialize::deserialize(document).map_err(|e: serde_yaml::Error| e.to_string())
+        })
+        .collect::<Result<Vec<serde_json::Value>, String>>()?;
+    let value = if documents.len() == 1 {
+        documents.remove(0)
     } else {
-        // It's a list of primitives -> just convert each to string
-        let items: Vec<String> = arr
-            .iter()
-            .map(|v| {
-                if v.is_string() {
-                    v.as_str().unwrap().to_string()
-                } else {
-                    v.to_string()
-                }
-            })
-            .collect();
-        Ok((items, repaired))
+        serde_json::Value::Array(documents)
+    };
+    if !value.is_array() && !value.is_object() {
+        return Err("YAML input must be a sequence or a mapping".to_string());
     }
+    let json = value.to_string();
+    let (items, _) = parse_json_to_list(&json, target_sep, quote, strategy, nested)?;
+    Ok((items, json))
+}
+
+/// Parse YAML and convert to a list of items
+///
+/// # Errors
+/// Always fails: this build was made without the `yaml` feature.
+#[cfg(not(feature = "yaml"))]
+pub fn parse_yaml_to_list(
+    _input: &str,
+    _target_sep: char,
+    _quote: bool,
+    _strategy: JsonArrayStrategy,
+    _nested: NestedJson,
+) -> Result<(Vec<String>, String), String> {
+    Err("YAML support is not built in (enable the yaml feature)".to_string())
+}
+
+/// Convert a JSON, JSON Lines or YAML `source` with the parser for its format
+///
+/// Returns the items and the parsed document as JSON text, which for JSON is
+/// the (possibly repaired) input itself.
+///
+/// # Errors
+/// Returns the parse error of the input.
+pub fn parse_structured_to_list(
+    input: &str,
+    source: Delimiter,
+    target_sep: char,
+    quote: bool,
+    strategy: JsonArrayStrategy,
+    nested: NestedJson,
+) -> Result<(Vec<String>, String), String> {
+    let parse = match source {
+        Delimiter::JsonLines => parse_json_lines_to_list,
+        Delimiter::Yaml => parse_yaml_to_list,
+        _ => parse_json_to_list,
+    };
+    parse(input, target_sep, quote, strategy, nested)
 }
 
 /// Helper to wrap unquoted keys in double quotes to support 'Lax JSON'
-fn repair_json(input: &str) -> String {
+///
+/// Valid JSON is returned unchanged, so string values that merely look like
+/// lax keys (`"{a:1}"`) are never rewritten.
+pub fn repair_json(input: &str) -> String {
+    if serde_json::from_str::<serde_json::Value>(input).is_ok() {
+        return input.to_string();
+    }
     // Regex that matches unquoted keys:
     // It looks for a word followed by a colon, preceded by {, [ or , (or start of string)
     // We escape [ as \[
@@ -191,10 +1110,180 @@ fn normalize_line_endings(input: &str) -> String {
     }
 }
 
+/// Line break written between lines when saving
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LineEnding {
+    /// `\n` (Unix, macOS)
+    #[default]
+    Lf,
+    /// `\r\n` (Windows)
+    Crlf,
+}
+
+impl LineEnding {
+    /// Line ending of the first line break in `text` (`None` without line breaks)
+    pub fn detect(text: &str) -> Option<Self> {
+        let i = text.find('\n')?;
+        Some(if text[..i].ends_with('\r') {
+            LineEnding::Crlf
+        } else {
+            LineEnding::Lf
+        })
+    }
+
+    /// Rewrite every line break of `text` (LF, CRLF or CR) with this ending
+    pub fn apply(self, text: &str) -> String {
+        let normalized = normalize_line_endings(text);
+        match self {
+            LineEnding::Lf => normalized,
+            LineEnding::Crlf => normalized.replace('\n', "\r\n"),
+        }
+    }
+
+    /// Short name shown to the user
+    pub fn display_name(self) -> &'static str {
+        match self {
+            LineEnding::Lf => "LF",
+            LineEnding::Crlf => "CRLF",
+        }
+    }
+}
+
+/// Text encoding of saved files
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Encoding {
+    /// Plain UTF-8
+    #[default]
+    Utf8,
+    /// UTF-8 starting with a byte order mark (recognized by Excel)
+    Utf8Bom,
+    /// UTF-16 little endian with a byte order mark (legacy Windows tools)
+    Utf16Le,
+}
+
+impl Encoding {
+    /// The encoding to switch to next
+    pub fn next(self) -> Self {
+        match self {
+            Encoding::Utf8 => Encoding::Utf8Bom,
+            Encoding::Utf8Bom => Encoding::Utf16Le,
+            Encoding::Utf16Le => Encoding::Utf8,
+        }
+    }
+
+    /// Bytes of `text` in this encoding
+    pub fn encode(self, text: &str) -> Vec<u8> {
+        match self {
+            Encoding::Utf8 => text.as_bytes().to_vec(),
+            Encoding::Utf8Bom => {
+                let mut bytes = Vec::with_capacity(text.len() + 3);
+                bytes.extend_from_slice(&[0xEF, 0xBB, 0xBF]);
+                bytes.extend_from_slice(text.as_bytes());
+                bytes
+            }
+            Encoding::Utf16Le => std::iter::once(0xFEFF)
+                .chain(text.encode_utf16())
+                .flat_map(u16::to_le_bytes)
+                .collect(),
+        }
+    }
+
+    /// Short name shown to the user
+    pub fn display_name(self) -> &'static str {
+        match self {
+            Encoding::Utf8 => "UTF-8",
+            Encoding::Utf8Bom => "UTF-8 BOM",
+            Encoding::Utf16Le => "UTF-16LE",
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_item_wrap() {
+        let quotes = ItemWrap::parse("\"");
+        assert_eq!(quotes.apply("a"), "\"a\"");
+        assert_eq!(quotes.spec(), "\"");
+        let template = ItemWrap::parse("<li>{}</li>");
+        assert_eq!(template.apply("a"), "<li>a</li>");
+        assert_eq!(template.spec(), "<li>{}</li>");
+        assert_eq!(ItemWrap::parse("{}").apply("a"), "a");
+        assert!(ItemWrap::parse("").is_empty());
+    }
+
+    #[test]
+    fn test_record_header_and_column_widths() {
+        assert!(looks_like_header("id, name, email", ','));
+        assert!(!looks_like_header("1, Ana, ana@example.com", ','));
+        assert!(!looks_like_header("id,,email", ','));
+        assert!(!looks_like_header("name", ','));
+
+        let records = ["id,name", "1,Ana", "22,José,extra", "3,東京"];
+        assert_eq!(column_widths(&records, ','), vec![2, 4, 5]);
+    }
+
+    #[test]
+    fn test_detect_record_separator() {
+        let records = vec!["1,alice".to_string(), "2,bob".to_string()];
+        assert_eq!(detect_record_separator(&records), Some(','));
+
+        let piped = vec!["a | b | c".to_string(), "d | e | f".to_string()];
+        assert_eq!(detect_record_separator(&piped), Some('|'));
+        assert_eq!(split_record(&piped[0], '|'), vec!["a", "b", "c"]);
+
+        let ragged = vec!["1,alice".to_string(), "2".to_string()];
+        assert_eq!(detect_record_separator(&ragged), None);
+        assert_eq!(detect_record_separator(&[]), None);
+    }
+
+    #[test]
+    fn test_align_columns() {
+        let rows = vec!["id,name".to_string(), "100,al".to_string()];
+        assert_eq!(align_columns(&rows, ','), vec!["id , name", "100, al"]);
+
+        let tabbed = vec!["a\tb".to_string(), "ccc\td".to_string()];
+        assert_eq!(align_columns(&tabbed, '\t'), vec!["a    b", "ccc  d"]);
+
+        // Wide characters take two cells each
+        let wide = vec!["東京,x".to_string(), "abcde,y".to_string()];
+        assert_eq!(align_columns(&wide, ','), vec!["東京 , x", "abcde, y"]);
+    }
+
+    #[test]
+    fn test_parse_items_borrows_and_matches_parse_list() {
+        let cases = [
+            ("a\nb\n", Delimiter::Newline),
+            ("a\r\nb\rc\r\n", Delimiter::Newline),
+            ("\r", Delimiter::Newline),
+            ("a,b\r\nc,", Delimiter::Comma),
+            ("x\ty", Delimiter::Tab),
+        ];
+        for (input, delimiter) in cases {
+            let borrowed: Vec<String> = parse_items(input, delimiter)
+                .into_iter()
+                .map(Cow::into_owned)
+                .collect();
+            let mut expected: Vec<String> = normalize_line_endings(input)
+                .split(delimiter.as_char())
+                .map(str::to_string)
+                .collect();
+            if expected.last().is_some_and(|l| l.is_empty()) {
+                expected.pop();
+            }
+            assert_eq!(borrowed, expected, "input {:?}", input);
+        }
+
+        let items = parse_items("a\r\nb", Delimiter::Newline);
+        assert!(items.iter().all(|item| matches!(item, Cow::Borrowed(_))));
+        assert!(matches!(
+            parse_items("a\r\nb,c", Delimiter::Comma)[0],
+            Cow::Owned(_)
+        ));
+    }
+
     #[test]
     fn test_parse_newline() {
         let input = "item1\nitem2\nitem3";
@@ -234,8 +1323,123 @@ mod tests {
         let d = Delimiter::Newline;
         assert_eq!(d.next(), Delimiter::Tab);
         assert_eq!(d.next().next(), Delimiter::Comma);
-        assert_eq!(d.next().next().next(), Delimiter::Semicolon);
-        assert_eq!(d.next().next().next().next(), Delimiter::Newline);
+        assert_eq!(d.next().next().next(), Delimiter::CsvComma);
+        assert_eq!(d.next().next().next().next(), Delimiter::Semicolon);
+        assert_eq!(
+            d.next().next().next().next().next(),
+            Delimiter::CsvSemicolon
+        );
+        assert_eq!(Delimiter::CsvSemicolon.next(), Delimiter::Json);
+        assert_eq!(Delimiter::Json.next(), Delimiter::JsonLines);
+        assert_eq!(Delimiter::JsonLines.next(), Delimiter::Yaml);
+        assert_eq!(Delimiter::Yaml.next(), Delimiter::Newline);
+    }
+
+    #[test]
+    fn test_delimiter_from_name() {
+        assert_eq!(Delimiter::from_name(","), Some(Delimiter::Comma));
+        assert_eq!(Delimiter::from_name("TAB"), Some(Delimiter::Tab));
+        assert_eq!(Delimiter::from_name("\\n"), Some(Delimiter::Newline));
+        assert_eq!(Delimiter::from_name("\t"), Some(Delimiter::Tab));
+        assert_eq!(Delimiter::from_name("json"), Some(Delimiter::Json));
+        assert_eq!(Delimiter::from_name("NDJSON"), Some(Delimiter::JsonLines));
+        assert_eq!(Delimiter::from_name("yml"), Some(Delimiter::Yaml));
+        assert_eq!(Delimiter::from_name("CSV"), Some(Delimiter::CsvComma));
+        assert_eq!(Delimiter::from_name("csv;"), Some(Delimiter::CsvSemicolon));
+        assert_eq!(Delimiter::from_name("|"), None);
+    }
+
+    #[test]
+    fn test_detect_delimiter() {
+        assert_eq!(detect_delimiter("a\nb\nc\n"), Some(Delimiter::Newline));
+        assert_eq!(detect_delimiter("a,b,c"), Some(Delimiter::Comma));
+        assert_eq!(detect_delimiter("a;b;c,d"), Some(Delimiter::Semicolon));
+        assert_eq!(detect_delimiter("a\tb;c"), Some(Delimiter::Tab));
+        assert_eq!(detect_delimiter("1,2,3\r\n4,5,6"), Some(Delimiter::Comma));
+        assert_eq!(
+            detect_delimiter("Smith, John\nDoe, Jane\nRoe, Rick"),
+            Some(Delimiter::Newline)
+        );
+        assert_eq!(detect_delimiter(r#" [{"id": 1}] "#), Some(Delimiter::Json));
+        assert_eq!(
+            detect_delimiter("{\"id\": 1, \"a\": 2, \"b\": 3}\n\n{\"id\": 2}\n"),
+            Some(Delimiter::JsonLines)
+        );
+        assert_eq!(
+            detect_delimiter("{\"id\": 1}\n[2]"),
+            Some(Delimiter::Newline)
+        );
+        assert_eq!(detect_delimiter("[a],[b]"), Some(Delimiter::Comma));
+        assert_eq!(
+            detect_delimiter(r#""Smith, John",Doe,"Roe, Rick""#),
+            Some(Delimiter::CsvComma)
+        );
+        assert_eq!(detect_delimiter("single"), None);
+        assert_eq!(detect_delimiter(""), None);
+    }
+
+    #[test]
+    fn test_suggest_delimiter() {
+        let csv = vec!["x"; 150].join(",");
+        assert_eq!(
+            suggest_delimiter(&csv, Delimiter::Newline),
+            Some((Delimiter::Comma, 149))
+        );
+        let lines = vec!["a;b"; 120].join("\n");
+        assert_eq!(
+            suggest_delimiter(&lines, Delimiter::Comma),
+            Some((Delimiter::Semicolon, 120))
+        );
+        // Several items, or too few separators, are fine
+        assert_eq!(suggest_delimiter(&lines, Delimiter::Newline), None);
+        assert_eq!(suggest_delimiter("a,b,c", Delimiter::Newline), None);
+        assert_eq!(suggest_delimiter(&csv, Delimiter::Json), None);
+    }
+
+    #[test]
+    fn test_quoted_csv() {
+        let csv = Delimiter::CsvComma;
+        assert_eq!(
+            parse_list(r#""Smith, John",Doe, "Roe, Rick",x"#, csv),
+            vec!["Smith, John", "Doe", "Roe, Rick", "x"]
+        );
+        // Escaped quotes, empty quoted fields and text after the closing quote
+        assert_eq!(
+            parse_list(r#""say ""hi""","",a"b"c,"d"e,"#, csv),
+            vec![r#"say "hi""#, "", r#"a"b"c"#, "de"]
+        );
+        assert_eq!(parse_list(r#"a,"open, end"#, csv), vec!["a", "open, end"]);
+        assert_eq!(
+            parse_list(r#"1;"2;3";"4,5""#, Delimiter::CsvSemicolon),
+            vec!["1", "2;3", "4,5"]
+        );
+        assert!(matches!(
+            &split_quoted(r#""plain""#, ',')[0],
+            Cow::Borrowed("plain")
+        ));
+        // The plain comma still splits inside quotes
+        assert_eq!(
+            parse_list(r#""a,b",c"#, Delimiter::Comma),
+            vec!["\"a", "b\"", "c"]
+        );
+    }
+
+    #[test]
+    fn test_custom_delimiter() {
+        let pipes = Delimiter::Custom(CustomDelimiter::new("||").unwrap());
+        assert_eq!(parse_list("a||b|c||", pipes), vec!["a", "b|c"]);
+        assert_eq!(pipes.display_name(), "||");
+        assert_eq!(pipes.field_separator(), None);
+        assert_eq!(pipes.next(), Delimiter::Newline);
+
+        let pipe = Delimiter::Custom(CustomDelimiter::new("|").unwrap());
+        assert_eq!(pipe.field_separator(), Some('|'));
+        assert_eq!(Delimiter::Json.field_separator(), None);
+
+        assert_eq!(CustomDelimiter::new(""), None);
+        assert_eq!(CustomDelimiter::new("a\nb"), None);
+        assert_eq!(CustomDelimiter::new(&"x".repeat(17)), None);
+        assert_eq!(CustomDelimiter::new("→").unwrap().as_str(), "→");
     }
 
     #[test]
@@ -246,6 +1450,41 @@ mod tests {
         assert_eq!(result, vec!["item1", "item2", "item3"]);
     }
 
+    #[test]
+    fn test_trim_pasted() {
+        assert_eq!(trim_pasted("  a \n b\t\n\n \n", Delimiter::Newline), "a\nb");
+        assert_eq!(trim_pasted("a, b ,, c, ,", Delimiter::Comma), "a,b,,c");
+        assert_eq!(trim_pasted(" \n ", Delimiter::Newline), "");
+    }
+
+    #[test]
+    fn test_clip_long_items() {
+        assert!(!is_long_item("abc", 3));
+        assert!(is_long_item("abcd", 3));
+        assert_eq!(clip_item("abc", 3), "abc");
+        assert_eq!(clip_item("abcdef", 4), "abc…");
+        // Clipping counts characters, not bytes
+        assert_eq!(clip_item("ñandú-ñandú", 5), "ñand…");
+    }
+
+    #[test]
+    fn test_parse_collapses_empty_items() {
+        assert_eq!(parse_list("a,,b,", Delimiter::Comma), vec!["a", "", "b"]);
+        assert_eq!(
+            parse_list_with("a,,b,", Delimiter::Comma, EmptyItems::Collapse),
+            vec!["a", "b"]
+        );
+        assert_eq!(
+            parse_list_with("a\n\n\nb", Delimiter::Newline, EmptyItems::Collapse),
+            vec!["a", "b"]
+        );
+        // Spaces are content: only truly empty items are dropped
+        assert_eq!(
+            parse_list_with("a, ,b", Delimiter::Comma, EmptyItems::Collapse),
+            vec!["a", " ", "b"]
+        );
+    }
+
     #[test]
     fn test_parse_trailing_comma() {
         let input = "a,b,c,";
@@ -263,16 +1502,204 @@ mod tests {
     #[test]
     fn test_json_to_csv() {
         let input = "[{\"a\":1,\"b\":2},{\"a\":3,\"b\":5}]";
-        let (result, _) = parse_json_to_list(input, ',').unwrap();
+        let (result, _) =
+            parse_json_to_list(input, ',', false, JsonArrayStrategy::Union, NestedJson::Raw)
+                .unwrap();
         assert_eq!(result, vec!["a,b", "1,2", "3,5"]);
     }
 
+    #[test]
+    fn test_json_to_quoted_csv() {
+        let input = r#"[{"name": "Doe, John", "note": "said \"hi\""}, {"name": "Ann"}]"#;
+        let (result, _) =
+            parse_json_to_list(input, ',', true, JsonArrayStrategy::Union, NestedJson::Raw)
+                .unwrap();
+        assert_eq!(
+            result,
+            vec!["name,note", "\"Doe, John\",\"said \"\"hi\"\"\"", "Ann,"]
+        );
+    }
+
+    #[test]
+    fn test_json_lines_to_csv() {
+        let input = "{\"a\":1,\"b\":\"x\"}\n\n{\"a\":2}\r\n\"loose\"\n";
+        let (result, array) =
+            parse_json_lines_to_list(input, ';', false, JsonArrayStrategy::Union, NestedJson::Raw)
+                .unwrap();
+        assert_eq!(result, vec!["a;b;(value)", "1;x;", "2;;", ";;loose"]);
+        assert_eq!(array, r#"[{"a":1,"b":"x"},{"a":2},"loose"]"#);
+        let error = parse_json_lines_to_list(
+            "{}\n{a:1}",
+            ',',
+            false,
+            JsonArrayStrategy::Union,
+            NestedJson::Raw,
+        )
+        .unwrap_err();
+        assert!(error.starts_with("line 2: "));
+    }
+
+    #[cfg(feature = "yaml")]
+    #[test]
+    fn test_yaml_to_csv() {
+        let input = "- name: Ann\n  age: 30\n- name: Bob\n  tags: [a, b]\n";
+        let (result, json) =
+            parse_yaml_to_list(input, ',', false, JsonArrayStrategy::Union, NestedJson::Raw)
+                .unwrap();
+        assert_eq!(
+            result,
+            vec!["age,name,tags", "30,Ann,", r#",Bob,["a","b"]"#]
+        );
+        assert!(json.starts_with(r#"[{"age":30"#));
+
+        let convert = |input| {
+            parse_yaml_to_list(input, ',', false, JsonArrayStrategy::Union, NestedJson::Raw)
+                .map(|(items, _)| items)
+        };
+        assert_eq!(
+            convert("- one\n- 2\n- true\n").unwrap(),
+            vec!["one", "2", "true"]
+        );
+        assert_eq!(convert("id: 7\nok: yes\n").unwrap(), vec!["id,ok", "7,yes"]);
+        assert_eq!(
+            convert("---\nid: 1\n---\nid: 2\n").unwrap(),
+            vec!["id", "1", "2"]
+        );
+        assert!(convert("just text").is_err());
+        assert!(convert("a: [1, 2").is_err());
+    }
+
+    #[test]
+    fn test_convert_table_keeps_rows() {
+        let input = "id\tname\r\n1\tDoe, John\n\n2\t\n";
+        assert_eq!(
+            convert_table(input, '\t', ',', false),
+            vec!["id,name", "1,Doe, John", "2,"]
+        );
+        assert_eq!(
+            convert_table(input, '\t', ',', true),
+            vec!["id,name", "1,\"Doe, John\"", "2,"]
+        );
+    }
+
+    #[test]
+    fn test_fixed_width_columns() {
+        let lines: Vec<String> = [
+            "ID   NAME        CITY",
+            "1    John Doe    Madrid",
+            "",
+            "22   Ann         Buenos Aires",
+        ]
+        .map(String::from)
+        .to_vec();
+        let widths = detect_fixed_widths(&lines);
+        assert_eq!(widths, vec![5, 12, 12]);
+        assert_eq!(
+            split_fixed_width(&lines[3], &widths),
+            vec!["22", "Ann", "Buenos Aires"]
+        );
+        assert_eq!(
+            convert_fixed_width(&lines.join("\n"), &widths, ';', false),
+            vec!["ID;NAME;CITY", "1;John Doe;Madrid", "22;Ann;Buenos Aires"]
+        );
+        // Short rows yield empty fields, long rows keep their tail in the last field
+        assert_eq!(split_fixed_width("7", &[2, 3, 4]), vec!["7", "", ""]);
+        assert_eq!(split_fixed_width("abcdef", &[2, 2]), vec!["ab", "cdef"]);
+    }
+
+    #[test]
+    fn test_parse_column_widths() {
+        assert_eq!(parse_column_widths("10, 8 12"), Some(vec![10, 8, 12]));
+        assert_eq!(parse_column_widths("  "), Some(vec![]));
+        assert_eq!(parse_column_widths("10,0"), None);
+        assert_eq!(parse_column_widths("10,x"), None);
+    }
+
+    #[test]
+    fn test_quote_csv_field() {
+        assert!(matches!(
+            quote_csv_field("plain", ','),
+            Cow::Borrowed("plain")
+        ));
+        assert_eq!(quote_csv_field("a;b", ','), "a;b");
+        assert_eq!(quote_csv_field("a;b", ';'), "\"a;b\"");
+        assert_eq!(quote_csv_field("6\" pipe", ','), "\"6\"\" pipe\"");
+        assert_eq!(quote_csv_field("two\nlines", ','), "\"two\nlines\"");
+    }
+
+    #[test]
+    fn test_heterogeneous_json_strategies() {
+        let input = r#"[{"a": 1}, "loose", {"b": {"c": true}}]"#;
+        let convert = |strategy| {
+            parse_json_to_list(input, ',', false, strategy, NestedJson::Raw)
+                .unwrap()
+                .0
+        };
+        assert_eq!(
+            convert(JsonArrayStrategy::Union),
+            vec!["a,b,(value)", "1,,", ",,loose", r#",{"c":true},"#]
+        );
+        assert_eq!(
+            convert(JsonArrayStrategy::ObjectsOnly),
+            vec!["a,b", "1,", r#",{"c":true}"#]
+        );
+        assert_eq!(
+            convert(JsonArrayStrategy::Stringify),
+            vec![r#"{"a":1}"#, "loose", r#"{"b":{"c":true}}"#]
+        );
+    }
+
+    #[test]
+    fn test_flatten_nested_json() {
+        let input =
+            r#"[{"id": 1, "user": {"name": "Ann", "tags": ["a", {"x": 2}]}, "meta": {}}, "loose"]"#;
+        let (result, _) = parse_json_to_list(
+            input,
+            ',',
+            false,
+            JsonArrayStrategy::Union,
+            NestedJson::Flatten,
+        )
+        .unwrap();
+        assert_eq!(
+            result,
+            vec![
+                "id,meta,user.name,user.tags.0,user.tags.1.x,(value)",
+                "1,{},Ann,a,2,",
+                ",,,,,loose",
+            ]
+        );
+    }
+
     #[test]
     fn test_lax_json() {
         // Unquoted keys should now be auto-repaired and valid
         let input = "[{a:1,b:2}]";
-        let (result, repaired) = parse_json_to_list(input, ',').unwrap();
+        let (result, repaired) =
+            parse_json_to_list(input, ',', false, JsonArrayStrategy::Union, NestedJson::Raw)
+                .unwrap();
         assert_eq!(result, vec!["a,b", "1,2"]);
         assert!(repaired.contains("\"a\""));
     }
+
+    #[test]
+    fn test_line_ending_detect_and_apply() {
+        assert_eq!(LineEnding::detect("a\r\nb\nc"), Some(LineEnding::Crlf));
+        assert_eq!(LineEnding::detect("a\nb\r\n"), Some(LineEnding::Lf));
+        assert_eq!(LineEnding::detect("a,b"), None);
+
+        assert_eq!(LineEnding::Crlf.apply("a\nb\r\nc\rd"), "a\r\nb\r\nc\r\nd");
+        assert_eq!(LineEnding::Lf.apply("a\r\nb\r\n"), "a\nb\n");
+    }
+
+    #[test]
+    fn test_encodings() {
+        assert_eq!(Encoding::Utf8.encode("añ"), "añ".as_bytes());
+        assert_eq!(Encoding::Utf8Bom.encode("a"), vec![0xEF, 0xBB, 0xBF, b'a']);
+        assert_eq!(
+            Encoding::Utf16Le.encode("añ"),
+            vec![0xFF, 0xFE, b'a', 0x00, 0xF1, 0x00]
+        );
+        assert_eq!(Encoding::Utf16Le.next(), Encoding::Utf8);
+    }
 }
@@ -0,0 +1,397 @@
+//! Comparison bundles: both input lists, the options and the results of a
+//! comparison in one JSON file (`.lcmp`), so a colleague can replay exactly the
+//! same comparison in their own terminal
+use crate::operations::{
+    compare_lists, CompareOptions, CompareResult, NormalizePreset, NumberPlacement, SmartSort,
+    TieBreak, Tolerance, TrimSet,
+};
+use crate::parser::{parse_list_with, CustomDelimiter, Delimiter, EmptyItems};
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// File extension of comparison bundles
+pub const BUNDLE_EXTENSION: &str = "lcmp";
+
+/// Format version written to new bundles; newer versions are refused
+pub const BUNDLE_VERSION: u32 = 1;
+
+/// Options a comparison was run with
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BundleOptions {
+    pub case_sensitive: bool,
+    pub trim_spaces: bool,
+    pub trim_quotes: bool,
+    pub trim_brackets: bool,
+    pub trim_punctuation: bool,
+    /// Extra characters trimmed along with the spaces
+    pub trim_chars: String,
+    pub fold_typography: bool,
+    /// Normalization preset name (absent in bundles written before presets existed)
+    #[serde(default)]
+    pub normalize: String,
+    /// Numeric tolerance as typed (`0.01`, `1%`), empty for exact matching
+    #[serde(default)]
+    pub tolerance: String,
+    /// Whether results kept the input order instead of being sorted
+    #[serde(default)]
+    pub preserve_order: bool,
+    /// Where numbers were sorted among text (`first` or `last`)
+    #[serde(default)]
+    pub sort_numbers: String,
+    /// How items that sort equal were ordered (`text` or `input`)
+    #[serde(default)]
+    pub sort_ties: String,
+    /// Whether empty items were dropped when parsing
+    pub collapse_empty: bool,
+}
+
+/// Result categories recorded when the bundle was exported
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BundleResults {
+    pub only_in_first: Vec<String>,
+    pub only_in_second: Vec<String>,
+    pub intersection: Vec<String>,
+    pub union: Vec<String>,
+    /// When the comparison was run, in seconds since the Unix epoch
+    pub compared_at: u64,
+}
+
+/// A recorded comparison
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ComparisonBundle {
+    pub version: u32,
+    /// Delimiter text (`\n`, `\t`, `,`, `;`, `csv`, `csv;`, `json`, `jsonl`, `yaml` or a custom string)
+    pub delimiter: String,
+    pub options: BundleOptions,
+    /// Lines of the List 1 editor
+    pub list1: Vec<String>,
+    /// Lines of the List 2 editor
+    pub list2: Vec<String>,
+    /// Results, when the lists had been compared
+    pub results: Option<BundleResults>,
+}
+
+impl ComparisonBundle {
+    /// Record the editors, parsing settings and (optionally) the results of a comparison
+    pub fn new(
+        list1: &[String],
+        list2: &[String],
+        delimiter: Delimiter,
+        empty: EmptyItems,
+        options: CompareOptions,
+        result: Option<&CompareResult>,
+    ) -> Self {
+        Self {
+            version: BUNDLE_VERSION,
+            delimiter: match delimiter {
+                Delimiter::Json => "json".to_string(),
+                Delimiter::JsonLines => "jsonl".to_string(),
+                Delimiter::Yaml => "yaml".to_string(),
+                Delimiter::CsvComma => "csv".to_string(),
+                Delimiter::CsvSemicolon => "csv;".to_string(),
+                delimiter => delimiter.as_str().to_string(),
+            },
+            options: BundleOptions {
+                case_sensitive: options.case_sensitive,
+                trim_spaces: options.trim_spaces,
+                trim_quotes: options.trim_set.quotes,
+                trim_brackets: options.trim_set.brackets,
+                trim_punctuation: options.trim_set.punctuation,
+                trim_chars: options.trim_set.custom().iter().collect(),
+                fold_typography: options.fold_typography,
+                normalize: options.normalize.name().to_string(),
+                tolerance: options
+                    .tolerance
+                    .map_or(String::new(), |tolerance| tolerance.to_string()),
+                preserve_order: options.preserve_order,
+                sort_numbers: options.smart_sort.numbers.name().to_string(),
+                sort_ties: options.smart_sort.ties.name().to_string(),
+                collapse_empty: empty == EmptyItems::Collapse,
+            },
+            list1: list1.to_vec(),
+            list2: list2.to_vec(),
+            results: result.map(|result| BundleResults {
+                only_in_first: result.only_in_first.clone(),
+                only_in_second: result.only_in_second.clone(),
+                intersection: result.intersection.clone(),
+                union: result.union.clone(),
+                compared_at: result
+                    .compared_at
+                    .duration_since(UNIX_EPOCH)
+                    .map_or(0, |d| d.as_secs()),
+            }),
+        }
+    }
+
+    /// Pretty-printed JSON, as written to `.lcmp` files
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).unwrap_or_default()
+    }
+
+    /// Read a bundle, refusing versions newer than [`BUNDLE_VERSION`] and unknown delimiters
+    pub fn from_json(text: &str) -> Result<Self, String> {
+        let bundle: Self = serde_json::from_str(text).map_err(|err| err.to_string())?;
+        if bundle.version > BUNDLE_VERSION {
+            return Err(format!(
+                "bundle version {} is newer than the supported version {}",
+                bundle.version, BUNDLE_VERSION
+            ));
+        }
+        if bundle.parsed_delimiter().is_none() {
+            return Err(format!("invalid delimiter {:?}", bundle.delimiter));
+        }
+        Ok(bundle)
+    }
+
+    fn parsed_delimiter(&self) -> Option<Delimiter> {
+        Delimiter::from_name(&self.delimiter)
+            .or_else(|| CustomDelimiter::new(&self.delimiter).map(Delimiter::Custom))
+    }
+
+    /// Delimiter the lists were parsed with
+    pub fn delimiter(&self) -> Delimiter {
+        self.parsed_delimiter().unwrap_or(Delimiter::Newline)
+    }
+
+    /// Whether empty items were kept or dropped
+    pub fn empty_items(&self) -> EmptyItems {
+        if self.options.collapse_empty {
+            EmptyItems::Collapse
+        } else {
+            EmptyItems::Keep
+        }
+    }
+
+    /// Comparison options the lists were compared with
+    pub fn compare_options(&self) -> CompareOptions {
+        let options = &self.options;
+        CompareOptions {
+            case_sensitive: options.case_sensitive,
+            trim_spaces: options.trim_spaces,
+            trim_set: TrimSet::new(
+                options.trim_quotes,
+                options.trim_brackets,
+                options.trim_punctuation,
+            )
+            .with_custom(&options.trim_chars),
+            fold_typography: options.fold_typography,
+            normalize: NormalizePreset::from_name(&options.normalize).unwrap_or_default(),
+            tolerance: Tolerance::parse(&options.tolerance),
+            preserve_order: options.preserve_order,
+            smart_sort: SmartSort {
+                numbers: NumberPlacement::from_name(&options.sort_numbers).unwrap_or_default(),
+                ties: TieBreak::from_name(&options.sort_ties).unwrap_or_default(),
+            },
+        }
+    }
+
+    /// When the recorded comparison was run
+    pub fn compared_at(&self) -> Option<SystemTime> {
+        let results = self.results.as_ref()?;
+        Some(UNIX_EPOCH + Duration::from_secs(results.compared_at))
+    }
+
+    /// Compare the recorded lists again with the recorded settings
+    pub fn replay(&self) -> CompareResult {
+        let delimiter = self.delimiter();
+        let parse = |lines: &[String]| {
+            parse_list_with(
+                &lines.join(delimiter.as_str()),
+                delimiter,
+                self.empty_items(),
+            )
+        };
+        compare_lists(
+            &parse(&self.list1),
+            &parse(&self.list2),
+            self.compare_options(),
+        )
+    }
+
+    /// Items only in one of the lists, from the recorded results or, when
+    /// there are none, from a replay
+    pub fn differences(&self) -> Vec<(DiffSide, String)> {
+        let (first, second) = match &self.results {
+            Some(results) => (
+                results.only_in_first.clone(),
+                results.only_in_second.clone(),
+            ),
+            None => {
+                let result = self.replay();
+                (result.only_in_first, result.only_in_second)
+            }
+        };
+        let first = first.into_iter().map(|item| (DiffSide::First, item));
+        let second = second.into_iter().map(|item| (DiffSide::Second, item));
+        first.chain(second).collect()
+    }
+
+    /// Whether `result` has the same categories as the recorded results
+    pub fn matches(&self, result: &CompareResult) -> bool {
+        self.results.as_ref().is_some_and(|recorded| {
+            recorded.only_in_first == result.only_in_first
+                && recorded.only_in_second == result.only_in_second
+                && recorded.intersection == result.intersection
+                && recorded.union == result.union
+        })
+    }
+}
+
+/// List a difference was found in
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DiffSide {
+    /// Only in List 1
+    First,
+    /// Only in List 2
+    Second,
+}
+
+/// How the differences changed from an older bundle to a newer one
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct BundleDiff {
+    /// Differences of the old bundle that are gone from the new one
+    pub resolved: Vec<(DiffSide, String)>,
+    /// Differences of the new bundle that the old one did not have
+    pub new: Vec<(DiffSide, String)>,
+    /// Differences found in both bundles
+    pub open: Vec<(DiffSide, String)>,
+}
+
+/// Meta-diff of two comparisons, e.g. yesterday's and today's reconciliation
+///
+/// A difference is an item together with the list it was missing from, so an
+/// item that moved from "only in List 1" to "only in List 2" is both resolved
+/// and new.
+pub fn diff_bundles(old: &ComparisonBundle, new: &ComparisonBundle) -> BundleDiff {
+    let old = old.differences();
+    let new = new.differences();
+    let old_set: HashSet<&(DiffSide, String)> = old.iter().collect();
+    let new_set: HashSet<&(DiffSide, String)> = new.iter().collect();
+
+    let mut diff = BundleDiff::default();
+    for entry in &old {
+        if !new_set.contains(entry) {
+            diff.resolved.push(entry.clone());
+        }
+    }
+    for entry in &new {
+        if old_set.contains(entry) {
+            diff.open.push(entry.clone());
+        } else {
+            diff.new.push(entry.clone());
+        }
+    }
+    diff
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::operations::compare_lists;
+    use crate::parser::parse_list;
+
+    #[test]
+    fn test_bundle_roundtrip_and_replay() {
+        let lines1 = vec!["a||B".to_string(), "c".to_string()];
+        let lines2 = vec!["b||d".to_string()];
+        let delimiter = Delimiter::Custom(CustomDelimiter::new("||").unwrap());
+        let options = CompareOptions {
+            trim_set: TrimSet::new(true, false, false).with_custom("#"),
+            ..CompareOptions::default()
+        };
+        let parse = |lines: &[String]| parse_list(&lines.join("||"), delimiter);
+        let result = compare_lists(&parse(&lines1), &parse(&lines2), options);
+        let bundle = ComparisonBundle::new(
+            &lines1,
+            &lines2,
+            delimiter,
+            EmptyItems::Collapse,
+            options,
+            Some(&result),
+        );
+
+        let read = ComparisonBundle::from_json(&bundle.to_json()).unwrap();
+        assert_eq!(read, bundle);
+        assert_eq!(read.delimiter(), delimiter);
+        assert_eq!(read.empty_items(), EmptyItems::Collapse);
+        assert_eq!(read.compare_options().trim_set, options.trim_set);
+
+        let replayed = compare_lists(
+            &parse(&read.list1),
+            &parse(&read.list2),
+            read.compare_options(),
+        );
+        assert!(read.matches(&replayed));
+        assert_eq!(read.results.unwrap().intersection, vec!["B"]);
+    }
+
+    #[test]
+    fn test_bundle_rejects_bad_input() {
+        assert!(ComparisonBundle::from_json("not json").is_err());
+        let mut bundle = ComparisonBundle::new(
+            &[],
+            &[],
+            Delimiter::Json,
+            EmptyItems::Keep,
+            CompareOptions::default(),
+            None,
+        );
+        assert_eq!(
+            ComparisonBundle::from_json(&bundle.to_json())
+                .unwrap()
+                .delimiter(),
+            Delimiter::Json
+        );
+        bundle.version = BUNDLE_VERSION + 1;
+        assert!(ComparisonBundle::from_json(&bundle.to_json()).is_err());
+        bundle.version = BUNDLE_VERSION;
+        bundle.delimiter = String::new();
+        assert!(ComparisonBundle::from_json(&bundle.to_json()).is_err());
+    }
+
+    #[test]
+    fn test_diff_bundles() {
+        let bundle = |list1: &[&str], list2: &[&str], recorded: bool| {
+            let lines = |items: &[&str]| items.iter().map(|s| s.to_string()).collect::<Vec<_>>();
+            let mut bundle = ComparisonBundle::new(
+                &lines(list1),
+                &lines(list2),
+                Delimiter::Newline,
+                EmptyItems::Keep,
+                CompareOptions::default(),
+                None,
+            );
+            if recorded {
+                let result = bundle.replay();
+                bundle = ComparisonBundle::new(
+                    &bundle.list1,
+                    &bundle.list2,
+                    Delimiter::Newline,
+                    EmptyItems::Keep,
+                    CompareOptions::default(),
+                    Some(&result),
+                );
+            }
+            bundle
+        };
+        let yesterday = bundle(&["a", "b", "c"], &["c", "d", "e"], true);
+        // Without recorded results the lists are compared again
+        let today = bundle(&["b", "c", "d"], &["c", "f"], false);
+
+        let diff = diff_bundles(&yesterday, &today);
+        let side = |side, items: &[&str]| {
+            items
+                .iter()
+                .map(|item| (side, item.to_string()))
+                .collect::<Vec<_>>()
+        };
+        let mut resolved = side(DiffSide::First, &["a"]);
+        resolved.extend(side(DiffSide::Second, &["d", "e"]));
+        assert_eq!(diff.resolved, resolved);
+        let mut new = side(DiffSide::First, &["d"]);
+        new.extend(side(DiffSide::Second, &["f"]));
+        assert_eq!(diff.new, new);
+        assert_eq!(diff.open, side(DiffSide::First, &["b"]));
+    }
+}
@@ -0,0 +1,245 @@
+//! Report builders that turn comparison results into shareable text
+use crate::numbers::NumberFormat;
+use crate::operations::{CompareOptions, CompareResult};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+pub mod bundle;
+
+pub use bundle::*;
+
+/// Format a point in time as `YYYY-MM-DD HH:MM:SS UTC`
+///
+/// # Arguments
+/// * `time` - The time to format
+pub fn format_timestamp(time: SystemTime) -> String {
+    let secs = time
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+    let days = (secs / 86_400) as i64;
+    let rem = secs % 86_400;
+    let (year, month, day) = civil_from_days(days);
+
+    format!(
+        "{:04}-{:02}-{:02} {:02}:{:02}:{:02} UTC",
+        year,
+        month,
+        day,
+        rem / 3600,
+        (rem % 3600) / 60,
+        rem % 60
+    )
+}
+
+/// Format a point in time as `YYYYMMDD_HHMMSS` (UTC), safe for file and directory names
+///
+/// # Arguments
+/// * `time` - The time to format
+pub fn file_stamp(time: SystemTime) -> String {
+    let secs = time
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+    let rem = secs % 86_400;
+    let (year, month, day) = civil_from_days((secs / 86_400) as i64);
+
+    format!(
+        "{:04}{:02}{:02}_{:02}{:02}{:02}",
+        year,
+        month,
+        day,
+        rem / 3600,
+        (rem % 3600) / 60,
+        rem % 60
+    )
+}
+
+/// Convert days since the Unix epoch into a (year, month, day) civil date
+fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    // Howard Hinnant's days_from_civil inverse
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = (doy - (153 * mp + 2) / 5 + 1) as u32;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+    let year = yoe + era * 400 + if month <= 2 { 1 } else { 0 };
+    (year, month, day)
+}
+
+fn on_off(flag: bool) -> &'static str {
+    if flag {
+        "ON"
+    } else {
+        "OFF"
+    }
+}
+
+/// Trim state plus the extra trimmed characters, if any
+fn trim_label(options: CompareOptions) -> String {
+    if options.trim_spaces && !options.trim_set.is_empty() {
+        format!("{} (+{})", on_off(true), options.trim_set.chars())
+    } else {
+        on_off(options.trim_spaces).to_string()
+    }
+}
+
+/// Summary table rows, coverage ratios and the trailing options/timestamp line
+fn summary_lines(result: &CompareResult, numbers: &NumberFormat) -> Vec<String> {
+    let total = result.union.len();
+    let mut lines = vec![
+        "| Category | Items | % of union |".to_string(),
+        "|---|---:|---:|".to_string(),
+    ];
+    for (label, items) in categories(result) {
+        lines.push(format!(
+            "| {} | {} | {} |",
+            label,
+            numbers.count(items.len()),
+            numbers.percent(items.len(), total)
+        ));
+    }
+    lines.push(String::new());
+    lines.push(format!(
+        "{} of List 1 found in List 2, {} of List 2 found in List 1, overlap {}.",
+        numbers.ratio(result.stats.first_in_second()),
+        numbers.ratio(result.stats.second_in_first()),
+        numbers.ratio(result.stats.overlap())
+    ));
+    lines.push(String::new());
+    lines.push(format!(
+        "_Options: case sensitive {}, trim spaces {}, delimiter `{}` · {}_",
+        on_off(result.options.case_sensitive),
+        trim_label(result.options),
+        result.delimiter.display_name(),
+        format_timestamp(result.compared_at)
+    ));
+    lines
+}
+
+/// Result categories in display order
+fn categories(result: &CompareResult) -> [(&'static str, &[String]); 4] {
+    [
+        ("Only in List 1", &result.only_in_first),
+        ("Only in List 2", &result.only_in_second),
+        ("Intersection", &result.intersection),
+        ("Union", &result.union),
+    ]
+}
+
+/// Build a Markdown summary of a comparison (counts, percentages, options, timestamp)
+///
+/// # Arguments
+/// * `result` - The comparison result to summarize (carries its options and time)
+/// * `numbers` - Formatting for counts and percentages
+///
+/// # Returns
+/// A Markdown string ready to paste into a ticket or chat
+pub fn summary_markdown(result: &CompareResult, numbers: &NumberFormat) -> String {
+    let mut lines = vec!["### List comparison summary".to_string(), String::new()];
+    lines.extend(summary_lines(result, numbers));
+    lines.join("\n")
+}
+
+/// Build a full Markdown report: summary table followed by a collapsible
+/// `<details>` section listing the items of each result category
+///
+/// # Arguments
+/// * `result` - The comparison result to export (carries its options and time)
+/// * `numbers` - Formatting for counts and percentages
+pub fn report_markdown(result: &CompareResult, numbers: &NumberFormat) -> String {
+    let mut lines = vec![
+        "# List comparison report".to_string(),
+        String::new(),
+        "## Summary".to_string(),
+        String::new(),
+    ];
+    lines.extend(summary_lines(result, numbers));
+    lines.push(String::new());
+    lines.push("## Details".to_string());
+
+    for (label, items) in categories(result) {
+        lines.push(String::new());
+        lines.push("<details>".to_string());
+        lines.push(format!(
+            "<summary>{} ({} items)</summary>",
+            label,
+            numbers.count(items.len())
+        ));
+        lines.push(String::new());
+        if items.is_empty() {
+            lines.push("_No items_".to_string());
+        } else {
+            lines.push("```text".to_string());
+            lines.extend(items.iter().cloned());
+            lines.push("```".to_string());
+        }
+        lines.push(String::new());
+        lines.push("</details>".to_string());
+    }
+
+    let mut report = lines.join("\n");
+    report.push('\n');
+    report
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::operations::{CompareOptions, CompareStats};
+    use crate::parser::Delimiter;
+    use std::time::Duration;
+
+    fn sample_result(delimiter: Delimiter) -> CompareResult {
+        CompareResult {
+            only_in_first: vec!["a".to_string()],
+            only_in_second: vec![],
+            intersection: vec!["b".to_string()],
+            union: vec!["a".to_string(), "b".to_string()],
+            stats: CompareStats {
+                first_unique: 2,
+                second_unique: 1,
+                shared: 1,
+                combined: 2,
+            },
+            near_matches: Vec::new(),
+            occurrences: Default::default(),
+            options: CompareOptions::default(),
+            delimiter,
+            compared_at: UNIX_EPOCH + Duration::from_secs(1_700_000_000),
+        }
+    }
+
+    #[test]
+    fn test_format_timestamp() {
+        let time = UNIX_EPOCH + Duration::from_secs(1_700_000_000);
+        assert_eq!(format_timestamp(time), "2023-11-14 22:13:20 UTC");
+        assert_eq!(file_stamp(time), "20231114_221320");
+    }
+
+    #[test]
+    fn test_summary_markdown() {
+        let md = summary_markdown(&sample_result(Delimiter::Newline), &NumberFormat::default());
+
+        assert!(md.contains("| Only in List 1 | 1 | 50.0% |"));
+        assert!(md.contains("| Only in List 2 | 0 | 0.0% |"));
+        assert!(md.contains("| Union | 2 | 100.0% |"));
+        assert!(md.contains(
+            "50.0% of List 1 found in List 2, 100.0% of List 2 found in List 1, overlap 50.0%."
+        ));
+        assert!(md.contains("case sensitive OFF, trim spaces ON"));
+        assert!(md.contains("2023-11-14 22:13:20 UTC"));
+    }
+
+    #[test]
+    fn test_report_markdown_sections() {
+        let md = report_markdown(&sample_result(Delimiter::Comma), &NumberFormat::default());
+
+        assert!(md.starts_with("# List comparison report"));
+        assert_eq!(md.matches("<details>").count(), 4);
+        assert!(md.contains("<summary>Only in List 1 (1 items)</summary>\n\n```text\na\n```"));
+        assert!(md.contains("<summary>Only in List 2 (0 items)</summary>\n\n_No items_"));
+    }
+}
@@ -0,0 +1,69 @@
+use crate::i18n::{tr, Text};
+use crate::ui::help::centered_rect;
+use ratatui::{
+    layout::Alignment,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph, Wrap},
+    Frame,
+};
+
+/// Render the diagnostics modal: environment details plus the key tester log
+///
+/// # Arguments
+/// * `frame` - The frame to render to
+/// * `details` - Labelled environment values
+/// * `key_log` - Recent key events, oldest first
+pub fn render_diagnostics_modal(frame: &mut Frame, details: &[(Text, String)], key_log: &[String]) {
+    let area = centered_rect(70, 80, frame.area());
+    frame.render_widget(Clear, area);
+
+    let block = Block::default()
+        .title(tr(Text::TitleDiagnostics))
+        .title_alignment(Alignment::Center)
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Magenta))
+        .style(Style::default().bg(Color::Black));
+
+    let heading = Style::default()
+        .add_modifier(Modifier::BOLD)
+        .fg(Color::Cyan);
+
+    let mut text = Vec::new();
+    for (label, value) in details {
+        text.push(Line::from(vec![
+            Span::styled(
+                format!("  {:<28}", tr(*label)),
+                Style::default().fg(Color::Yellow),
+            ),
+            Span::raw(value.as_str()),
+        ]));
+    }
+
+    text.push(Line::from(""));
+    text.push(Line::from(Span::styled(tr(Text::DiagKeyTester), heading)));
+    if key_log.is_empty() {
+        text.push(Line::from(Span::styled(
+            format!("  {}", tr(Text::DiagKeyPrompt)),
+            Style::default().fg(Color::DarkGray),
+        )));
+    }
+    for key in key_log {
+        text.push(Line::from(format!("  {}", key)));
+    }
+
+    text.push(Line::from(""));
+    text.push(Line::from(Span::styled(
+        tr(Text::DiagClose),
+        Style::default()
+            .add_modifier(Modifier::ITALIC)
+            .fg(Color::DarkGray),
+    )));
+
+    let paragraph = Paragraph::new(text)
+        .block(block)
+        .alignment(Alignment::Left)
+        .wrap(Wrap { trim: false });
+
+    frame.render_widget(paragraph, area);
+}
@@ -1,3 +1,4 @@
+use crate::i18n::{tr, Text};
 use ratatui::{
     layout::{Alignment, Constraint, Direction, Layout, Rect},
     style::{Color, Modifier, Style},
@@ -17,171 +18,182 @@ pub fn render_help_modal(frame: &mut Frame) {
     frame.render_widget(Clear, help_area);
 
     let block = Block::default()
-        .title(" Help - Keyboard Shortcuts ")
+        .title(tr(Text::TitleHelp))
         .title_alignment(Alignment::Center)
         .borders(Borders::ALL)
         .border_style(Style::default().fg(Color::Yellow))
         .style(Style::default().bg(Color::Black));
 
-    let mut text = Vec::new();
-
-    // Section: Vim Mode
-    text.push(Line::from(vec![Span::styled(
-        "Vim Mode",
-        Style::default()
-            .add_modifier(Modifier::BOLD)
-            .fg(Color::Cyan),
-    )]));
-    text.push(Line::from(vec![
-        Span::styled("  i          ", Style::default().fg(Color::Yellow)),
-        Span::raw("Enter INSERT mode to type"),
-    ]));
-    text.push(Line::from(vec![
-        Span::styled("  Esc        ", Style::default().fg(Color::Yellow)),
-        Span::raw("Back to NORMAL mode (from Insert)"),
-    ]));
-    text.push(Line::from(vec![
-        Span::styled("  h, j, k, l ", Style::default().fg(Color::Yellow)),
-        Span::raw("Move cursor (Normal mode)"),
-    ]));
-    text.push(Line::from(vec![
-        Span::styled("  w, b       ", Style::default().fg(Color::Yellow)),
-        Span::raw("Move Word Forward / Back"),
-    ]));
-    text.push(Line::from(vec![
-        Span::styled("  0, $       ", Style::default().fg(Color::Yellow)),
-        Span::raw("Move to Line Start / End"),
-    ]));
-    text.push(Line::from(vec![
-        Span::styled("  g, G       ", Style::default().fg(Color::Yellow)),
-        Span::raw("Move to Top / Bottom of list"),
-    ]));
-    text.push(Line::from(""));
-
-    // Section: Navigation
-    text.push(Line::from(vec![Span::styled(
-        "General Navigation",
-        Style::default()
-            .add_modifier(Modifier::BOLD)
-            .fg(Color::Cyan),
-    )]));
-    text.push(Line::from(vec![
-        Span::styled("  Alt+1/2/3  ", Style::default().fg(Color::Yellow)),
-        Span::raw("Switch between Tabs (Input, Results, Convert)"),
-    ]));
-    text.push(Line::from(vec![
-        Span::styled("  Tab        ", Style::default().fg(Color::Yellow)),
-        Span::raw("Switch between panels"),
-    ]));
-    text.push(Line::from(vec![
-        Span::styled("  Esc        ", Style::default().fg(Color::Yellow)),
-        Span::raw("Quit application / Close Help"),
-    ]));
-    text.push(Line::from(""));
-
-    // Section: Data Operations
-    text.push(Line::from(vec![Span::styled(
-        "Data Operations",
-        Style::default()
-            .add_modifier(Modifier::BOLD)
-            .fg(Color::Cyan),
-    )]));
-    text.push(Line::from(vec![
-        Span::styled("  F5         ", Style::default().fg(Color::Yellow)),
-        Span::raw("Cycle global delimiter"),
-    ]));
-    text.push(Line::from(vec![
-        Span::styled("  F6 / F7    ", Style::default().fg(Color::Yellow)),
-        Span::raw("Sort Ascending / Descending (replaces content)"),
-    ]));
-    text.push(Line::from(vec![
-        Span::styled("  F8         ", Style::default().fg(Color::Yellow)),
-        Span::raw("Trim spaces & Deduplicate (replaces content)"),
-    ]));
-    text.push(Line::from(vec![
-        Span::styled("  F12        ", Style::default().fg(Color::Yellow)),
-        Span::raw("Compare List 1 and List 2"),
-    ]));
-    text.push(Line::from(""));
-
-    // Section: Configuration
-    text.push(Line::from(vec![Span::styled(
-        "Configuration",
-        Style::default()
-            .add_modifier(Modifier::BOLD)
-            .fg(Color::Cyan),
-    )]));
-    text.push(Line::from(vec![
-        Span::styled("  F3         ", Style::default().fg(Color::Yellow)),
-        Span::raw("Toggle Case Sensitivity"),
-    ]));
-    text.push(Line::from(vec![
-        Span::styled("  F4         ", Style::default().fg(Color::Yellow)),
-        Span::raw("Toggle Trim Spaces"),
-    ]));
-    text.push(Line::from(""));
-
-    // Section: Files & Clipboard
-    text.push(Line::from(vec![Span::styled(
-        "Files & Clipboard",
-        Style::default()
-            .add_modifier(Modifier::BOLD)
-            .fg(Color::Cyan),
-    )]));
-    text.push(Line::from(vec![
-        Span::styled("  F1 / F2    ", Style::default().fg(Color::Yellow)),
-        Span::raw("Save / Load active panel from file"),
-    ]));
-    let copy_key = if cfg!(target_os = "macos") {
-        "Cmd+C/V"
+    let (copy_key, undo_key, redo_key, duplicates_key) = if cfg!(target_os = "macos") {
+        ("Cmd+C/V", "u, Cmd+Z", "Cmd+Y", "d / Cmd+D")
     } else {
-        "Ctrl+C/V"
+        ("Ctrl+C/V", "u, Ctrl+Z", "Ctrl+Y", "d / Ctrl+D")
     };
-    text.push(Line::from(vec![
-        Span::styled(
-            format!("  {}   ", copy_key),
-            Style::default().fg(Color::Yellow),
-        ),
-        Span::raw("Copy panel / Paste into input"),
-    ]));
-    text.push(Line::from(""));
 
-    // Section: Convert Tab
-    text.push(Line::from(vec![Span::styled(
-        "Convert Tab (Alt+3)",
-        Style::default()
-            .add_modifier(Modifier::BOLD)
-            .fg(Color::Cyan),
-    )]));
-    text.push(Line::from(vec![
-        Span::styled("  F10        ", Style::default().fg(Color::Yellow)),
-        Span::raw("Cycle Source Delimiter (JSON support)"),
-    ]));
-    text.push(Line::from(vec![
-        Span::styled("  F11        ", Style::default().fg(Color::Yellow)),
-        Span::raw("Cycle Target Delimiter"),
-    ]));
-    text.push(Line::from(vec![
-        Span::styled("  F12        ", Style::default().fg(Color::Yellow)),
-        Span::raw("Execute delimiter conversion"),
-    ]));
-    text.push(Line::from(""));
+    let sections: [(Text, Vec<(&str, Text)>); 9] = [
+        (
+            Text::HelpVimMode,
+            vec![
+                ("i", Text::HelpInsert),
+                ("Esc", Text::HelpNormal),
+                ("h, j, k, l", Text::HelpMoveCursor),
+                ("w, b", Text::HelpMoveWord),
+                ("0, $", Text::HelpMoveLine),
+                ("g, G", Text::HelpMoveTopBottom),
+            ],
+        ),
+        (
+            Text::HelpNavigation,
+            vec![
+                ("Alt+1..6", Text::HelpSwitchTabs),
+                ("Alt+Shift+n", Text::HelpSwitchWorkspaces),
+                ("Tab", Text::HelpSwitchPanels),
+                ("Shift+Tab", Text::HelpPreviousPanel),
+                ("1..4", Text::HelpFocusPanel),
+                ("Esc", Text::HelpQuit),
+                ("D", Text::HelpDiagnostics),
+            ],
+        ),
+        (
+            Text::HelpDataOperations,
+            vec![
+                ("F5", Text::HelpCycleDelimiter),
+                (":", Text::HelpCustomDelimiter),
+                ("F6 / F7", Text::HelpSort),
+                ("F8", Text::HelpTrimDedup),
+                ("Shift+F8", Text::HelpFrequencies),
+                (duplicates_key, Text::HelpDuplicates),
+                ("N", Text::HelpStopwords),
+                ("S", Text::HelpLogPrefixes),
+                ("R", Text::HelpReadOnly),
+                ("F12", Text::HelpCompare),
+                ("V / X", Text::HelpValidate),
+                ("Z", Text::HelpCompareKeyed),
+                ("z", Text::HelpCycleKeyedPreset),
+                ("B / I", Text::HelpFilterList1),
+                ("/", Text::HelpFilterRegex),
+                ("&", Text::HelpReplaceRegex),
+                (undo_key, Text::HelpUndo),
+                (redo_key, Text::HelpRedo),
+            ],
+        ),
+        (
+            Text::HelpConfiguration,
+            vec![
+                ("F3", Text::HelpToggleCase),
+                ("F4", Text::HelpToggleTrim),
+                ("Q", Text::HelpTrimSet),
+                ("C", Text::HelpFoldTypography),
+                ("Y", Text::HelpPreserveOrder),
+                ("c", Text::HelpNormalizePreset),
+                ("#", Text::HelpCompareColumn),
+                ("%", Text::HelpTolerance),
+                ("A", Text::HelpAutoCompare),
+                ("F9", Text::HelpToggleInfo),
+                ("+ / -", Text::HelpResizeInfo),
+                ("U", Text::HelpToggleUsage),
+            ],
+        ),
+        (
+            Text::HelpFilesClipboard,
+            vec![
+                ("F1 / F2", Text::HelpSaveLoad),
+                ("J", Text::HelpLineEnding),
+                ("F", Text::HelpEncoding),
+                (",", Text::HelpEmptyItems),
+                ("~", Text::HelpTrimOnPaste),
+                ("a", Text::HelpAcceptDelimiter),
+                ("W", Text::HelpSaveWorkspace),
+                ("O", Text::HelpReferences),
+                ("K", Text::HelpSaveReference),
+                ("L", Text::HelpLoadCompare),
+                ("M", Text::HelpWatch),
+                ("H", Text::HelpGitRevisions),
+                (copy_key, Text::HelpCopyPaste),
+                ("y", Text::HelpCopySummary),
+                ("m", Text::HelpReport),
+                ("e", Text::HelpExportBundle),
+                ("E", Text::HelpImportBundle),
+            ],
+        ),
+        (
+            Text::HelpConvertTab,
+            vec![
+                ("F10", Text::HelpCycleSource),
+                ("F11", Text::HelpCycleTarget),
+                ("F12", Text::HelpConvert),
+                ("P", Text::HelpAlignedPreview),
+                ("\"", Text::HelpQuoteFields),
+                ("'", Text::HelpItemWrap),
+                ("(", Text::HelpLiteral),
+                ("v", Text::HelpMarkdownTable),
+                ("T", Text::HelpTableMode),
+                ("|", Text::HelpFixedWidths),
+                ("=", Text::HelpKeyValue),
+                ("!, @", Text::HelpSendToList),
+                ("p, n", Text::HelpConvertHistory),
+                ("a", Text::HelpAcceptRepair),
+                ("o", Text::HelpJsonStrategy),
+                ("x", Text::HelpJsonPath),
+                ("f", Text::HelpFlattenJson),
+            ],
+        ),
+        (
+            Text::HelpResultsTab,
+            vec![
+                ("F12", Text::HelpToggleView),
+                ("j, k, g, G", Text::HelpSelectResult),
+                ("r", Text::HelpRefresh),
+                ("T", Text::HelpTableView),
+                ("> / < / =", Text::HelpColumnWidth),
+                ("E", Text::HelpHideEmpty),
+                ("s", Text::HelpResultSort),
+                ("[ ] / { }", Text::HelpResizeGrid),
+            ],
+        ),
+        (
+            Text::HelpAnalysisTab,
+            vec![
+                ("F12", Text::HelpAnalyze),
+                ("s", Text::HelpAnalysisSource),
+                ("j, k, g, G", Text::HelpSelectResult),
+            ],
+        ),
+        (
+            Text::HelpMergeTab,
+            vec![
+                ("F12", Text::HelpJoin),
+                ("t", Text::HelpJoinType),
+                ("[ ] / { }", Text::HelpJoinKeys),
+                ("s", Text::HelpJoinSeparator),
+                ("j, k, g, G", Text::HelpSelectResult),
+            ],
+        ),
+    ];
 
-    // Section: Results Tab (Alt+2)
-    text.push(Line::from(vec![Span::styled(
-        "Results Tab (Alt+2)",
-        Style::default()
-            .add_modifier(Modifier::BOLD)
-            .fg(Color::Cyan),
-    )]));
-    text.push(Line::from(vec![
-        Span::styled("  F12        ", Style::default().fg(Color::Yellow)),
-        Span::raw("Toggle between Grid and Unified Diff view"),
-    ]));
-    text.push(Line::from(""));
+    let mut text = Vec::new();
+    for (title, rows) in sections {
+        text.push(Line::from(vec![Span::styled(
+            tr(title),
+            Style::default()
+                .add_modifier(Modifier::BOLD)
+                .fg(Color::Cyan),
+        )]));
+        for (keys, description) in rows {
+            text.push(Line::from(vec![
+                // The space after the column keeps a full-width key off its description
+                Span::styled(
+                    format!("  {:<11} ", keys),
+                    Style::default().fg(Color::Yellow),
+                ),
+                Span::raw(tr(description)),
+            ]));
+        }
+        text.push(Line::from(""));
+    }
 
     text.push(Line::from(vec![Span::styled(
-        "Press any key or '?' to close",
+        tr(Text::HelpClose),
         Style::default()
             .add_modifier(Modifier::ITALIC)
             .fg(Color::DarkGray),
@@ -196,7 +208,7 @@ pub fn render_help_modal(frame: &mut Frame) {
 }
 
 /// Helper function to create a centered rect using up certain percentage of available area
-fn centered_rect(percent_x: u16, percent_y: u16, r: Rect) -> Rect {
+pub(crate) fn centered_rect(percent_x: u16, percent_y: u16, r: Rect) -> Rect {
     let popup_layout = Layout::default()
         .direction(Direction::Vertical)
         .constraints([
@@ -1,22 +1,34 @@
 /// Main layout component that arranges panels
-use ratatui::layout::{Constraint, Layout, Rect};
+use ratatui::layout::{Constraint, Direction, Layout, Rect};
+
+/// Terminal width below which panels are stacked instead of placed side by side
+pub const NARROW_WIDTH: u16 = 80;
+
+/// Whether the area is too narrow for side-by-side panels
+pub fn is_narrow(area: Rect) -> bool {
+    area.width < NARROW_WIDTH
+}
 
 /// Create the main layout with tabs, three sections: list1, list2, and results
 ///
 /// # Arguments
 /// * `area` - The area to divide
+/// * `info_height` - Height of the INFO panel (0 hides it and gives the space to the lists)
 ///
 /// # Returns
 /// Tuple of (tabs_area, list1_area, list2_area, results_area, status_area, content_area_for_tab2)
 /// content_area_for_tab2 is the combined area for Tab 2 (everything except tabs and status)
-pub fn create_layout_with_tabs(area: Rect) -> (Rect, Rect, Rect, Rect, Rect, Rect) {
+pub fn create_layout_with_tabs(
+    area: Rect,
+    info_height: u16,
+) -> (Rect, Rect, Rect, Rect, Rect, Rect) {
     let vertical = Layout::default()
         .direction(ratatui::layout::Direction::Vertical)
         .constraints([
-            Constraint::Length(3), // Tabs area
-            Constraint::Min(10),   // Lists area
-            Constraint::Length(4), // INFO area
-            Constraint::Length(1), // Status bar at bottom
+            Constraint::Length(3),           // Tabs area
+            Constraint::Min(10),             // Lists area
+            Constraint::Length(info_height), // INFO area
+            Constraint::Length(1),           // Status bar at bottom
         ])
         .split(area);
 
@@ -28,8 +40,14 @@ pub fn create_layout_with_tabs(area: Rect) -> (Rect, Rect, Rect, Rect, Rect, Rec
     // Combined area for Tab 1 (Results) - now just lists_area to leave room for INFO
     let content_area_for_tab2 = lists_area;
 
+    // Stack List 1 above List 2 on narrow terminals
+    let direction = if is_narrow(area) {
+        Direction::Vertical
+    } else {
+        Direction::Horizontal
+    };
     let horizontal = Layout::default()
-        .direction(ratatui::layout::Direction::Horizontal)
+        .direction(direction)
         .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
         .split(lists_area);
 
@@ -79,36 +97,131 @@ pub fn create_layout(area: Rect) -> (Rect, Rect, Rect, Rect) {
     (list1_area, list2_area, results_area, status_area)
 }
 
+/// Split the results area for the differences view: Only L1 and Only L2 side
+/// by side at full height (stacked on narrow terminals)
+///
+/// # Returns
+/// Tuple of (only_l1_area, only_l2_area)
+pub fn create_differences_layout(area: Rect) -> (Rect, Rect) {
+    let direction = if is_narrow(area) {
+        Direction::Vertical
+    } else {
+        Direction::Horizontal
+    };
+    split_percent(area, direction, 50)
+}
+
+/// Step used when resizing the results grid, in percent
+pub const GRID_STEP: u16 = 10;
+
+/// Split ratios of the 2x2 results grid, in percent
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GridLayout {
+    /// Height of the top row (Only L1 / Only L2)
+    pub rows: u16,
+    /// Width of the left panel in the top row (Only L1)
+    pub top: u16,
+    /// Width of the left panel in the bottom row (Intersection)
+    pub bottom: u16,
+}
+
+impl Default for GridLayout {
+    fn default() -> Self {
+        Self {
+            rows: 50,
+            top: 50,
+            bottom: 50,
+        }
+    }
+}
+
+impl GridLayout {
+    /// Move the row split down (positive) or up (negative) by one step
+    pub fn resize_rows(&mut self, direction: i16) {
+        self.rows = step(self.rows, direction);
+    }
+
+    /// Collapse the splits so hidden panels take no space and their
+    /// neighbours grow into it (all panels hidden keeps the layout as is)
+    ///
+    /// # Arguments
+    /// * `visible` - Visibility of Only L1, Only L2, Intersection and Union
+    pub fn collapse(self, visible: [bool; 4]) -> Self {
+        if !visible.contains(&true) {
+            return self;
+        }
+        let split = |left: bool, right: bool, percent: u16| match (left, right) {
+            (true, false) => 100,
+            (false, true) => 0,
+            _ => percent,
+        };
+        Self {
+            rows: split(
+                visible[0] || visible[1],
+                visible[2] || visible[3],
+                self.rows,
+            ),
+            top: split(visible[0], visible[1], self.top),
+            bottom: split(visible[2], visible[3], self.bottom),
+        }
+    }
+
+    /// Move the column split of the row holding `panel` right (positive) or left (negative)
+    pub fn resize_columns(&mut self, panel: usize, direction: i16) {
+        if panel < 2 {
+            self.top = step(self.top, direction);
+        } else {
+            self.bottom = step(self.bottom, direction);
+        }
+    }
+}
+
+fn step(percent: u16, direction: i16) -> u16 {
+    (percent as i16 + direction.signum() * GRID_STEP as i16).clamp(0, 100) as u16
+}
+
+/// Split an area in two along `direction`, giving `percent` to the first part
+fn split_percent(area: Rect, direction: Direction, percent: u16) -> (Rect, Rect) {
+    let parts = Layout::default()
+        .direction(direction)
+        .constraints([
+            Constraint::Percentage(percent),
+            Constraint::Percentage(100 - percent),
+        ])
+        .split(area);
+    (parts[0], parts[1])
+}
+
 /// Create a 2x2 grid layout for results panels
 ///
 /// # Arguments
 /// * `area` - The area to divide
+/// * `grid` - Split ratios (a 0% or 100% split hides one panel of a row)
 ///
 /// # Returns
 /// Tuple of (only_l1_area, only_l2_area, intersection_area, union_area)
-pub fn create_results_grid(area: Rect) -> (Rect, Rect, Rect, Rect) {
-    let vertical = Layout::default()
-        .direction(ratatui::layout::Direction::Vertical)
-        .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
-        .split(area);
-
-    let top_row = vertical[0];
-    let bottom_row = vertical[1];
-
-    let top_horizontal = Layout::default()
-        .direction(ratatui::layout::Direction::Horizontal)
-        .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
-        .split(top_row);
-
-    let bottom_horizontal = Layout::default()
-        .direction(ratatui::layout::Direction::Horizontal)
-        .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
-        .split(bottom_row);
+pub fn create_results_grid(area: Rect, grid: GridLayout) -> (Rect, Rect, Rect, Rect) {
+    let (top_row, bottom_row) = split_percent(area, Direction::Vertical, grid.rows);
+    let top_horizontal = split_percent(top_row, Direction::Horizontal, grid.top);
+    let bottom_horizontal = split_percent(bottom_row, Direction::Horizontal, grid.bottom);
 
     (
-        top_horizontal[0],    // Only in List 1
-        top_horizontal[1],    // Only in List 2
-        bottom_horizontal[0], // Intersection
-        bottom_horizontal[1], // Union
+        top_horizontal.0,    // Only in List 1
+        top_horizontal.1,    // Only in List 2
+        bottom_horizontal.0, // Intersection
+        bottom_horizontal.1, // Union
     )
 }
+
+/// Split a one-line header off the top of an area
+///
+/// # Returns
+/// Tuple of (header_area, body_area)
+pub fn split_header(area: Rect) -> (Rect, Rect) {
+    let vertical = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(1), Constraint::Min(0)])
+        .split(area);
+
+    (vertical[0], vertical[1])
+}
@@ -1,6 +1,7 @@
 /// List panel component for displaying and editing lists
 use ratatui::{
     style::{Color, Style},
+    text::Line,
     widgets::Block,
     Frame,
 };
@@ -14,12 +15,14 @@ use tui_textarea::TextArea;
 /// * `title` - The title of the panel
 /// * `textarea` - The text area widget
 /// * `is_active` - Whether this panel is currently active
-pub fn render_list_panel<'a>(
+/// * `read_only` - Whether edits are blocked (shows a lock after the title)
+pub fn render_list_panel(
     frame: &mut Frame,
     area: ratatui::layout::Rect,
-    title: &'a str,
-    textarea: &mut TextArea<'a>,
+    title: &str,
+    textarea: &mut TextArea<'_>,
     is_active: bool,
+    read_only: bool,
 ) {
     let border_style = if is_active {
         Style::default().fg(Color::Yellow)
@@ -28,8 +31,13 @@ pub fn render_list_panel<'a>(
     };
 
     // Create block - tui-textarea accepts ratatui::widgets::Block
+    let title = if read_only {
+        format!("{} 🔒", title)
+    } else {
+        title.to_string()
+    };
     let block = Block::default()
-        .title(title)
+        .title(Line::from(title))
         .borders(ratatui::widgets::Borders::ALL)
         .border_style(border_style);
 
@@ -5,11 +5,15 @@ pub mod results_panel;
 pub mod status_bar;
 pub mod tabs;
 
+pub mod diagnostics;
 pub mod help;
+pub mod picker;
 
+pub use diagnostics::*;
 pub use help::*;
 pub use layout::*;
 pub use list_panel::*;
+pub use picker::*;
 pub use results_panel::*;
 pub use status_bar::*;
 pub use tabs::*;
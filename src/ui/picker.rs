@@ -0,0 +1,84 @@
+use crate::i18n::{tr, Text};
+use crate::ui::help::centered_rect;
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+    Frame,
+};
+use tui_textarea::TextArea;
+
+/// Render a modal list to pick one entry from
+///
+/// # Arguments
+/// * `frame` - The frame to render to
+/// * `title` - Title of the modal
+/// * `items` - Entries to choose from
+/// * `selected` - Highlighted entry
+pub fn render_picker_modal(frame: &mut Frame, title: &str, items: &[String], selected: usize) {
+    let area = centered_rect(50, 60, frame.area());
+    frame.render_widget(Clear, area);
+
+    let block = Block::default()
+        .title(title)
+        .title_alignment(Alignment::Center)
+        .title_bottom(Line::from(tr(Text::PickerHint)).centered())
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Magenta))
+        .style(Style::default().bg(Color::Black));
+
+    // Keep the highlighted entry on screen
+    let visible = (area.height as usize).saturating_sub(2);
+    let offset = (selected + 1).saturating_sub(visible);
+    let lines: Vec<Line> = items
+        .iter()
+        .enumerate()
+        .skip(offset)
+        .take(visible)
+        .map(|(i, item)| {
+            if i == selected {
+                Line::from(Span::styled(
+                    item.as_str(),
+                    Style::default()
+                        .fg(Color::Black)
+                        .bg(Color::Yellow)
+                        .add_modifier(Modifier::BOLD),
+                ))
+            } else {
+                Line::from(item.as_str())
+            }
+        })
+        .collect();
+
+    frame.render_widget(Paragraph::new(lines).block(block), area);
+}
+
+/// Render a single-line text prompt
+///
+/// # Arguments
+/// * `frame` - The frame to render to
+/// * `title` - Question shown as the title
+/// * `input` - Text typed so far
+pub fn render_prompt_modal(frame: &mut Frame, title: &str, input: &mut TextArea<'_>) {
+    let popup = centered_rect(50, 100, frame.area());
+    let area = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Fill(1),
+            Constraint::Length(3),
+            Constraint::Fill(1),
+        ])
+        .split(popup)[1];
+    frame.render_widget(Clear, area);
+
+    input.set_block(
+        Block::default()
+            .title(Line::from(title.to_string()))
+            .title_bottom(Line::from(tr(Text::PromptHint)).centered())
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Magenta)),
+    );
+    input.set_style(Style::default().fg(Color::White));
+    frame.render_widget(input.widget(), area);
+}
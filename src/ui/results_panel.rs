@@ -1,13 +1,18 @@
 /// Results panel component for displaying operation results
 use ratatui::{
-    style::{Color, Style},
+    layout::Constraint,
+    style::{Color, Modifier, Style},
     text::{Line, Span},
-    widgets::{Block, Borders, Paragraph},
+    widgets::{Block, Borders, Paragraph, Row, Table},
     Frame,
 };
 
+use crate::i18n::{tr, trf, Text};
 use crate::operations::CompareResult;
+use crate::parser::{clip_item, split_record};
+use std::borrow::Cow;
 use std::collections::HashSet;
+use unicode_width::UnicodeWidthStr;
 
 /// Render the results panel (summary view for Tab 1)
 ///
@@ -24,6 +29,11 @@ pub fn render_results_panel(
     scroll_offset: usize,
     is_active: bool,
 ) {
+    // INFO panel is hidden
+    if area.height == 0 {
+        return;
+    }
+
     let border_style = if is_active {
         Style::default().fg(Color::Yellow)
     } else {
@@ -31,14 +41,14 @@ pub fn render_results_panel(
     };
 
     let block = Block::default()
-        .title("INFO")
+        .title(tr(Text::TitleInfo))
         .borders(Borders::ALL)
         .border_style(border_style);
 
     let lines: Vec<Line> = results
         .iter()
         .skip(scroll_offset)
-        .take(area.height as usize - 2) // Account for borders
+        .take((area.height as usize).saturating_sub(2)) // Account for borders
         .map(|line| Line::from(Span::raw(line.as_str())))
         .collect();
 
@@ -49,6 +59,33 @@ pub fn render_results_panel(
     frame.render_widget(paragraph, area);
 }
 
+/// Render the one-line header describing how the displayed results were produced
+///
+/// # Arguments
+/// * `frame` - The frame to render to
+/// * `area` - The area to render in
+/// * `text` - Header text
+/// * `stale` - Whether the inputs changed since the results were computed
+pub fn render_results_header(
+    frame: &mut Frame,
+    area: ratatui::layout::Rect,
+    text: &str,
+    stale: bool,
+) {
+    let mut spans = vec![Span::styled(text, Style::default().fg(Color::Magenta))];
+    if stale {
+        spans.push(Span::raw(" "));
+        spans.push(Span::styled(
+            tr(Text::ResultsStale),
+            Style::default()
+                .fg(Color::Black)
+                .bg(Color::Red)
+                .add_modifier(Modifier::BOLD),
+        ));
+    }
+    frame.render_widget(Paragraph::new(Line::from(spans)), area);
+}
+
 /// Render a result list panel (detailed view for Tab 2)
 ///
 /// # Arguments
@@ -56,13 +93,17 @@ pub fn render_results_panel(
 /// * `area` - The area to render in
 /// * `title` - Title of the panel
 /// * `items` - Vector of items to display (one per line)
+/// * `selection` - Selected row index and its position label (e.g. `item 4 of 10`)
 /// * `is_active` - Whether this panel is currently active
+/// * `max_len` - Length at which items are clipped with an ellipsis, if any
 pub fn render_result_list_panel(
     frame: &mut Frame,
     area: ratatui::layout::Rect,
     title: &str,
     items: &[String],
+    selection: Option<(usize, &str)>,
     is_active: bool,
+    max_len: Option<usize>,
 ) {
     let border_style = if is_active {
         Style::default().fg(Color::Yellow)
@@ -70,15 +111,32 @@ pub fn render_result_list_panel(
         Style::default().fg(Color::Cyan)
     };
 
-    let block = Block::default()
-        .title(title)
-        .borders(Borders::ALL)
-        .border_style(border_style);
+    let block = selection_footer(
+        Block::default()
+            .title(title)
+            .borders(Borders::ALL)
+            .border_style(border_style),
+        selection,
+    );
 
+    let visible = (area.height as usize).saturating_sub(2); // Account for borders
+    let selected = selection.map(|(index, _)| index);
     let lines: Vec<Line> = items
         .iter()
-        .take(area.height as usize - 2) // Account for borders
-        .map(|item| Line::from(Span::raw(item.as_str())))
+        .enumerate()
+        .skip(scroll_offset(selected, visible))
+        .take(visible)
+        .map(|(i, item)| {
+            let text = match max_len {
+                Some(max_len) => clip_item(item, max_len),
+                None => Cow::Borrowed(item.as_str()),
+            };
+            if is_active && selected == Some(i) {
+                Line::from(Span::styled(text, selected_style()))
+            } else {
+                Line::from(Span::raw(text))
+            }
+        })
         .collect();
 
     let paragraph = Paragraph::new(lines)
@@ -88,6 +146,126 @@ pub fn render_result_list_panel(
     frame.render_widget(paragraph, area);
 }
 
+/// First row to draw so that the selected row stays visible
+fn scroll_offset(selected: Option<usize>, visible: usize) -> usize {
+    match selected {
+        Some(index) if visible > 0 && index >= visible => index + 1 - visible,
+        _ => 0,
+    }
+}
+
+/// Style of the selected row in the active result panel
+fn selected_style() -> Style {
+    Style::default().fg(Color::Black).bg(Color::Yellow)
+}
+
+/// Add the selected row's position label to the bottom border
+fn selection_footer<'a>(block: Block<'a>, selection: Option<(usize, &'a str)>) -> Block<'a> {
+    match selection {
+        Some((_, label)) => block.title_bottom(Line::from(format!(" {} ", label)).right_aligned()),
+        None => block,
+    }
+}
+
+/// Widest column when fitting columns to their content
+const MAX_AUTO_COLUMN_WIDTH: usize = 40;
+
+/// Render record-style items as an aligned table (detailed view for Tab 2)
+///
+/// # Arguments
+/// * `frame` - The frame to render to
+/// * `area` - The area to render in
+/// * `title` - Title of the panel
+/// * `items` - Records to display (one row each)
+/// * `separator` - Field separator inside each record
+/// * `header` - Header record labelling the columns (numbered labels without one)
+/// * `content_widths` - Widest field of each column over all items
+/// * `column_width` - Fixed column width, or 0 to fit each column to its content
+/// * `selection` - Selected row index and its position label (e.g. `item 4 of 10`)
+/// * `is_active` - Whether this panel is currently active
+#[allow(clippy::too_many_arguments)]
+pub fn render_result_table_panel(
+    frame: &mut Frame,
+    area: ratatui::layout::Rect,
+    title: &str,
+    items: &[String],
+    separator: char,
+    header: Option<&str>,
+    content_widths: &[usize],
+    column_width: u16,
+    selection: Option<(usize, &str)>,
+    is_active: bool,
+) {
+    let border_style = if is_active {
+        Style::default().fg(Color::Yellow)
+    } else {
+        Style::default().fg(Color::Cyan)
+    };
+
+    let block = selection_footer(
+        Block::default()
+            .title(title)
+            .borders(Borders::ALL)
+            .border_style(border_style),
+        selection,
+    );
+
+    let visible = (area.height as usize).saturating_sub(3); // Borders and header
+    let selected = selection.map(|(index, _)| index);
+    let offset = scroll_offset(selected, visible);
+    let records: Vec<Vec<&str>> = items
+        .iter()
+        .skip(offset)
+        .take(visible)
+        .map(|item| split_record(item, separator))
+        .collect();
+    let header_fields = header.map_or_else(Vec::new, |header| split_record(header, separator));
+    let columns = content_widths.len().max(header_fields.len());
+    let headers: Vec<String> = (0..columns)
+        .map(|col| match header_fields.get(col) {
+            Some(field) => field.to_string(),
+            None => trf(Text::ColumnHeader, &[&(col + 1)]),
+        })
+        .collect();
+
+    let widths: Vec<Constraint> = headers
+        .iter()
+        .enumerate()
+        .map(|(col, label)| {
+            if column_width > 0 {
+                return Constraint::Length(column_width);
+            }
+            let content = content_widths
+                .get(col)
+                .copied()
+                .unwrap_or(0)
+                .max(label.width());
+            Constraint::Length(content.min(MAX_AUTO_COLUMN_WIDTH) as u16)
+        })
+        .collect();
+
+    let header = Row::new(headers).style(
+        Style::default()
+            .fg(Color::Yellow)
+            .add_modifier(Modifier::BOLD),
+    );
+    let rows = records.into_iter().enumerate().map(|(i, fields)| {
+        let row = Row::new(fields);
+        if is_active && selected == Some(offset + i) {
+            row.style(selected_style())
+        } else {
+            row
+        }
+    });
+
+    let table = Table::new(rows, widths)
+        .header(header)
+        .column_spacing(2)
+        .block(block);
+
+    frame.render_widget(table, area);
+}
+
 /// Render a unified diff view of the comparison results
 pub fn render_unified_diff_panel(
     frame: &mut Frame,
@@ -95,7 +273,7 @@ pub fn render_unified_diff_panel(
     results: &CompareResult,
 ) {
     let block = Block::default()
-        .title(" Unified Diff (- L1, + L2) ")
+        .title(tr(Text::TitleUnifiedDiff))
         .borders(Borders::ALL)
         .border_style(Style::default().fg(Color::Yellow));
 
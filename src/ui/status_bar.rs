@@ -7,6 +7,7 @@ use ratatui::{
 };
 
 use crate::app::Mode;
+use crate::i18n::{tr, trf, Text};
 use crate::parser::Delimiter;
 
 /// Render the status bar at the bottom
@@ -15,14 +16,27 @@ use crate::parser::Delimiter;
 /// * `frame` - The frame to render to
 /// * `area` - The area to render in
 /// * `delimiter` - Current delimiter
+/// * `detected` - Whether the shown (source) delimiter was detected from pasted or loaded text
+/// * `suggested` - Delimiter offered for `a` instead of the shown one
 /// * `active_tab` - Current tab index
+/// * `usage_info` - Optional item count / memory usage segment
+/// * `undo_info` - Undo slots used, while there is something to undo or redo
+/// * `watch_info` - Watch mode segment, highlighted while a change is unseen
+/// * `task_info` - Name, percent done and elapsed time of a background task
+#[allow(clippy::too_many_arguments)]
 pub fn render_status_bar(
     frame: &mut Frame,
     area: ratatui::layout::Rect,
     main_delimiter: Delimiter,
     convert_delimiters: Option<(Delimiter, Delimiter)>,
+    detected: bool,
+    suggested: Option<Delimiter>,
     active_tab: usize,
     active_panel_info: Option<&str>,
+    usage_info: Option<&str>,
+    undo_info: Option<&str>,
+    watch_info: Option<(&str, bool)>,
+    task_info: Option<&str>,
     mode: Mode,
 ) {
     let copy_label = if cfg!(target_os = "macos") {
@@ -30,21 +44,31 @@ pub fn render_status_bar(
     } else {
         "Ctrl+C/V"
     };
-    let shortcuts = format!("Alt+1/2/3: Tabs | Tab: Next | {}", copy_label);
+    let shortcuts = trf(Text::StatusShortcuts, &[&copy_label]);
 
-    let delim_info = if active_tab == 2 {
+    let mut delim_info = if active_tab == 2 {
         if let Some((src, dst)) = convert_delimiters {
-            format!("Src: {} | Dst: {}", src.display_name(), dst.display_name())
+            trf(
+                Text::StatusConvertDelims,
+                &[&src.display_name(), &dst.display_name()],
+            )
         } else {
-            "Converter".to_string()
+            tr(Text::StatusConverter).to_string()
         }
     } else {
-        format!("Delim: {}", main_delimiter.display_name())
+        trf(Text::StatusDelim, &[&main_delimiter.display_name()])
     };
+    if detected {
+        delim_info = format!("{} {}", delim_info, tr(Text::StatusDetected));
+    }
+    if let Some(suggested) = suggested {
+        let offer = trf(Text::StatusSuggested, &[&suggested.display_name()]);
+        delim_info = format!("{} {}", delim_info, offer);
+    }
 
     let mode_label = match mode {
-        Mode::Normal => (" NORMAL ", Color::Cyan),
-        Mode::Insert => (" INSERT ", Color::Green),
+        Mode::Normal => (tr(Text::ModeNormal), Color::Cyan),
+        Mode::Insert => (tr(Text::ModeInsert), Color::Green),
     };
 
     let mut spans = vec![
@@ -53,18 +77,47 @@ pub fn render_status_bar(
             Style::default().fg(Color::Black).bg(mode_label.1),
         ),
         Span::raw(" "),
+    ];
+    // Right after the mode so it stays visible on narrow terminals
+    if let Some((watch, unseen)) = watch_info {
+        let style = if unseen {
+            Style::default().fg(Color::White).bg(Color::Red)
+        } else {
+            Style::default().fg(Color::Cyan)
+        };
+        spans.push(Span::styled(watch, style));
+        spans.push(Span::raw(" | "));
+    }
+    if let Some(task) = task_info {
+        spans.push(Span::styled(
+            task,
+            Style::default().fg(Color::Black).bg(Color::Yellow),
+        ));
+        spans.push(Span::raw(" | "));
+    }
+    spans.extend([
         Span::styled(shortcuts, Style::default().fg(Color::White)),
         Span::raw(" | "),
         Span::styled(delim_info, Style::default().fg(Color::Yellow)),
         Span::raw(" | "),
-        Span::styled("?: Help | Esc", Style::default().fg(Color::White)),
-    ];
+        Span::styled(tr(Text::StatusHelp), Style::default().fg(Color::White)),
+    ]);
 
     if let Some(info) = active_panel_info {
         spans.push(Span::raw(" | "));
         spans.push(Span::styled(info, Style::default().fg(Color::Green)));
     }
 
+    if let Some(undo) = undo_info {
+        spans.push(Span::raw(" | "));
+        spans.push(Span::styled(undo, Style::default().fg(Color::Cyan)));
+    }
+
+    if let Some(usage) = usage_info {
+        spans.push(Span::raw(" | "));
+        spans.push(Span::styled(usage, Style::default().fg(Color::Magenta)));
+    }
+
     let line = Line::from(spans);
 
     let paragraph = Paragraph::new(line).style(Style::default().bg(Color::DarkGray));
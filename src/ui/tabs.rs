@@ -1,21 +1,72 @@
 /// Tabs component for navigation between Input and Results views
+use crate::i18n::{tr, Text};
 use ratatui::{
     style::{Color, Style},
+    text::{Line, Span},
     widgets::{Block, Borders, Tabs},
     Frame,
 };
 
+/// Per-tab metadata shown next to the tab title
+#[derive(Debug, Clone, Default)]
+pub struct TabMeta {
+    /// Short badge such as a count (e.g. `12Δ`)
+    pub badge: Option<String>,
+    /// Highlight the badge as a warning (e.g. stale results)
+    pub warning: bool,
+}
+
 /// Render the tabs bar
 ///
 /// # Arguments
 /// * `frame` - The frame to render to
 /// * `area` - The area to render in
-/// * `active_tab` - Currently active tab index (0 = Input, 1 = Results)
-pub fn render_tabs(frame: &mut Frame, area: ratatui::layout::Rect, active_tab: usize) {
-    let titles = vec![" Input ", " Results ", " Convert "];
+/// * `active_tab` - Currently active tab index (0 = Input, 1 = Results, 2 = Convert, 3 = List, 4 = Analysis, 5 = Merge)
+/// * `meta` - Badges for each tab, in tab order (missing entries show no badge)
+/// * `workspace` - Label of the active workspace, shown on the right of the border
+pub fn render_tabs(
+    frame: &mut Frame,
+    area: ratatui::layout::Rect,
+    active_tab: usize,
+    meta: &[TabMeta],
+    workspace: &str,
+) {
+    let names = [
+        tr(Text::TabInput),
+        tr(Text::TabResults),
+        tr(Text::TabConvert),
+        tr(Text::TabList),
+        tr(Text::TabAnalysis),
+        tr(Text::TabMerge),
+    ];
+
+    let titles: Vec<Line> = names
+        .iter()
+        .enumerate()
+        .map(|(i, name)| {
+            let mut spans = vec![Span::raw(*name)];
+            if let Some(TabMeta {
+                badge: Some(badge),
+                warning,
+            }) = meta.get(i)
+            {
+                let style = if *warning {
+                    Style::default().fg(Color::Red)
+                } else {
+                    Style::default().fg(Color::Cyan)
+                };
+                spans.push(Span::styled(format!(" ({})", badge), style));
+            }
+            Line::from(spans)
+        })
+        .collect();
 
     let tabs = Tabs::new(titles)
-        .block(Block::default().borders(Borders::ALL))
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title_top(Line::from(workspace).right_aligned()),
+        )
         .select(active_tab)
         .style(Style::default().fg(Color::Gray))
         .highlight_style(
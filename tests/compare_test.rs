@@ -22,6 +22,7 @@ fn test_compare_case_insensitive() {
     let options = CompareOptions {
         case_sensitive: false,
         trim_spaces: false,
+        ..CompareOptions::default()
     };
     let result = compare_lists(&list1, &list2, options);
 
@@ -37,6 +38,7 @@ fn test_compare_case_sensitive() {
     let options = CompareOptions {
         case_sensitive: true,
         trim_spaces: false,
+        ..CompareOptions::default()
     };
     let result = compare_lists(&list1, &list2, options);
 
@@ -52,6 +54,7 @@ fn test_compare_trim_spaces() {
     let options = CompareOptions {
         case_sensitive: false,
         trim_spaces: true,
+        ..CompareOptions::default()
     };
     let result = compare_lists(&list1, &list2, options);
 
@@ -0,0 +1,166 @@
+/// Property-based tests for the parser: arbitrary input never panics and
+/// parsing round-trips
+use list_utils::parser::{
+    parse_items_with, parse_json_to_list, parse_list, parse_list_with, quote_csv_field,
+    repair_json, Delimiter, EmptyItems, JsonArrayStrategy, NestedJson,
+};
+use proptest::prelude::*;
+
+const DELIMITERS: [Delimiter; 4] = [
+    Delimiter::Newline,
+    Delimiter::Comma,
+    Delimiter::Tab,
+    Delimiter::Semicolon,
+];
+
+const STRATEGIES: [JsonArrayStrategy; 3] = [
+    JsonArrayStrategy::Union,
+    JsonArrayStrategy::ObjectsOnly,
+    JsonArrayStrategy::Stringify,
+];
+
+fn delimiter() -> impl Strategy<Value = Delimiter> {
+    prop::sample::select(DELIMITERS.to_vec())
+}
+
+fn empty_items() -> impl Strategy<Value = EmptyItems> {
+    prop::sample::select(vec![EmptyItems::Keep, EmptyItems::Collapse])
+}
+
+/// Items free of every delimiter and line break, the last one non-empty
+fn plain_items() -> impl Strategy<Value = Vec<String>> {
+    (prop::collection::vec("[^,;\t\r\n]*", 0..8), "[^,;\t\r\n]+").prop_map(|(mut items, last)| {
+        items.push(last);
+        items
+    })
+}
+
+/// Lax JSON fragments mixed with arbitrary text
+fn json_like() -> impl Strategy<Value = String> {
+    prop::collection::vec(
+        prop_oneof![
+            Just("{".to_string()),
+            Just("}".to_string()),
+            Just("[".to_string()),
+            Just("]".to_string()),
+            Just(",".to_string()),
+            Just(":".to_string()),
+            Just("\"".to_string()),
+            "[a-z_][a-z0-9_]{0,4}",
+            "-?[0-9]{1,3}",
+            "\\PC{0,3}",
+        ],
+        0..24,
+    )
+    .prop_map(|parts| parts.concat())
+}
+
+proptest! {
+    #[test]
+    fn parse_list_never_panics(input in "\\PC*", delimiter in delimiter(), empty in empty_items()) {
+        let _ = parse_list_with(&input, delimiter, empty);
+    }
+
+    #[test]
+    fn parse_list_round_trips(items in plain_items(), delimiter in delimiter()) {
+        let input = items.join(&delimiter.as_char().to_string());
+        prop_assert_eq!(parse_list(&input, delimiter), items);
+    }
+
+    #[test]
+    fn quoted_csv_round_trips(
+        mut items in prop::collection::vec("[^\r\n]*", 0..8),
+        last in "[^\r\n]+",
+        delimiter in prop::sample::select(vec![Delimiter::CsvComma, Delimiter::CsvSemicolon]),
+    ) {
+        items.push(last);
+        let input = items
+            .iter()
+            .map(|item| quote_csv_field(item, delimiter.as_char()))
+            .collect::<Vec<_>>()
+            .join(delimiter.as_str());
+        prop_assert_eq!(parse_list(&input, delimiter), items);
+    }
+
+    #[test]
+    fn parse_items_matches_parse_list(
+        input in "[a-c,;\t\r\n ]*",
+        delimiter in delimiter(),
+        empty in empty_items(),
+    ) {
+        let borrowed: Vec<String> = parse_items_with(&input, delimiter, empty)
+            .into_iter()
+            .map(|item| item.into_owned())
+            .collect();
+        prop_assert_eq!(borrowed, parse_list_with(&input, delimiter, empty));
+    }
+
+    #[test]
+    fn collapsing_drops_exactly_the_empty_items(input in "[a-c,;\t\r\n]*", delimiter in delimiter()) {
+        let kept: Vec<String> = parse_list(&input, delimiter)
+            .into_iter()
+            .filter(|item| !item.is_empty())
+            .collect();
+        prop_assert_eq!(parse_list_with(&input, delimiter, EmptyItems::Collapse), kept);
+    }
+
+    #[test]
+    fn parsed_items_hold_no_line_breaks(input in "[a-c\r\n]*") {
+        for item in parse_list(&input, Delimiter::Newline) {
+            prop_assert!(!item.contains(['\r', '\n']));
+        }
+    }
+
+    #[test]
+    fn repair_json_never_panics_and_is_idempotent(input in json_like()) {
+        let repaired = repair_json(&input);
+        prop_assert_eq!(repair_json(&repaired), repaired);
+    }
+
+    #[test]
+    fn repair_json_leaves_valid_json_alone(values in prop::collection::vec("\\PC*", 0..6)) {
+        let json = serde_json::to_string(&values).unwrap();
+        prop_assert_eq!(repair_json(&json), json);
+    }
+
+    #[test]
+    fn parse_json_to_list_never_panics(
+        input in json_like(),
+        quote in any::<bool>(),
+        strategy in prop::sample::select(STRATEGIES.to_vec()),
+    ) {
+        let _ = parse_json_to_list(&input, ',', quote, strategy, NestedJson::Raw);
+    }
+
+    #[test]
+    fn json_string_arrays_round_trip(values in prop::collection::vec("\\PC*", 1..8)) {
+        let json = serde_json::to_string(&values).unwrap();
+        let (items, repaired) =
+            parse_json_to_list(&json, ',', false, JsonArrayStrategy::Union, NestedJson::Raw).unwrap();
+        prop_assert_eq!(items, values);
+        prop_assert_eq!(repaired, json);
+    }
+
+    #[test]
+    fn json_objects_give_a_header_and_one_row_each(
+        records in prop::collection::vec(
+            prop::collection::btree_map("[a-z]{1,3}", "[a-z0-9 ]*", 1..4),
+            1..6,
+        ),
+    ) {
+        let json = serde_json::to_string(&records).unwrap();
+        let (lines, _) = parse_json_to_list(&json, ';', false, JsonArrayStrategy::Union, NestedJson::Raw).unwrap();
+        prop_assert_eq!(lines.len(), records.len() + 1);
+        let columns = lines[0].split(';').count();
+        for line in &lines[1..] {
+            prop_assert_eq!(line.split(';').count(), columns);
+        }
+    }
+}
+
+#[test]
+fn test_repair_keeps_lax_keys_inside_strings() {
+    let json = r#"["{a:1}", "x, b: 2"]"#;
+    assert_eq!(repair_json(json), json);
+    assert_eq!(repair_json("{a: 1, b: [2]}"), r#"{"a": 1, "b": [2]}"#);
+}